@@ -20,6 +20,12 @@ pub enum ConfigError {
     #[error("Metrics configuration error")]
     MetricsError(#[from] crate::metrics::MetricsError),
 
+    #[error("Fee configuration error")]
+    FeeError(#[from] crate::fee::FeeError),
+
     #[error("Invalid multi-chain URL JSON")]
     InvalidMultiChainJson(#[from] serde_json::Error),
+
+    #[error("Invalid fallback URL JSON")]
+    InvalidFallbackUrlsJson(#[source] serde_json::Error),
 }
@@ -21,8 +21,39 @@ pub enum ExpressError {
     #[error("Keep-alive timeout cannot be 0")]
     KeepAliveTimeoutZero,
 
+    #[error("Request timeout cannot be 0")]
+    RequestTimeoutZero,
+
     #[error("Block fetch concurrency cannot be 0")]
     BlockFetchConcurrencyZero,
+
+    #[error("Asset fetch concurrency cannot be 0")]
+    AssetFetchConcurrencyZero,
+
+    #[error("Fee fetch concurrency cannot be 0")]
+    FeeFetchConcurrencyZero,
+
+    #[error("Max block subscriptions cannot be 0")]
+    MaxBlockSubscriptionsZero,
+
+    #[error(
+        "Invalid CORS origin '{0}'. Must be '*' or an origin like 'https://example.com' without a path"
+    )]
+    InvalidCorsOrigin(String),
+
+    #[error("CORS origin '*' cannot be combined with explicit origins")]
+    CorsWildcardWithOrigins,
+}
+
+/// Which cross-origin requests the HTTP server allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CorsOrigins {
+    /// No CORS headers are sent, so browsers block cross-origin requests
+    Disabled,
+    /// Any origin is allowed
+    Any,
+    /// Only the listed origins are allowed
+    List(Vec<String>),
 }
 
 // "Express" naming is an artifact of substrate-api-sidecar that is
@@ -53,11 +84,42 @@ pub struct ExpressConfig {
     /// Default: 5000
     pub keep_alive_timeout: u64,
 
+    /// Maximum time in milliseconds to produce a response before replying 504
+    ///
+    /// Env: SAS_EXPRESS_REQUEST_TIMEOUT
+    /// Default: 60000
+    pub request_timeout: u64,
+
     /// Maximum number of concurrent block fetches when querying block ranges
     ///
     /// Env: SAS_EXPRESS_BLOCK_FETCH_CONCURRENCY
     /// Default: 10
     pub block_fetch_concurrency: usize,
+
+    /// Maximum number of concurrent per-asset storage queries when fetching account asset balances
+    ///
+    /// Env: SAS_EXPRESS_ASSET_FETCH_CONCURRENCY
+    /// Default: 8
+    pub asset_fetch_concurrency: usize,
+
+    /// Maximum number of concurrent per-extrinsic fee queries when building a block
+    ///
+    /// Env: SAS_EXPRESS_FEE_FETCH_CONCURRENCY
+    /// Default: 8
+    pub fee_fetch_concurrency: usize,
+
+    /// Maximum number of concurrent finalized block subscriptions (`/ws/blocks` and `/sse/blocks` clients)
+    ///
+    /// Env: SAS_EXPRESS_MAX_BLOCK_SUBSCRIPTIONS
+    /// Default: 100
+    pub max_block_subscriptions: usize,
+
+    /// Origins allowed to make cross-origin requests: `*` for any origin, a
+    /// comma-separated list of origins, or empty to disable CORS
+    ///
+    /// Env: SAS_EXPRESS_CORS_ORIGINS
+    /// Default: * (matches the previously hardcoded permissive CORS)
+    pub cors_origins: String,
 }
 
 fn default_bind_host() -> String {
@@ -76,11 +138,62 @@ fn default_keep_alive_timeout() -> u64 {
     5000 // 5 seconds in milliseconds
 }
 
+fn default_request_timeout() -> u64 {
+    60_000 // 60 seconds in milliseconds
+}
+
 fn default_block_fetch_concurrency() -> usize {
     10
 }
 
+fn default_asset_fetch_concurrency() -> usize {
+    8
+}
+
+fn default_fee_fetch_concurrency() -> usize {
+    8
+}
+
+fn default_max_block_subscriptions() -> usize {
+    100
+}
+
+fn default_cors_origins() -> String {
+    "*".to_string()
+}
+
+/// An origin is `scheme://host[:port]`, with no path, query or whitespace.
+fn is_valid_origin(origin: &str) -> bool {
+    let Some(authority) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+
+    !authority.is_empty()
+        && !authority.contains(['/', '?', '#'])
+        && !authority.chars().any(char::is_whitespace)
+}
+
 impl ExpressConfig {
+    /// Parse `cors_origins`. Call [`ExpressConfig::validate`] first to reject bad origins.
+    pub fn cors_origins(&self) -> CorsOrigins {
+        let origins: Vec<String> = self
+            .cors_origins
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        match origins.as_slice() {
+            [] => CorsOrigins::Disabled,
+            [origin] if origin == "*" => CorsOrigins::Any,
+            _ => CorsOrigins::List(origins),
+        }
+    }
+
     pub(crate) fn validate(&self) -> Result<(), ExpressError> {
         // Validate port
         if self.port == 0 {
@@ -105,11 +218,43 @@ impl ExpressConfig {
             return Err(ExpressError::KeepAliveTimeoutZero);
         }
 
+        // Validate request_timeout is not zero
+        if self.request_timeout == 0 {
+            return Err(ExpressError::RequestTimeoutZero);
+        }
+
         // Validate block_fetch_concurrency is at least 1
         if self.block_fetch_concurrency == 0 {
             return Err(ExpressError::BlockFetchConcurrencyZero);
         }
 
+        // Validate asset_fetch_concurrency is at least 1
+        if self.asset_fetch_concurrency == 0 {
+            return Err(ExpressError::AssetFetchConcurrencyZero);
+        }
+
+        // Validate fee_fetch_concurrency is at least 1
+        if self.fee_fetch_concurrency == 0 {
+            return Err(ExpressError::FeeFetchConcurrencyZero);
+        }
+
+        // Validate max_block_subscriptions is at least 1
+        if self.max_block_subscriptions == 0 {
+            return Err(ExpressError::MaxBlockSubscriptionsZero);
+        }
+
+        // Validate CORS origins: either `*` alone or well-formed origins
+        if let CorsOrigins::List(origins) = self.cors_origins() {
+            for origin in &origins {
+                if origin == "*" {
+                    return Err(ExpressError::CorsWildcardWithOrigins);
+                }
+                if !is_valid_origin(origin) {
+                    return Err(ExpressError::InvalidCorsOrigin(origin.clone()));
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -121,7 +266,12 @@ impl Default for ExpressConfig {
             port: default_port(),
             request_limit: default_request_limit(),
             keep_alive_timeout: default_keep_alive_timeout(),
+            request_timeout: default_request_timeout(),
             block_fetch_concurrency: default_block_fetch_concurrency(),
+            asset_fetch_concurrency: default_asset_fetch_concurrency(),
+            fee_fetch_concurrency: default_fee_fetch_concurrency(),
+            max_block_subscriptions: default_max_block_subscriptions(),
+            cors_origins: default_cors_origins(),
         }
     }
 }
@@ -235,4 +385,101 @@ mod tests {
         };
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_validate_request_timeout_zero() {
+        let config = ExpressConfig {
+            request_timeout: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ExpressError::RequestTimeoutZero)
+        ));
+    }
+
+    #[test]
+    fn test_validate_asset_fetch_concurrency_zero() {
+        let config = ExpressConfig {
+            asset_fetch_concurrency: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ExpressError::AssetFetchConcurrencyZero)
+        ));
+    }
+
+    #[test]
+    fn test_validate_fee_fetch_concurrency_zero() {
+        let config = ExpressConfig {
+            fee_fetch_concurrency: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ExpressError::FeeFetchConcurrencyZero)
+        ));
+    }
+
+    #[test]
+    fn test_validate_max_block_subscriptions_zero() {
+        let config = ExpressConfig {
+            max_block_subscriptions: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ExpressError::MaxBlockSubscriptionsZero)
+        ));
+    }
+
+    #[test]
+    fn test_cors_origins_parsing() {
+        let with = |origins: &str| ExpressConfig {
+            cors_origins: origins.to_string(),
+            ..Default::default()
+        };
+
+        assert_eq!(ExpressConfig::default().cors_origins(), CorsOrigins::Any);
+        assert_eq!(with("").cors_origins(), CorsOrigins::Disabled);
+        assert_eq!(
+            with("https://a.example, http://localhost:3000").cors_origins(),
+            CorsOrigins::List(vec![
+                "https://a.example".to_string(),
+                "http://localhost:3000".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_cors_origins() {
+        let with = |origins: &str| ExpressConfig {
+            cors_origins: origins.to_string(),
+            ..Default::default()
+        };
+
+        assert!(with("*").validate().is_ok());
+        assert!(with("").validate().is_ok());
+        assert!(
+            with("https://a.example,http://[::1]:8080")
+                .validate()
+                .is_ok()
+        );
+        assert!(matches!(
+            with("*,https://a.example").validate(),
+            Err(ExpressError::CorsWildcardWithOrigins)
+        ));
+        for bad in [
+            "a.example",
+            "https://a.example/path",
+            "ftp://a.example",
+            "https://",
+        ] {
+            assert!(matches!(
+                with(bad).validate(),
+                Err(ExpressError::InvalidCorsOrigin(_))
+            ));
+        }
+    }
 }
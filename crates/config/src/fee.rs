@@ -0,0 +1,60 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FeeError {
+    #[error("Fee cache size cannot be 0")]
+    CacheSizeZero,
+}
+
+/// Configuration for fee calculation
+#[derive(Debug, Clone)]
+pub struct FeeConfig {
+    /// Maximum number of entries in the `queryFeeDetails` availability cache
+    ///
+    /// Env: SAS_FEE_CACHE_SIZE
+    /// Default: 1024
+    pub cache_size: usize,
+}
+
+fn default_cache_size() -> usize {
+    1024
+}
+
+impl FeeConfig {
+    pub(crate) fn validate(&self) -> Result<(), FeeError> {
+        if self.cache_size == 0 {
+            return Err(FeeError::CacheSizeZero);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            cache_size: default_cache_size(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_fee_config() {
+        let config = FeeConfig::default();
+        assert_eq!(config.cache_size, 1024);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_cache_size_zero() {
+        let config = FeeConfig { cache_size: 0 };
+        assert!(matches!(config.validate(), Err(FeeError::CacheSizeZero)));
+    }
+}
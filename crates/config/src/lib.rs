@@ -5,6 +5,7 @@ mod args;
 mod chain;
 mod error;
 mod express;
+mod fee;
 mod log;
 mod metrics;
 mod spec_versions;
@@ -15,7 +16,8 @@ pub use chain::{
     QueryFeeDetailsStatus as ChainQueryFeeDetailsStatus,
 };
 pub use error::ConfigError;
-pub use express::{ExpressConfig, ExpressError};
+pub use express::{CorsOrigins, ExpressConfig, ExpressError};
+pub use fee::{FeeConfig, FeeError};
 pub use log::{LogConfig, LogError};
 pub use metrics::{MetricsConfig, MetricsError};
 pub use spec_versions::SpecVersionChanges;
@@ -77,9 +79,24 @@ struct EnvConfig {
     #[serde(default = "default_express_keep_alive_timeout")]
     express_keep_alive_timeout: u64,
 
+    #[serde(default = "default_express_request_timeout")]
+    express_request_timeout: u64,
+
     #[serde(default = "default_express_block_fetch_concurrency")]
     express_block_fetch_concurrency: usize,
 
+    #[serde(default = "default_express_asset_fetch_concurrency")]
+    express_asset_fetch_concurrency: usize,
+
+    #[serde(default = "default_express_fee_fetch_concurrency")]
+    express_fee_fetch_concurrency: usize,
+
+    #[serde(default = "default_express_max_block_subscriptions")]
+    express_max_block_subscriptions: usize,
+
+    #[serde(default = "default_express_cors_origins")]
+    express_cors_origins: String,
+
     #[serde(default = "default_log_level")]
     log_level: String,
 
@@ -107,14 +124,32 @@ struct EnvConfig {
     #[serde(default = "default_substrate_multi_chain_url")]
     substrate_multi_chain_url: String,
 
+    #[serde(default = "default_substrate_fallback_urls")]
+    substrate_fallback_urls: String,
+
     #[serde(default = "default_substrate_reconnect_initial_delay_ms")]
     substrate_reconnect_initial_delay_ms: u64,
 
     #[serde(default = "default_substrate_reconnect_max_delay_ms")]
     substrate_reconnect_max_delay_ms: u64,
 
-    #[serde(default = "default_substrate_reconnect_request_timeout_ms")]
-    substrate_reconnect_request_timeout_ms: u64,
+    #[serde(default = "default_substrate_reconnect_max_retries")]
+    substrate_reconnect_max_retries: u32,
+
+    #[serde(
+        default = "default_substrate_rpc_timeout_ms",
+        alias = "substrate_reconnect_request_timeout_ms"
+    )]
+    substrate_rpc_timeout_ms: u64,
+
+    #[serde(default = "default_substrate_max_connections")]
+    substrate_max_connections: u32,
+
+    #[serde(default)]
+    substrate_token_symbol: Option<String>,
+
+    #[serde(default)]
+    substrate_token_decimals: Option<u8>,
 
     #[serde(default = "default_metrics_enabled")]
     metrics_enabled: bool,
@@ -136,6 +171,12 @@ struct EnvConfig {
 
     #[serde(default = "default_metrics_include_queryparams")]
     metrics_include_queryparams: bool,
+
+    #[serde(default = "default_metrics_cache_stats")]
+    metrics_cache_stats: bool,
+
+    #[serde(default = "default_fee_cache_size")]
+    fee_cache_size: usize,
 }
 
 fn default_express_bind_host() -> String {
@@ -154,10 +195,30 @@ fn default_express_keep_alive_timeout() -> u64 {
     5000 // 5 seconds in milliseconds
 }
 
+fn default_express_request_timeout() -> u64 {
+    60_000
+}
+
 fn default_express_block_fetch_concurrency() -> usize {
     10
 }
 
+fn default_express_asset_fetch_concurrency() -> usize {
+    8
+}
+
+fn default_express_fee_fetch_concurrency() -> usize {
+    8
+}
+
+fn default_express_max_block_subscriptions() -> usize {
+    100
+}
+
+fn default_express_cors_origins() -> String {
+    "*".to_string()
+}
+
 fn default_log_level() -> String {
     "info".to_string()
 }
@@ -194,6 +255,10 @@ fn default_substrate_multi_chain_url() -> String {
     String::new()
 }
 
+fn default_substrate_fallback_urls() -> String {
+    String::new()
+}
+
 fn default_substrate_reconnect_initial_delay_ms() -> u64 {
     100
 }
@@ -202,10 +267,18 @@ fn default_substrate_reconnect_max_delay_ms() -> u64 {
     10000
 }
 
-fn default_substrate_reconnect_request_timeout_ms() -> u64 {
+fn default_substrate_reconnect_max_retries() -> u32 {
+    0
+}
+
+fn default_substrate_rpc_timeout_ms() -> u64 {
     30000
 }
 
+fn default_substrate_max_connections() -> u32 {
+    1024
+}
+
 fn default_metrics_enabled() -> bool {
     false
 }
@@ -234,6 +307,14 @@ fn default_metrics_include_queryparams() -> bool {
     false
 }
 
+fn default_metrics_cache_stats() -> bool {
+    false
+}
+
+fn default_fee_cache_size() -> usize {
+    1024
+}
+
 /// Main configuration struct
 #[derive(Debug, Clone, Default)]
 pub struct SidecarConfig {
@@ -241,6 +322,7 @@ pub struct SidecarConfig {
     pub log: LogConfig,
     pub substrate: SubstrateConfig,
     pub metrics: MetricsConfig,
+    pub fee: FeeConfig,
 }
 
 impl SidecarConfig {
@@ -251,6 +333,8 @@ impl SidecarConfig {
     /// - SAS_EXPRESS_PORT
     /// - SAS_EXPRESS_REQUEST_LIMIT
     /// - SAS_EXPRESS_KEEP_ALIVE_TIMEOUT
+    /// - SAS_EXPRESS_REQUEST_TIMEOUT
+    /// - SAS_EXPRESS_CORS_ORIGINS
     /// - SAS_LOG_LEVEL
     /// - SAS_LOG_JSON
     /// - SAS_LOG_STRIP_ANSI
@@ -260,9 +344,14 @@ impl SidecarConfig {
     /// - SAS_LOG_WRITE_MAX_FILES
     /// - SAS_SUBSTRATE_URL
     /// - SAS_SUBSTRATE_MULTI_CHAIN_URL
+    /// - SAS_SUBSTRATE_FALLBACK_URLS (default: none)
     /// - SAS_SUBSTRATE_RECONNECT_INITIAL_DELAY_MS
     /// - SAS_SUBSTRATE_RECONNECT_MAX_DELAY_MS
-    /// - SAS_SUBSTRATE_RECONNECT_REQUEST_TIMEOUT_MS
+    /// - SAS_SUBSTRATE_RECONNECT_MAX_RETRIES (default: 0, no limit)
+    /// - SAS_SUBSTRATE_RPC_TIMEOUT_MS (default: 30000; formerly SAS_SUBSTRATE_RECONNECT_REQUEST_TIMEOUT_MS)
+    /// - SAS_SUBSTRATE_MAX_CONNECTIONS (default: 1024)
+    /// - SAS_SUBSTRATE_TOKEN_SYMBOL (default: none)
+    /// - SAS_SUBSTRATE_TOKEN_DECIMALS (default: none)
     /// - SAS_METRICS_ENABLED
     /// - SAS_METRICS_PROM_HOST
     /// - SAS_METRICS_PROM_PORT
@@ -270,6 +359,8 @@ impl SidecarConfig {
     /// - SAS_METRICS_LOKI_HOST
     /// - SAS_METRICS_LOKI_PORT
     /// - SAS_METRICS_INCLUDE_QUERYPARAMS
+    /// - SAS_METRICS_CACHE_STATS
+    /// - SAS_FEE_CACHE_SIZE
     pub fn from_env() -> Result<Self, ConfigError> {
         // Get the path to the env file from the command line argument
         let env_file = args::Args::parse_args().env_file;
@@ -290,6 +381,14 @@ impl SidecarConfig {
             serde_json::from_str(&env_config.substrate_multi_chain_url)?
         };
 
+        // Parse fallback URLs from JSON
+        let fallback_urls = if env_config.substrate_fallback_urls.is_empty() {
+            vec![]
+        } else {
+            serde_json::from_str(&env_config.substrate_fallback_urls)
+                .map_err(ConfigError::InvalidFallbackUrlsJson)?
+        };
+
         // Map to nested structure
         let config = Self {
             express: ExpressConfig {
@@ -297,7 +396,12 @@ impl SidecarConfig {
                 port: env_config.express_port,
                 request_limit: env_config.express_request_limit,
                 keep_alive_timeout: env_config.express_keep_alive_timeout,
+                request_timeout: env_config.express_request_timeout,
                 block_fetch_concurrency: env_config.express_block_fetch_concurrency,
+                asset_fetch_concurrency: env_config.express_asset_fetch_concurrency,
+                fee_fetch_concurrency: env_config.express_fee_fetch_concurrency,
+                max_block_subscriptions: env_config.express_max_block_subscriptions,
+                cors_origins: env_config.express_cors_origins,
             },
             log: LogConfig {
                 level: env_config.log_level,
@@ -311,9 +415,14 @@ impl SidecarConfig {
             substrate: SubstrateConfig {
                 url: env_config.substrate_url,
                 multi_chain_urls,
+                fallback_urls,
                 reconnect_initial_delay_ms: env_config.substrate_reconnect_initial_delay_ms,
                 reconnect_max_delay_ms: env_config.substrate_reconnect_max_delay_ms,
-                reconnect_request_timeout_ms: env_config.substrate_reconnect_request_timeout_ms,
+                reconnect_max_retries: env_config.substrate_reconnect_max_retries,
+                rpc_timeout_ms: env_config.substrate_rpc_timeout_ms,
+                max_connections: env_config.substrate_max_connections,
+                token_symbol: env_config.substrate_token_symbol,
+                token_decimals: env_config.substrate_token_decimals,
             },
             metrics: MetricsConfig {
                 enabled: env_config.metrics_enabled,
@@ -323,6 +432,10 @@ impl SidecarConfig {
                 loki_host: env_config.metrics_loki_host,
                 loki_port: env_config.metrics_loki_port,
                 include_queryparams: env_config.metrics_include_queryparams,
+                cache_stats: env_config.metrics_cache_stats,
+            },
+            fee: FeeConfig {
+                cache_size: env_config.fee_cache_size,
             },
         };
 
@@ -336,6 +449,7 @@ impl SidecarConfig {
         self.log.validate()?;
         self.substrate.validate()?;
         self.metrics.validate()?;
+        self.fee.validate()?;
         Ok(())
     }
 }
@@ -404,6 +518,37 @@ mod tests {
         cleanup_sas_env_vars();
     }
 
+    #[test]
+    #[serial]
+    fn test_from_env_fallback_urls() {
+        cleanup_sas_env_vars();
+
+        unsafe {
+            std::env::set_var("SAS_SUBSTRATE_URL", "ws://localhost:9944");
+            std::env::set_var(
+                "SAS_SUBSTRATE_FALLBACK_URLS",
+                r#"["ws://fallback-1:9944","ws://fallback-2:9944"]"#,
+            );
+        }
+
+        let config = SidecarConfig::from_env_with_file(".env.nonexistent").unwrap();
+        assert_eq!(
+            config.substrate.fallback_urls,
+            vec!["ws://fallback-1:9944", "ws://fallback-2:9944"]
+        );
+
+        unsafe {
+            std::env::set_var("SAS_SUBSTRATE_FALLBACK_URLS", "not-valid-json");
+        }
+        let result = SidecarConfig::from_env_with_file(".env.nonexistent");
+        assert!(matches!(
+            result,
+            Err(ConfigError::InvalidFallbackUrlsJson(_))
+        ));
+
+        cleanup_sas_env_vars();
+    }
+
     #[test]
     #[serial]
     fn test_from_env_invalid_multi_chain_json() {
@@ -448,6 +593,58 @@ mod tests {
         assert!(!config.has_relay_chain());
     }
 
+    #[test]
+    #[serial]
+    fn test_from_env_rpc_timeout_and_max_connections() {
+        cleanup_sas_env_vars();
+
+        let config = SidecarConfig::from_env_with_file("").unwrap();
+        assert_eq!(config.substrate.rpc_timeout_ms, 30000);
+        assert_eq!(config.substrate.max_connections, 1024);
+
+        unsafe {
+            std::env::set_var("SAS_SUBSTRATE_RPC_TIMEOUT_MS", "120000");
+            std::env::set_var("SAS_SUBSTRATE_MAX_CONNECTIONS", "64");
+        }
+        let config = SidecarConfig::from_env_with_file("").unwrap();
+        assert_eq!(config.substrate.rpc_timeout_ms, 120000);
+        assert_eq!(config.substrate.max_connections, 64);
+
+        // The previous name of the timeout variable is still honoured
+        cleanup_sas_env_vars();
+        unsafe { std::env::set_var("SAS_SUBSTRATE_RECONNECT_REQUEST_TIMEOUT_MS", "45000") };
+        let config = SidecarConfig::from_env_with_file("").unwrap();
+        assert_eq!(config.substrate.rpc_timeout_ms, 45000);
+
+        unsafe { std::env::set_var("SAS_SUBSTRATE_MAX_CONNECTIONS", "0") };
+        assert!(SidecarConfig::from_env_with_file("").is_err());
+
+        cleanup_sas_env_vars();
+    }
+
+    #[test]
+    #[serial]
+    fn test_from_env_token_overrides() {
+        cleanup_sas_env_vars();
+
+        let config = SidecarConfig::from_env_with_file("").unwrap();
+        assert_eq!(config.substrate.token_symbol, None);
+        assert_eq!(config.substrate.token_decimals, None);
+
+        unsafe {
+            std::env::set_var("SAS_SUBSTRATE_TOKEN_SYMBOL", "ACA");
+            std::env::set_var("SAS_SUBSTRATE_TOKEN_DECIMALS", "12");
+        }
+        let config = SidecarConfig::from_env_with_file("").unwrap();
+        assert_eq!(config.substrate.token_symbol.as_deref(), Some("ACA"));
+        assert_eq!(config.substrate.token_decimals, Some(12));
+
+        unsafe { std::env::set_var("SAS_SUBSTRATE_TOKEN_DECIMALS", "256") };
+        assert!(SidecarConfig::from_env_with_file("").is_err());
+
+        cleanup_sas_env_vars();
+    }
+
     #[test]
     #[serial]
     fn test_env_file_non_existent() {
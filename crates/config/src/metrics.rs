@@ -64,6 +64,9 @@ pub struct MetricsConfig {
 
     /// Include query parameters in route labels (matches sidecar's INCLUDE_QUERYPARAMS)
     pub include_queryparams: bool,
+
+    /// Expose hit/miss/eviction counters for the internal caches
+    pub cache_stats: bool,
 }
 
 impl Default for MetricsConfig {
@@ -76,6 +79,7 @@ impl Default for MetricsConfig {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         }
     }
 }
@@ -153,6 +157,7 @@ mod tests {
             loki_host: "::1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -167,6 +172,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -181,6 +187,7 @@ mod tests {
             loki_host: "loki".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -195,6 +202,7 @@ mod tests {
             loki_host: "loki.monitoring.svc.cluster.local".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -209,6 +217,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_err());
     }
@@ -223,6 +232,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_err());
     }
@@ -237,6 +247,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 0,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_err());
     }
@@ -251,6 +262,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -265,6 +277,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -279,6 +292,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_ok());
     }
@@ -293,6 +307,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_err());
     }
@@ -307,6 +322,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_err());
     }
@@ -321,6 +337,7 @@ mod tests {
             loki_host: "127.0.0.1".to_string(),
             loki_port: 3100,
             include_queryparams: false,
+            cache_stats: false,
         };
         assert!(config.validate().is_err());
     }
@@ -21,6 +21,26 @@ pub enum SubstrateError {
 
     #[error("Duplicate URL found in multi-chain configuration: {url}")]
     DuplicateUrl { url: String },
+
+    #[error("RPC timeout must be greater than 0")]
+    RpcTimeoutZero,
+
+    #[error("Max connections must be greater than 0")]
+    MaxConnectionsZero,
+
+    #[error("Token symbol override must not be empty")]
+    TokenSymbolEmpty,
+
+    #[error("Reconnect initial delay must be greater than 0")]
+    ReconnectInitialDelayZero,
+
+    #[error(
+        "Reconnect max delay ({max_delay_ms}ms) must not be less than the initial delay ({initial_delay_ms}ms)"
+    )]
+    ReconnectMaxDelayBelowInitial {
+        initial_delay_ms: u64,
+        max_delay_ms: u64,
+    },
 }
 
 /// Known relay chains in the ecosystem
@@ -152,6 +172,16 @@ impl ChainType {
             _ => None,
         }
     }
+
+    /// Name used for this chain type in `multi_chain_urls` and `/chains/{chainType}` paths
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Relay => "relay",
+            Self::AssetHub => "assethub",
+            Self::Coretime => "coretime",
+            Self::Parachain => "parachain",
+        }
+    }
 }
 
 /// Multi-chain URL configuration
@@ -179,6 +209,15 @@ pub struct SubstrateConfig {
     /// Default: []
     pub multi_chain_urls: Vec<ChainUrl>,
 
+    /// Fallback URLs for the primary chain, used for read-only RPCs while the
+    /// primary connection is down
+    ///
+    /// Env: SAS_SUBSTRATE_FALLBACK_URLS
+    /// Format: JSON array of URLs
+    /// Example: '["wss://rpc-2.example.com","wss://rpc-3.example.com"]'
+    /// Default: []
+    pub fallback_urls: Vec<String>,
+
     /// Initial delay in milliseconds for reconnection backoff
     ///
     /// Env: SAS_SUBSTRATE_RECONNECT_INITIAL_DELAY_MS
@@ -191,11 +230,44 @@ pub struct SubstrateConfig {
     /// Default: 10000 (10 seconds)
     pub reconnect_max_delay_ms: u64,
 
+    /// Maximum number of retries after the first failed connection attempt at startup,
+    /// 0 for no limit
+    ///
+    /// The startup connection is still abandoned after 60 seconds, whichever limit is
+    /// reached first. Once exhausted, the server still starts and `/v1/health` reports
+    /// the node as unreachable until a later attempt succeeds. Reconnects after a
+    /// dropped connection are not limited.
+    ///
+    /// Env: SAS_SUBSTRATE_RECONNECT_MAX_RETRIES
+    /// Default: 0
+    pub reconnect_max_retries: u32,
+
     /// Request timeout in milliseconds for RPC calls
     ///
-    /// Env: SAS_SUBSTRATE_RECONNECT_REQUEST_TIMEOUT_MS
+    /// Env: SAS_SUBSTRATE_RPC_TIMEOUT_MS
+    /// (SAS_SUBSTRATE_RECONNECT_REQUEST_TIMEOUT_MS is still accepted)
     /// Default: 30000 (30 seconds)
-    pub reconnect_request_timeout_ms: u64,
+    pub rpc_timeout_ms: u64,
+
+    /// Maximum number of requests in flight on each node connection
+    ///
+    /// Requests beyond this limit wait for a slot instead of being sent.
+    ///
+    /// Env: SAS_SUBSTRATE_MAX_CONNECTIONS
+    /// Default: 1024
+    pub max_connections: u32,
+
+    /// Native token symbol of the primary chain, overriding `system_properties`
+    ///
+    /// Env: SAS_SUBSTRATE_TOKEN_SYMBOL
+    /// Default: none (use the chain's properties)
+    pub token_symbol: Option<String>,
+
+    /// Native token decimals of the primary chain, overriding `system_properties`
+    ///
+    /// Env: SAS_SUBSTRATE_TOKEN_DECIMALS
+    /// Default: none (use the chain's properties)
+    pub token_decimals: Option<u8>,
 }
 
 impl SubstrateConfig {
@@ -207,10 +279,18 @@ impl SubstrateConfig {
 
         Self::validate_url(&self.url)?;
 
-        // Validate multi-chain URLs
+        // Validate multi-chain and fallback URLs
         let mut seen_urls = std::collections::HashSet::new();
         seen_urls.insert(self.url.clone());
 
+        for url in &self.fallback_urls {
+            Self::validate_url(url)?;
+
+            if !seen_urls.insert(url.clone()) {
+                return Err(SubstrateError::DuplicateUrl { url: url.clone() });
+            }
+        }
+
         for chain_url in &self.multi_chain_urls {
             // Validate URL format
             Self::validate_url(&chain_url.url)?;
@@ -223,6 +303,33 @@ impl SubstrateConfig {
             }
         }
 
+        if self.reconnect_initial_delay_ms == 0 {
+            return Err(SubstrateError::ReconnectInitialDelayZero);
+        }
+
+        if self.reconnect_max_delay_ms < self.reconnect_initial_delay_ms {
+            return Err(SubstrateError::ReconnectMaxDelayBelowInitial {
+                initial_delay_ms: self.reconnect_initial_delay_ms,
+                max_delay_ms: self.reconnect_max_delay_ms,
+            });
+        }
+
+        if self.rpc_timeout_ms == 0 {
+            return Err(SubstrateError::RpcTimeoutZero);
+        }
+
+        if self.max_connections == 0 {
+            return Err(SubstrateError::MaxConnectionsZero);
+        }
+
+        if self
+            .token_symbol
+            .as_ref()
+            .is_some_and(|symbol| symbol.trim().is_empty())
+        {
+            return Err(SubstrateError::TokenSymbolEmpty);
+        }
+
         Ok(())
     }
 
@@ -264,9 +371,14 @@ impl Default for SubstrateConfig {
         Self {
             url: "ws://127.0.0.1:9944".to_string(),
             multi_chain_urls: vec![],
+            fallback_urls: vec![],
             reconnect_initial_delay_ms: 100,
             reconnect_max_delay_ms: 10000,
-            reconnect_request_timeout_ms: 30000,
+            reconnect_max_retries: 0,
+            rpc_timeout_ms: 30000,
+            max_connections: 1024,
+            token_symbol: None,
+            token_decimals: None,
         }
     }
 }
@@ -396,6 +508,112 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_rpc_limits() {
+        let config = SubstrateConfig {
+            rpc_timeout_ms: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::RpcTimeoutZero)
+        ));
+
+        let config = SubstrateConfig {
+            max_connections: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::MaxConnectionsZero)
+        ));
+
+        let config = SubstrateConfig {
+            rpc_timeout_ms: 120_000,
+            max_connections: 16,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_token_symbol_override() {
+        let config = SubstrateConfig {
+            token_symbol: Some(" ".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::TokenSymbolEmpty)
+        ));
+
+        let config = SubstrateConfig {
+            token_symbol: Some("DOT".to_string()),
+            token_decimals: Some(10),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reconnect_backoff() {
+        let config = SubstrateConfig {
+            reconnect_initial_delay_ms: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::ReconnectInitialDelayZero)
+        ));
+
+        let config = SubstrateConfig {
+            reconnect_initial_delay_ms: 500,
+            reconnect_max_delay_ms: 100,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::ReconnectMaxDelayBelowInitial { .. })
+        ));
+
+        let config = SubstrateConfig {
+            reconnect_initial_delay_ms: 500,
+            reconnect_max_delay_ms: 500,
+            reconnect_max_retries: 5,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_fallback_urls_validated() {
+        let config = SubstrateConfig {
+            url: "ws://localhost:9944".to_string(),
+            fallback_urls: vec!["wss://rpc-2.example.com".to_string()],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = SubstrateConfig {
+            url: "ws://localhost:9944".to_string(),
+            fallback_urls: vec!["ws://localhost:9944".to_string()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::DuplicateUrl { .. })
+        ));
+
+        let config = SubstrateConfig {
+            fallback_urls: vec!["ftp://rpc-2.example.com".to_string()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(SubstrateError::InvalidUrlScheme { .. })
+        ));
+    }
+
     #[test]
     fn test_chain_type_deserialization() {
         let json = r#"{"url":"ws://test:9944","type":"relay"}"#;
@@ -411,6 +629,23 @@ mod tests {
         assert_eq!(chain_url.chain_type, ChainType::Parachain);
     }
 
+    #[test]
+    fn test_chain_type_as_str_round_trips() {
+        for chain_type in [
+            ChainType::Relay,
+            ChainType::AssetHub,
+            ChainType::Coretime,
+            ChainType::Parachain,
+        ] {
+            let json = format!(
+                r#"{{"url":"ws://test:9944","type":"{}"}}"#,
+                chain_type.as_str()
+            );
+            let chain_url: ChainUrl = serde_json::from_str(&json).unwrap();
+            assert_eq!(chain_url.chain_type, chain_type);
+        }
+    }
+
     #[test]
     fn test_get_relay_chain_url() {
         // With relay chain configured
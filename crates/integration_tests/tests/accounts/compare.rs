@@ -340,6 +340,41 @@ async fn test_compare_no_addresses() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_compare_balance_diff_requires_both_blocks() -> Result<()> {
+    let local_client = get_client().await?;
+
+    let addr = "1xN1Q5eKQmS5AzASdjt6R6sHF76611vKR4PFpFjy1kXau4m";
+    let endpoint = format!("/accounts/compare?addresses={}&at=1", addr);
+
+    println!(
+        "\n{} Testing compare endpoint with `at` but no `at2`",
+        "Testing".cyan().bold()
+    );
+    println!("{}", "═".repeat(80).bright_white());
+
+    let (local_status, local_json) = local_client
+        .get_json(&format!("/v1{}", endpoint))
+        .await
+        .context("Failed to fetch from local API")?;
+
+    assert_eq!(
+        local_status.as_u16(),
+        400,
+        "Expected 400 Bad Request when `at2` is missing"
+    );
+
+    let response_obj = local_json.as_object().expect("Response is not an object");
+    assert!(
+        response_obj.contains_key("error"),
+        "Error response should contain 'error' field"
+    );
+
+    println!("{} Missing `at2` handled correctly!", "✓".green().bold());
+    println!("{}", "═".repeat(80).bright_white());
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_compare_response_structure() -> Result<()> {
     let local_client = get_client().await?;
@@ -1,7 +1,12 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::{logging::http_logger_middleware, openapi::ApiDoc, routes, state::AppState};
+use crate::{
+    logging::http_logger_middleware,
+    openapi::ApiDoc,
+    routes::{self, RouteRegistry},
+    state::AppState,
+};
 use axum::{
     Router,
     http::{StatusCode, header},
@@ -10,7 +15,13 @@ use axum::{
     routing::get,
 };
 use include_dir::{Dir, include_dir};
-use tower_http::{cors::CorsLayer, limit::RequestBodyLimitLayer, trace::TraceLayer};
+use polkadot_rest_api_config::{CorsOrigins, ExpressConfig, MetricsConfig, SidecarConfig};
+use std::time::Duration;
+use tower_http::{
+    cors::{AllowOrigin, Any, CorsLayer},
+    limit::RequestBodyLimitLayer,
+    trace::TraceLayer,
+};
 use utoipa::OpenApi;
 
 static DOCS_DIR: Dir = include_dir!("$OUT_DIR/docs_dist");
@@ -38,11 +49,71 @@ async fn serve_docs(uri: axum::http::Uri) -> Response {
     }
 }
 
-pub fn create_app(state: AppState) -> Router {
-    let request_limit = state.config.express.request_limit;
-    let metrics_enabled = state.config.metrics.enabled;
-    let registry = &state.route_registry;
+/// Build the CORS layer from `SAS_EXPRESS_CORS_ORIGINS`.
+///
+/// A layer without allowed origins sends no CORS headers, so browsers block
+/// cross-origin requests.
+fn cors_layer(express: &ExpressConfig) -> CorsLayer {
+    match express.cors_origins() {
+        CorsOrigins::Disabled => CorsLayer::new(),
+        CorsOrigins::Any => CorsLayer::permissive(),
+        CorsOrigins::List(origins) => {
+            let origins = origins
+                .iter()
+                .filter_map(|origin| origin.parse().ok())
+                .collect::<Vec<_>>();
+            CorsLayer::new()
+                .allow_origin(AllowOrigin::list(origins))
+                .allow_methods(Any)
+                .allow_headers(Any)
+                .expose_headers(Any)
+        }
+    }
+}
+
+/// Apply the metrics middleware when metrics are enabled.
+///
+/// Added to a router after its routes, so the middleware sees the matched route.
+fn with_metrics<S: Clone + Send + Sync + 'static>(
+    router: Router<S>,
+    metrics: &MetricsConfig,
+) -> Router<S> {
+    if metrics.enabled {
+        router.layer(middleware::from_fn_with_state(
+            metrics.include_queryparams,
+            crate::metrics::metrics_middleware,
+        ))
+    } else {
+        router
+    }
+}
+
+/// Apply the layers every app is served with: request timeout, HTTP logging,
+/// request ids, CORS, tracing and the request body limit.
+fn with_service_layers<S: Clone + Send + Sync + 'static>(
+    app: Router<S>,
+    config: &SidecarConfig,
+) -> Router<S> {
+    let request_timeout = Duration::from_millis(config.express.request_timeout);
+
+    app.layer(middleware::from_fn_with_state(
+        request_timeout,
+        crate::middleware::timeout::request_timeout_middleware,
+    ))
+    .layer(middleware::from_fn(http_logger_middleware))
+    .layer(middleware::from_fn(
+        crate::middleware::request_id::request_id_middleware,
+    ))
+    .layer(cors_layer(&config.express))
+    .layer(TraceLayer::new_for_http())
+    .layer(RequestBodyLimitLayer::new(config.express.request_limit))
+}
 
+/// Build the `/v1` API router serving `state`'s chain, registering its routes in `registry`.
+fn v1_router<S: Clone + Send + Sync + 'static>(
+    state: &AppState,
+    registry: &RouteRegistry,
+) -> Router<S> {
     let rc_routes = Router::new()
         .merge(routes::accounts::accounts_routes(registry))
         .merge(routes::blocks::blocks_routes(registry))
@@ -62,6 +133,7 @@ pub fn create_app(state: AppState) -> Router {
         .route("/", get(routes::root::root_handler))
         .merge(routes::ahm::routes(registry))
         .merge(routes::capabilities::routes(registry))
+        .merge(routes::chains::routes(registry))
         .merge(routes::coretime::routes(
             registry,
             &state.chain_info.chain_type,
@@ -74,6 +146,7 @@ pub fn create_app(state: AppState) -> Router {
         ))
         .merge(routes::rc::routes(registry, &state.chain_info.chain_type))
         .merge(routes::runtime::routes(registry))
+        .merge(routes::subscriptions::subscriptions_routes(registry))
         .merge(routes::transaction::routes(
             registry,
             &state.chain_info.chain_type,
@@ -83,14 +156,27 @@ pub fn create_app(state: AppState) -> Router {
         .merge(rc_routes);
 
     // Apply metrics middleware if enabled (needs to be after with_state)
-    let v1_routes = if metrics_enabled {
-        v1_routes.layer(middleware::from_fn_with_state(
-            state.clone(),
-            crate::metrics::metrics_middleware,
-        ))
-    } else {
-        v1_routes
-    };
+    with_metrics(v1_routes, &state.config.metrics)
+}
+
+pub fn create_app(state: AppState) -> Router {
+    let metrics_enabled = state.config.metrics.enabled;
+
+    // Every connected chain is also served under /v1/chains/{chainType}. These routes
+    // mirror the primary ones, so they are kept out of the route registry.
+    let chain_routers = state
+        .chain_states()
+        .iter()
+        .map(|chain_state| {
+            (
+                chain_state.chain_info.chain_type.as_str(),
+                v1_router(chain_state, &RouteRegistry::new()),
+            )
+        })
+        .collect();
+
+    let v1_routes = v1_router(&state, &state.route_registry)
+        .nest("/chains", routes::chains::chain_routes(chain_routers));
 
     // Build root router
     let mut app = Router::new()
@@ -109,9 +195,202 @@ pub fn create_app(state: AppState) -> Router {
         app = app.merge(routes::metrics::routes());
     }
 
-    app.layer(middleware::from_fn(http_logger_middleware))
-        .layer(CorsLayer::permissive())
-        .layer(TraceLayer::new_for_http())
-        .layer(RequestBodyLimitLayer::new(request_limit))
-        .with_state(state)
+    with_service_layers(app, &state.config).with_state(state)
+}
+
+/// Build the app served while the node can't be reached at startup.
+///
+/// `/v1/health` reports the node as unreachable and every other route answers 503,
+/// so orchestrators see an unready instance instead of a crash loop. It is served
+/// with the same middleware as the full app.
+pub fn create_unavailable_app(config: &SidecarConfig) -> Router {
+    let routes = Router::new()
+        .route(
+            "/v1/health",
+            get(crate::handlers::health::get_health_unavailable),
+        )
+        .fallback(|| async {
+            let body = axum::Json(serde_json::json!({
+                "error": "Not connected to the node yet, retrying",
+            }));
+            (StatusCode::SERVICE_UNAVAILABLE, body)
+        });
+    let mut app = with_metrics(routes, &config.metrics);
+
+    if config.metrics.enabled {
+        app = app.merge(routes::metrics::routes());
+    }
+
+    with_service_layers(app, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    /// Returns the `Access-Control-Allow-Origin` sent back to a request from `origin`.
+    async fn allowed_origin(cors_origins: &str, origin: &str) -> Option<String> {
+        let express = ExpressConfig {
+            cors_origins: cors_origins.to_string(),
+            ..Default::default()
+        };
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(cors_layer(&express));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(header::ORIGIN, origin)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .map(|v| v.to_str().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn test_cors_any_origin() {
+        assert_eq!(
+            allowed_origin("*", "https://dash.example").await.as_deref(),
+            Some("*")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cors_origin_list() {
+        let origins = "https://dash.example,http://localhost:3000";
+        assert_eq!(
+            allowed_origin(origins, "http://localhost:3000")
+                .await
+                .as_deref(),
+            Some("http://localhost:3000")
+        );
+        assert_eq!(allowed_origin(origins, "https://evil.example").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_cors_disabled() {
+        assert_eq!(allowed_origin("", "https://dash.example").await, None);
+    }
+
+    /// App for a relay chain with an Asset Hub connection from multi_chain_urls.
+    async fn multi_chain_app() -> Router {
+        use crate::state::{ChainConnection, ChainInfo};
+        use polkadot_rest_api_config::ChainType;
+        use std::sync::Arc;
+        use subxt_rpcs::RpcClient;
+
+        let rpc_client = Arc::new(RpcClient::new(
+            crate::test_fixtures::mock_rpc_client_builder().build(),
+        ));
+        let legacy_rpc = Arc::new(subxt_rpcs::LegacyRpcMethods::new((*rpc_client).clone()));
+        let client = Arc::new(
+            subxt::OnlineClient::from_rpc_client((*rpc_client).clone())
+                .await
+                .expect("Failed to create test OnlineClient"),
+        );
+        let chain_info = |chain_type| ChainInfo {
+            chain_type,
+            spec_name: "test".to_string(),
+            spec_version: 1,
+            ss58_prefix: 42,
+        };
+
+        let state = AppState {
+            config: polkadot_rest_api_config::SidecarConfig::default(),
+            client: client.clone(),
+            legacy_rpc: legacy_rpc.clone(),
+            rpc_client: rpc_client.clone(),
+            chain_info: chain_info(ChainType::Relay),
+            relay_client: Arc::new(tokio::sync::OnceCell::new()),
+            relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
+            relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
+            fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
+            chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
+            chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
+                polkadot_rest_api_config::ChainConfig::default(),
+            )),
+            route_registry: RouteRegistry::new(),
+            relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(vec![ChainConnection {
+                url: "ws://127.0.0.1:9945".to_string(),
+                client,
+                legacy_rpc,
+                rpc_client,
+                chain_info: chain_info(ChainType::AssetHub),
+                chain_config: polkadot_rest_api_config::ChainConfig::default(),
+            }]),
+        };
+
+        create_app(state)
+    }
+
+    async fn status_of(app: &Router, path: &str) -> StatusCode {
+        app.clone()
+            .oneshot(Request::builder().uri(path).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_chains_routes_serve_connected_chains() {
+        let app = multi_chain_app().await;
+
+        assert_eq!(status_of(&app, "/v1/version").await, StatusCode::OK);
+        assert_eq!(status_of(&app, "/v1/chains").await, StatusCode::OK);
+        assert_eq!(
+            status_of(&app, "/v1/chains/relay/version").await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status_of(&app, "/v1/chains/assethub/version").await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            status_of(&app, "/v1/chains/coretime/version").await,
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_app_reports_unhealthy() {
+        let app = create_unavailable_app(&SidecarConfig::default());
+        let get = |uri: &'static str| {
+            app.clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        };
+
+        let response = get("/v1/health").await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        // Served with the same middleware as the full app
+        assert!(
+            response
+                .headers()
+                .contains_key(&crate::middleware::request_id::REQUEST_ID_HEADER)
+        );
+        let body = http_body_util::BodyExt::collect(response.into_body())
+            .await
+            .unwrap()
+            .to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], "degraded");
+        assert_eq!(json["connected"], false);
+
+        let response = get("/v1/blocks/head").await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
 }
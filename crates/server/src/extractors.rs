@@ -3,11 +3,13 @@
 
 //! Custom Axum extractors that return JSON error responses.
 
+use crate::utils::csv::{CsvTable, csv_response};
 use axum::Json;
 use axum::extract::Query;
 use axum::extract::rejection::QueryRejection;
-use axum::http::StatusCode;
+use axum::http::{StatusCode, header};
 use axum::response::{IntoResponse, Response};
+use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::json;
 
@@ -108,6 +110,96 @@ fn json_query_error(rejection: QueryRejection) -> Response {
         .into_response()
 }
 
+// ============================================================================
+// ResponseFormat - Content negotiation from the Accept header
+// ============================================================================
+
+/// The representation a client asked for in its `Accept` header.
+///
+/// JSON is the default. CSV or NDJSON is chosen when `text/csv` or `application/x-ndjson`
+/// is listed with a higher quality than `application/json`, so `Accept: text/csv` selects
+/// it while browsers' and tools' usual `Accept` values keep getting JSON. Only endpoints
+/// that render through [`ResponseFormat::respond`] serve CSV, and only streaming
+/// endpoints serve NDJSON; the rest always answer JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    Csv,
+    Ndjson,
+}
+
+impl ResponseFormat {
+    pub fn from_accept(accept: &str) -> Self {
+        let json = media_quality(accept, "application/json").unwrap_or(-1.0);
+        [
+            (ResponseFormat::Csv, media_quality(accept, "text/csv")),
+            (
+                ResponseFormat::Ndjson,
+                media_quality(accept, "application/x-ndjson"),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(format, quality)| Some((format, quality?)))
+        .filter(|&(_, quality)| quality > 0.0 && quality > json)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(ResponseFormat::Json, |(format, _)| format)
+    }
+
+    /// Serialize `body` in the negotiated format. NDJSON falls back to JSON, since a
+    /// single body isn't a stream of records.
+    pub fn respond<T: Serialize + CsvTable>(self, body: &T) -> Response {
+        match self {
+            ResponseFormat::Json | ResponseFormat::Ndjson => Json(body).into_response(),
+            ResponseFormat::Csv => csv_response(body),
+        }
+    }
+}
+
+/// Quality (`q`) the `Accept` header gives `media`, `None` if it isn't listed.
+fn media_quality(accept: &str, media: &str) -> Option<f32> {
+    accept.split(',').find_map(|range| {
+        let mut parts = range.split(';').map(str::trim);
+        if !parts.next()?.eq_ignore_ascii_case(media) {
+            return None;
+        }
+        let quality = parts
+            .filter_map(|param| param.split_once('='))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+            .and_then(|(_, value)| value.trim().parse().ok())
+            .unwrap_or(1.0);
+        Some(quality)
+    })
+}
+
+impl<S> axum::extract::FromRequestParts<S> for ResponseFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    fn from_request_parts<'life0, 'life1, 'async_trait>(
+        parts: &'life0 mut axum::http::request::Parts,
+        _state: &'life1 S,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Self, Self::Rejection>> + Send + 'async_trait>,
+    >
+    where
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(async move {
+            Ok(parts
+                .headers
+                .get(header::ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .map(ResponseFormat::from_accept)
+                .unwrap_or_default())
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,8 +218,8 @@ mod tests {
         pub at: Option<String>,
     }
 
-    async fn test_handler(JsonQuery(_params): JsonQuery<TestParams>) -> &'static str {
-        "ok"
+    async fn test_handler(JsonQuery(params): JsonQuery<TestParams>) -> String {
+        format!("{} {:?}", params.event_docs, params.at)
     }
 
     async fn send_request(app: Router, uri: &str) -> (StatusCode, String) {
@@ -150,8 +242,9 @@ mod tests {
     #[tokio::test]
     async fn valid_params_return_200() {
         let app = Router::new().route("/test", get(test_handler));
-        let (status, _) = send_request(app, "/test?eventDocs=true&at=100").await;
+        let (status, body) = send_request(app, "/test?eventDocs=true&at=100").await;
         assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, r#"true Some("100")"#);
     }
 
     #[tokio::test]
@@ -184,4 +277,47 @@ mod tests {
             serde_json::from_str(&body).expect("Response must be valid JSON, not plain text");
         assert!(parsed.get("error").is_some());
     }
+
+    #[test]
+    fn response_format_from_accept() {
+        assert_eq!(ResponseFormat::from_accept("text/csv"), ResponseFormat::Csv);
+        assert_eq!(
+            ResponseFormat::from_accept("Text/CSV; charset=utf-8"),
+            ResponseFormat::Csv
+        );
+        assert_eq!(
+            ResponseFormat::from_accept("text/csv, */*;q=0.1"),
+            ResponseFormat::Csv
+        );
+        assert_eq!(
+            ResponseFormat::from_accept("application/json;q=0.5, text/csv"),
+            ResponseFormat::Csv
+        );
+
+        assert_eq!(ResponseFormat::from_accept("*/*"), ResponseFormat::Json);
+        assert_eq!(
+            ResponseFormat::from_accept("application/json, text/csv"),
+            ResponseFormat::Json
+        );
+        assert_eq!(
+            ResponseFormat::from_accept("text/csv;q=0"),
+            ResponseFormat::Json
+        );
+    }
+
+    #[test]
+    fn response_format_from_accept_ndjson() {
+        assert_eq!(
+            ResponseFormat::from_accept("application/x-ndjson"),
+            ResponseFormat::Ndjson
+        );
+        assert_eq!(
+            ResponseFormat::from_accept("text/csv;q=0.5, application/x-ndjson"),
+            ResponseFormat::Ndjson
+        );
+        assert_eq!(
+            ResponseFormat::from_accept("application/json, application/x-ndjson;q=0.9"),
+            ResponseFormat::Json
+        );
+    }
 }
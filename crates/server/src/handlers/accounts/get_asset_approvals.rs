@@ -1,10 +1,14 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::types::{AccountsError, AssetApprovalQueryParams, AssetApprovalResponse, BlockInfo};
+use super::types::{
+    AccountsError, AssetApprovalEntry, AssetApprovalQueryParams, AssetApprovalResponse,
+    AssetApprovalsListResponse, BlockInfo,
+};
 use super::utils::validate_and_parse_address;
 use crate::extractors::JsonQuery;
 use crate::handlers::runtime_queries::assets as assets_queries;
+use crate::handlers::runtime_queries::assets_common::format_account_id;
 use crate::state::AppState;
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block};
 use axum::{
@@ -13,10 +17,86 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use polkadot_rest_api_config::ChainType;
+use serde::Serialize;
 use serde_json::json;
 use sp_core::crypto::AccountId32;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 
+/// Page size used when listing approvals without an explicit `limit`.
+const DEFAULT_APPROVALS_LIMIT: usize = 100;
+
+/// Largest accepted `limit` when listing approvals.
+const MAX_APPROVALS_LIMIT: usize = 1000;
+
+/// Either the approval for a single delegate or a page of all approvals.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum AssetApprovals {
+    Single(AssetApprovalResponse),
+    List(AssetApprovalsListResponse),
+}
+
+impl AssetApprovals {
+    fn set_rc_block(&mut self, hash: String, number: String, ah_timestamp: Option<String>) {
+        let (rc_block_hash, rc_block_number, ts) = match self {
+            AssetApprovals::Single(r) => (
+                &mut r.rc_block_hash,
+                &mut r.rc_block_number,
+                &mut r.ah_timestamp,
+            ),
+            AssetApprovals::List(r) => (
+                &mut r.rc_block_hash,
+                &mut r.rc_block_number,
+                &mut r.ah_timestamp,
+            ),
+        };
+        *rc_block_hash = Some(hash);
+        *rc_block_number = Some(number);
+        *ts = ah_timestamp;
+    }
+}
+
+/// Validated paging window for listing approvals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Page {
+    limit: usize,
+    offset: usize,
+}
+
+impl Page {
+    /// `limit` and `offset` only page the listing, so they are rejected alongside a delegate.
+    fn reject_with_delegate(params: &AssetApprovalQueryParams) -> Result<(), AccountsError> {
+        if params.limit.is_some() || params.offset.is_some() {
+            return Err(AccountsError::InvalidPagination(
+                "limit and offset can only be used without a delegate".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn from_params(params: &AssetApprovalQueryParams) -> Result<Self, AccountsError> {
+        let limit = params.limit.unwrap_or(DEFAULT_APPROVALS_LIMIT);
+        if limit == 0 || limit > MAX_APPROVALS_LIMIT {
+            return Err(AccountsError::InvalidPagination(format!(
+                "limit must be between 1 and {MAX_APPROVALS_LIMIT}"
+            )));
+        }
+
+        Ok(Page {
+            limit,
+            offset: params.offset.unwrap_or(0),
+        })
+    }
+
+    fn apply<T>(&self, items: Vec<T>) -> Vec<T> {
+        items
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect()
+    }
+}
+
 // ================================================================================================
 // Main Handler
 // ================================================================================================
@@ -24,27 +104,32 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
 /// Handler for GET /accounts/{accountId}/asset-approvals
 ///
 /// Returns asset approval information for a given account, asset, and delegate.
+/// Without a delegate, returns a page of all approvals the account granted for the asset.
 ///
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
 /// - `assetId` (required): The asset ID to query approval for
-/// - `delegate` (required): The delegate address with spending approval
+/// - `delegate` (optional): The delegate address with spending approval
+/// - `limit` (optional): Page size when listing approvals (default 100, max 1000); rejected with `delegate`
+/// - `offset` (optional): Number of approvals to skip when listing; rejected with `delegate`
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/asset-approvals",
     tag = "accounts",
     summary = "Account asset approvals",
-    description = "Returns asset approval information for a given account, asset, and delegate.",
+    description = "Returns asset approval information for a given account, asset, and delegate. When `delegate` is omitted, returns all approvals the account granted for the asset, paged with `limit` and `offset` and ordered by delegate.",
     params(
         ("accountId" = String, Path, description = "SS58-encoded account address"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
         ("assetId" = String, Query, description = "The asset ID to query approval for"),
-        ("delegate" = String, Query, description = "The delegate address with spending approval")
+        ("delegate" = Option<String>, Query, description = "The delegate address with spending approval; omit to list all approvals"),
+        ("limit" = Option<usize>, Query, description = "Page size when listing approvals (default 100, max 1000); not allowed with `delegate`"),
+        ("offset" = Option<usize>, Query, description = "Number of approvals to skip when listing; not allowed with `delegate`")
     ),
     responses(
-        (status = 200, description = "Asset approval information", body = AssetApprovalResponse),
+        (status = 200, description = "Asset approval information, or a page of approvals when no delegate is given", body = AssetApprovalResponse),
         (status = 400, description = "Invalid parameters"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -57,11 +142,19 @@ pub async fn get_asset_approvals(
 ) -> Result<Response, AccountsError> {
     let account = validate_and_parse_address(&account_id, state.chain_info.ss58_prefix)?;
 
-    let delegate = validate_and_parse_address(&params.delegate, state.chain_info.ss58_prefix)
-        .map_err(|_| AccountsError::InvalidDelegateAddress(params.delegate.clone()))?;
+    let target = match &params.delegate {
+        Some(delegate) => {
+            Page::reject_with_delegate(&params)?;
+            ApprovalTarget::Delegate(
+                validate_and_parse_address(delegate, state.chain_info.ss58_prefix)
+                    .map_err(|_| AccountsError::InvalidDelegateAddress(delegate.clone()))?,
+            )
+        }
+        None => ApprovalTarget::All(Page::from_params(&params)?),
+    };
 
     if params.use_rc_block {
-        return handle_use_rc_block(state, account, delegate, params).await;
+        return handle_use_rc_block(state, account, target, params).await;
     }
 
     let block_id = params
@@ -73,18 +166,88 @@ pub async fn get_asset_approvals(
 
     let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
 
-    let response = query_asset_approval(
+    let response = query_asset_approvals(
         &client_at_block,
         &account,
-        &delegate,
+        &target,
         params.asset_id,
         &resolved_block,
+        state.chain_info.ss58_prefix,
     )
     .await?;
 
     Ok(Json(response).into_response())
 }
 
+/// Which approvals of the owner to return.
+enum ApprovalTarget {
+    Delegate(AccountId32),
+    All(Page),
+}
+
+async fn query_asset_approvals(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    owner: &AccountId32,
+    target: &ApprovalTarget,
+    asset_id: u32,
+    block: &utils::ResolvedBlock,
+    ss58_prefix: u16,
+) -> Result<AssetApprovals, AccountsError> {
+    match target {
+        ApprovalTarget::Delegate(delegate) => {
+            query_asset_approval(client_at_block, owner, delegate, asset_id, block)
+                .await
+                .map(AssetApprovals::Single)
+        }
+        ApprovalTarget::All(page) => {
+            list_asset_approvals(client_at_block, owner, *page, asset_id, block, ss58_prefix)
+                .await
+                .map(AssetApprovals::List)
+        }
+    }
+}
+
+async fn list_asset_approvals(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    owner: &AccountId32,
+    page: Page,
+    asset_id: u32,
+    block: &utils::ResolvedBlock,
+    ss58_prefix: u16,
+) -> Result<AssetApprovalsListResponse, AccountsError> {
+    if !assets_queries::is_assets_pallet_available(client_at_block) {
+        return Err(AccountsError::PalletNotAvailable("Assets".to_string()));
+    }
+
+    let approvals =
+        assets_queries::get_asset_approvals_for_owner(client_at_block, asset_id, owner).await?;
+    let total = approvals.len();
+
+    let approvals = page
+        .apply(approvals)
+        .into_iter()
+        .map(|(delegate, approval)| AssetApprovalEntry {
+            delegate: format_account_id(&delegate, ss58_prefix),
+            amount: approval.amount,
+            deposit: approval.deposit,
+        })
+        .collect();
+
+    Ok(AssetApprovalsListResponse {
+        at: BlockInfo {
+            hash: block.hash.clone(),
+            height: block.number.to_string(),
+        },
+        approvals,
+        total: total.to_string(),
+        limit: page.limit.to_string(),
+        offset: page.offset.to_string(),
+        rc_block_hash: None,
+        rc_block_number: None,
+        ah_timestamp: None,
+    })
+}
+
 async fn query_asset_approval(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     owner: &AccountId32,
@@ -98,13 +261,8 @@ async fn query_asset_approval(
     }
 
     // Use centralized query function
-    let approval = assets_queries::get_asset_approval(client_at_block, asset_id, owner, delegate)
-        .await
-        .map_err(|_| {
-            AccountsError::DecodeFailed(parity_scale_codec::Error::from(
-                "Failed to query asset approval",
-            ))
-        })?;
+    let approval =
+        assets_queries::get_asset_approval(client_at_block, asset_id, owner, delegate).await?;
 
     let (amount, deposit) = match approval {
         Some(a) => (Some(a.amount), Some(a.deposit)),
@@ -131,7 +289,7 @@ async fn query_asset_approval(
 async fn handle_use_rc_block(
     state: AppState,
     account: AccountId32,
-    delegate: AccountId32,
+    target: ApprovalTarget,
     params: AssetApprovalQueryParams,
 ) -> Result<Response, AccountsError> {
     // Validate Asset Hub
@@ -170,26 +328,116 @@ async fn handle_use_rc_block(
 
         let client_at_block = state.client.at_block(ah_resolved.number).await?;
 
-        let mut response = query_asset_approval(
+        let mut response = query_asset_approvals(
             &client_at_block,
             &account,
-            &delegate,
+            &target,
             params.asset_id,
             &ah_resolved,
+            state.chain_info.ss58_prefix,
         )
         .await?;
 
-        // Add RC block info
-        response.rc_block_hash = Some(rc_block_hash.clone());
-        response.rc_block_number = Some(rc_block_number.clone());
-
-        // Fetch AH timestamp
-        if let Some(timestamp) = fetch_block_timestamp(&client_at_block).await {
-            response.ah_timestamp = Some(timestamp);
-        }
+        // Add RC block info and AH timestamp
+        let timestamp = fetch_block_timestamp(&client_at_block).await;
+        response.set_rc_block(rc_block_hash.clone(), rc_block_number.clone(), timestamp);
 
         results.push(response);
     }
 
     Ok(Json(results).into_response())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(query: &str) -> AssetApprovalQueryParams {
+        serde_qs::from_str(query).unwrap()
+    }
+
+    #[test]
+    fn test_delegate_is_optional() {
+        let params = params("assetId=1984");
+        assert_eq!(params.asset_id, 1984);
+        assert!(params.delegate.is_none());
+    }
+
+    #[test]
+    fn test_page_defaults_and_bounds() {
+        assert_eq!(
+            Page::from_params(&params("assetId=1")).unwrap(),
+            Page {
+                limit: DEFAULT_APPROVALS_LIMIT,
+                offset: 0
+            }
+        );
+        assert_eq!(
+            Page::from_params(&params("assetId=1&limit=1000&offset=20")).unwrap(),
+            Page {
+                limit: 1000,
+                offset: 20
+            }
+        );
+
+        for query in ["assetId=1&limit=0", "assetId=1&limit=1001"] {
+            let err = Page::from_params(&params(query)).unwrap_err();
+            assert!(matches!(err, AccountsError::InvalidPagination(_)));
+            assert_eq!(
+                err.into_response().status(),
+                axum::http::StatusCode::BAD_REQUEST
+            );
+        }
+    }
+
+    #[test]
+    fn test_page_rejected_with_delegate() {
+        assert!(Page::reject_with_delegate(&params("assetId=1&delegate=x")).is_ok());
+
+        for query in [
+            "assetId=1&delegate=x&limit=5",
+            "assetId=1&delegate=x&offset=5",
+        ] {
+            let err = Page::reject_with_delegate(&params(query)).unwrap_err();
+            assert_eq!(
+                err.into_response().status(),
+                axum::http::StatusCode::BAD_REQUEST
+            );
+        }
+    }
+
+    #[test]
+    fn test_storage_failures_are_not_decode_errors() {
+        let err = AccountsError::from(assets_queries::AssetsStorageError::StorageFetchFailed(
+            "connection reset".to_string(),
+        ));
+        assert!(matches!(err, AccountsError::AssetsQueryFailed(_)));
+        assert_eq!(
+            err.into_response().status(),
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[test]
+    fn test_page_apply() {
+        let items: Vec<u32> = (0..10).collect();
+
+        let page = Page {
+            limit: 3,
+            offset: 4,
+        };
+        assert_eq!(page.apply(items.clone()), vec![4, 5, 6]);
+
+        let past_end = Page {
+            limit: 3,
+            offset: 9,
+        };
+        assert_eq!(past_end.apply(items.clone()), vec![9]);
+
+        let beyond = Page {
+            limit: 3,
+            offset: 20,
+        };
+        assert!(beyond.apply(items).is_empty());
+    }
+}
@@ -3,10 +3,11 @@
 
 use super::types::{AccountsError, AssetBalancesQueryParams, AssetBalancesResponse, BlockInfo};
 use super::utils::validate_and_parse_address;
-use crate::extractors::QsQuery;
+use crate::extractors::{QsQuery, ResponseFormat};
 use crate::handlers::accounts::utils::{query_all_assets_id, query_assets};
 use crate::handlers::runtime_queries::assets as assets_queries;
 use crate::state::AppState;
+use crate::utils::csv::CsvTable;
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block};
 use axum::{
     Json,
@@ -29,23 +30,35 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
-/// - `assets` (optional): List of asset IDs to query (queries all if omitted)
+/// - `assets` (optional): List of asset IDs to query, as `assets[]=` entries or a
+///   comma-separated list (queries all if omitted)
 /// - `showEmpty`  (optional): When true, include assets with zero balance (default: false)
+/// - `showErrors` (optional): When true, list assets whose query failed in `errors` (default: false)
+/// - `denominated` (optional): When true, format balances with each asset's decimals and
+///   include its `symbol` and `decimals` (default: false)
+///
+/// Sending `Accept: text/csv` returns the assets as CSV (`assetId,balance,status`)
+/// instead of JSON. `useRcBlock` responses are always JSON.
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/asset-balances",
     tag = "accounts",
     summary = "Account asset balances",
-    description = "Returns asset balances for a given account on Asset Hub chains.",
+    description = "Returns asset balances for a given account on Asset Hub chains. Send `Accept: text/csv` to get the assets as CSV with the columns `assetId,balance,status`; `useRcBlock` responses are always JSON.",
     params(
         ("accountId" = String, Path, description = "SS58-encoded account address"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
-        ("assets" = Option<String>, Query, description = "Comma-separated list of asset IDs to query"),
-        ("showEmpty" = Option<bool>, Query, description = "When true, include assets with zero balance (default: false)")
+        ("assets" = Option<String>, Query, description = "Asset IDs to query, comma-separated (assets=1984,1337) or bracket notation (assets[]=1984). Queries all assets if omitted"),
+        ("showEmpty" = Option<bool>, Query, description = "When true, include assets with zero balance (default: false)"),
+        ("showErrors" = Option<bool>, Query, description = "When true, list assets whose balance query failed in an `errors` array (default: false)"),
+        ("denominated" = Option<bool>, Query, description = "When true, format each balance using the asset's metadata decimals and include its `symbol` and `decimals` (default: false)")
     ),
     responses(
-        (status = 200, description = "Account asset balances", body = AssetBalancesResponse),
+        (status = 200, description = "Account asset balances", content(
+            (AssetBalancesResponse = "application/json"),
+            (String = "text/csv")
+        )),
         (status = 400, description = "Invalid parameters"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -54,6 +67,7 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
 pub async fn get_asset_balances(
     State(state): State<AppState>,
     Path(account_id): Path<String>,
+    format: ResponseFormat,
     QsQuery(params): QsQuery<AssetBalancesQueryParams>,
 ) -> Result<Response, AccountsError> {
     let account = validate_and_parse_address(&account_id, state.chain_info.ss58_prefix)?;
@@ -71,25 +85,40 @@ pub async fn get_asset_balances(
 
     let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
 
-    let assets = params.assets.as_deref().unwrap_or(&[]);
-    let show_empty = params.show_empty;
     let response = query_asset_balances(
         &client_at_block,
         &account,
         &resolved_block,
-        assets,
-        show_empty,
+        &params,
+        state.config.express.asset_fetch_concurrency,
     )
     .await?;
-    Ok(Json(response).into_response())
+    Ok(format.respond(&response))
+}
+
+impl CsvTable for AssetBalancesResponse {
+    const HEADER: &'static [&'static str] = &["assetId", "balance", "status"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.assets
+            .iter()
+            .map(|asset| {
+                vec![
+                    asset.asset_id.clone(),
+                    asset.balance.clone(),
+                    asset.status.clone().unwrap_or_default(),
+                ]
+            })
+            .collect()
+    }
 }
 
 async fn query_asset_balances(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
     block: &utils::ResolvedBlock,
-    asset_ids: &[u32],
-    show_empty: bool,
+    params: &AssetBalancesQueryParams,
+    concurrency: usize,
 ) -> Result<AssetBalancesResponse, AccountsError> {
     // Check if Assets pallet is available using centralized function
     if !assets_queries::is_assets_pallet_available(client_at_block) {
@@ -97,6 +126,7 @@ async fn query_asset_balances(
     }
 
     // Determine which assets to query
+    let asset_ids = params.assets.as_deref().unwrap_or(&[]);
     let assets_to_query = if asset_ids.is_empty() {
         // Query all asset IDs using centralized function
         query_all_assets_id(client_at_block).await.map_err(|e| {
@@ -107,8 +137,16 @@ async fn query_asset_balances(
         asset_ids.to_vec()
     };
 
-    // Query each asset balance in parallel
-    let assets = query_assets(client_at_block, account, &assets_to_query, show_empty).await?;
+    // Query asset balances in parallel, bounded by the configured concurrency
+    let (assets, errors) = query_assets(
+        client_at_block,
+        account,
+        &assets_to_query,
+        params.show_empty,
+        params.denominated,
+        concurrency,
+    )
+    .await?;
 
     Ok(AssetBalancesResponse {
         at: BlockInfo {
@@ -116,6 +154,7 @@ async fn query_asset_balances(
             height: block.number.to_string(),
         },
         assets,
+        errors: params.show_errors.then_some(errors),
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
@@ -142,7 +181,8 @@ async fn handle_use_rc_block(
     // Resolve RC block
     let rc_block_id = params
         .at
-        .unwrap_or_else(|| "head".to_string())
+        .as_deref()
+        .unwrap_or("head")
         .parse::<utils::BlockId>()?;
     let rc_resolved =
         utils::resolve_block_with_rpc(&rc_rpc_client, &rc_rpc, Some(rc_block_id)).await?;
@@ -158,8 +198,6 @@ async fn handle_use_rc_block(
     let rc_block_number = rc_resolved.number.to_string();
 
     // Process each AH block
-    let assets = params.assets.as_deref().unwrap_or(&[]);
-    let show_empty = params.show_empty;
     let mut results = Vec::new();
     for ah_block in ah_blocks {
         let ah_resolved = utils::ResolvedBlock {
@@ -167,9 +205,14 @@ async fn handle_use_rc_block(
             number: ah_block.number,
         };
         let client_at_block = state.client.at_block(ah_resolved.number).await?;
-        let mut response =
-            query_asset_balances(&client_at_block, &account, &ah_resolved, assets, show_empty)
-                .await?;
+        let mut response = query_asset_balances(
+            &client_at_block,
+            &account,
+            &ah_resolved,
+            &params,
+            state.config.express.asset_fetch_concurrency,
+        )
+        .await?;
 
         // Add RC block info
         response.rc_block_hash = Some(rc_block_hash.clone());
@@ -185,3 +228,46 @@ async fn handle_use_rc_block(
 
     Ok(Json(results).into_response())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::accounts::types::AssetBalance;
+    use crate::utils::csv::to_csv;
+
+    fn asset(asset_id: &str, balance: &str, status: Option<&str>) -> AssetBalance {
+        AssetBalance {
+            asset_id: asset_id.to_string(),
+            balance: balance.to_string(),
+            is_frozen: false,
+            is_sufficient: true,
+            status: status.map(str::to_string),
+            reason: None,
+            symbol: None,
+            decimals: None,
+        }
+    }
+
+    #[test]
+    fn test_asset_balances_csv() {
+        let response = AssetBalancesResponse {
+            at: BlockInfo {
+                hash: "0x00".to_string(),
+                height: "100".to_string(),
+            },
+            assets: vec![
+                asset("1984", "1500000", Some("Liquid")),
+                asset("1337", "0", None),
+            ],
+            errors: None,
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+        };
+
+        assert_eq!(
+            to_csv(&response),
+            "assetId,balance,status\r\n1984,1500000,Liquid\r\n1337,0,\r\n"
+        );
+    }
+}
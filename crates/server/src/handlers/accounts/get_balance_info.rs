@@ -7,8 +7,8 @@ use super::types::{
 use super::utils::validate_and_parse_address;
 use crate::extractors::JsonQuery;
 use crate::handlers::common::accounts::{
-    RawBalanceInfo, format_balance, format_frozen_fields, format_locks, format_transferable,
-    query_balance_info,
+    RawBalanceInfo, fetch_chain_tokens, format_balance, format_frozen_fields, format_locks,
+    format_transferable, query_balance_info,
 };
 use crate::state::AppState;
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block};
@@ -31,8 +31,10 @@ use serde_json::json;
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
-/// - `token` (optional): Token symbol for chains with multiple tokens (defaults to native)
-/// - `denominated` (optional): When true, denominate balances using chain decimals
+/// - `token` (optional): Token symbol for chains with multiple tokens (defaults to native).
+///   Non-native tokens listed in the chain properties are read from the ORML Tokens pallet.
+/// - `denominated` (optional): Denominate balances using chain decimals (default: true).
+///   When false, raw planck values are returned as integer strings.
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/balance-info",
@@ -44,7 +46,7 @@ use serde_json::json;
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
         ("token" = Option<String>, Query, description = "Token symbol for chains with multiple tokens"),
-        ("denominated" = Option<bool>, Query, description = "When true, denominate balances using chain decimals")
+        ("denominated" = Option<bool>, Query, description = "Denominate balances using chain decimals (default: true). When false, raw planck values are returned")
     ),
     responses(
         (status = 200, description = "Account balance information", body = BalanceInfoResponse),
@@ -70,17 +72,18 @@ pub async fn get_balance_info(
         number: client_at_block.block_number(),
     };
 
+    let chain_tokens = fetch_chain_tokens(&state, params.token.as_ref()).await;
     let raw_info = query_balance_info(
         &client_at_block,
         &state.chain_info.spec_name,
         &account,
         &resolved_block,
         params.token.clone(),
+        Some(&chain_tokens),
     )
     .await?;
 
-    let denominated = params.denominated.unwrap_or(false);
-    let response = format_response(&raw_info, denominated, None, None, None);
+    let response = format_response(&raw_info, params.denominated, None, None, None);
 
     Ok(Json(response).into_response())
 }
@@ -166,13 +169,15 @@ async fn handle_use_rc_block(
     let rc_block_number = rc_resolved.number.to_string();
 
     // Process all AH blocks concurrently
-    let denominated = params.denominated.unwrap_or(false);
+    let denominated = params.denominated;
+    let chain_tokens = fetch_chain_tokens(&state, params.token.as_ref()).await;
     let results = futures::future::try_join_all(ah_blocks.into_iter().map(|ah_block| {
         let state = &state;
         let account = &account;
         let params = &params;
         let rc_block_hash = &rc_block_hash;
         let rc_block_number = &rc_block_number;
+        let chain_tokens = Some(&chain_tokens);
         async move {
             let ah_resolved = utils::ResolvedBlock {
                 hash: ah_block.hash.clone(),
@@ -186,6 +191,7 @@ async fn handle_use_rc_block(
                 account,
                 &ah_resolved,
                 params.token.clone(),
+                chain_tokens,
             )
             .await?;
 
@@ -2,14 +2,20 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::types::{
-    AccountCompareQueryParams, AccountCompareResponse, AccountsError, AddressDetails,
+    AccountBalanceDelta, AccountBalanceDiff, AccountBalanceSnapshot, AccountCompareQueryParams,
+    AccountCompareResponse, AccountsError, AddressDetails, BlockInfo,
 };
-use super::utils::validate_address;
+use super::utils::{validate_address, validate_and_parse_address};
 use crate::extractors::JsonQuery;
+use crate::handlers::common::accounts::{DecodedAccountData, query_balance_info};
+use crate::state::AppState;
+use crate::utils;
 use axum::{
     Json,
+    extract::State,
     response::{IntoResponse, Response},
 };
+use sp_core::crypto::AccountId32;
 
 // ================================================================================================
 // Main Handler
@@ -23,18 +29,22 @@ use axum::{
 ///
 /// Query Parameters:
 /// - `addresses`: Comma-separated list of SS58 addresses to compare (max 30)
+/// - `at`, `at2` (optional): Two blocks to compare a single address's balance at
 ///
 /// Returns:
 /// - `areEqual`: Whether all addresses have the same underlying public key
 /// - `addresses`: Array of address details with ss58Format, ss58Prefix, network, publicKey
+/// - `balanceDiff`: Free/reserved/frozen balance at both blocks and the delta, when `at` and `at2` are given
 #[utoipa::path(
     get,
     path = "/v1/accounts/compare",
     tag = "accounts",
     summary = "Compare account addresses",
-    description = "Compares multiple SS58 addresses to determine if they have the same underlying public key.",
+    description = "Compares multiple SS58 addresses to determine if they have the same underlying public key. Given a single address with `at` and `at2`, also returns its free, reserved and frozen balance at both blocks and the change between them.",
     params(
-        ("addresses" = String, Query, description = "Comma-separated list of SS58 addresses to compare (max 30)")
+        ("addresses" = String, Query, description = "Comma-separated list of SS58 addresses to compare (max 30)"),
+        ("at" = Option<String>, Query, description = "First block hash or number to compare the balance at (requires `at2`)"),
+        ("at2" = Option<String>, Query, description = "Second block hash or number to compare the balance at (requires `at`)")
     ),
     responses(
         (status = 200, description = "Comparison result", body = AccountCompareResponse),
@@ -42,6 +52,7 @@ use axum::{
     )
 )]
 pub async fn get_compare(
+    State(state): State<AppState>,
     JsonQuery(params): JsonQuery<AccountCompareQueryParams>,
 ) -> Result<Response, AccountsError> {
     // Parse comma-separated addresses
@@ -77,10 +88,165 @@ pub async fn get_compare(
         }
     };
 
+    let balance_diff = match (&params.at, &params.at2) {
+        (None, None) => None,
+        (Some(at), Some(at2)) if addresses.len() == 1 => {
+            let account = validate_and_parse_address(addresses[0], state.chain_info.ss58_prefix)?;
+            let from = balance_snapshot(&state, &account, at).await?;
+            let to = balance_snapshot(&state, &account, at2).await?;
+            Some(diff_snapshots(from, to))
+        }
+        _ => return Err(AccountsError::InvalidBalanceDiffParams),
+    };
+
     let response = AccountCompareResponse {
         are_equal,
         addresses: address_details,
+        balance_diff,
     };
 
     Ok(Json(response).into_response())
 }
+
+// ================================================================================================
+// Balance Diff
+// ================================================================================================
+
+/// Balance amounts kept alongside the formatted snapshot so the delta can be computed.
+struct Snapshot {
+    amounts: [u128; 3],
+    formatted: AccountBalanceSnapshot,
+}
+
+async fn balance_snapshot(
+    state: &AppState,
+    account: &AccountId32,
+    at: &String,
+) -> Result<Snapshot, AccountsError> {
+    let client_at_block = utils::resolve_client_at_block(&state.client, Some(at)).await?;
+    let block = utils::ResolvedBlock {
+        hash: format!("{:#x}", client_at_block.block_hash()),
+        number: client_at_block.block_number(),
+    };
+    let raw = query_balance_info(
+        &client_at_block,
+        &state.chain_info.spec_name,
+        account,
+        &block,
+        None,
+        None,
+    )
+    .await?;
+
+    let data = &raw.account_data;
+    let amounts = [data.free, data.reserved, frozen_balance(data)];
+    Ok(Snapshot {
+        amounts,
+        formatted: AccountBalanceSnapshot {
+            at: BlockInfo {
+                hash: raw.block.hash,
+                height: raw.block.number.to_string(),
+            },
+            free: amounts[0].to_string(),
+            reserved: amounts[1].to_string(),
+            frozen: amounts[2].to_string(),
+        },
+    })
+}
+
+/// Runtimes before the holds/freezes model split the frozen balance into
+/// `miscFrozen` and `feeFrozen`; the larger of the two is what's frozen.
+fn frozen_balance(data: &DecodedAccountData) -> u128 {
+    data.frozen.unwrap_or_else(|| {
+        data.misc_frozen
+            .unwrap_or(0)
+            .max(data.fee_frozen.unwrap_or(0))
+    })
+}
+
+fn diff_snapshots(from: Snapshot, to: Snapshot) -> AccountBalanceDiff {
+    let [free, reserved, frozen] =
+        std::array::from_fn(|i| signed_delta(from.amounts[i], to.amounts[i]));
+    AccountBalanceDiff {
+        from: from.formatted,
+        to: to.formatted,
+        delta: AccountBalanceDelta {
+            free,
+            reserved,
+            frozen,
+        },
+    }
+}
+
+/// `to - from` as a decimal string; `u128` amounts can't be subtracted as `i128` safely.
+fn signed_delta(from: u128, to: u128) -> String {
+    if to >= from {
+        (to - from).to_string()
+    } else {
+        format!("-{}", from - to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(height: u64, free: u128, reserved: u128, frozen: u128) -> Snapshot {
+        Snapshot {
+            amounts: [free, reserved, frozen],
+            formatted: AccountBalanceSnapshot {
+                at: BlockInfo {
+                    hash: format!("0x{height:064x}"),
+                    height: height.to_string(),
+                },
+                free: free.to_string(),
+                reserved: reserved.to_string(),
+                frozen: frozen.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_signed_delta() {
+        assert_eq!(signed_delta(100, 250), "150");
+        assert_eq!(signed_delta(250, 100), "-150");
+        assert_eq!(signed_delta(7, 7), "0");
+        assert_eq!(signed_delta(u128::MAX, 0), format!("-{}", u128::MAX));
+    }
+
+    #[test]
+    fn test_diff_snapshots() {
+        let diff = diff_snapshots(snapshot(100, 1_000, 50, 0), snapshot(200, 900, 150, 80));
+
+        assert_eq!(diff.from.at.height, "100");
+        assert_eq!(diff.to.at.height, "200");
+        assert_eq!(
+            diff.delta,
+            AccountBalanceDelta {
+                free: "-100".to_string(),
+                reserved: "100".to_string(),
+                frozen: "80".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_frozen_balance_legacy_fields() {
+        let data = DecodedAccountData {
+            nonce: 0,
+            free: 100,
+            reserved: 0,
+            misc_frozen: Some(30),
+            fee_frozen: Some(40),
+            frozen: None,
+        };
+        assert_eq!(frozen_balance(&data), 40);
+        assert_eq!(
+            frozen_balance(&DecodedAccountData {
+                frozen: Some(25),
+                ..data
+            }),
+            25
+        );
+    }
+}
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::types::{AccountConvertQueryParams, AccountConvertResponse, AccountsError};
-use super::utils::get_network_name;
+use super::utils::{get_network_name, validate_and_parse_address};
 use crate::extractors::JsonQuery;
+use crate::utils::hex_with_prefix;
 use axum::{
     Json,
     extract::Path,
@@ -20,12 +21,14 @@ use sp_core::crypto::{AccountId32, Ss58AddressFormat, Ss58Codec};
 /// Converts an AccountId or Public Key (hex) to an SS58 address.
 ///
 /// Path Parameters:
-/// - `accountId`: The AccountId or Public Key as hex string (with or without 0x prefix)
+/// - `accountId`: The AccountId or Public Key as hex string (with or without 0x prefix),
+///   or an SS58 address of any network
 ///
 /// Query Parameters:
 /// - `scheme` (optional): Cryptographic scheme - "ed25519", "sr25519", or "ecdsa" (default: "sr25519")
 /// - `prefix` (optional): SS58 prefix number (default: 42)
 /// - `publicKey` (optional): If true, treat the input as a public key (default: false)
+/// - `toHex` (optional): If true, include the 0x-prefixed hex AccountId32 (default: false)
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/convert",
@@ -36,7 +39,8 @@ use sp_core::crypto::{AccountId32, Ss58AddressFormat, Ss58Codec};
         ("accountId" = String, Path, description = "SS58-encoded account address or hex public key"),
         ("scheme" = Option<String>, Query, description = "Cryptographic scheme: ed25519, sr25519, or ecdsa (default: sr25519)"),
         ("prefix" = Option<u16>, Query, description = "SS58 prefix number (default: 42)"),
-        ("publicKey" = Option<bool>, Query, description = "If true, treat input as a public key")
+        ("publicKey" = Option<bool>, Query, description = "If true, treat input as a public key"),
+        ("toHex" = Option<bool>, Query, description = "If true, include the 0x-prefixed hex AccountId32 in the response")
     ),
     responses(
         (status = 200, description = "Converted account information", body = AccountConvertResponse),
@@ -61,19 +65,21 @@ pub async fn get_convert(
     // Get prefix with default
     let prefix = params.prefix.unwrap_or(42);
 
-    // Validate that account_id is valid hex
+    // Accept hex input as-is; anything else must be an SS58 address
     let account_id_clean = account_id.trim_start_matches("0x");
-    if !is_valid_hex(account_id_clean) {
-        return Err(AccountsError::InvalidHexAccountId);
-    }
+    let (account_bytes, account_id_hex) = if is_valid_hex(account_id_clean) {
+        let bytes =
+            hex::decode(account_id_clean).map_err(|_| AccountsError::InvalidHexAccountId)?;
+        (bytes, format!("0x{}", account_id_clean))
+    } else {
+        let account = parse_ss58_input(&account_id)?;
+        let bytes: &[u8] = account.as_ref();
+        (bytes.to_vec(), hex_with_prefix(bytes))
+    };
 
     // Get the network name for this prefix
     let network = get_network_name(prefix).ok_or(AccountsError::InvalidPrefix)?;
 
-    // Decode the hex to bytes
-    let account_bytes =
-        hex::decode(account_id_clean).map_err(|_| AccountsError::InvalidHexAccountId)?;
-
     // For ecdsa with public key > 32 bytes, we need to hash it first
     let final_bytes = if params.public_key && scheme == "ecdsa" && account_bytes.len() > 32 {
         // Hash with blake2_256
@@ -99,13 +105,17 @@ pub async fn get_convert(
     let ss58_format = Ss58AddressFormat::custom(prefix);
     let address = account_id32.to_ss58check_with_version(ss58_format);
 
+    // The encoded AccountId32, which differs from the input for hashed ecdsa public keys
+    let public_key_hex = params.to_hex.then(|| hex_with_prefix(&account_id_bytes));
+
     let response = AccountConvertResponse {
         ss58_prefix: prefix,
         network,
         address,
-        account_id: format!("0x{}", account_id_clean),
+        account_id: account_id_hex,
         scheme: scheme.to_string(),
         public_key: params.public_key,
+        public_key_hex,
     };
 
     Ok(Json(response).into_response())
@@ -119,3 +129,89 @@ pub async fn get_convert(
 fn is_valid_hex(s: &str) -> bool {
     !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit())
 }
+
+/// Decode an SS58 address of any network, validated against its own prefix
+fn parse_ss58_input(address: &str) -> Result<AccountId32, AccountsError> {
+    let (_, version) = AccountId32::from_ss58check_with_version(address)
+        .map_err(|_| AccountsError::InvalidHexAccountId)?;
+    Ok(validate_and_parse_address(address, version.into())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    const ALICE_HEX: &str = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+    const ALICE_SS58: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    fn params(scheme: Option<&str>, public_key: bool, to_hex: bool) -> AccountConvertQueryParams {
+        AccountConvertQueryParams {
+            scheme: scheme.map(str::to_string),
+            prefix: Some(0),
+            public_key,
+            to_hex,
+        }
+    }
+
+    async fn convert(input: &str, params: AccountConvertQueryParams) -> serde_json::Value {
+        let response = get_convert(Path(input.to_string()), JsonQuery(params))
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_convert_to_hex_includes_public_key_hex() {
+        let json = convert(ALICE_HEX, params(None, false, true)).await;
+
+        assert_eq!(
+            json["address"],
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+        assert_eq!(json["publicKeyHex"], ALICE_HEX);
+    }
+
+    #[tokio::test]
+    async fn test_convert_omits_public_key_hex_by_default() {
+        let json = convert(ALICE_HEX, params(None, false, false)).await;
+
+        assert!(json.get("publicKeyHex").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_convert_ecdsa_public_key_reports_hashed_account_id() {
+        let public_key = "0x02509540919faacf9ab52146c9aa40db68172d83777250b28e4679176e49ccdd9f";
+        let json = convert(public_key, params(Some("ecdsa"), true, true)).await;
+
+        let public_key_bytes = hex::decode(public_key.trim_start_matches("0x")).unwrap();
+        let expected = hex_with_prefix(&sp_core::blake2_256(&public_key_bytes));
+        assert_eq!(json["accountId"], public_key);
+        assert_eq!(json["publicKeyHex"], expected);
+        assert_ne!(json["publicKeyHex"], json["accountId"]);
+    }
+
+    #[tokio::test]
+    async fn test_convert_accepts_ss58_input() {
+        let json = convert(ALICE_SS58, params(None, false, true)).await;
+
+        assert_eq!(
+            json["address"],
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
+        assert_eq!(json["accountId"], ALICE_HEX);
+        assert_eq!(json["publicKeyHex"], ALICE_HEX);
+    }
+
+    #[tokio::test]
+    async fn test_convert_rejects_invalid_input() {
+        let result = get_convert(
+            Path("not-an-address".to_string()),
+            JsonQuery(params(None, false, false)),
+        )
+        .await;
+
+        assert!(matches!(result, Err(AccountsError::InvalidHexAccountId)));
+    }
+}
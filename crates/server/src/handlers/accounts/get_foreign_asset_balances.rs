@@ -33,7 +33,8 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
-/// - `foreignAssets` (optional): List of multilocation JSON strings to filter by
+/// - `foreignAssets` (optional): Locations to query, as JSON or compact strings such as
+///   `1/Parachain(2011)`. Only these balances are fetched; invalid locations return 400
 /// - `showEmpty` (optional): When true, include assets with zero balance (default: false)
 #[utoipa::path(
     get,
@@ -45,7 +46,7 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
         ("accountId" = String, Path, description = "SS58-encoded account address"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
-        ("foreignAssets" = Option<Vec<String>>, Query, description = "List of multilocation JSON strings to filter by"),
+        ("foreignAssets" = Option<Vec<String>>, Query, description = "Locations to query instead of every registered foreign asset. Each is a JSON multilocation or a compact string like `2/GlobalConsensus(Ethereum(1))/AccountKey20(0x...)` or `1/Parachain(2011)/GeneralIndex(1)`. Invalid locations return 400"),
         ("showEmpty" = Option<bool>, Query, description = "When true, include assets with zero balance (default: false)")
     ),
     responses(
@@ -6,6 +6,7 @@ use super::types::{
 };
 use super::utils::{query_all_pool_assets_id, query_pool_assets, validate_and_parse_address};
 use crate::extractors::QsQuery;
+use crate::handlers::pallets::asset_conversion::fetch_pool_pairs_by_lp_token;
 use crate::state::AppState;
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block};
 use axum::{
@@ -31,6 +32,10 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
 /// - `assets` (optional): List of asset IDs to query (queries all if omitted)
 /// - `showEmpty` (optional): When true, include assets with zero balance (default: false)
+/// - `denominated` (optional): When true, format balances with each LP token's decimals and
+///   include its `symbol` and `decimals` (default: false)
+/// - `includePool` (optional): When true, include the asset pair of each LP token's pool
+///   (default: false)
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/pool-asset-balances",
@@ -42,7 +47,9 @@ use subxt::{OnlineClientAtBlock, SubstrateConfig};
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
         ("assets" = Option<String>, Query, description = "Comma-separated list of pool asset IDs to query"),
-        ("showEmpty" = Option<bool>, Query, description = "When true, include assets with zero balance (default: false)")
+        ("showEmpty" = Option<bool>, Query, description = "When true, include assets with zero balance (default: false)"),
+        ("denominated" = Option<bool>, Query, description = "When true, format each balance using the LP token's metadata decimals and include its `symbol` and `decimals` (default: false)"),
+        ("includePool" = Option<bool>, Query, description = "When true, include the asset pair of each LP token's AssetConversion pool as `pool` (default: false)")
     ),
     responses(
         (status = 200, description = "Pool asset balances", body = PoolAssetBalancesResponse),
@@ -71,16 +78,8 @@ pub async fn get_pool_asset_balances(
 
     let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
 
-    let assets = params.assets.as_deref().unwrap_or(&[]);
-    let show_empty = params.show_empty;
-    let response = query_pool_asset_balances(
-        &client_at_block,
-        &account,
-        &resolved_block,
-        assets,
-        show_empty,
-    )
-    .await?;
+    let response =
+        query_pool_asset_balances(&client_at_block, &account, &resolved_block, &params).await?;
 
     Ok(Json(response).into_response())
 }
@@ -89,8 +88,7 @@ async fn query_pool_asset_balances(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
     block: &utils::ResolvedBlock,
-    asset_ids: &[u32],
-    show_empty: bool,
+    params: &PoolAssetBalancesQueryParams,
 ) -> Result<PoolAssetBalancesResponse, AccountsError> {
     let storage_query = ("PoolAssets", "Account");
 
@@ -101,6 +99,7 @@ async fn query_pool_asset_balances(
     }
 
     // Determine which assets to query
+    let asset_ids = params.assets.as_deref().unwrap_or(&[]);
     let assets_to_query = if asset_ids.is_empty() {
         // Query all pool asset IDs
         let assets = query_all_pool_assets_id(client_at_block).await;
@@ -116,8 +115,26 @@ async fn query_pool_asset_balances(
     };
 
     // Query each pool asset balance in parallel
-    let pool_assets =
-        query_pool_assets(client_at_block, account, &assets_to_query, show_empty).await?;
+    let mut pool_assets = query_pool_assets(
+        client_at_block,
+        account,
+        &assets_to_query,
+        params.show_empty,
+        params.denominated,
+    )
+    .await?;
+
+    if params.include_pool {
+        // Without the AssetConversion pallet there are no pairs to link
+        let pairs = fetch_pool_pairs_by_lp_token(client_at_block).await?;
+        for pool_asset in &mut pool_assets {
+            pool_asset.pool = pool_asset
+                .asset_id
+                .parse::<u32>()
+                .ok()
+                .and_then(|id| pairs.get(&id).cloned());
+        }
+    }
 
     Ok(PoolAssetBalancesResponse {
         at: BlockInfo {
@@ -151,7 +168,8 @@ async fn handle_use_rc_block(
     // Resolve RC block
     let rc_block_id = params
         .at
-        .unwrap_or_else(|| "head".to_string())
+        .as_deref()
+        .unwrap_or("head")
         .parse::<utils::BlockId>()?;
     let rc_resolved =
         utils::resolve_block_with_rpc(&rc_rpc_client, &rc_rpc, Some(rc_block_id)).await?;
@@ -167,8 +185,6 @@ async fn handle_use_rc_block(
     let rc_block_number = rc_resolved.number.to_string();
 
     // Process each AH block
-    let assets = params.assets.as_deref().unwrap_or(&[]);
-    let show_empty = params.show_empty;
     let mut results = Vec::new();
     for ah_block in ah_blocks {
         let ah_resolved = utils::ResolvedBlock {
@@ -178,8 +194,7 @@ async fn handle_use_rc_block(
 
         let client_at_block = state.client.at_block(ah_resolved.number).await?;
         let mut response =
-            query_pool_asset_balances(&client_at_block, &account, &ah_resolved, assets, show_empty)
-                .await?;
+            query_pool_asset_balances(&client_at_block, &account, &ah_resolved, &params).await?;
 
         // Add RC block info
         response.rc_block_hash = Some(rc_block_hash.clone());
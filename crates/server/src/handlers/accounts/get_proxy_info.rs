@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::types::{
-    AccountsError, BlockInfo, ProxyDefinition, ProxyInfoQueryParams, ProxyInfoResponse,
+    AccountsError, BlockInfo, ProxyDefinition, ProxyDelegator, ProxyDelegatorsResponse,
+    ProxyInfoQueryParams, ProxyInfoResponse,
 };
 use super::utils::validate_and_parse_address;
 use crate::extractors::JsonQuery;
-use crate::handlers::common::accounts::{RawProxyInfo, query_proxy_info};
+use crate::handlers::common::accounts::{
+    RawProxyDelegators, RawProxyInfo, query_proxy_delegators, query_proxy_info,
+};
 use crate::state::AppState;
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block};
 use axum::{
@@ -15,8 +18,43 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use polkadot_rest_api_config::ChainType;
+use serde::Serialize;
 use serde_json::json;
 use sp_core::crypto::AccountId32;
+use std::time::Duration;
+use subxt::{OnlineClientAtBlock, SubstrateConfig};
+
+/// Longest time spent scanning `Proxy::Proxies` for `asDelegate=true` before
+/// returning partial results.
+const MAX_DELEGATOR_SCAN_TIME: Duration = Duration::from_secs(20);
+
+/// Proxy info for one block: the account's proxies, or its delegators with `asDelegate=true`
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum ProxyInfo {
+    Proxies(ProxyInfoResponse),
+    Delegators(ProxyDelegatorsResponse),
+}
+
+impl ProxyInfo {
+    fn set_rc_block(&mut self, hash: String, number: String, ah_timestamp: Option<String>) {
+        let (rc_block_hash, rc_block_number, ts) = match self {
+            ProxyInfo::Proxies(r) => (
+                &mut r.rc_block_hash,
+                &mut r.rc_block_number,
+                &mut r.ah_timestamp,
+            ),
+            ProxyInfo::Delegators(r) => (
+                &mut r.rc_block_hash,
+                &mut r.rc_block_number,
+                &mut r.ah_timestamp,
+            ),
+        };
+        *rc_block_hash = Some(hash);
+        *rc_block_number = Some(number);
+        *ts = ah_timestamp;
+    }
+}
 
 // ================================================================================================
 // Main Handler
@@ -29,6 +67,13 @@ use sp_core::crypto::AccountId32;
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
+/// - `asDelegate` (optional): When true, list the accounts that added this account as a proxy
+/// - `ss58Format` (optional): SS58 prefix to encode returned accounts with
+///
+/// `asDelegate=true` has no storage index to use: it scans every `Proxy::Proxies` entry,
+/// costing one `state_getKeysPaged` and one `state_queryStorageAt` call per 1000 proxied
+/// accounts. The scan is capped at `MAX_DELEGATOR_SCAN_TIME`, after which the delegators
+/// found so far are returned with `scanComplete: false`.
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/proxy-info",
@@ -38,10 +83,12 @@ use sp_core::crypto::AccountId32;
     params(
         ("accountId" = String, Path, description = "SS58-encoded account address"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("asDelegate" = Option<bool>, Query, description = "List the accounts that delegated to this account instead. Scans all proxy storage, so it is slow and may return partial results (`scanComplete: false`)"),
+        ("ss58Format" = Option<u16>, Query, description = "SS58 prefix to encode returned accounts with (defaults to the chain's)")
     ),
     responses(
-        (status = 200, description = "Proxy information", body = ProxyInfoResponse),
+        (status = 200, description = "Proxy information, or `ProxyDelegatorsResponse` with asDelegate=true", body = ProxyInfoResponse),
         (status = 400, description = "Invalid parameters"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -53,9 +100,11 @@ pub async fn get_proxy_info(
     JsonQuery(params): JsonQuery<ProxyInfoQueryParams>,
 ) -> Result<Response, AccountsError> {
     let account = validate_and_parse_address(&account_id, state.chain_info.ss58_prefix)?;
+    let ss58_prefix =
+        utils::response_ss58_prefix(params.ss58_format, state.chain_info.ss58_prefix)?;
 
     if params.use_rc_block {
-        return handle_use_rc_block(state, account, params).await;
+        return handle_use_rc_block(state, account, params, ss58_prefix).await;
     }
 
     let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
@@ -64,29 +113,50 @@ pub async fn get_proxy_info(
         number: client_at_block.block_number(),
     };
 
-    let raw_info = query_proxy_info(
+    let response = query_at_block(
+        &state,
         &client_at_block,
         &account,
         &resolved_block,
-        state.chain_info.ss58_prefix,
+        params.as_delegate,
+        ss58_prefix,
     )
     .await?;
 
-    let response = format_response(&raw_info, None, None, None);
-
     Ok(Json(response).into_response())
 }
 
+/// Query the account's proxies, or its delegators when `as_delegate` is set.
+async fn query_at_block(
+    state: &AppState,
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    account: &AccountId32,
+    block: &utils::ResolvedBlock,
+    as_delegate: bool,
+    ss58_prefix: u16,
+) -> Result<ProxyInfo, AccountsError> {
+    if as_delegate {
+        let raw = query_proxy_delegators(
+            client_at_block,
+            &state.rpc_client,
+            account,
+            block,
+            ss58_prefix,
+            MAX_DELEGATOR_SCAN_TIME,
+        )
+        .await?;
+        Ok(ProxyInfo::Delegators(format_delegators_response(&raw)))
+    } else {
+        let raw = query_proxy_info(client_at_block, account, block, ss58_prefix).await?;
+        Ok(ProxyInfo::Proxies(format_response(&raw)))
+    }
+}
+
 // ================================================================================================
 // Response Formatting
 // ================================================================================================
 
-fn format_response(
-    raw: &RawProxyInfo,
-    rc_block_hash: Option<String>,
-    rc_block_number: Option<String>,
-    ah_timestamp: Option<String>,
-) -> ProxyInfoResponse {
+fn format_response(raw: &RawProxyInfo) -> ProxyInfoResponse {
     let delegated_accounts = raw
         .delegated_accounts
         .iter()
@@ -104,9 +174,33 @@ fn format_response(
         },
         delegated_accounts,
         deposit_held: raw.deposit_held.clone(),
-        rc_block_hash,
-        rc_block_number,
-        ah_timestamp,
+        rc_block_hash: None,
+        rc_block_number: None,
+        ah_timestamp: None,
+    }
+}
+
+fn format_delegators_response(raw: &RawProxyDelegators) -> ProxyDelegatorsResponse {
+    let delegators = raw
+        .delegators
+        .iter()
+        .map(|d| ProxyDelegator {
+            delegator: d.delegator.clone(),
+            proxy_type: d.proxy_type.clone(),
+            delay: d.delay.clone(),
+        })
+        .collect();
+
+    ProxyDelegatorsResponse {
+        at: BlockInfo {
+            hash: raw.block.hash.clone(),
+            height: raw.block.number.to_string(),
+        },
+        delegators,
+        scan_complete: raw.scan_complete,
+        rc_block_hash: None,
+        rc_block_number: None,
+        ah_timestamp: None,
     }
 }
 
@@ -118,6 +212,7 @@ async fn handle_use_rc_block(
     state: AppState,
     account: AccountId32,
     params: ProxyInfoQueryParams,
+    ss58_prefix: u16,
 ) -> Result<Response, AccountsError> {
     // Validate Asset Hub
     if state.chain_info.chain_type != ChainType::AssetHub {
@@ -128,6 +223,7 @@ async fn handle_use_rc_block(
     let rc_rpc = state.get_relay_chain_rpc().await?;
 
     // Resolve RC block
+    let as_delegate = params.as_delegate;
     let rc_block_id = params
         .at
         .unwrap_or_else(|| "head".to_string())
@@ -157,18 +253,19 @@ async fn handle_use_rc_block(
                 number: ah_block.number,
             };
             let client_at_block = state.client.at_block(ah_resolved.number).await?;
-            let raw_info = query_proxy_info(
+            let mut response = query_at_block(
+                state,
                 &client_at_block,
                 account,
                 &ah_resolved,
-                state.chain_info.ss58_prefix,
+                as_delegate,
+                ss58_prefix,
             )
             .await?;
 
-            let response = format_response(
-                &raw_info,
-                Some(rc_block_hash.clone()),
-                Some(rc_block_number.clone()),
+            response.set_rc_block(
+                rc_block_hash.clone(),
+                rc_block_number.clone(),
                 fetch_block_timestamp(&client_at_block).await,
             );
 
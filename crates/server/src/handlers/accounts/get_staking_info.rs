@@ -32,6 +32,7 @@ use sp_core::crypto::AccountId32;
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `useRcBlock` (optional): When true, treat 'at' as relay chain block identifier
+/// - `ss58Format` (optional): SS58 prefix to encode returned accounts with
 #[utoipa::path(
     get,
     path = "/v1/accounts/{accountId}/staking-info",
@@ -42,7 +43,8 @@ use sp_core::crypto::AccountId32;
         ("accountId" = String, Path, description = "SS58-encoded stash account address"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
-        ("includeClaimedRewards" = Option<bool>, Query, description = "When true, include claimed rewards in the response")
+        ("includeClaimedRewards" = Option<bool>, Query, description = "When true, include claimed rewards in the response"),
+        ("ss58Format" = Option<u16>, Query, description = "SS58 prefix to encode returned accounts with (defaults to the chain's)")
     ),
     responses(
         (status = 200, description = "Staking information", body = StakingInfoResponse),
@@ -57,9 +59,11 @@ pub async fn get_staking_info(
     JsonQuery(params): JsonQuery<StakingInfoQueryParams>,
 ) -> Result<Response, AccountsError> {
     let account = validate_and_parse_address(&account_id, state.chain_info.ss58_prefix)?;
+    let ss58_prefix =
+        utils::response_ss58_prefix(params.ss58_format, state.chain_info.ss58_prefix)?;
 
     if params.use_rc_block {
-        return handle_use_rc_block(state, account, params).await;
+        return handle_use_rc_block(state, account, params, ss58_prefix).await;
     }
 
     let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
@@ -73,7 +77,7 @@ pub async fn get_staking_info(
         &account,
         &resolved_block,
         params.include_claimed_rewards,
-        state.chain_info.ss58_prefix,
+        ss58_prefix,
         &state.chain_info.spec_name,
     )
     .await?;
@@ -140,6 +144,16 @@ fn format_response(
         active: raw.staking.active.clone(),
         unlocking,
         claimed_rewards,
+        ledger_claimed_rewards: raw
+            .staking
+            .ledger_claimed_rewards
+            .as_ref()
+            .map(|claimed| claimed.eras.iter().map(|era| era.to_string()).collect()),
+        ledger_claimed_rewards_source: raw
+            .staking
+            .ledger_claimed_rewards
+            .as_ref()
+            .map(|claimed| claimed.source.as_str().to_string()),
     };
 
     StakingInfoResponse {
@@ -149,6 +163,7 @@ fn format_response(
         },
         controller: raw.controller.clone(),
         reward_destination,
+        payee_account: raw.payee_account.clone(),
         num_slashing_spans: raw.num_slashing_spans.to_string(),
         nominations,
         staking,
@@ -166,6 +181,7 @@ async fn handle_use_rc_block(
     state: AppState,
     account: AccountId32,
     params: StakingInfoQueryParams,
+    ss58_prefix: u16,
 ) -> Result<Response, AccountsError> {
     // Validate Asset Hub
     if state.chain_info.chain_type != ChainType::AssetHub {
@@ -211,7 +227,7 @@ async fn handle_use_rc_block(
                 account,
                 &ah_resolved,
                 include_claimed_rewards,
-                state.chain_info.ss58_prefix,
+                ss58_prefix,
                 &state.chain_info.spec_name,
             )
             .await?;
@@ -20,7 +20,7 @@ use axum::{
 };
 use polkadot_rest_api_config::ChainType;
 use serde_json::json;
-use sp_core::crypto::AccountId32;
+use sp_core::crypto::{AccountId32, Ss58Codec};
 
 // ================================================================================================
 // Main Handler
@@ -41,9 +41,9 @@ use sp_core::crypto::AccountId32;
     path = "/v1/accounts/{accountId}/staking-payouts",
     tag = "accounts",
     summary = "Account staking payouts",
-    description = "Returns staking payout history for a given account including era rewards and claimed status.",
+    description = "Returns staking payout history for a given account including era rewards and claimed status. The account may be a stash or a controller; a controller is resolved to its stash, and `accountType` reports which one was given.",
     params(
-        ("accountId" = String, Path, description = "SS58-encoded stash account address"),
+        ("accountId" = String, Path, description = "SS58-encoded stash or controller account address"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("depth" = Option<String>, Query, description = "Number of eras to query (default: 1)"),
         ("era" = Option<String>, Query, description = "The era to query at (default: active_era - 1)"),
@@ -97,7 +97,7 @@ pub async fn get_staking_payouts(
     )
     .await?;
 
-    let response = format_response(&raw_payouts, None, None, None);
+    let response = format_response(&raw_payouts, state.chain_info.ss58_prefix, None, None, None);
 
     Ok(Json(response).into_response())
 }
@@ -121,6 +121,7 @@ async fn create_relay_client_for_migration(
 
 fn format_response(
     raw: &RawStakingPayouts,
+    ss58_prefix: u16,
     rc_block_hash: Option<String>,
     rc_block_number: Option<String>,
     ah_timestamp: Option<String>,
@@ -158,6 +159,11 @@ fn format_response(
             hash: raw.block.hash.clone(),
             height: raw.block.number.to_string(),
         },
+        account_type: raw.account_type.map(|t| t.as_str().to_string()),
+        stash: raw
+            .account_type
+            .map(|_| raw.stash.to_ss58check_with_version(ss58_prefix.into())),
+        history_depth: raw.history_depth.to_string(),
         eras_payouts,
         rc_block_hash,
         rc_block_number,
@@ -231,6 +237,7 @@ async fn handle_use_rc_block(
 
         let response = format_response(
             &raw_payouts,
+            state.chain_info.ss58_prefix,
             Some(rc_block_hash.clone()),
             Some(rc_block_number.clone()),
             fetch_block_timestamp(&client_at_block).await,
@@ -90,6 +90,8 @@ fn format_response(
             locked: s.locked.clone(),
             per_block: s.per_block.clone(),
             starting_block: s.starting_block.clone(),
+            unlocked_at: s.unlocked_at.clone(),
+            currently_vested: s.currently_vested.clone(),
         })
         .collect();
 
@@ -99,6 +101,9 @@ fn format_response(
             height: raw.block.number.to_string(),
         },
         vesting: schedules,
+        total_locked: raw.total_locked.clone(),
+        total_vested: raw.total_vested.clone(),
+        total_remaining: raw.total_remaining.clone(),
         rc_block_hash,
         rc_block_number,
         ah_timestamp,
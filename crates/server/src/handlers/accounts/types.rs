@@ -4,7 +4,10 @@
 //! Types for account-related handlers.
 
 use super::utils::AddressValidationError;
-use crate::handlers::common::accounts::StakingPayoutsQueryError;
+use crate::handlers::common::accounts::{
+    BalanceQueryError, ProxyQueryError, StakingPayoutsQueryError,
+};
+use crate::handlers::runtime_queries::tokens::TokensStorageError;
 use crate::state::RelayChainError;
 use crate::utils::{self, RcBlockError};
 use axum::{Json, http::StatusCode, response::IntoResponse};
@@ -40,13 +43,93 @@ pub struct AssetBalancesQueryParams {
     pub use_rc_block: bool,
 
     /// Optional list of asset IDs to query (queries all if omitted).
-    /// Use PHP-style bracket notation: `?assets[]=1984&assets[]=2000`
-    #[serde(default)]
+    /// Accepts PHP-style bracket notation (`?assets[]=1984&assets[]=2000`)
+    /// or a comma-separated list (`?assets=1984,2000`).
+    #[serde(default, deserialize_with = "deserialize_asset_ids")]
     pub assets: Option<Vec<u32>>,
 
     /// When true, include assets with zero balance. Defaults to false.
     #[serde(default)]
     pub show_empty: bool,
+
+    /// When true, include per-asset query failures in the response. Defaults to false.
+    #[serde(default)]
+    pub show_errors: bool,
+
+    /// When true, format balances using each asset's decimals and include its
+    /// `symbol` and `decimals`. Defaults to false.
+    #[serde(default)]
+    pub denominated: bool,
+}
+
+/// Deserialize asset IDs from either a list or a comma-separated string.
+///
+/// Each ID must parse as a `u32`; the offending value is named in the error otherwise.
+fn deserialize_asset_ids<'de, D>(deserializer: D) -> Result<Option<Vec<u32>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct AssetIdsVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for AssetIdsVisitor {
+        type Value = Vec<u32>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a list or comma-separated string of asset IDs")
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            parse_asset_id(&v.to_string()).map(|id| vec![id])
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(parse_asset_id)
+                .collect()
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+            self,
+            mut seq: A,
+        ) -> Result<Self::Value, A::Error> {
+            let mut ids = Vec::new();
+            while let Some(raw) = seq.next_element::<serde_json::Value>()? {
+                let raw = match raw {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                ids.push(parse_asset_id(raw.trim())?);
+            }
+            Ok(ids)
+        }
+    }
+
+    fn parse_asset_id<E: serde::de::Error>(raw: &str) -> Result<u32, E> {
+        raw.parse::<u32>()
+            .map_err(|_| E::custom(format!("Invalid asset ID '{raw}': expected a u32")))
+    }
+
+    deserializer.deserialize_any(AssetIdsVisitor).map(Some)
+}
+
+/// Deserialize either a single string or a list of strings.
+fn deserialize_string_or_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrList {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match StringOrList::deserialize(deserializer)? {
+        StringOrList::One(value) => vec![value],
+        StringOrList::Many(values) => values,
+    })
 }
 
 // ================================================================================================
@@ -60,6 +143,10 @@ pub struct AssetBalancesResponse {
     pub at: BlockInfo,
     pub assets: Vec<AssetBalance>,
 
+    /// Assets whose balance could not be queried (only present when showErrors=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<AssetBalanceError>>,
+
     // Only present when useRcBlock=true
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rc_block_hash: Option<String>,
@@ -87,6 +174,29 @@ pub struct AssetBalance {
     pub balance: String,
     pub is_frozen: bool,
     pub is_sufficient: bool,
+    /// Account status: `Liquid`, `Frozen` or `Blocked`. Omitted for assets the account doesn't hold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Why the account exists: `Consumer`, `Sufficient`, `DepositHeld`, `DepositRefunded`
+    /// or `DepositFrom`. Omitted for assets the account doesn't hold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Asset symbol from its metadata (only present when denominated=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Asset decimals from its metadata (only present when denominated=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<String>,
+}
+
+/// Asset whose balance query failed
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetBalanceError {
+    /// Asset ID as string (matches Sidecar format)
+    pub asset_id: String,
+    /// Why the balance could not be determined
+    pub reason: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -116,8 +226,15 @@ pub struct AssetApprovalQueryParams {
     /// The asset ID to query approval for (required)
     pub asset_id: u32,
 
-    /// The delegate address with spending approval (required)
-    pub delegate: String,
+    /// The delegate address with spending approval. When omitted, all approvals
+    /// granted for the asset are listed
+    pub delegate: Option<String>,
+
+    /// Maximum number of approvals to list (default 100, at most 1000)
+    pub limit: Option<usize>,
+
+    /// Number of approvals to skip when listing (default 0)
+    pub offset: Option<usize>,
 }
 
 /// Response for GET /accounts/{accountId}/asset-approvals
@@ -141,6 +258,43 @@ pub struct AssetApprovalResponse {
     pub ah_timestamp: Option<String>,
 }
 
+/// A single approval in the asset approvals list
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetApprovalEntry {
+    /// The delegate address with spending approval
+    pub delegate: String,
+    pub amount: String,
+    pub deposit: String,
+}
+
+/// Response for GET /accounts/{accountId}/asset-approvals without `delegate`
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetApprovalsListResponse {
+    pub at: BlockInfo,
+
+    /// Approvals in this page, ordered by delegate account
+    pub approvals: Vec<AssetApprovalEntry>,
+
+    /// Total number of approvals the account granted for the asset
+    pub total: String,
+
+    /// Applied page size
+    pub limit: String,
+
+    /// Applied number of skipped approvals
+    pub offset: String,
+
+    // Only present when useRcBlock=true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ah_timestamp: Option<String>,
+}
+
 /// Decoded asset approval data
 #[derive(Debug, Clone)]
 pub struct DecodedAssetApproval {
@@ -167,6 +321,12 @@ pub enum AccountsError {
     #[error("Invalid delegate address: {0}")]
     InvalidDelegateAddress(String),
 
+    #[error("{0}")]
+    InvalidSs58Format(#[from] utils::InvalidSs58Format),
+
+    #[error("{0}")]
+    InvalidPagination(String),
+
     #[error("The runtime does not include the {0} pallet at this block")]
     PalletNotAvailable(String),
 
@@ -190,15 +350,15 @@ pub enum AccountsError {
     RcBlockMappingFailed(#[from] RcBlockError),
 
     // ---- Balance-specific errors ----
-    #[error("Invalid use of denominated parameter: this chain doesn't have valid decimals")]
-    InvalidDenominatedParam,
-
     #[error("Invalid token: {0}")]
     InvalidToken(String),
 
     #[error("Balance query failed: {0}")]
     BalanceQueryFailed(Box<crate::handlers::common::accounts::BalanceQueryError>),
 
+    #[error("Assets query failed: {0}")]
+    AssetsQueryFailed(Box<crate::handlers::runtime_queries::assets::AssetsStorageError>),
+
     // ---- Proxy-specific errors ----
     #[error("Proxy query failed: {0}")]
     ProxyQueryFailed(Box<crate::handlers::common::accounts::ProxyQueryError>),
@@ -210,11 +370,11 @@ pub enum AccountsError {
     #[error("Staking payouts query failed: {0}")]
     StakingPayoutsQueryFailed(Box<StakingPayoutsQueryError>),
 
-    #[error("Invalid era: requested era {0} is beyond history depth")]
-    InvalidEra(u32),
+    #[error("{0}")]
+    InvalidEra(String),
 
-    #[error("Depth must be greater than 0 and less than history depth")]
-    InvalidDepth,
+    #[error("{0}")]
+    InvalidDepth(String),
 
     #[error("No active era found")]
     NoActiveEra,
@@ -254,6 +414,11 @@ pub enum AccountsError {
     #[error("At least one address is required")]
     NoAddresses,
 
+    #[error(
+        "Comparing balances across blocks requires both `at` and `at2` and exactly one address"
+    )]
+    InvalidBalanceDiffParams,
+
     // ---- Foreign asset errors ----
     #[error("Invalid foreign asset multilocation: {0}")]
     InvalidForeignAsset(String),
@@ -306,6 +471,12 @@ impl From<crate::handlers::common::accounts::BalanceQueryError> for AccountsErro
     }
 }
 
+impl From<crate::handlers::runtime_queries::assets::AssetsStorageError> for AccountsError {
+    fn from(err: crate::handlers::runtime_queries::assets::AssetsStorageError) -> Self {
+        AccountsError::AssetsQueryFailed(Box::new(err))
+    }
+}
+
 impl From<crate::handlers::common::accounts::ProxyQueryError> for AccountsError {
     fn from(err: crate::handlers::common::accounts::ProxyQueryError) -> Self {
         AccountsError::ProxyQueryFailed(Box::new(err))
@@ -339,8 +510,13 @@ impl From<StakingPayoutsQueryError> for AccountsError {
                 AccountsError::PalletNotAvailable("Staking".to_string())
             }
             StakingPayoutsQueryError::NoActiveEra => AccountsError::NoActiveEra,
-            StakingPayoutsQueryError::InvalidEra(era) => AccountsError::InvalidEra(era),
-            StakingPayoutsQueryError::InvalidDepth => AccountsError::InvalidDepth,
+            err @ (StakingPayoutsQueryError::EraNotCompleted { .. }
+            | StakingPayoutsQueryError::EraBeyondHistoryDepth { .. }) => {
+                AccountsError::InvalidEra(err.to_string())
+            }
+            err @ StakingPayoutsQueryError::InvalidDepth { .. } => {
+                AccountsError::InvalidDepth(err.to_string())
+            }
             StakingPayoutsQueryError::BadStakingBlock(msg) => AccountsError::BadStakingBlock(msg),
             StakingPayoutsQueryError::RelayChainConnectionRequired => {
                 AccountsError::RelayChainConnectionRequired
@@ -356,21 +532,23 @@ impl IntoResponse for AccountsError {
             AccountsError::InvalidBlockParam(_)
             | AccountsError::InvalidAddress(_)
             | AccountsError::InvalidDelegateAddress(_)
+            | AccountsError::InvalidPagination(_)
             | AccountsError::PalletNotAvailable(_)
             | AccountsError::UseRcBlockNotSupported
-            | AccountsError::InvalidDenominatedParam
             | AccountsError::InvalidToken(_)
             | AccountsError::InvalidEra(_)
-            | AccountsError::InvalidDepth
+            | AccountsError::InvalidDepth(_)
             | AccountsError::NoActiveEra
             | AccountsError::BadStakingBlock(_)
             | AccountsError::RelayChainConnectionRequired
             | AccountsError::NotAStashAccount
             | AccountsError::InvalidHexAccountId
             | AccountsError::InvalidPrefix
+            | AccountsError::InvalidSs58Format(_)
             | AccountsError::InvalidScheme
             | AccountsError::TooManyAddresses
             | AccountsError::NoAddresses
+            | AccountsError::InvalidBalanceDiffParams
             | AccountsError::InvalidForeignAsset(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             AccountsError::RelayChain(RelayChainError::NotConfigured) => {
                 (StatusCode::BAD_REQUEST, self.to_string())
@@ -389,6 +567,20 @@ impl IntoResponse for AccountsError {
                     (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
                 }
             }
+            AccountsError::BalanceQueryFailed(inner) => match inner.as_ref() {
+                BalanceQueryError::TokenQueryFailed(TokensStorageError::FetchFailed {
+                    source,
+                    ..
+                }) if utils::is_storage_disconnected_error(source) => (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "Service temporarily unavailable".to_string(),
+                ),
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
+            },
+            AccountsError::ProxyQueryFailed(inner) => match inner.as_ref() {
+                ProxyQueryError::ScanFailed(err) => utils::rpc_error_to_status(err),
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
+            },
             AccountsError::RcBlockMappingFailed(inner)
                 if matches!(inner, RcBlockError::BlockNotFound(_)) =>
             {
@@ -418,8 +610,13 @@ pub struct BalanceInfoQueryParams {
     /// Token symbol for chains with multiple tokens (ORML). Defaults to native token.
     pub token: Option<String>,
 
-    /// When true, denominate balances using chain decimals
-    pub denominated: Option<bool>,
+    /// Denominate balances using chain decimals (default: true)
+    #[serde(default = "default_denominated")]
+    pub denominated: bool,
+}
+
+fn default_denominated() -> bool {
+    true
 }
 
 /// Response for GET /accounts/{accountId}/balance-info
@@ -522,6 +719,15 @@ pub struct PoolAssetBalancesQueryParams {
     /// When true, include assets with zero balance. Defaults to false.
     #[serde(default)]
     pub show_empty: bool,
+
+    /// When true, format balances using each LP token's decimals and include its
+    /// `symbol` and `decimals`. Defaults to false.
+    #[serde(default)]
+    pub denominated: bool,
+
+    /// When true, include the asset pair of each LP token's pool. Defaults to false.
+    #[serde(default)]
+    pub include_pool: bool,
 }
 
 /// Response for GET /accounts/{accountId}/pool-asset-balances
@@ -550,6 +756,16 @@ pub struct PoolAssetBalance {
     pub balance: String,
     pub is_frozen: bool,
     pub is_sufficient: bool,
+    /// LP token symbol from its metadata (only present when denominated=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// LP token decimals from its metadata (only present when denominated=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<String>,
+    /// Asset pair of the LP token's AssetConversion pool (only present when includePool=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub pool: Option<serde_json::Value>,
 }
 
 // ================================================================================================
@@ -617,6 +833,10 @@ pub struct AccountConvertQueryParams {
     /// If true, treat the input as a public key (default: false)
     #[serde(default)]
     pub public_key: bool,
+
+    /// If true, include the 0x-prefixed hex AccountId32 in the response (default: false)
+    #[serde(default)]
+    pub to_hex: bool,
 }
 
 /// Response for GET /accounts/{accountId}/convert
@@ -640,6 +860,10 @@ pub struct AccountConvertResponse {
 
     /// Whether the input was treated as a public key
     pub public_key: bool,
+
+    /// The 0x-prefixed hex AccountId32 the address encodes (only when `toHex=true`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_hex: Option<String>,
 }
 
 // ================================================================================================
@@ -656,6 +880,13 @@ pub struct ProxyInfoQueryParams {
     /// When true, treat 'at' as relay chain block identifier
     #[serde(default)]
     pub use_rc_block: bool,
+
+    /// When true, list the accounts that delegated to this account instead
+    #[serde(default)]
+    pub as_delegate: bool,
+
+    /// SS58 prefix to encode returned accounts with, instead of the chain's
+    pub ss58_format: Option<u16>,
 }
 
 /// Response for GET /accounts/{accountId}/proxy-info
@@ -693,6 +924,41 @@ pub struct ProxyDefinition {
     pub delay: String,
 }
 
+/// Response for GET /accounts/{accountId}/proxy-info?asDelegate=true
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyDelegatorsResponse {
+    pub at: BlockInfo,
+
+    /// Proxy definitions other accounts have set with this account as the delegate
+    pub delegators: Vec<ProxyDelegator>,
+
+    /// False if the storage scan hit its time limit and `delegators` may be incomplete
+    pub scan_complete: bool,
+
+    // Only present when useRcBlock=true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ah_timestamp: Option<String>,
+}
+
+/// An account that added the queried account as a proxy
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyDelegator {
+    /// The account that delegated to the queried account
+    pub delegator: String,
+
+    /// The type of proxy (e.g., "Any", "Staking", "Governance", etc.)
+    pub proxy_type: String,
+
+    /// The announcement delay in blocks
+    pub delay: String,
+}
+
 // ================================================================================================
 // Staking Info Types
 // ================================================================================================
@@ -711,6 +977,9 @@ pub struct StakingInfoQueryParams {
     /// When true, include claimed rewards in the response
     #[serde(default)]
     pub include_claimed_rewards: bool,
+
+    /// SS58 prefix to encode returned accounts with, instead of the chain's
+    pub ss58_format: Option<u16>,
 }
 
 /// Response for GET /accounts/{accountId}/staking-info
@@ -725,6 +994,10 @@ pub struct StakingInfoResponse {
     /// Reward destination configuration
     pub reward_destination: RewardDestination,
 
+    /// SS58 address that receives staking rewards (absent when rewards are not paid out)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payee_account: Option<String>,
+
     /// Number of slashing spans
     pub num_slashing_spans: String,
 
@@ -792,6 +1065,16 @@ pub struct StakingLedger {
     /// Claimed rewards per era (only when includeClaimedRewards=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub claimed_rewards: Option<Vec<ClaimedReward>>,
+
+    /// Eras recorded as claimed in the ledger itself. Omitted when the runtime's
+    /// ledger no longer carries them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_claimed_rewards: Option<Vec<String>>,
+
+    /// Ledger field `ledgerClaimedRewards` was read from: `claimedRewards` on older
+    /// runtimes, `legacyClaimedRewards` once paged staking renamed it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ledger_claimed_rewards_source: Option<String>,
 }
 
 /// Unlocking chunk with value and era when funds become available
@@ -827,7 +1110,7 @@ pub struct StakingPayoutsQueryParams {
     /// Block identifier (hash or height) - defaults to latest finalized
     pub at: Option<String>,
 
-    /// Number of eras to query. Must be less than HISTORY_DEPTH. Defaults to 1.
+    /// Number of eras to query. Must be at most HISTORY_DEPTH. Defaults to 1.
     #[serde(default = "default_depth")]
     pub depth: u32,
 
@@ -857,6 +1140,18 @@ fn default_unclaimed_only() -> bool {
 pub struct StakingPayoutsResponse {
     pub at: BlockInfo,
 
+    /// Whether the queried address was detected as a `stash` or a `controller`.
+    /// Omitted when the address isn't bonded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+
+    /// Stash the payouts were computed for, resolved from the controller if needed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stash: Option<String>,
+
+    /// History depth the requested era range was validated against
+    pub history_depth: String,
+
     /// Array of era payouts
     pub eras_payouts: Vec<EraPayouts>,
 
@@ -947,6 +1242,15 @@ pub struct VestingInfoResponse {
     /// Array of vesting schedules (empty array if no vesting)
     pub vesting: Vec<VestingSchedule>,
 
+    /// Sum of `locked` across all schedules
+    pub total_locked: String,
+
+    /// Sum of the amounts vested as of the queried block
+    pub total_vested: String,
+
+    /// Amount still locked as of the queried block
+    pub total_remaining: String,
+
     // Only present when useRcBlock=true
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rc_block_hash: Option<String>,
@@ -968,6 +1272,12 @@ pub struct VestingSchedule {
 
     /// Block when vesting begins
     pub starting_block: String,
+
+    /// Block at which the schedule is fully vested
+    pub unlocked_at: String,
+
+    /// Amount vested as of the queried block
+    pub currently_vested: String,
 }
 
 // ================================================================================================
@@ -980,6 +1290,12 @@ pub struct VestingSchedule {
 pub struct AccountCompareQueryParams {
     /// Comma-separated list of SS58 addresses to compare (max 30)
     pub addresses: String,
+
+    /// First block to compare the address's balance at (requires `at2`)
+    pub at: Option<String>,
+
+    /// Second block to compare the address's balance at (requires `at`)
+    pub at2: Option<String>,
 }
 
 /// Response for GET /accounts/compare
@@ -991,6 +1307,43 @@ pub struct AccountCompareResponse {
 
     /// Details for each address
     pub addresses: Vec<AddressDetails>,
+
+    /// Balance change between `at` and `at2`, only present when both are given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_diff: Option<AccountBalanceDiff>,
+}
+
+/// Balance of one address at two blocks and the change between them
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalanceDiff {
+    /// Balance at `at`
+    pub from: AccountBalanceSnapshot,
+
+    /// Balance at `at2`
+    pub to: AccountBalanceSnapshot,
+
+    /// `to` minus `from`, negative values are prefixed with `-`
+    pub delta: AccountBalanceDelta,
+}
+
+/// Free, reserved and frozen balance at a block
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalanceSnapshot {
+    pub at: BlockInfo,
+    pub free: String,
+    pub reserved: String,
+    pub frozen: String,
+}
+
+/// Signed change in free, reserved and frozen balance
+#[derive(Debug, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalanceDelta {
+    pub free: String,
+    pub reserved: String,
+    pub frozen: String,
 }
 
 /// Details about a single address
@@ -1060,10 +1413,11 @@ pub struct ForeignAssetBalancesQueryParams {
     #[serde(default)]
     pub use_rc_block: bool,
 
-    /// Optional list of foreign asset multilocations as JSON strings to query
-    /// (queries all if omitted). Each element is a JSON-encoded XCM Location.
-    /// Use PHP-style bracket notation: `?foreignAssets[]=JSON1&foreignAssets[]=JSON2`
-    #[serde(default)]
+    /// Optional list of foreign asset locations to query (queries all if omitted).
+    /// Each element is a JSON-encoded XCM Location or a compact location string
+    /// such as `1/Parachain(2011)`. Use PHP-style bracket notation
+    /// (`?foreignAssets[]=LOC1&foreignAssets[]=LOC2`) or a single `?foreignAssets=LOC`.
+    #[serde(default, deserialize_with = "deserialize_string_or_list")]
     pub foreign_assets: Vec<String>,
 
     /// When true, include assets with zero balance. Defaults to false.
@@ -1145,7 +1499,17 @@ mod tests {
         assert_eq!(params.at, Some("200".to_string()));
         assert!(!params.use_rc_block);
         assert_eq!(params.token, Some("DOT".to_string()));
-        assert_eq!(params.denominated, Some(true));
+        assert!(params.denominated);
+    }
+
+    #[test]
+    fn test_balance_info_query_denominated_defaults_to_true() {
+        let params: BalanceInfoQueryParams = serde_json::from_str("{}").unwrap();
+        assert!(params.denominated);
+
+        let params: BalanceInfoQueryParams =
+            serde_json::from_str(r#"{"denominated": false}"#).unwrap();
+        assert!(!params.denominated);
     }
 
     #[test]
@@ -1225,6 +1589,42 @@ mod tests {
         assert_eq!(params.assets, Some(vec![1984, 2000]));
     }
 
+    #[test]
+    fn test_asset_balances_csv_via_serde_qs() {
+        let config = serde_qs::Config::new(5, false);
+        let params: AssetBalancesQueryParams = config.deserialize_str("assets=1984,1337").unwrap();
+        assert_eq!(params.assets, Some(vec![1984, 1337]));
+
+        let params: AssetBalancesQueryParams =
+            config.deserialize_str("assets=1984%2C%201337").unwrap();
+        assert_eq!(params.assets, Some(vec![1984, 1337]));
+
+        let params: AssetBalancesQueryParams = config.deserialize_str("assets=1984").unwrap();
+        assert_eq!(params.assets, Some(vec![1984]));
+    }
+
+    #[test]
+    fn test_asset_balances_invalid_id_is_named() {
+        let config = serde_qs::Config::new(5, false);
+        let err = config
+            .deserialize_str::<AssetBalancesQueryParams>("assets=1984,abc")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid asset ID 'abc'"), "got: {err}");
+
+        let err = config
+            .deserialize_str::<AssetBalancesQueryParams>("assets[]=1984&assets[]=-1")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid asset ID '-1'"), "got: {err}");
+
+        let json = r#"{"assets": [1984, 4294967296]}"#;
+        let err = serde_json::from_str::<AssetBalancesQueryParams>(json)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid asset ID '4294967296'"), "got: {err}");
+    }
+
     #[test]
     fn test_pool_asset_balances_bracket_notation() {
         let config = serde_qs::Config::new(5, false);
@@ -1245,6 +1645,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_foreign_assets_single_value() {
+        let config = serde_qs::Config::new(5, false);
+        let params: ForeignAssetBalancesQueryParams = config
+            .deserialize_str("foreignAssets=1/Parachain(2011)")
+            .unwrap();
+        assert_eq!(params.foreign_assets, vec!["1/Parachain(2011)"]);
+    }
+
     #[test]
     fn test_empty_object_accepted_for_all_optional_params() {
         // All query params should accept empty JSON (all fields are optional or have defaults)
@@ -1258,4 +1667,15 @@ mod tests {
         let _: AccountValidateQueryParams = serde_json::from_str(json).unwrap();
         let _: ForeignAssetBalancesQueryParams = serde_json::from_str(json).unwrap();
     }
+
+    #[test]
+    fn test_token_query_failure_is_not_an_empty_balance() {
+        let err = AccountsError::from(BalanceQueryError::from(
+            TokensStorageError::UnsupportedCurrencyId("KSM".to_string()),
+        ));
+        assert_eq!(
+            err.into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
 }
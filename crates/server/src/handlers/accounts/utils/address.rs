@@ -68,6 +68,23 @@ pub fn validate_and_parse_address(
     Err(AddressValidationError(addr.to_string()))
 }
 
+/// Decode the SS58 network prefix from the leading bytes of a decoded SS58 payload.
+///
+/// Returns the prefix and the number of bytes it occupies:
+/// - `0..=63`: simple format, 1 byte
+/// - `64..=127`: full format, 2 bytes encoding prefixes 64-16383
+fn decode_ss58_prefix(bytes: &[u8]) -> Option<(u16, usize)> {
+    match bytes {
+        [first, ..] if *first < 64 => Some((*first as u16, 1)),
+        [first, second, ..] if *first < 128 => {
+            let (first, second) = (*first as u16, *second as u16);
+            let prefix = ((first & 0x3f) << 2) | (second >> 6) | ((second & 0x3f) << 8);
+            Some((prefix, 2))
+        }
+        _ => None,
+    }
+}
+
 /// Calculate SS58 checksum (first 2 bytes of blake2b hash with SS58PRE prefix)
 fn ss58_checksum(data: &[u8]) -> [u8; 2] {
     use sp_core::hashing::blake2_512;
@@ -90,9 +107,10 @@ fn encode_ss58_to_bytes(account: &AccountId32, prefix: u16) -> Vec<u8> {
     if prefix < 64 {
         result.push(prefix as u8);
     } else {
-        // Two-byte prefix encoding
-        let first = 0x40 | ((prefix & 0x3f) as u8);
-        let second = ((prefix >> 6) & 0xff) as u8;
+        // Two-byte prefix encoding: the low byte is split across both bytes,
+        // and the top six bits go in the low bits of the second byte
+        let first = 0x40 | ((prefix & 0xfc) >> 2) as u8;
+        let second = (((prefix >> 8) & 0x3f) | ((prefix & 0x03) << 6)) as u8;
         result.push(first);
         result.push(second);
     }
@@ -159,18 +177,14 @@ fn validate_hex_address(address: &str) -> AddressDetails {
         return invalid_address_details(address);
     }
 
-    // Extract the prefix
-    let (prefix, account_start) = if bytes[0] < 64 {
-        // Single byte prefix (0-63)
-        (bytes[0] as u16, 1)
-    } else if bytes[0] < 128 && bytes.len() == 36 {
-        // Two byte prefix (64-16383)
-        let lower = (bytes[0] & 0x3f) as u16;
-        let upper = bytes[1] as u16;
-        let prefix = lower | (upper << 6);
-        (prefix, 2)
-    } else {
-        return invalid_address_details(address);
+    // Extract the prefix (1 byte for 0-63, 2 bytes for 64-16383)
+    let (prefix, account_start) = match decode_ss58_prefix(&bytes) {
+        Some((prefix, 2)) if bytes.len() != 36 => {
+            tracing::debug!("Two-byte SS58 prefix {prefix} with unexpected length");
+            return invalid_address_details(address);
+        }
+        Some(decoded) => decoded,
+        None => return invalid_address_details(address),
     };
 
     // Extract the account ID (32 bytes after prefix)
@@ -286,6 +300,122 @@ mod tests {
         assert!(validate_and_parse_address(addr, POLKADOT_PREFIX).is_err());
     }
 
+    #[test]
+    fn test_address_validation_two_byte_prefix_round_trip() {
+        use sp_core::crypto::Ss58AddressFormat;
+
+        let account = AccountId32::new([0xd4; 32]);
+        for prefix in [64u16, 128, 268, 16383] {
+            let addr = account.to_ss58check_with_version(Ss58AddressFormat::custom(prefix));
+            let parsed = validate_and_parse_address(&addr, prefix)
+                .unwrap_or_else(|e| panic!("prefix {prefix} should validate: {e}"));
+            assert_eq!(parsed, account);
+            assert_eq!(
+                parsed.to_ss58check_with_version(Ss58AddressFormat::custom(prefix)),
+                addr
+            );
+        }
+    }
+
+    #[test]
+    fn test_address_validation_prefix_268() {
+        // Alice encoded with two-byte prefix 268
+        let alice = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        let account = validate_and_parse_address(alice, 268).unwrap();
+        let addr =
+            account.to_ss58check_with_version(sp_core::crypto::Ss58AddressFormat::custom(268));
+
+        let parsed = validate_and_parse_address(&addr, 268).unwrap();
+        assert_eq!(parsed, account);
+    }
+
+    #[test]
+    fn test_address_validation_two_byte_prefix_mismatch() {
+        use sp_core::crypto::Ss58AddressFormat;
+
+        let account = AccountId32::new([0xd4; 32]);
+        let addr = account.to_ss58check_with_version(Ss58AddressFormat::custom(268));
+
+        let err = validate_and_parse_address(&addr, 128).unwrap_err();
+        assert!(
+            err.0
+                .contains("uses SS58 prefix 268 but expected prefix 128")
+        );
+
+        let err = validate_and_parse_address(&addr, SUBSTRATE_PREFIX).unwrap_err();
+        assert!(err.0.contains("expected prefix 42"));
+    }
+
+    #[test]
+    fn test_decode_ss58_prefix() {
+        assert_eq!(decode_ss58_prefix(&[0]), Some((0, 1)));
+        assert_eq!(decode_ss58_prefix(&[42, 0]), Some((42, 1)));
+        assert_eq!(decode_ss58_prefix(&[63]), Some((63, 1)));
+
+        // Round-trip every two-byte prefix through the encoder
+        let account = AccountId32::new([1; 32]);
+        for prefix in [64u16, 128, 255, 268, 1000, 16383] {
+            let bytes = encode_ss58_to_bytes(&account, prefix);
+            assert_eq!(bytes.len(), 36);
+            assert_eq!(decode_ss58_prefix(&bytes), Some((prefix, 2)));
+        }
+
+        // Reserved leading byte range and truncated input
+        assert_eq!(decode_ss58_prefix(&[128, 0]), None);
+        assert_eq!(decode_ss58_prefix(&[64]), None);
+        assert_eq!(decode_ss58_prefix(&[]), None);
+    }
+
+    #[test]
+    fn test_validate_address_hex_two_byte_prefix() {
+        let account = AccountId32::new([0xd4; 32]);
+        let bytes = encode_ss58_to_bytes(&account, 268);
+        let details = validate_address(&format!("0x{}", hex::encode(bytes)));
+
+        assert_eq!(details.ss58_prefix, Some(268));
+    }
+
+    // Alice under two-byte prefixes from the ss58-registry, as SS58 and as the
+    // hex of the decoded SS58 payload (prefix bytes + account + checksum)
+    const ALICE_PIONEER: &str = "WWcErrHi2JHpPVVWe7uVq2a8Wrn6NMMTz31z9So5GWYhqWVRi";
+    const ALICE_PIONEER_HEX: &str =
+        "0x4301d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d6951";
+    const ALICE_SUBSPACE_TESTNET: &str = "stB4S14whneyomiEa22Fu2PzVoibMB7n5PvBFUwafbCbRkC1K";
+    const ALICE_SUBSPACE_TESTNET_HEX: &str =
+        "0x7388d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d07d6";
+
+    #[test]
+    fn test_two_byte_prefix_known_vectors() {
+        let alice = AccountId32::new(
+            hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        );
+
+        for (prefix, network, ss58, ss58_hex) in [
+            (268, "pioneer_network", ALICE_PIONEER, ALICE_PIONEER_HEX),
+            (
+                2254,
+                "subspace_testnet",
+                ALICE_SUBSPACE_TESTNET,
+                ALICE_SUBSPACE_TESTNET_HEX,
+            ),
+        ] {
+            let bytes = hex::decode(&ss58_hex[2..]).unwrap();
+            assert_eq!(decode_ss58_prefix(&bytes), Some((prefix, 2)));
+            assert_eq!(encode_ss58_to_bytes(&alice, prefix), bytes);
+
+            assert_eq!(validate_and_parse_address(ss58, prefix).unwrap(), alice);
+
+            for address in [ss58, ss58_hex] {
+                let details = validate_address(address);
+                assert_eq!(details.ss58_prefix, Some(prefix), "{address}");
+                assert_eq!(details.network.as_deref(), Some(network));
+            }
+        }
+    }
+
     #[test]
     fn test_address_validation_wrong_prefix_error_message() {
         // Polkadot address with Kusama prefix should give informative error
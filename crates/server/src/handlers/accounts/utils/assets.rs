@@ -6,8 +6,10 @@
 //! This module provides wrapper functions that delegate to the centralized
 //! `runtime_queries::assets` module for storage queries.
 
-use crate::handlers::accounts::{AccountsError, AssetBalance};
+use crate::handlers::accounts::{AccountsError, AssetBalance, AssetBalanceError};
+use crate::handlers::common::accounts::format_balance;
 use crate::handlers::runtime_queries::assets as assets_queries;
+use crate::handlers::runtime_queries::assets_common::AssetMetadata;
 use sp_core::crypto::AccountId32;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 
@@ -24,28 +26,89 @@ pub async fn query_all_assets_id(
 
 /// Query asset balances for an account.
 ///
-/// Delegates to `runtime_queries::assets::get_asset_balances`.
+/// Delegates to `runtime_queries::assets::get_asset_balances`. Returns the balances
+/// together with the assets whose query failed.
+///
+/// When `denominated` is true, each asset's metadata is fetched with its balance and the
+/// balance is shifted by the asset's decimals, with `symbol` and `decimals` included. If an
+/// asset's metadata cannot be fetched, its balance is left raw and the failure is reported.
 pub async fn query_assets(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
     assets: &[u32],
     show_empty: bool,
-) -> Result<Vec<AssetBalance>, AccountsError> {
-    let balances = assets_queries::get_asset_balances(client_at_block, account, assets, show_empty)
-        .await
-        .map_err(|_| {
-            AccountsError::DecodeFailed(parity_scale_codec::Error::from(
-                "Failed to query asset balances",
-            ))
-        })?;
+    denominated: bool,
+    concurrency: usize,
+) -> Result<(Vec<AssetBalance>, Vec<AssetBalanceError>), AccountsError> {
+    let result = assets_queries::get_asset_balances(
+        client_at_block,
+        account,
+        assets,
+        show_empty,
+        denominated,
+        concurrency,
+    )
+    .await
+    .map_err(|_| {
+        AccountsError::DecodeFailed(parity_scale_codec::Error::from(
+            "Failed to query asset balances",
+        ))
+    })?;
+
+    let balances = result
+        .balances
+        .into_iter()
+        .map(|(asset_id, decoded)| {
+            let metadata = decoded.metadata.as_ref();
+            AssetBalance {
+                asset_id: asset_id.to_string(),
+                balance: metadata
+                    .map(|m| denominate(&decoded.balance, m))
+                    .unwrap_or(decoded.balance),
+                is_frozen: decoded.is_frozen,
+                is_sufficient: decoded.is_sufficient,
+                status: decoded.status,
+                reason: decoded.reason,
+                symbol: metadata.map(|m| String::from_utf8_lossy(&m.symbol).into_owned()),
+                decimals: metadata.map(|m| m.decimals.to_string()),
+            }
+        })
+        .collect();
 
-    Ok(balances
+    let errors = result
+        .failures
         .into_iter()
-        .map(|(asset_id, decoded)| AssetBalance {
-            asset_id: asset_id.to_string(),
-            balance: decoded.balance,
-            is_frozen: decoded.is_frozen,
-            is_sufficient: decoded.is_sufficient,
+        .map(|failure| AssetBalanceError {
+            asset_id: failure.asset_id.to_string(),
+            reason: failure.reason,
         })
-        .collect())
+        .collect();
+
+    Ok((balances, errors))
+}
+
+/// Shift a raw balance string by the asset's decimals.
+pub(super) fn denominate(balance: &str, metadata: &AssetMetadata) -> String {
+    balance
+        .parse::<u128>()
+        .map(|amount| format_balance(amount, true, metadata.decimals))
+        .unwrap_or_else(|_| balance.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denominate_shifts_by_asset_decimals() {
+        let metadata = AssetMetadata {
+            deposit: 0,
+            name: b"Tether USD".to_vec(),
+            symbol: b"USDt".to_vec(),
+            decimals: 6,
+            is_frozen: false,
+        };
+        assert_eq!(denominate("1500000", &metadata), "1.500000");
+        assert_eq!(denominate("0", &metadata), "0");
+    }
 }
@@ -145,12 +145,18 @@ async fn try_fallback_foreign_asset_account(
 
 /// Parse foreign asset location JSON strings into Location objects.
 ///
-/// Uses `staging_xcm::v4::Location` for JSON deserialization (which has full
+/// Uses `staging_xcm::v5::Location` for JSON deserialization (which has full
 /// serde support), then SCALE-encodes and decodes into our typed Location struct.
+/// Locations naming a network V5 dropped (`Westend`, `Rococo`, `Wococo`) are
+/// parsed as `staging_xcm::v4::Location`, which encodes identically otherwise.
 ///
-/// Accepts both numeric (`"parents": 2`) and string-encoded (`"parents": "2"`)
-/// number values to be compatible with Sidecar's query format where numbers
-/// are represented as strings.
+/// Accepts the format locations are returned in, so a `multiLocation` from a
+/// response can be passed back as is: numbers may be plain (`"parents": 2`),
+/// string-encoded (`"parents": "2"`) or comma-grouped (`"Parachain": "2,011"`),
+/// and byte arrays may be `0x`-prefixed hex strings.
+///
+/// Strings not starting with `{` are read as compact locations, see
+/// [`compact_location_to_json`].
 pub fn parse_foreign_asset_locations(
     json_strings: &[String],
 ) -> Result<Vec<Location>, AccountsError> {
@@ -158,22 +164,37 @@ pub fn parse_foreign_asset_locations(
 
     let mut locations = Vec::new();
     for json_str in json_strings {
-        // Parse JSON string
-        let mut json_value: serde_json::Value = serde_json::from_str(json_str)
-            .map_err(|e| AccountsError::InvalidForeignAsset(format!("Invalid JSON: {}", e)))?;
+        // Parse JSON string, or expand a compact location into the same JSON
+        let mut json_value: serde_json::Value = if json_str.trim_start().starts_with('{') {
+            serde_json::from_str(json_str)
+                .map_err(|e| AccountsError::InvalidForeignAsset(format!("Invalid JSON: {}", e)))?
+        } else {
+            compact_location_to_json(json_str).map_err(|e| {
+                AccountsError::InvalidForeignAsset(format!(
+                    "Invalid compact location '{json_str}': {e}"
+                ))
+            })?
+        };
 
-        // Coerce string-encoded numbers to actual numbers so staging_xcm can
-        // deserialize them (it expects u8/u32/u64/u128, not strings).
-        coerce_string_numbers(&mut json_value);
+        // Turn Sidecar-formatted numbers and hex bytes into the plain numbers and
+        // byte arrays staging_xcm deserializes.
+        normalize_location_json(&mut json_value);
 
         // Deserialize into staging_xcm Location (which has Deserialize)
-        let xcm_location: staging_xcm::v4::Location =
-            serde_json::from_value(json_value).map_err(|e| {
-                AccountsError::InvalidForeignAsset(format!("Invalid XCM location: {}", e))
-            })?;
+        let encoded = match serde_json::from_value::<staging_xcm::v5::Location>(json_value.clone())
+        {
+            Ok(location) => location.encode(),
+            Err(v5_error) => serde_json::from_value::<staging_xcm::v4::Location>(json_value)
+                .map(|location| location.encode())
+                .map_err(|_| {
+                    AccountsError::InvalidForeignAsset(format!(
+                        "Invalid XCM location: {}",
+                        v5_error
+                    ))
+                })?,
+        };
 
         // SCALE roundtrip: encode staging_xcm Location, decode as our Location
-        let encoded = xcm_location.encode();
         let our_location = Location::decode(&mut &encoded[..]).map_err(|e| {
             AccountsError::InvalidForeignAsset(format!("Failed to decode location: {}", e))
         })?;
@@ -183,30 +204,269 @@ pub fn parse_foreign_asset_locations(
     Ok(locations)
 }
 
-/// Recursively convert string-encoded numbers to JSON numbers.
+/// Expand a compact location string into its JSON form.
+///
+/// The format is the parents count followed by `/`-separated junctions, e.g.
+/// `1/Parachain(2011)/PalletInstance(50)/GeneralIndex(1984)` or
+/// `2/GlobalConsensus(Ethereum(1))/AccountKey20(0xc02a...)`; a bare `1` is the parent
+/// location itself. Supported junctions are `Parachain`, `PalletInstance`,
+/// `GeneralIndex`, `AccountId32`, `AccountKey20` and `GeneralKey` (hex bytes, network
+/// unset), `OnlyChild` and `GlobalConsensus` with a named network, `Ethereum(chainId)`
+/// or `ByGenesis(0x...)`. Other junctions need the JSON form.
+fn compact_location_to_json(compact: &str) -> Result<serde_json::Value, String> {
+    use serde_json::{Value, json};
+
+    let mut parts = compact.trim().split('/');
+    let parents = parts.next().unwrap_or_default();
+    let parents: u8 = parents
+        .parse()
+        .map_err(|_| format!("expected the parents count first, found '{parents}'"))?;
+
+    let junctions = parts
+        .map(|part| match split_call(part) {
+            ("Parachain" | "PalletInstance" | "GeneralIndex", Some(arg)) => {
+                Ok(json!({ junction_name(part): arg }))
+            }
+            ("AccountId32", Some(id)) => {
+                Ok(json!({ "AccountId32": { "network": null, "id": id } }))
+            }
+            ("AccountKey20", Some(key)) => {
+                Ok(json!({ "AccountKey20": { "network": null, "key": key } }))
+            }
+            ("GeneralKey", Some(data)) => {
+                let bytes = hex::decode(data.trim_start_matches("0x"))
+                    .map_err(|_| format!("GeneralKey data '{data}' is not hex"))?;
+                if bytes.len() > 32 {
+                    return Err("GeneralKey data is longer than 32 bytes".to_string());
+                }
+                let mut padded = [0u8; 32];
+                padded[..bytes.len()].copy_from_slice(&bytes);
+                Ok(json!({ "GeneralKey": {
+                    "length": bytes.len(),
+                    "data": format!("0x{}", hex::encode(padded)),
+                } }))
+            }
+            ("OnlyChild", None) => Ok(json!({ "OnlyChild": null })),
+            ("GlobalConsensus", Some(network)) => {
+                let network = match split_call(network) {
+                    ("Ethereum", Some(chain_id)) => json!({ "Ethereum": { "chainId": chain_id } }),
+                    ("ByGenesis", Some(hash)) => json!({ "ByGenesis": hash }),
+                    (name, None) => Value::String(name.to_string()),
+                    _ => return Err(format!("unsupported network '{network}'")),
+                };
+                Ok(json!({ "GlobalConsensus": network }))
+            }
+            _ => Err(format!("unsupported junction '{part}'")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let interior = match junctions.len() {
+        0 => json!({ "Here": null }),
+        len @ 1..=8 => json!({ format!("X{len}"): junctions }),
+        len => return Err(format!("{len} junctions given, at most 8 are allowed")),
+    };
+
+    Ok(json!({ "parents": parents, "interior": interior }))
+}
+
+/// Split `Name(arg)` into its name and argument; `Name` alone has no argument.
+fn split_call(call: &str) -> (&str, Option<&str>) {
+    let call = call.trim();
+    match call.split_once('(') {
+        Some((name, rest)) => match rest.strip_suffix(')') {
+            Some(arg) => (name, Some(arg)),
+            None => (call, None),
+        },
+        None => (call, None),
+    }
+}
+
+/// Name of a compact junction, without its argument.
+fn junction_name(junction: &str) -> &str {
+    split_call(junction).0
+}
+
+/// Recursively convert Sidecar-formatted strings into the JSON `staging_xcm` expects.
 ///
 /// Sidecar formats all numbers as strings (e.g., `"parents": "2"`,
-/// `"Parachain": "1000"`), but `staging_xcm`'s serde `Deserialize` expects
-/// actual JSON numbers. This coerces any string that parses as a `u128`
-/// into a `Number`. Hex strings ("0x...") and other non-numeric strings
-/// are left unchanged.
-fn coerce_string_numbers(value: &mut serde_json::Value) {
+/// `"Parachain": "2,011"`) and byte arrays as hex (e.g., an `AccountKey20` key),
+/// but `staging_xcm`'s serde `Deserialize` expects actual JSON numbers and arrays.
+/// Strings that parse as a `u128` once comma separators are dropped become a
+/// `Number`, and `0x`-prefixed hex strings become an array of byte numbers.
+/// Other strings are left unchanged. camelCase field names (`chainId`,
+/// `blockNumber`) are renamed to the snake_case `staging_xcm` uses.
+fn normalize_location_json(value: &mut serde_json::Value) {
     match value {
         serde_json::Value::String(s) => {
-            if let Ok(n) = s.parse::<u128>() {
+            if let Some(hex_str) = s.strip_prefix("0x") {
+                if let Ok(bytes) = hex::decode(hex_str) {
+                    *value = serde_json::json!(bytes);
+                }
+            } else if let Ok(n) = s.replace(',', "").parse::<u128>() {
                 *value = serde_json::json!(n);
             }
         }
         serde_json::Value::Array(arr) => {
             for item in arr.iter_mut() {
-                coerce_string_numbers(item);
+                normalize_location_json(item);
             }
         }
         serde_json::Value::Object(map) => {
-            for val in map.values_mut() {
-                coerce_string_numbers(val);
-            }
+            *map = std::mem::take(map)
+                .into_iter()
+                .map(|(key, mut val)| {
+                    normalize_location_json(&mut val);
+                    (camel_to_snake_case(&key), val)
+                })
+                .collect();
         }
         _ => {}
     }
 }
+
+/// Rename a camelCase field to snake_case. PascalCase variant names are kept.
+fn camel_to_snake_case(key: &str) -> String {
+    if !key.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return key.to_string();
+    }
+    key.chars().fold(String::new(), |mut out, c| {
+        if c.is_ascii_uppercase() {
+            out.push('_');
+        }
+        out.push(c.to_ascii_lowercase());
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::common::xcm_types::{Junction, Junctions, NetworkId};
+    use parity_scale_codec::Decode;
+
+    /// `ForeignAssets::Asset` key of Snowbridge-bridged WETH on Polkadot Asset Hub:
+    /// twox128("ForeignAssets") ++ twox128("Asset") ++ blake2_128_concat(location)
+    const WETH_ASSET_KEY: &str = "0x30e64a56026f4b5e3c2d196283a9a17dd34371a193a751eea5883e9553457b2e\
+    1bb4b49f9a32f31e068e193911c066e102020907040300c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+
+    fn location_from_asset_key(key: &str) -> Location {
+        let key = hex::decode(key.trim_start_matches("0x")).unwrap();
+        // Skip the pallet and storage prefixes and the Blake2_128 hash
+        Location::decode(&mut &key[16 + 16 + 16..]).unwrap()
+    }
+
+    #[test]
+    fn test_decode_global_consensus_location_from_asset_key() {
+        let location = location_from_asset_key(WETH_ASSET_KEY);
+
+        let mut weth = [0u8; 20];
+        hex::decode_to_slice("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2", &mut weth).unwrap();
+        assert_eq!(
+            location,
+            Location {
+                parents: 2,
+                interior: Junctions::X2([
+                    Junction::GlobalConsensus(NetworkId::Ethereum { chain_id: 1 }),
+                    Junction::AccountKey20 {
+                        network: None,
+                        key: weth,
+                    },
+                ]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_value(&location).unwrap(),
+            serde_json::json!({
+                "parents": "2",
+                "interior": { "X2": [
+                    { "GlobalConsensus": { "Ethereum": { "chainId": "1" } } },
+                    { "AccountKey20": {
+                        "network": null,
+                        "key": "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"
+                    } }
+                ] }
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_returned_location_round_trips() {
+        let location = location_from_asset_key(WETH_ASSET_KEY);
+        let json = serde_json::to_string(&location).unwrap();
+
+        let parsed = parse_foreign_asset_locations(&[json]).unwrap();
+        assert_eq!(parsed, vec![location]);
+    }
+
+    #[test]
+    fn test_parse_comma_grouped_parachain() {
+        let json = r#"{"parents":"1","interior":{"X3":[{"Parachain":"2,011"},{"PalletInstance":"50"},{"GeneralIndex":"1,984"}]}}"#;
+
+        let parsed = parse_foreign_asset_locations(&[json.to_string()]).unwrap();
+        assert_eq!(
+            parsed[0].interior,
+            Junctions::X3([
+                Junction::Parachain(2011),
+                Junction::PalletInstance(50),
+                Junction::GeneralIndex(1984),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_compact_location_matches_json() {
+        let compact = "2/GlobalConsensus(Ethereum(1))/AccountKey20(0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2)";
+
+        let parsed = parse_foreign_asset_locations(&[compact.to_string()]).unwrap();
+        assert_eq!(parsed, vec![location_from_asset_key(WETH_ASSET_KEY)]);
+    }
+
+    #[test]
+    fn test_parse_compact_location_junctions() {
+        let parsed = parse_foreign_asset_locations(&[
+            "1".to_string(),
+            "2/GlobalConsensus(Kusama)".to_string(),
+            "1/Parachain(2011)/GeneralKey(0x0001)".to_string(),
+        ])
+        .unwrap();
+
+        assert_eq!(parsed[0].interior, Junctions::Here);
+        assert_eq!(
+            parsed[1].interior,
+            Junctions::X1([Junction::GlobalConsensus(NetworkId::Kusama)])
+        );
+        let mut data = [0u8; 32];
+        data[1] = 1;
+        assert_eq!(
+            parsed[2].interior,
+            Junctions::X2([
+                Junction::Parachain(2011),
+                Junction::GeneralKey { length: 2, data },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_compact_location() {
+        let err = parse_foreign_asset_locations(&["1/Teleport(5)".to_string()]).unwrap_err();
+        assert!(matches!(err, AccountsError::InvalidForeignAsset(_)));
+        assert!(
+            err.to_string()
+                .contains("unsupported junction 'Teleport(5)'")
+        );
+
+        let err = parse_foreign_asset_locations(&["Parachain(1000)".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("parents count"));
+    }
+
+    #[test]
+    fn test_parse_network_dropped_in_v5() {
+        let json = r#"{"parents":2,"interior":{"X1":[{"GlobalConsensus":"Westend"}]}}"#;
+
+        let parsed = parse_foreign_asset_locations(&[json.to_string()]).unwrap();
+        assert_eq!(
+            parsed[0].interior,
+            Junctions::X1([Junction::GlobalConsensus(NetworkId::Westend)])
+        );
+    }
+}
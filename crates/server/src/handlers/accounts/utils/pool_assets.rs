@@ -6,6 +6,7 @@
 //! This module provides wrapper functions that delegate to the centralized
 //! `runtime_queries::pool_assets` module for storage queries.
 
+use super::assets::denominate;
 use crate::handlers::accounts::{AccountsError, PoolAssetBalance};
 use crate::handlers::runtime_queries::pool_assets as pool_assets_queries;
 use sp_core::crypto::AccountId32;
@@ -25,28 +26,46 @@ pub async fn query_all_pool_assets_id(
 /// Query pool asset balances for an account.
 ///
 /// Delegates to `runtime_queries::pool_assets::get_pool_asset_balances`.
+///
+/// When `denominated` is true, each LP token's metadata is fetched with its balance and
+/// the balance is shifted by its decimals, with `symbol` and `decimals` included. If
+/// an LP token's metadata can't be fetched, its raw balance is returned instead.
 pub async fn query_pool_assets(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
     assets: &[u32],
     show_empty: bool,
+    denominated: bool,
 ) -> Result<Vec<PoolAssetBalance>, AccountsError> {
-    let balances =
-        pool_assets_queries::get_pool_asset_balances(client_at_block, account, assets, show_empty)
-            .await
-            .map_err(|_| {
-                AccountsError::DecodeFailed(parity_scale_codec::Error::from(
-                    "Failed to query pool asset balances",
-                ))
-            })?;
+    let balances = pool_assets_queries::get_pool_asset_balances(
+        client_at_block,
+        account,
+        assets,
+        show_empty,
+        denominated,
+    )
+    .await
+    .map_err(|_| {
+        AccountsError::DecodeFailed(parity_scale_codec::Error::from(
+            "Failed to query pool asset balances",
+        ))
+    })?;
 
     Ok(balances
         .into_iter()
-        .map(|(asset_id, decoded)| PoolAssetBalance {
-            asset_id: asset_id.to_string(),
-            balance: decoded.balance,
-            is_frozen: decoded.is_frozen,
-            is_sufficient: decoded.is_sufficient,
+        .map(|(asset_id, decoded)| {
+            let metadata = decoded.metadata.as_ref();
+            PoolAssetBalance {
+                asset_id: asset_id.to_string(),
+                balance: metadata
+                    .map(|m| denominate(&decoded.balance, m))
+                    .unwrap_or(decoded.balance),
+                is_frozen: decoded.is_frozen,
+                is_sufficient: decoded.is_sufficient,
+                symbol: metadata.map(|m| String::from_utf8_lossy(&m.symbol).into_owned()),
+                decimals: metadata.map(|m| m.decimals.to_string()),
+                pool: None,
+            }
         })
         .collect())
 }
@@ -179,12 +179,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
@@ -14,7 +14,7 @@
 use crate::state::AppState;
 use crate::utils::{self, hex_with_prefix};
 use axum::{Json, http::StatusCode, response::IntoResponse};
-use heck::ToUpperCamelCase;
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use parity_scale_codec::Decode;
 use serde_json::{Value, json};
 use sp_core::crypto::{AccountId32, Ss58Codec};
@@ -27,7 +27,9 @@ use thiserror::Error;
 use serde::Serialize;
 
 use super::docs::Docs;
-use super::types::{DigestLog, Event, ExtrinsicInfo, ExtrinsicOutcome, GetBlockError};
+use super::types::{
+    DecodedDigestLog, DigestLog, Event, ExtrinsicInfo, ExtrinsicOutcome, GetBlockError,
+};
 use heck::ToSnakeCase;
 
 /// Relay chain block header response
@@ -154,6 +156,137 @@ pub fn convert_digest_items_to_logs(items: &[DigestItem]) -> Vec<DigestLog> {
         .collect()
 }
 
+/// Decode the consensus payloads of digest logs.
+///
+/// BABE and Aura pre-runtime digests, BABE consensus logs and seals are decoded into
+/// JSON; logs from other engines keep their payload as hex in `data`.
+pub fn decode_digest_logs(logs: &[DigestLog]) -> Vec<DecodedDigestLog> {
+    logs.iter().map(decode_digest_log).collect()
+}
+
+/// Build a header's `digest` object: Sidecar-format `logs`, plus `decodedLogs`
+/// when requested.
+pub fn build_header_digest(logs: Vec<DigestLog>, decode_logs: bool) -> Value {
+    let decoded_logs = decode_logs.then(|| decode_digest_logs(&logs));
+    let mut digest = json!({
+        "logs": super::types::convert_digest_logs_to_sidecar_format(logs)
+    });
+    if let Some(decoded_logs) = decoded_logs {
+        digest["decodedLogs"] = json!(decoded_logs);
+    }
+    digest
+}
+
+fn decode_digest_log(log: &DigestLog) -> DecodedDigestLog {
+    let log_type = log.log_type.to_lower_camel_case();
+
+    let (engine_id, payload) = match log.value.as_array().map(Vec::as_slice) {
+        Some([engine, payload]) => (
+            engine.as_str().and_then(decode_hex),
+            payload.as_str().and_then(decode_hex),
+        ),
+        _ => {
+            // RuntimeEnvironmentUpdated and Other logs carry no engine ID
+            return DecodedDigestLog {
+                log_type,
+                engine: None,
+                data: log.value.clone(),
+                raw: log.value.clone(),
+            };
+        }
+    };
+
+    let engine = engine_id.as_deref().map(engine_name);
+    let data = match (engine_id.as_deref(), payload.as_deref()) {
+        (Some(engine_id), Some(payload)) => {
+            decode_digest_payload(&log.log_type, engine_id, payload)
+                .unwrap_or_else(|| json!(hex_with_prefix(payload)))
+        }
+        _ => log
+            .value
+            .as_array()
+            .and_then(|a| a.get(1))
+            .cloned()
+            .unwrap_or(Value::Null),
+    };
+
+    DecodedDigestLog {
+        log_type,
+        engine,
+        data,
+        raw: log.value.clone(),
+    }
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value)).ok()
+}
+
+/// Engine IDs are four ASCII bytes (e.g. `BABE`); anything else is shown as hex.
+fn engine_name(engine_id: &[u8]) -> String {
+    match std::str::from_utf8(engine_id) {
+        Ok(name) if name.chars().all(|c| c.is_ascii_graphic()) => name.to_string(),
+        _ => hex_with_prefix(engine_id),
+    }
+}
+
+fn decode_digest_payload(log_type: &str, engine_id: &[u8], payload: &[u8]) -> Option<Value> {
+    use sp_consensus_babe::digests::{NextConfigDescriptor, PreDigest};
+    use sp_consensus_babe::{AllowedSlots, ConsensusLog};
+
+    let mut cursor = payload;
+    let data = match (log_type, engine_id) {
+        ("Seal", _) => return Some(json!({ "signature": hex_with_prefix(payload) })),
+        ("PreRuntime", b"BABE") => {
+            let pre_digest = PreDigest::decode(&mut cursor).ok()?;
+            let kind = match pre_digest {
+                PreDigest::Primary(_) => "primary",
+                PreDigest::SecondaryPlain(_) => "secondaryPlain",
+                PreDigest::SecondaryVRF(_) => "secondaryVRF",
+            };
+            json!({
+                "kind": kind,
+                "authorityIndex": pre_digest.authority_index(),
+                "slot": u64::from(pre_digest.slot()).to_string(),
+            })
+        }
+        ("PreRuntime", b"aura") => {
+            let slot = u64::decode(&mut cursor).ok()?;
+            json!({ "slot": slot.to_string() })
+        }
+        ("Consensus", b"BABE") => match ConsensusLog::decode(&mut cursor).ok()? {
+            ConsensusLog::NextEpochData(next_epoch) => json!({
+                "nextEpochData": {
+                    "authorities": next_epoch
+                        .authorities
+                        .iter()
+                        .map(|(id, weight)| json!([hex_with_prefix(id.as_ref()), weight.to_string()]))
+                        .collect::<Vec<_>>(),
+                    "randomness": hex_with_prefix(&next_epoch.randomness),
+                }
+            }),
+            ConsensusLog::OnDisabled(index) => json!({ "onDisabled": index }),
+            ConsensusLog::NextConfigData(NextConfigDescriptor::V1 { c, allowed_slots }) => {
+                let allowed_slots = match allowed_slots {
+                    AllowedSlots::PrimarySlots => "PrimarySlots",
+                    AllowedSlots::PrimaryAndSecondaryPlainSlots => "PrimaryAndSecondaryPlainSlots",
+                    AllowedSlots::PrimaryAndSecondaryVRFSlots => "PrimaryAndSecondaryVRFSlots",
+                };
+                json!({
+                    "nextConfigData": {
+                        "c": [c.0.to_string(), c.1.to_string()],
+                        "allowedSlots": allowed_slots,
+                    }
+                })
+            }
+        },
+        _ => return None,
+    };
+
+    // Trailing bytes mean we guessed the format wrong; fall back to hex
+    cursor.is_empty().then_some(data)
+}
+
 // ================================================================================================
 // Block Header & Chain State
 // ================================================================================================
@@ -341,6 +474,7 @@ pub fn associate_events_with_extrinsics(
                 extrinsic.events = std::mem::take(events);
             }
             extrinsic.success = outcome.success;
+            extrinsic.dispatch_error = outcome.dispatch_error.clone();
             if extrinsic.signature.is_some() {
                 if outcome.pays_fee.is_some() {
                     extrinsic.pays_fee = outcome.pays_fee;
@@ -459,8 +593,9 @@ pub async fn get_canonical_hash_at_number_with_rpc(
 
 use super::decode::XcmDecoder;
 use super::processing::{
-    categorize_events, extract_extrinsics_with_prefix, extract_fee_info_for_extrinsic,
-    fetch_block_events_with_prefix,
+    categorize_events, collect_fee_infos, extract_extrinsics_with_prefix,
+    extract_fee_info_for_extrinsic, fetch_block_events_with_prefix, paginate_block_events,
+    summarize_block_weight,
 };
 use super::types::{BlockBuildParams, BlockResponse};
 use polkadot_rest_api_config::ChainType;
@@ -571,7 +706,25 @@ pub async fn build_block_response_generic(
     };
 
     let (on_initialize, mut per_extrinsic_events, on_finalize, extrinsic_outcomes) =
-        categorize_events(block_events, extrinsics.len());
+        categorize_events(
+            block_events,
+            extrinsics.len(),
+            params.events_filter.as_ref(),
+        );
+
+    let block_weight = if params.include_block_weight {
+        use crate::handlers::runtime_queries::system;
+
+        let limits = system::get_block_weights(client_at_block);
+        let consumed = system::get_block_weight(client_at_block).await?;
+        Some(summarize_block_weight(
+            consumed.as_ref(),
+            &extrinsic_outcomes,
+            limits.as_ref(),
+        ))
+    } else {
+        None
+    };
 
     let mut extrinsics_with_events = extrinsics;
     for (i, outcome) in extrinsic_outcomes.iter().enumerate() {
@@ -580,6 +733,7 @@ pub async fn build_block_response_generic(
                 extrinsic.events = std::mem::take(events);
             }
             extrinsic.success = outcome.success;
+            extrinsic.dispatch_error = outcome.dispatch_error.clone();
             if extrinsic.signature.is_some() {
                 // For signed extrinsics, use the value from the event's DispatchInfo
                 if outcome.pays_fee.is_some() {
@@ -612,15 +766,19 @@ pub async fn build_block_response_generic(
                         ctx.state,
                         &client_at_parent,
                         &extrinsic.raw_hex,
+                        extrinsic.tip.as_deref(),
                         &extrinsic.events,
                         extrinsic_outcomes.get(i),
                         spec_version,
                         &ctx.spec_name,
+                        params.fee_breakdown,
                     )
                 })
                 .collect();
 
-            let fee_results = futures::future::join_all(fee_futures).await;
+            let fee_results =
+                collect_fee_infos(ctx.state.config.express.fee_fetch_concurrency, fee_futures)
+                    .await;
 
             for (idx, fee_info) in fee_indices.into_iter().zip(fee_results) {
                 extrinsics_with_events[idx].info = fee_info;
@@ -628,8 +786,27 @@ pub async fn build_block_response_generic(
         }
     }
 
+    // Filter extrinsic events only after fees, which are read from those events
+    if params.filter_extrinsic_events
+        && let Some(filter) = &params.events_filter
+    {
+        for extrinsic in extrinsics_with_events.iter_mut() {
+            extrinsic
+                .events
+                .retain(|event| filter.matches(&event.method.pallet, &event.method.method));
+        }
+    }
+
     let (mut on_initialize, mut on_finalize) = (on_initialize, on_finalize);
 
+    // Page after fees and filtering, which need every extrinsic event
+    let events_pagination = params.events_page.map(|page| {
+        let phases = std::iter::once(&mut on_initialize.events)
+            .chain(extrinsics_with_events.iter_mut().map(|e| &mut e.events))
+            .chain(std::iter::once(&mut on_finalize.events));
+        paginate_block_events(phases, page)
+    });
+
     if params.event_docs || params.extrinsic_docs || params.use_evm_format {
         let metadata = client_at_block.metadata();
 
@@ -674,14 +851,86 @@ pub async fn build_block_response_generic(
         state_root,
         extrinsics_root,
         author_id,
+        decoded_logs: params.decode_logs.then(|| decode_digest_logs(&logs)),
         logs,
         on_initialize,
         extrinsics: extrinsics_with_events,
         on_finalize,
         finalized,
         decoded_xcm_msgs,
+        block_weight,
+        events_pagination,
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+    use sp_consensus_babe::digests::{PreDigest, SecondaryPlainPreDigest};
+
+    #[test]
+    fn test_decode_digest_logs_babe_pre_runtime_and_seal() {
+        let pre_digest = PreDigest::SecondaryPlain(SecondaryPlainPreDigest {
+            authority_index: 7,
+            slot: 295_000_000u64.into(),
+        });
+        let items = vec![
+            DigestItem::PreRuntime(*b"BABE", pre_digest.encode()),
+            DigestItem::Seal(*b"BABE", vec![0xab; 64]),
+        ];
+        let logs = convert_digest_items_to_logs(&items);
+
+        let decoded = serde_json::to_value(decode_digest_logs(&logs)).unwrap();
+
+        assert_eq!(decoded[0]["type"], "preRuntime");
+        assert_eq!(decoded[0]["engine"], "BABE");
+        assert_eq!(
+            decoded[0]["data"],
+            json!({"kind": "secondaryPlain", "authorityIndex": 7, "slot": "295000000"})
+        );
+        assert_eq!(decoded[0]["raw"], logs[0].value);
+        assert_eq!(decoded[1]["type"], "seal");
+        assert_eq!(
+            decoded[1]["data"]["signature"],
+            hex_with_prefix(&[0xab; 64])
+        );
+    }
+
+    #[test]
+    fn test_decode_digest_logs_unknown_payload_falls_back_to_hex() {
+        let items = vec![
+            DigestItem::PreRuntime(*b"aura", 42u64.encode()),
+            DigestItem::Consensus(*b"FRNK", vec![0x01, 0x02]),
+            DigestItem::RuntimeEnvironmentUpdated,
+        ];
+        let logs = convert_digest_items_to_logs(&items);
+
+        let decoded = serde_json::to_value(decode_digest_logs(&logs)).unwrap();
+
+        assert_eq!(decoded[0]["data"], json!({"slot": "42"}));
+        assert_eq!(decoded[1]["engine"], "FRNK");
+        assert_eq!(decoded[1]["data"], "0x0102");
+        assert_eq!(decoded[2]["type"], "runtimeEnvironmentUpdated");
+        assert!(decoded[2].get("engine").is_none());
+    }
+
+    #[test]
+    fn test_build_header_digest_only_decodes_when_requested() {
+        let items = vec![DigestItem::PreRuntime(*b"aura", 42u64.encode())];
+
+        let plain = build_header_digest(convert_digest_items_to_logs(&items), false);
+        assert!(plain.get("decodedLogs").is_none());
+        assert_eq!(
+            plain["logs"],
+            json!([{"preRuntime": ["0x61757261", "0x2a00000000000000"]}])
+        );
+
+        let decoded = build_header_digest(convert_digest_items_to_logs(&items), true);
+        assert_eq!(decoded["logs"], plain["logs"]);
+        assert_eq!(decoded["decodedLogs"][0]["engine"], "aura");
+    }
+}
@@ -21,6 +21,9 @@ use scale_info::PortableRegistry;
 use scale_type_resolver::TypeResolver;
 use serde_json::Value as JsonValue;
 use sp_core::crypto::{AccountId32, Ss58Codec};
+use subxt::Metadata;
+
+use crate::utils::lookup_module_error;
 
 /// Check if an enum type is "basic" (all variants have no associated data).
 fn is_basic_enum(resolver: &PortableRegistry, type_id: u32) -> bool {
@@ -993,3 +996,115 @@ pub fn try_convert_accountid_to_ss58(value: &JsonValue, ss58_prefix: u16) -> Opt
 
     None
 }
+
+/// Add the pallet name, error name and docs to every `DispatchError::Module`
+/// found in decoded event data.
+///
+/// Module errors are rendered as `{"module": {"index": "10", "error": "0x02000000"}}`,
+/// which only carries indices. They are resolved wherever they appear, since besides
+/// `System.ExtrinsicFailed` they are nested in results of other events
+/// (e.g. `Utility.BatchInterrupted`, `Proxy.ProxyExecuted`).
+pub fn resolve_module_errors(value: &mut JsonValue, metadata: &Metadata) {
+    match value {
+        JsonValue::Object(map) => {
+            if let Some(JsonValue::Object(module)) = map.get_mut("module")
+                && let Some((pallet_index, error_index)) = module_error_indices(module)
+                && let Some(info) = lookup_module_error(metadata, pallet_index, error_index)
+            {
+                module.insert("pallet".to_string(), JsonValue::String(info.pallet));
+                module.insert("name".to_string(), JsonValue::String(info.name));
+                module.insert("docs".to_string(), serde_json::json!(info.docs));
+            }
+            for nested in map.values_mut() {
+                resolve_module_errors(nested, metadata);
+            }
+        }
+        JsonValue::Array(items) => {
+            for nested in items {
+                resolve_module_errors(nested, metadata);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Pallet and error indices of a transformed `ModuleError`.
+///
+/// `error` is a `[u8; 4]` (hex) on current runtimes, where the first byte is the
+/// error index, and a plain `u8` on older ones.
+fn module_error_indices(module: &serde_json::Map<String, JsonValue>) -> Option<(u8, u8)> {
+    let pallet_index = module.get("index")?.as_str()?.parse().ok()?;
+    let error = module.get("error")?.as_str()?;
+    let error_index = match error.strip_prefix("0x") {
+        Some(hex_str) => *hex::decode(hex_str).ok()?.first()?,
+        None => error.parse().ok()?,
+    };
+    Some((pallet_index, error_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::Decode;
+    use serde_json::json;
+
+    fn asset_hub_metadata() -> Metadata {
+        Metadata::decode(&mut &ASSET_HUB_METADATA[..]).expect("fixture metadata decodes")
+    }
+
+    fn insufficient_balance(metadata: &Metadata) -> (u8, u8, Vec<String>) {
+        let balances = metadata.pallet_by_name("Balances").unwrap();
+        let variant = balances
+            .error_variants()
+            .unwrap()
+            .iter()
+            .find(|v| v.name == "InsufficientBalance")
+            .unwrap();
+        (balances.error_index(), variant.index, variant.docs.clone())
+    }
+
+    #[test]
+    fn test_resolve_module_errors_in_extrinsic_failed() {
+        let metadata = asset_hub_metadata();
+        let (pallet_index, error_index, docs) = insufficient_balance(&metadata);
+
+        let mut data = json!([
+            {"module": {"index": pallet_index.to_string(), "error": format!("0x{error_index:02x}000000")}},
+            {"weight": {"refTime": "1", "proofSize": "2"}, "class": "Normal", "paysFee": "Yes"}
+        ]);
+        resolve_module_errors(&mut data, &metadata);
+
+        let module = &data[0]["module"];
+        assert_eq!(module["pallet"], "Balances");
+        assert_eq!(module["name"], "InsufficientBalance");
+        assert_eq!(module["docs"], json!(docs));
+        assert_eq!(module["index"], pallet_index.to_string());
+        assert!(data[1].get("pallet").is_none());
+    }
+
+    #[test]
+    fn test_resolve_module_errors_nested_and_legacy_u8_error() {
+        let metadata = asset_hub_metadata();
+        let (pallet_index, error_index, _) = insufficient_balance(&metadata);
+
+        let mut data = json!([
+            "3",
+            {"err": {"module": {"index": pallet_index.to_string(), "error": error_index.to_string()}}}
+        ]);
+        resolve_module_errors(&mut data, &metadata);
+
+        assert_eq!(data[1]["err"]["module"]["name"], "InsufficientBalance");
+    }
+
+    #[test]
+    fn test_resolve_module_errors_leaves_unknown_errors_untouched() {
+        let metadata = asset_hub_metadata();
+        let original = json!({"module": {"index": "10", "error": "0xff000000"}});
+
+        let mut data = original.clone();
+        resolve_module_errors(&mut data, &metadata);
+
+        assert_eq!(data, original);
+    }
+}
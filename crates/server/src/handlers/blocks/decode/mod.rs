@@ -24,8 +24,8 @@ pub mod xcm;
 // Re-export commonly used types
 pub use args::JsonVisitor;
 pub use events::{
-    EventField, EventInfo, EventPhase, EventsVisitor, convert_bytes_to_hex, transform_json_unified,
-    try_convert_accountid_to_ss58,
+    EventField, EventInfo, EventPhase, EventsVisitor, convert_bytes_to_hex, resolve_module_errors,
+    transform_json_unified, try_convert_accountid_to_ss58,
 };
 pub use type_name::GetTypeName;
 pub use xcm::XcmDecoder;
@@ -187,35 +187,156 @@ pub fn scale_value_to_json(value: scale_value::Value<u32>, registry: &PortableRe
 // XCM Decoder
 // ================================================================================================
 
-/// Build a portable registry containing just the VersionedXcm type
-fn build_xcm_registry() -> (PortableRegistry, u32) {
+/// Discriminant of `VersionedXcm::V2`, which current staging-xcm no longer knows.
+const XCM_V2_DISCRIMINANT: u8 = 2;
+
+/// Build a portable registry containing just the VersionedXcm type able to decode
+/// a message with the given version discriminant.
+///
+/// V3 onwards (including V5) use the current staging-xcm; V2 messages are only
+/// found in historical blocks and fall back to the legacy crate.
+fn build_xcm_registry(version: Option<u8>) -> (PortableRegistry, u32) {
     let mut registry = scale_info::Registry::new();
-    let type_id = registry.register_type(&scale_info::meta_type::<staging_xcm::VersionedXcm<()>>());
+    let type_id = if version == Some(XCM_V2_DISCRIMINANT) {
+        registry.register_type(&scale_info::meta_type::<staging_xcm_legacy::VersionedXcm<()>>())
+    } else {
+        registry.register_type(&scale_info::meta_type::<staging_xcm::VersionedXcm<()>>())
+    };
     (registry.into(), type_id.id)
 }
 
+/// A decoded XCM message along with the version it was decoded as.
+struct DecodedXcm {
+    data: Value,
+    /// XCM version (e.g. "5"), or `None` when the message fell back to raw hex
+    version: Option<String>,
+}
+
+impl DecodedXcm {
+    fn raw(hex_str: &str) -> Self {
+        Self {
+            data: Value::String(hex_str.to_string()),
+            version: None,
+        }
+    }
+}
+
 /// Decode a hex-encoded XCM message into a JSON value.
-/// Returns the decoded XCM instructions if successful, or the raw hex string if decoding fails.
-fn decode_xcm_message(hex_str: &str) -> Value {
+/// Returns the decoded XCM instructions if successful, falling back to raw hex for the
+/// instructions that can't be decoded, or for the whole message if its envelope can't be read.
+fn decode_xcm_message(hex_str: &str) -> DecodedXcm {
     let hex_clean = hex_str.strip_prefix("0x").unwrap_or(hex_str);
     let Ok(bytes) = hex::decode(hex_clean) else {
-        return Value::String(hex_str.to_string());
+        return DecodedXcm::raw(hex_str);
+    };
+
+    // The leading byte is the VersionedXcm discriminant, i.e. the XCM version
+    let Some(&version) = bytes.first() else {
+        return DecodedXcm::raw(hex_str);
     };
 
     // Build registry with VersionedXcm type
-    let (registry, type_id) = build_xcm_registry();
+    let (registry, type_id) = build_xcm_registry(Some(version));
 
     // Decode using scale-value for proper JSON serialization
-    match decode_as_type(&mut &bytes[..], type_id, &registry) {
+    let data = match decode_as_type(&mut &bytes[..], type_id, &registry) {
         Ok(value) => {
             // Wrap in array to match sidecar format: "data": [{ "v4": [...] }]
             Value::Array(vec![scale_value_to_json(value, &registry)])
         }
         Err(e) => {
-            tracing::debug!("Failed to decode XCM message: {e:?}");
-            Value::String(hex_str.to_string())
+            tracing::debug!("Failed to decode XCM message, decoding per instruction: {e:?}");
+            match decode_xcm_instructions(&bytes, type_id, &registry) {
+                Some(data) => data,
+                None => return DecodedXcm::raw(hex_str),
+            }
+        }
+    };
+
+    DecodedXcm {
+        data,
+        version: Some(version.to_string()),
+    }
+}
+
+/// Decode a versioned XCM message one instruction at a time.
+///
+/// Instructions are decoded in order until one fails (e.g. a variant newer than our
+/// staging-xcm). An instruction's length is only known once it decodes, so the failed
+/// instruction and everything after it are emitted as a single `{ "raw": "0x.." }` entry.
+/// Returns `None` if the version or instruction count can't be read at all.
+fn decode_xcm_instructions(
+    bytes: &[u8],
+    type_id: u32,
+    registry: &PortableRegistry,
+) -> Option<Value> {
+    use parity_scale_codec::{Compact, Decode};
+
+    let (version, mut cursor) = bytes.split_first()?;
+    let (version_name, instruction_type_id) = xcm_instruction_type(*version, type_id, registry)?;
+    let count = Compact::<u32>::decode(&mut cursor).ok()?.0;
+
+    let mut instructions = Vec::new();
+    for _ in 0..count {
+        let remaining = cursor;
+        match decode_as_type(&mut cursor, instruction_type_id, registry) {
+            Ok(value) => instructions.push(scale_value_to_json(value, registry)),
+            Err(e) => {
+                tracing::debug!("Failed to decode XCM instruction: {e:?}");
+                instructions.push(serde_json::json!({
+                    "raw": format!("0x{}", hex::encode(remaining)),
+                }));
+                break;
+            }
         }
     }
+
+    let mut map = serde_json::Map::new();
+    map.insert(
+        version_name.to_lower_camel_case(),
+        Value::Array(instructions),
+    );
+    Some(Value::Array(vec![Value::Object(map)]))
+}
+
+/// Look up the `VersionedXcm` variant for `version` and the type id of its `Instruction`.
+///
+/// Each variant wraps `Xcm(Vec<Instruction>)`.
+fn xcm_instruction_type(
+    version: u8,
+    versioned_type_id: u32,
+    registry: &PortableRegistry,
+) -> Option<(String, u32)> {
+    let TypeDef::Variant(versioned) = &registry.resolve(versioned_type_id)?.type_def else {
+        return None;
+    };
+    let variant = versioned.variants.iter().find(|v| v.index == version)?;
+    let xcm_type_id = variant.fields.first()?.ty.id;
+
+    let TypeDef::Composite(xcm) = &registry.resolve(xcm_type_id)?.type_def else {
+        return None;
+    };
+    let instructions_type_id = xcm.fields.first()?.ty.id;
+
+    let TypeDef::Sequence(seq) = &registry.resolve(instructions_type_id)?.type_def else {
+        return None;
+    };
+    Some((variant.name.clone(), seq.type_param.id))
+}
+
+/// Keep only the messages sent or received by `para_id`.
+///
+/// Horizontal messages match on either end; upward messages match on their sender.
+/// Downward messages are always addressed to the connected chain, so they are kept.
+fn retain_para_messages(messages: &mut XcmMessages, para_id: u32) {
+    let para_id = para_id.to_string();
+
+    messages.horizontal_messages.retain(|msg| {
+        msg.origin_para_id == para_id || msg.destination_para_id.as_ref() == Some(&para_id)
+    });
+    messages
+        .upward_messages
+        .retain(|msg| msg.origin_para_id == para_id);
 }
 
 /// Decodes XCM messages from block extrinsics.
@@ -238,13 +359,31 @@ impl<'a> XcmDecoder<'a> {
         }
     }
 
-    /// Decode XCM messages from the extrinsics.
+    /// Decode XCM messages from the extrinsics, keeping only those involving
+    /// `para_id_filter` when one is set.
     pub fn decode(&self) -> XcmMessages {
-        match self.chain_type {
+        let mut messages = match self.chain_type {
             ChainType::Relay => self.decode_relay_messages(),
             ChainType::Parachain | ChainType::AssetHub | ChainType::Coretime => {
                 self.decode_parachain_messages()
             }
+        };
+
+        if let Some(para_id) = self.para_id_filter {
+            retain_para_messages(&mut messages, para_id);
+        }
+
+        messages
+    }
+
+    /// Whether an extrinsic, by its lowerCamelCase pallet and method names, carries the
+    /// XCM messages decoded for `chain_type`. Lets callers decode only these extrinsics.
+    pub fn carries_xcm(chain_type: &ChainType, pallet: &str, method: &str) -> bool {
+        match chain_type {
+            ChainType::Relay => pallet == "paraInherent" && method == "enter",
+            ChainType::Parachain | ChainType::AssetHub | ChainType::Coretime => {
+                pallet == "parachainSystem" && method == "setValidationData"
+            }
         }
     }
 
@@ -254,7 +393,11 @@ impl<'a> XcmDecoder<'a> {
         let mut messages = XcmMessages::default();
 
         for extrinsic in self.extrinsics {
-            if extrinsic.method.pallet != "paraInherent" || extrinsic.method.method != "enter" {
+            if !Self::carries_xcm(
+                &ChainType::Relay,
+                &extrinsic.method.pallet,
+                &extrinsic.method.method,
+            ) {
                 continue;
             }
 
@@ -278,13 +421,6 @@ impl<'a> XcmDecoder<'a> {
                     .and_then(|p| p.as_str())
                     .unwrap_or("0");
 
-                if self
-                    .para_id_filter
-                    .is_some_and(|filter| para_id != filter.to_string())
-                {
-                    continue;
-                }
-
                 let Some(commitments) = candidate_obj.get("commitments") else {
                     continue;
                 };
@@ -297,9 +433,11 @@ impl<'a> XcmDecoder<'a> {
                     if let Some(msg_data) = upward_value.as_str() {
                         // Single hex string - decode it directly
                         if !msg_data.is_empty() && msg_data != "0x" {
+                            let decoded = decode_xcm_message(msg_data);
                             messages.upward_messages.push(UpwardMessage {
                                 origin_para_id: para_id.to_string(),
-                                data: decode_xcm_message(msg_data),
+                                xcm_version: decoded.version,
+                                data: decoded.data,
                             });
                         }
                     } else if let Some(upward_msgs) = upward_value.as_array() {
@@ -308,9 +446,11 @@ impl<'a> XcmDecoder<'a> {
                             if let Some(msg_data) = msg.as_str()
                                 && !msg_data.is_empty()
                             {
+                                let decoded = decode_xcm_message(msg_data);
                                 messages.upward_messages.push(UpwardMessage {
                                     origin_para_id: para_id.to_string(),
-                                    data: decode_xcm_message(msg_data),
+                                    xcm_version: decoded.version,
+                                    data: decoded.data,
                                 });
                             }
                         }
@@ -328,11 +468,13 @@ impl<'a> XcmDecoder<'a> {
                         let msg_data = msg.get("data").and_then(|d| d.as_str()).unwrap_or("");
 
                         if !msg_data.is_empty() {
+                            let decoded = decode_xcm_message(msg_data);
                             messages.horizontal_messages.push(HorizontalMessage {
                                 origin_para_id: para_id.to_string(),
                                 destination_para_id: Some(recipient.to_string()),
                                 sent_at: None,
-                                data: decode_xcm_message(msg_data),
+                                xcm_version: decoded.version,
+                                data: decoded.data,
                             });
                         }
                     }
@@ -349,9 +491,11 @@ impl<'a> XcmDecoder<'a> {
         let mut messages = XcmMessages::default();
 
         for extrinsic in self.extrinsics {
-            if extrinsic.method.pallet != "parachainSystem"
-                || extrinsic.method.method != "setValidationData"
-            {
+            if !Self::carries_xcm(
+                &ChainType::Parachain,
+                &extrinsic.method.pallet,
+                &extrinsic.method.method,
+            ) {
                 continue;
             }
 
@@ -380,10 +524,12 @@ impl<'a> XcmDecoder<'a> {
                         .to_string();
 
                     if !msg_hex.is_empty() {
+                        let decoded = decode_xcm_message(&msg_hex);
                         messages.downward_messages.push(DownwardMessage {
                             sent_at,
                             msg: msg_hex.clone(),
-                            data: decode_xcm_message(&msg_hex),
+                            xcm_version: decoded.version,
+                            data: decoded.data,
                         });
                     }
                 }
@@ -409,20 +555,14 @@ impl<'a> XcmDecoder<'a> {
                         .unwrap_or("")
                         .to_string();
 
-                    // Apply paraId filter if specified
-                    if self
-                        .para_id_filter
-                        .is_some_and(|filter| origin_para_id != filter.to_string())
-                    {
-                        continue;
-                    }
-
                     if !msg_data.is_empty() {
+                        let decoded = decode_xcm_message(&msg_data);
                         messages.horizontal_messages.push(HorizontalMessage {
                             origin_para_id,
                             destination_para_id: None, // Not available for parachain perspective
                             sent_at,
-                            data: decode_xcm_message(&msg_data),
+                            xcm_version: decoded.version,
+                            data: decoded.data,
                         });
                     }
                 }
@@ -432,3 +572,164 @@ impl<'a> XcmDecoder<'a> {
         messages
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::{Compact, Decode, Encode};
+    use staging_xcm::IntoVersion;
+    use staging_xcm::v5::Instruction;
+
+    /// Upward message from para 2034 included in Polkadot block 28490503
+    /// (`blocks_28490503_decode_xcm.json` in the integration test fixtures).
+    const UMP_MESSAGE_28490503: &str = "0x041400040000000700e40b54020a130000000700f2052a01000e010204000100511f08130100000700f2052a01000d01020400010300e6b1e0460a0d1dc4be8a8d77b622ddf01bae8b192c2777311c88e98a5867f53fa1c4475be7b86114fa7d82321ba5706916717a8f6a";
+
+    const UMP_MESSAGE_28490503_INSTRUCTIONS: [&str; 5] = [
+        "withdrawAsset",
+        "clearOrigin",
+        "buyExecution",
+        "depositReserveAsset",
+        "setTopic",
+    ];
+
+    fn instruction_names(instructions: &Value) -> Vec<&str> {
+        instructions
+            .as_array()
+            .unwrap_or_else(|| panic!("expected decoded instructions, got {instructions}"))
+            .iter()
+            .filter_map(|instruction| instruction.as_object()?.keys().next())
+            .map(String::as_str)
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_fixture_ump_message() {
+        let decoded = decode_xcm_message(UMP_MESSAGE_28490503);
+        assert_eq!(decoded.version.as_deref(), Some("4"));
+
+        let decoded = decoded.data;
+        assert_eq!(
+            instruction_names(&decoded[0]["v4"]),
+            UMP_MESSAGE_28490503_INSTRUCTIONS
+        );
+        assert_eq!(
+            decoded[0]["v4"][0]["withdrawAsset"][0]["fun"]["fungible"],
+            "10000000000"
+        );
+    }
+
+    #[test]
+    fn test_decode_fixture_ump_message_as_v5() {
+        let bytes = hex::decode(&UMP_MESSAGE_28490503[2..]).unwrap();
+        let v5 = staging_xcm::VersionedXcm::<()>::decode(&mut &bytes[..])
+            .unwrap()
+            .into_version(5)
+            .unwrap();
+        let hex_str = format!("0x{}", hex::encode(v5.encode()));
+        assert!(hex_str.starts_with("0x05"));
+
+        let decoded = decode_xcm_message(&hex_str);
+        assert_eq!(decoded.version.as_deref(), Some("5"));
+
+        let decoded = decoded.data;
+        assert_eq!(
+            instruction_names(&decoded[0]["v5"]),
+            UMP_MESSAGE_28490503_INSTRUCTIONS
+        );
+        assert_eq!(
+            decoded[0]["v5"][0]["withdrawAsset"][0]["fun"]["fungible"],
+            "10000000000"
+        );
+    }
+
+    #[test]
+    fn test_decode_v4_and_v2_messages() {
+        let v4 = staging_xcm::VersionedXcm::<()>::V4(staging_xcm::v4::Xcm(vec![
+            staging_xcm::v4::Instruction::ClearOrigin,
+        ]));
+        let decoded = decode_xcm_message(&format!("0x{}", hex::encode(v4.encode())));
+        assert_eq!(decoded.version.as_deref(), Some("4"));
+        assert!(decoded.data[0]["v4"].is_array());
+
+        // V2 `[ClearOrigin]`: version 2, one instruction, variant index 10
+        let decoded = decode_xcm_message("0x02040a");
+        assert_eq!(decoded.version.as_deref(), Some("2"));
+        assert!(decoded.data[0]["v2"][0].get("clearOrigin").is_some());
+    }
+
+    #[test]
+    fn test_unknown_instruction_falls_back_to_raw() {
+        // V5 message of three instructions: two known, then an unknown variant index
+        let mut bytes = vec![5u8];
+        Compact(3u32).encode_to(&mut bytes);
+        Instruction::<()>::ClearOrigin.encode_to(&mut bytes);
+        Instruction::<()>::SetTopic([0x11; 32]).encode_to(&mut bytes);
+        bytes.extend_from_slice(&[0xff, 0x01, 0x02]);
+
+        let decoded = decode_xcm_message(&format!("0x{}", hex::encode(&bytes)));
+        assert_eq!(decoded.version.as_deref(), Some("5"));
+
+        let decoded = decoded.data;
+        let instructions = decoded[0]["v5"].as_array().unwrap();
+        assert_eq!(instructions.len(), 3);
+        assert!(instructions[0].get("clearOrigin").is_some());
+        assert_eq!(
+            instructions[1]["setTopic"],
+            Value::String(format!("0x{}", "11".repeat(32)))
+        );
+        assert_eq!(instructions[2], serde_json::json!({ "raw": "0xff0102" }));
+    }
+
+    #[test]
+    fn test_retain_para_messages() {
+        let hrmp = |origin: &str, destination: Option<&str>| HorizontalMessage {
+            origin_para_id: origin.to_string(),
+            destination_para_id: destination.map(str::to_string),
+            sent_at: None,
+            xcm_version: None,
+            data: Value::Null,
+        };
+        let ump = |origin: &str| UpwardMessage {
+            origin_para_id: origin.to_string(),
+            xcm_version: None,
+            data: Value::Null,
+        };
+        let mut messages = XcmMessages {
+            horizontal_messages: vec![
+                hrmp("1000", Some("2034")),
+                hrmp("2034", Some("1000")),
+                hrmp("2000", Some("2004")),
+                hrmp("1000", None),
+            ],
+            downward_messages: vec![DownwardMessage {
+                sent_at: "1".to_string(),
+                msg: "0x".to_string(),
+                xcm_version: None,
+                data: Value::Null,
+            }],
+            upward_messages: vec![ump("1000"), ump("2034")],
+        };
+
+        retain_para_messages(&mut messages, 2034);
+
+        let hrmp_ends: Vec<_> = messages
+            .horizontal_messages
+            .iter()
+            .map(|m| (m.origin_para_id.as_str(), m.destination_para_id.as_deref()))
+            .collect();
+        assert_eq!(
+            hrmp_ends,
+            vec![("1000", Some("2034")), ("2034", Some("1000"))]
+        );
+        assert_eq!(messages.upward_messages.len(), 1);
+        assert_eq!(messages.upward_messages[0].origin_para_id, "2034");
+        assert_eq!(messages.downward_messages.len(), 1);
+    }
+
+    #[test]
+    fn test_undecodable_message_falls_back_to_hex() {
+        let decoded = decode_xcm_message("0x09ff");
+        assert_eq!(decoded.data, Value::String("0x09ff".into()));
+        assert!(decoded.version.is_none());
+    }
+}
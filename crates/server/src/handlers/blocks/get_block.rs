@@ -7,10 +7,12 @@
 
 use crate::extractors::JsonQuery;
 use crate::state::AppState;
+use crate::utils::http_cache::{self, IMMUTABLE_CACHE_CONTROL};
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block};
 use axum::{
     Json,
     extract::{Path, State},
+    http::HeaderMap,
     response::{IntoResponse, Response},
 };
 use polkadot_rest_api_config::ChainType;
@@ -33,15 +35,23 @@ use super::types::{BlockQueryParams, BlockResponse, GetBlockError};
         ("blockId" = String, Path, description = "Block height number or block hash"),
         ("eventDocs" = Option<bool>, Query, description = "Include documentation for events"),
         ("extrinsicDocs" = Option<bool>, Query, description = "Include documentation for extrinsics"),
-        ("noFees" = Option<bool>, Query, description = "Skip fee calculation for extrinsics"),
+        ("noFees" = Option<bool>, Query, description = "Skip fee calculation for extrinsics, avoiding the per-extrinsic fee runtime calls. Each extrinsic's info is returned as an empty object"),
         ("finalizedKey" = Option<bool>, Query, description = "When true (default), include finalized status in response"),
         ("decodedXcmMsgs" = Option<bool>, Query, description = "Decode and include XCM messages"),
         ("paraId" = Option<u32>, Query, description = "Filter XCM messages by parachain ID"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat blockId as Relay Chain block and return Asset Hub blocks"),
-        ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events")
+        ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events"),
+        ("eventsFilter" = Option<String>, Query, description = "Comma-separated 'Pallet.Event' or 'Pallet.*' patterns. Only matching onInitialize/onFinalize events are returned; extrinsics keep all their events unless filterExtrinsicEvents=true"),
+        ("filterExtrinsicEvents" = Option<bool>, Query, description = "Also apply eventsFilter to each extrinsic's events"),
+        ("feeBreakdown" = Option<bool>, Query, description = "Include baseFee, lenFee, adjustedWeightFee and tip in the fee info. Needs the queryFeeDetails runtime call even when the fee is read from the TransactionFeePaid event"),
+        ("decodeLogs" = Option<bool>, Query, description = "Include decodedLogs with PreRuntime, Consensus and Seal payloads decoded"),
+        ("includeBlockWeight" = Option<bool>, Query, description = "Include blockWeight: the weight consumed per dispatch class (System::BlockWeight, including hooks) against the System::BlockWeights limits, with utilization percentages"),
+        ("eventsPage" = Option<u32>, Query, description = "Only return this 1-based page of the block's events, counted across onInitialize, each extrinsic and onFinalize, with eventsPagination.totalEvents. Extrinsics outside the page are returned without their events"),
+        ("eventsPerPage" = Option<u32>, Query, description = "Events per page when paging events (default: 100, max: 1000)")
     ),
     responses(
-        (status = 200, description = "Block information", body = Object),
+        (status = 200, description = "Block information. Finalized blocks addressed by hash, or by a number below the finalized head, carry an ETag (the block hash) and an immutable Cache-Control", body = Object),
+        (status = 304, description = "If-None-Match matched the ETag of a finalized block"),
         (status = 400, description = "Invalid block identifier"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -50,14 +60,75 @@ use super::types::{BlockQueryParams, BlockResponse, GetBlockError};
 pub async fn get_block(
     State(state): State<AppState>,
     Path(block_id): Path<String>,
+    headers: HeaderMap,
     JsonQuery(params): JsonQuery<BlockQueryParams>,
 ) -> Result<Response, GetBlockError> {
+    params.events_page()?;
+
     if params.use_rc_block {
         return handle_use_rc_block(state, block_id, params).await;
     }
 
-    let response = build_block_response(&state, block_id, &params).await?;
-    Ok(Json(response).into_response())
+    let (client_at_block, queried_by_hash) = resolve_block_client(&state, &block_id).await?;
+    let block_hash = format!("{:#x}", client_at_block.block_hash());
+    let block_number = client_at_block.block_number();
+
+    // Finalized blocks never change, so they can be cached forever
+    let etag = is_immutable_block(&state, &block_hash, block_number, queried_by_hash)
+        .await
+        .then(|| http_cache::etag(&block_hash));
+    if let Some(etag) = &etag
+        && http_cache::if_none_match(&headers, etag)
+    {
+        return Ok(http_cache::not_modified(etag, IMMUTABLE_CACHE_CONTROL));
+    }
+
+    let response = build_block_response_for_hash(
+        &state,
+        &block_hash,
+        block_number,
+        queried_by_hash,
+        &client_at_block,
+        &params,
+    )
+    .await?;
+
+    let response = Json(response).into_response();
+    Ok(match &etag {
+        Some(etag) => http_cache::with_cache_headers(response, etag, IMMUTABLE_CACHE_CONTROL),
+        None => response,
+    })
+}
+
+/// Whether the block's response can never change.
+///
+/// Blocks addressed by number must be below the finalized head, blocks addressed
+/// by hash must be finalized and canonical. Lookup failures count as mutable.
+async fn is_immutable_block(
+    state: &AppState,
+    block_hash: &str,
+    block_number: u64,
+    queried_by_hash: bool,
+) -> bool {
+    let Ok(finalized) = state.client.at_current_block().await else {
+        return false;
+    };
+    let finalized_number = finalized.block_number();
+
+    if !queried_by_hash {
+        return block_number < finalized_number;
+    }
+    if block_number > finalized_number {
+        return false;
+    }
+
+    match state.client.at_block(block_number).await {
+        Ok(canonical) => format!("{:#x}", canonical.block_hash()) == block_hash,
+        Err(e) => {
+            tracing::debug!("Failed to fetch canonical hash for block: {e:?}");
+            false
+        }
+    }
 }
 
 async fn handle_use_rc_block(
@@ -117,11 +188,11 @@ async fn handle_use_rc_block(
     Ok(Json(json!(results)).into_response())
 }
 
-pub(crate) async fn build_block_response(
+/// Resolve a block id to a client at that block, and whether it was given as a hash
+async fn resolve_block_client(
     state: &AppState,
-    block_id: String,
-    params: &BlockQueryParams,
-) -> Result<BlockResponse, GetBlockError> {
+    block_id: &str,
+) -> Result<(super::common::BlockClient, bool), GetBlockError> {
     let block_id_parsed = block_id.parse::<utils::BlockId>()?;
     let queried_by_hash = matches!(block_id_parsed, utils::BlockId::Hash(_));
 
@@ -132,19 +203,7 @@ pub(crate) async fn build_block_response(
         utils::BlockId::Number(number) => state.client.at_block(*number).await?,
     };
 
-    // Extract hash and number from the resolved client
-    let block_hash = format!("{:#x}", client_at_block.block_hash());
-    let block_number = client_at_block.block_number();
-
-    build_block_response_for_hash(
-        state,
-        &block_hash,
-        block_number,
-        queried_by_hash,
-        &client_at_block,
-        params,
-    )
-    .await
+    Ok((client_at_block, queried_by_hash))
 }
 
 pub(crate) async fn build_block_response_for_hash(
@@ -169,7 +228,7 @@ pub(crate) async fn build_block_response_for_hash(
         block_hash,
         block_number,
         queried_by_hash,
-        &params.to_build_params(),
+        &params.to_build_params()?,
         params.finalized_key,
     )
     .await
@@ -216,12 +275,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
@@ -278,7 +341,13 @@ mod tests {
 
         // Attempt to get the block - this will fail at metadata fetch in current setup
         // but validates the handler flow up to that point
-        let result = get_block(State(state), Path(block_id), JsonQuery(params)).await;
+        let result = get_block(
+            State(state),
+            Path(block_id),
+            HeaderMap::new(),
+            JsonQuery(params),
+        )
+        .await;
 
         // We expect an error due to metadata fetching limitations in mock environment
         assert!(result.is_err());
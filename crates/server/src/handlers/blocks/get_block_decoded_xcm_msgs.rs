@@ -0,0 +1,200 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for GET /blocks/{blockId}/decodedXcmMsgs endpoint.
+//!
+//! Returns only the decoded XCM messages of a block. Unlike `/blocks/{blockId}?decodedXcmMsgs=true`
+//! it skips events and fees, and decodes just the extrinsics that carry XCM messages.
+
+use crate::extractors::JsonQuery;
+use crate::state::AppState;
+use crate::utils;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use heck::ToLowerCamelCase;
+use serde::Deserialize;
+use serde_json::json;
+use thiserror::Error;
+
+use super::CommonBlockError;
+use super::common::BlockClient;
+use super::decode::XcmDecoder;
+use super::processing::extrinsics::extrinsic_to_info;
+use super::types::{ExtrinsicInfo, GetBlockError, XcmMessages};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DecodedXcmMsgsQueryParams {
+    /// Only include messages to or from this parachain
+    pub para_id: Option<u32>,
+}
+
+#[derive(Debug, Error)]
+pub enum DecodedXcmMsgsError {
+    #[error(transparent)]
+    Common(#[from] CommonBlockError),
+
+    #[error("Failed to fetch extrinsics: {0}")]
+    ExtrinsicsFetchFailed(String),
+
+    #[error("Failed to decode extrinsic: {0}")]
+    ExtrinsicDecodeFailed(#[from] GetBlockError),
+}
+
+impl From<utils::BlockIdParseError> for DecodedXcmMsgsError {
+    fn from(err: utils::BlockIdParseError) -> Self {
+        DecodedXcmMsgsError::Common(CommonBlockError::from(err))
+    }
+}
+
+impl From<subxt::error::OnlineClientAtBlockError> for DecodedXcmMsgsError {
+    fn from(err: subxt::error::OnlineClientAtBlockError) -> Self {
+        DecodedXcmMsgsError::Common(CommonBlockError::from(err))
+    }
+}
+
+impl IntoResponse for DecodedXcmMsgsError {
+    fn into_response(self) -> Response {
+        match self {
+            DecodedXcmMsgsError::Common(err) => err.into_response(),
+            DecodedXcmMsgsError::ExtrinsicsFetchFailed(_)
+            | DecodedXcmMsgsError::ExtrinsicDecodeFailed(_) => {
+                let body = Json(json!({
+                    "error": self.to_string(),
+                }));
+                (StatusCode::INTERNAL_SERVER_ERROR, body).into_response()
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/blocks/{blockId}/decodedXcmMsgs",
+    tag = "blocks",
+    summary = "Get decoded XCM messages",
+    description = "Returns only the horizontal, downward and upward XCM messages of a block, with decoded instructions. Skips event and fee processing, so it is much cheaper than requesting the full block with decodedXcmMsgs=true.",
+    params(
+        ("blockId" = String, Path, description = "Block height number or block hash"),
+        ("paraId" = Option<u32>, Query, description = "Filter XCM messages by parachain ID")
+    ),
+    responses(
+        (status = 200, description = "Decoded XCM messages", body = Object),
+        (status = 400, description = "Invalid block identifier"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_block_decoded_xcm_msgs(
+    State(state): State<AppState>,
+    Path(block_id): Path<String>,
+    JsonQuery(params): JsonQuery<DecodedXcmMsgsQueryParams>,
+) -> Result<Json<XcmMessages>, DecodedXcmMsgsError> {
+    let client_at_block = match block_id.parse::<utils::BlockId>()? {
+        utils::BlockId::Hash(hash) => state.client.at_block(hash).await?,
+        utils::BlockId::Number(number) => state.client.at_block(number).await?,
+    };
+
+    let extrinsics = extract_xcm_extrinsics(&state, &client_at_block).await?;
+    let decoder = XcmDecoder::new(
+        state.chain_info.chain_type.clone(),
+        &extrinsics,
+        params.para_id,
+    );
+
+    Ok(Json(decoder.decode()))
+}
+
+/// Decode only the extrinsics that carry XCM messages, leaving their events empty.
+async fn extract_xcm_extrinsics(
+    state: &AppState,
+    client_at_block: &BlockClient,
+) -> Result<Vec<ExtrinsicInfo>, DecodedXcmMsgsError> {
+    let metadata = client_at_block.metadata();
+    let chain_type = &state.chain_info.chain_type;
+
+    let extrinsics = client_at_block
+        .extrinsics()
+        .fetch()
+        .await
+        .map_err(|e| DecodedXcmMsgsError::ExtrinsicsFetchFailed(e.to_string()))?;
+
+    let mut result = Vec::new();
+    for extrinsic_result in extrinsics.iter() {
+        let extrinsic = match extrinsic_result {
+            Ok(ext) => ext,
+            Err(e) => {
+                tracing::debug!("Failed to decode extrinsic: {e:?}");
+                continue;
+            }
+        };
+
+        let pallet = extrinsic.pallet_name().to_lower_camel_case();
+        let method = extrinsic.call_name().to_lower_camel_case();
+        if !XcmDecoder::carries_xcm(chain_type, &pallet, &method) {
+            continue;
+        }
+
+        result.push(extrinsic_to_info(
+            state.chain_info.ss58_prefix,
+            metadata.types(),
+            &extrinsic,
+        )?);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polkadot_rest_api_config::ChainType;
+
+    #[test]
+    fn test_query_params_rejects_unknown_fields() {
+        let result: Result<DecodedXcmMsgsQueryParams, _> =
+            serde_json::from_str(r#"{"paraId": 1000, "noFees": true}"#);
+        assert!(result.unwrap_err().to_string().contains("unknown field"));
+    }
+
+    #[test]
+    fn test_query_params_accepts_para_id() {
+        let params: DecodedXcmMsgsQueryParams =
+            serde_json::from_str(r#"{"paraId": 1000}"#).unwrap();
+        assert_eq!(params.para_id, Some(1000));
+    }
+
+    #[test]
+    fn test_only_xcm_carrying_extrinsics_are_decoded() {
+        assert!(XcmDecoder::carries_xcm(
+            &ChainType::Relay,
+            "paraInherent",
+            "enter"
+        ));
+        assert!(!XcmDecoder::carries_xcm(
+            &ChainType::Relay,
+            "parachainSystem",
+            "setValidationData"
+        ));
+        assert!(XcmDecoder::carries_xcm(
+            &ChainType::AssetHub,
+            "parachainSystem",
+            "setValidationData"
+        ));
+        assert!(!XcmDecoder::carries_xcm(
+            &ChainType::AssetHub,
+            "balances",
+            "transferKeepAlive"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_block_id_is_bad_request() {
+        let err = DecodedXcmMsgsError::from("not-a-block".parse::<utils::BlockId>().unwrap_err());
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+}
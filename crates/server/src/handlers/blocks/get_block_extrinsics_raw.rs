@@ -205,7 +205,8 @@ async fn fetch_raw_extrinsics(
     extract_raw_extrinsics_from_json(&block_json)
 }
 
-fn extract_raw_extrinsics_from_json(
+/// Extract the hex-encoded extrinsics from a `chain_getBlock` JSON response.
+pub(crate) fn extract_raw_extrinsics_from_json(
     block_json: &serde_json::Value,
 ) -> Result<Vec<String>, GetBlockError> {
     let extrinsics = block_json
@@ -226,6 +227,23 @@ fn extract_raw_extrinsics_from_json(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extract_raw_extrinsics_from_json() {
+        let block_json = json!({
+            "block": {
+                "header": {},
+                "extrinsics": ["0x280401000b", "0x1c0407"]
+            },
+            "justifications": null
+        });
+        assert_eq!(
+            extract_raw_extrinsics_from_json(&block_json).unwrap(),
+            vec!["0x280401000b".to_string(), "0x1c0407".to_string()]
+        );
+
+        assert!(extract_raw_extrinsics_from_json(&json!({"block": {}})).is_err());
+    }
+
     #[test]
     fn test_block_raw_extrinsics_query_params_rejects_unknown_fields() {
         let json = r#"{"useRcBlock": true, "unknownField": true}"#;
@@ -18,11 +18,14 @@ use polkadot_rest_api_config::ChainType;
 use serde::Deserialize;
 use serde_json::json;
 
-use super::common::{add_docs_to_events, convert_digest_items_to_logs, extract_author};
+use super::common::{
+    add_docs_to_events, convert_digest_items_to_logs, decode_digest_logs, extract_author,
+};
 use super::decode::XcmDecoder;
 use super::docs::Docs;
 use super::processing::{
-    categorize_events, extract_extrinsics, extract_fee_info_for_extrinsic, fetch_block_events,
+    categorize_events, collect_fee_infos, extract_extrinsics, extract_fee_info_for_extrinsic,
+    fetch_block_events,
 };
 use super::types::{BlockResponse, GetBlockError};
 
@@ -58,6 +61,9 @@ pub struct BlockHeadQueryParams {
     /// When true, convert AccountId32 addresses to EVM format for revive pallet events
     #[serde(default)]
     pub use_evm_format: bool,
+    /// When true, include `decodedLogs` with PreRuntime, Consensus and Seal payloads decoded
+    #[serde(default)]
+    pub decode_logs: bool,
 }
 
 fn default_true() -> bool {
@@ -75,6 +81,7 @@ impl Default for BlockHeadQueryParams {
             para_id: None,
             use_rc_block: false,
             use_evm_format: false,
+            decode_logs: false,
         }
     }
 }
@@ -95,6 +102,7 @@ impl Default for BlockHeadQueryParams {
 /// - `decodedXcmMsgs` (boolean, default: false): Decode and include XCM messages
 /// - `paraId` (number, optional): Filter XCM messages by parachain ID
 /// - `useRcBlock` (boolean, default: false): When true, use relay chain head to find corresponding Asset Hub blocks
+/// - `decodeLogs` (boolean, default: false): Include `decodedLogs` with consensus payloads decoded
 #[utoipa::path(
     get,
     path = "/v1/blocks/head",
@@ -109,7 +117,8 @@ impl Default for BlockHeadQueryParams {
         ("decodedXcmMsgs" = Option<bool>, Query, description = "Decode and include XCM messages"),
         ("paraId" = Option<u32>, Query, description = "Filter XCM messages by parachain ID"),
         ("useRcBlock" = Option<bool>, Query, description = "When true, use relay chain head to find corresponding Asset Hub blocks"),
-        ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events")
+        ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events"),
+        ("decodeLogs" = Option<bool>, Query, description = "Include decodedLogs with PreRuntime, Consensus and Seal payloads decoded")
     ),
     responses(
         (status = 200, description = "Latest block information", body = Object),
@@ -238,7 +247,7 @@ async fn handle_use_rc_block(
 
 /// Build block response for a specific client_at_block
 /// This is extracted from the main handler to allow reuse in useRcBlock logic
-async fn build_head_block_response(
+pub(crate) async fn build_head_block_response(
     state: &AppState,
     client_at_block: &super::common::BlockClient,
     params: &BlockHeadQueryParams,
@@ -268,7 +277,7 @@ async fn build_head_block_response(
     let block_events = events_result?;
 
     let (on_initialize, mut per_extrinsic_events, on_finalize, extrinsic_outcomes) =
-        categorize_events(block_events, extrinsics.len());
+        categorize_events(block_events, extrinsics.len(), None);
 
     let mut extrinsics_with_events = extrinsics;
     for (i, outcome) in extrinsic_outcomes.iter().enumerate() {
@@ -277,6 +286,7 @@ async fn build_head_block_response(
                 extrinsic.events = std::mem::take(events);
             }
             extrinsic.success = outcome.success;
+            extrinsic.dispatch_error = outcome.dispatch_error.clone();
             if extrinsic.signature.is_some() && outcome.pays_fee.is_some() {
                 extrinsic.pays_fee = outcome.pays_fee;
             }
@@ -304,15 +314,18 @@ async fn build_head_block_response(
                         state,
                         &client_at_parent,
                         &extrinsic.raw_hex,
+                        extrinsic.tip.as_deref(),
                         &extrinsic.events,
                         extrinsic_outcomes.get(i),
                         spec_version,
                         &state.chain_info.spec_name,
+                        false,
                     )
                 })
                 .collect();
 
-            let fee_results = futures::future::join_all(fee_futures).await;
+            let fee_results =
+                collect_fee_infos(state.config.express.fee_fetch_concurrency, fee_futures).await;
 
             for (idx, fee_info) in fee_indices.into_iter().zip(fee_results) {
                 extrinsics_with_events[idx].info = fee_info;
@@ -367,12 +380,15 @@ async fn build_head_block_response(
         state_root,
         extrinsics_root,
         author_id,
+        decoded_logs: params.decode_logs.then(|| decode_digest_logs(&logs)),
         logs,
         on_initialize,
         extrinsics: extrinsics_with_events,
         on_finalize,
         finalized,
         decoded_xcm_msgs,
+        block_weight: None,
+        events_pagination: None,
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
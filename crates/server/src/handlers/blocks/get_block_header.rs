@@ -7,10 +7,11 @@
 //! for a specific block identified by hash or number.
 
 use crate::extractors::JsonQuery;
-use crate::handlers::blocks::common::convert_digest_items_to_logs;
+use crate::handlers::blocks::common::{
+    build_header_digest, convert_digest_items_to_logs, extract_author,
+};
 use crate::handlers::blocks::types::{
     BlockHeaderQueryParams, BlockHeaderResponse, GetBlockHeaderError,
-    convert_digest_logs_to_sidecar_format,
 };
 use crate::state::AppState;
 use crate::utils::{self, fetch_block_timestamp, find_ah_blocks_in_rc_block_at};
@@ -30,7 +31,9 @@ use serde_json::json;
     description = "Returns the header of the specified block (lightweight, no extrinsics/events).",
     params(
         ("blockId" = String, Path, description = "Block height number or block hash"),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat blockId as Relay Chain block and return Asset Hub blocks")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat blockId as Relay Chain block and return Asset Hub blocks"),
+        ("includeAuthor" = Option<bool>, Query, description = "Resolve the block author as authorId (default: false)"),
+        ("decodeLogs" = Option<bool>, Query, description = "Include digest.decodedLogs with consensus payloads decoded (default: false)")
     ),
     responses(
         (status = 200, description = "Block header information", body = Object),
@@ -56,17 +59,27 @@ pub async fn get_block_header(
         .map_err(GetBlockHeaderError::BlockHeaderFailed)?;
 
     let digest_logs = convert_digest_items_to_logs(&header.digest.logs);
-    let digest_logs_formatted = convert_digest_logs_to_sidecar_format(digest_logs);
+    let author_id = if params.include_author {
+        extract_author(
+            &state,
+            &client_at_block,
+            &digest_logs,
+            client_at_block.block_number(),
+        )
+        .await
+    } else {
+        None
+    };
+    let digest = build_header_digest(digest_logs, params.decode_logs);
 
     let response = BlockHeaderResponse {
         parent_hash: format!("{:#x}", header.parent_hash),
         number: client_at_block.block_number().to_string(),
         state_root: format!("{:#x}", header.state_root),
         extrinsics_root: format!("{:#x}", header.extrinsics_root),
-        digest: json!({
-            "logs": digest_logs_formatted
-        }),
+        digest,
         hash: None,
+        author_id,
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
@@ -78,7 +91,7 @@ pub async fn get_block_header(
 async fn handle_use_rc_block(
     state: AppState,
     block_id: String,
-    _params: BlockHeaderQueryParams,
+    params: BlockHeaderQueryParams,
 ) -> Result<Response, GetBlockHeaderError> {
     if state.chain_info.chain_type != ChainType::AssetHub {
         return Err(GetBlockHeaderError::UseRcBlockNotSupported);
@@ -115,7 +128,12 @@ async fn handle_use_rc_block(
         let extrinsics_root = format!("{:#x}", header.extrinsics_root);
 
         let digest_logs = convert_digest_items_to_logs(&header.digest.logs);
-        let digest_logs_formatted = convert_digest_logs_to_sidecar_format(digest_logs);
+        let author_id = if params.include_author {
+            extract_author(&state, &client_at_block, &digest_logs, ah_block.number).await
+        } else {
+            None
+        };
+        let digest = build_header_digest(digest_logs, params.decode_logs);
 
         let ah_timestamp = fetch_block_timestamp(&client_at_block).await;
 
@@ -124,10 +142,9 @@ async fn handle_use_rc_block(
             number: ah_block.number.to_string(),
             state_root,
             extrinsics_root,
-            digest: json!({
-                "logs": digest_logs_formatted
-            }),
+            digest,
             hash: None,
+            author_id,
             rc_block_hash: Some(rc_block_hash.clone()),
             rc_block_number: Some(rc_block_number.clone()),
             ah_timestamp,
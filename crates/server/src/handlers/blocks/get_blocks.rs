@@ -1,16 +1,19 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::extractors::JsonQuery;
+use crate::extractors::{JsonQuery, ResponseFormat};
 use crate::state::AppState;
 use crate::utils::{self, ResolvedBlock, fetch_block_timestamp, find_ah_blocks_in_rc_block};
 use axum::{
     Json,
+    body::Body,
     extract::State,
+    http::header,
     response::{IntoResponse, Response},
 };
-use futures::stream::{self, StreamExt, TryStreamExt};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use serde::Deserialize;
+use serde_json::json;
 use std::sync::Arc;
 
 use super::common::parse_range;
@@ -39,7 +42,7 @@ pub struct BlocksRangeQueryParams {
     path = "/v1/blocks",
     tag = "blocks",
     summary = "Get blocks by range",
-    description = "Returns a collection of blocks given a numeric range. Range is inclusive and limited to 500 blocks.",
+    description = "Returns a collection of blocks given a numeric range. Range is inclusive and limited to 500 blocks. With `Accept: application/x-ndjson`, blocks are streamed in order as newline-delimited JSON, one block per line, as soon as each is built.",
     params(
         ("range" = Option<String>, Query, description = "Block range in format 'start-end' (e.g. '100-200')"),
         ("eventDocs" = Option<bool>, Query, description = "Include documentation for events"),
@@ -49,7 +52,7 @@ pub struct BlocksRangeQueryParams {
         ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events")
     ),
     responses(
-        (status = 200, description = "Array of block information", body = Vec<Object>),
+        (status = 200, description = "Array of block information, or one block per line for application/x-ndjson", body = Vec<Object>),
         (status = 400, description = "Invalid range parameter"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -57,6 +60,7 @@ pub struct BlocksRangeQueryParams {
 )]
 pub async fn get_blocks(
     State(state): State<AppState>,
+    format: ResponseFormat,
     JsonQuery(params): JsonQuery<BlocksRangeQueryParams>,
 ) -> Result<Response, GetBlockError> {
     let range_str = params.range.clone().ok_or(GetBlockError::MissingRange)?;
@@ -72,17 +76,84 @@ pub async fn get_blocks(
     };
 
     if params.use_rc_block {
-        let blocks =
-            handle_use_rc_block_range(state.clone(), &base_block_params, start, end).await?;
-        return Ok(Json(blocks).into_response());
+        let rc_blocks = rc_block_range_stream(state, base_block_params, start, end).await?;
+        if format == ResponseFormat::Ndjson {
+            let blocks = rc_blocks
+                .map_ok(|responses| stream::iter(responses.into_iter().map(Ok)))
+                .try_flatten();
+            return Ok(ndjson_response(blocks));
+        }
+
+        // Flatten and sort results
+        let mut results: Vec<BlockResponse> = rc_blocks
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .flatten()
+            .collect();
+        results.sort_by(|a, b| {
+            let a_num = a.number.parse::<u64>().unwrap_or_default();
+            let b_num = b.number.parse::<u64>().unwrap_or_default();
+            a_num.cmp(&b_num)
+        });
+        return Ok(Json(results).into_response());
     }
 
+    let blocks = block_range_stream(state, base_block_params, start, end);
+    if format == ResponseFormat::Ndjson {
+        return Ok(ndjson_response(blocks));
+    }
+
+    let blocks: Vec<BlockResponse> = blocks.try_collect().await?;
+    Ok(Json(blocks).into_response())
+}
+
+/// Stream a range's blocks as NDJSON, one line per block as it's built.
+///
+/// The status is sent before the blocks are built, so an error part way through
+/// ends the stream with an `{"error": ...}` line.
+fn ndjson_response(
+    blocks: impl futures::Stream<Item = Result<BlockResponse, GetBlockError>> + Send + 'static,
+) -> Response {
+    let lines = blocks.scan(false, |failed, block| {
+        if *failed {
+            return futures::future::ready(None);
+        }
+        let value = match block {
+            Ok(block) => {
+                serde_json::to_value(block).unwrap_or_else(|e| json!({ "error": e.to_string() }))
+            }
+            Err(e) => {
+                *failed = true;
+                tracing::debug!("Failed to build block while streaming range: {e:?}");
+                json!({ "error": e.to_string() })
+            }
+        };
+        let mut line = value.to_string().into_bytes();
+        line.push(b'\n');
+        futures::future::ready(Some(Ok::<_, std::convert::Infallible>(line)))
+    });
+
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(lines),
+    )
+        .into_response()
+}
+
+/// Build the range's blocks with bounded concurrency, yielding them in block order.
+fn block_range_stream(
+    state: AppState,
+    params: BlockQueryParams,
+    start: u64,
+    end: u64,
+) -> BoxStream<'static, Result<BlockResponse, GetBlockError>> {
     let concurrency = state.config.express.block_fetch_concurrency;
 
-    let blocks: Vec<BlockResponse> = stream::iter(start..=end)
-        .map(|number| {
+    stream::iter(start..=end)
+        .map(move |number| {
             let state = state.clone();
-            let params = base_block_params.clone();
+            let params = params.clone();
             async move {
                 // Create client_at_block - this also resolves hash internally
                 let client_at_block = state.client.at_block(number).await?;
@@ -101,18 +172,17 @@ pub async fn get_blocks(
             }
         })
         .buffered(concurrency)
-        .try_collect()
-        .await?;
-
-    Ok(Json(blocks).into_response())
+        .boxed()
 }
 
-async fn handle_use_rc_block_range(
+/// Build the Asset Hub blocks included in each Relay Chain block of the range,
+/// yielding them per Relay Chain block, in order.
+async fn rc_block_range_stream(
     state: AppState,
-    params: &BlockQueryParams,
+    params: BlockQueryParams,
     start: u64,
     end: u64,
-) -> Result<Vec<BlockResponse>, GetBlockError> {
+) -> Result<BoxStream<'static, Result<Vec<BlockResponse>, GetBlockError>>, GetBlockError> {
     use polkadot_rest_api_config::ChainType;
 
     if state.chain_info.chain_type != ChainType::AssetHub {
@@ -125,8 +195,8 @@ async fn handle_use_rc_block_range(
     let concurrency = state.config.express.block_fetch_concurrency;
 
     // Fetch RC blocks in parallel, each returning a Vec of AH BlockResponses
-    let nested_results: Vec<Vec<BlockResponse>> = stream::iter(start..=end)
-        .map(|rc_number| {
+    let rc_blocks = stream::iter(start..=end)
+        .map(move |rc_number| {
             let state = state.clone();
             let params = params.clone();
             let rc_rpc = rc_rpc.clone();
@@ -172,19 +242,9 @@ async fn handle_use_rc_block_range(
             }
         })
         .buffered(concurrency)
-        .try_collect()
-        .await?;
-
-    // Flatten and sort results
-    let mut results: Vec<BlockResponse> = nested_results.into_iter().flatten().collect();
+        .boxed();
 
-    results.sort_by(|a, b| {
-        let a_num = a.number.parse::<u64>().unwrap_or_default();
-        let b_num = b.number.parse::<u64>().unwrap_or_default();
-        a_num.cmp(&b_num)
-    });
-
-    Ok(results)
+    Ok(rc_blocks)
 }
 
 async fn resolve_rc_block(
@@ -201,6 +261,73 @@ async fn resolve_rc_block(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::handlers::blocks::types::{OnFinalize, OnInitialize};
+    use http_body_util::BodyExt;
+
+    fn block(number: u64) -> BlockResponse {
+        BlockResponse {
+            number: number.to_string(),
+            hash: format!("0x{number:064x}"),
+            parent_hash: format!("0x{:064x}", number - 1),
+            state_root: "0x00".to_string(),
+            extrinsics_root: "0x00".to_string(),
+            author_id: None,
+            logs: vec![],
+            decoded_logs: None,
+            on_initialize: OnInitialize { events: vec![] },
+            extrinsics: vec![],
+            on_finalize: OnFinalize { events: vec![] },
+            finalized: None,
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+            decoded_xcm_msgs: None,
+            block_weight: None,
+            events_pagination: None,
+        }
+    }
+
+    async fn ndjson_lines(
+        blocks: Vec<Result<BlockResponse, GetBlockError>>,
+    ) -> (Option<String>, Vec<serde_json::Value>) {
+        let response = ndjson_response(stream::iter(blocks));
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let lines = String::from_utf8(body.to_vec())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        (content_type, lines)
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_response_emits_one_block_per_line() {
+        let (content_type, lines) = ndjson_lines(vec![Ok(block(10)), Ok(block(11))]).await;
+
+        assert_eq!(content_type.as_deref(), Some("application/x-ndjson"));
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["number"], "10");
+        assert_eq!(lines[1]["number"], "11");
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_response_ends_with_error_line() {
+        let (_, lines) = ndjson_lines(vec![
+            Ok(block(10)),
+            Err(GetBlockError::ExtrinsicsFetchFailed("boom".to_string())),
+            Ok(block(12)),
+        ])
+        .await;
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["number"], "10");
+        assert_eq!(lines[1]["error"], "Failed to fetch extrinsics");
+    }
 
     #[test]
     fn test_blocks_range_query_params_rejects_unknown_fields() {
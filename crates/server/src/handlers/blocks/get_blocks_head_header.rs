@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::extractors::JsonQuery;
-use crate::handlers::blocks::common::convert_digest_items_to_logs;
-use crate::handlers::blocks::types::{BlockHeaderResponse, convert_digest_logs_to_sidecar_format};
+use crate::handlers::blocks::common::{
+    BlockClient, build_header_digest, convert_digest_items_to_logs, extract_author,
+    extract_author_with_prefix,
+};
+use crate::handlers::blocks::types::BlockHeaderResponse;
 use crate::state::{AppState, RelayChainError};
 use crate::utils::{self, RcBlockError, fetch_block_timestamp, find_ah_blocks_in_rc_block_at};
 use axum::{
@@ -28,6 +31,12 @@ pub struct BlockQueryParams {
     /// When true, treat block identifier as Relay Chain block and return Asset Hub blocks included in it
     #[serde(default, rename = "useRcBlock")]
     pub use_rc_block: bool,
+    /// When true, resolve the block author from the consensus digest and the validator set
+    #[serde(default, rename = "includeAuthor")]
+    pub include_author: bool,
+    /// When true, include `digest.decodedLogs` with known consensus payloads decoded
+    #[serde(default, rename = "decodeLogs")]
+    pub decode_logs: bool,
 }
 
 fn default_finalized() -> bool {
@@ -144,6 +153,7 @@ impl IntoResponse for GetBlockHeadHeaderError {
 /// Query Parameters:
 /// - `finalized` (boolean, default: true): When true, returns finalized head. When false, returns canonical head.
 /// - `useRcBlock` (boolean, default: false): When true, treat as Relay Chain block and return Asset Hub blocks
+/// - `decodeLogs` (boolean, default: false): Include `digest.decodedLogs` with consensus payloads decoded
 #[utoipa::path(
     get,
     path = "/v1/blocks/head/header",
@@ -152,7 +162,9 @@ impl IntoResponse for GetBlockHeadHeaderError {
     description = "Returns the header of the latest finalized or canonical block (lightweight, no extrinsics/events).",
     params(
         ("finalized" = Option<bool>, Query, description = "When true (default), returns finalized head header. When false, returns canonical head header."),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat as Relay Chain block and return Asset Hub blocks")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat as Relay Chain block and return Asset Hub blocks"),
+        ("includeAuthor" = Option<bool>, Query, description = "Resolve the block author as authorId (default: false)"),
+        ("decodeLogs" = Option<bool>, Query, description = "Include digest.decodedLogs with consensus payloads decoded (default: false)")
     ),
     responses(
         (status = 200, description = "Block header information", body = Object),
@@ -187,6 +199,23 @@ pub async fn get_blocks_head_header(
         state.client.at_block(best_hash).await?
     };
 
+    let author_ss58_prefix = params
+        .include_author
+        .then_some(state.chain_info.ss58_prefix);
+    let response =
+        build_head_header_response(&client_at_block, author_ss58_prefix, params.decode_logs)
+            .await?;
+
+    Ok(Json(response).into_response())
+}
+
+/// Build the header response for a block, including its hash. The author is
+/// resolved only when an SS58 prefix to encode it with is given.
+pub(crate) async fn build_head_header_response(
+    client_at_block: &BlockClient,
+    author_ss58_prefix: Option<u16>,
+    decode_logs: bool,
+) -> Result<BlockHeaderResponse, GetBlockHeadHeaderError> {
     let block_hash = format!("{:#x}", client_at_block.block_hash());
     let block_number = client_at_block.block_number();
 
@@ -200,23 +229,27 @@ pub async fn get_blocks_head_header(
     let extrinsics_root = format!("{:#x}", header.extrinsics_root);
 
     let digest_logs = convert_digest_items_to_logs(&header.digest.logs);
-    let digest_logs_formatted = convert_digest_logs_to_sidecar_format(digest_logs);
+    let author_id = match author_ss58_prefix {
+        Some(ss58_prefix) => {
+            extract_author_with_prefix(client_at_block, &digest_logs, ss58_prefix, block_number)
+                .await
+        }
+        None => None,
+    };
+    let digest = build_header_digest(digest_logs, decode_logs);
 
-    let response = BlockHeaderResponse {
+    Ok(BlockHeaderResponse {
         parent_hash,
         number: block_number.to_string(),
         state_root,
         extrinsics_root,
-        digest: json!({
-            "logs": digest_logs_formatted
-        }),
+        digest,
         hash: Some(block_hash),
+        author_id,
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
-    };
-
-    Ok(Json(response).into_response())
+    })
 }
 
 async fn handle_use_rc_block(
@@ -273,7 +306,12 @@ async fn handle_use_rc_block(
         let extrinsics_root = format!("{:#x}", header.extrinsics_root);
 
         let digest_logs = convert_digest_items_to_logs(&header.digest.logs);
-        let digest_logs_formatted = convert_digest_logs_to_sidecar_format(digest_logs);
+        let author_id = if params.include_author {
+            extract_author(&state, &client_at_block, &digest_logs, ah_block.number).await
+        } else {
+            None
+        };
+        let digest = build_header_digest(digest_logs, params.decode_logs);
 
         let ah_timestamp = fetch_block_timestamp(&client_at_block).await;
 
@@ -282,10 +320,9 @@ async fn handle_use_rc_block(
             number: ah_block.number.to_string(),
             state_root,
             extrinsics_root,
-            digest: json!({
-                "logs": digest_logs_formatted
-            }),
+            digest,
             hash: Some(ah_block.hash),
+            author_id,
             rc_block_hash: Some(rc_block_hash.clone()),
             rc_block_number: Some(rc_block_number.clone()),
             ah_timestamp,
@@ -325,5 +362,13 @@ mod tests {
         let params = result.unwrap();
         assert!(params.finalized); // default is true
         assert!(!params.use_rc_block); // default is false
+        assert!(!params.include_author); // default is false
+    }
+
+    #[test]
+    fn test_block_query_params_accepts_include_author() {
+        let json = r#"{"includeAuthor": true}"#;
+        let params: BlockQueryParams = serde_json::from_str(json).unwrap();
+        assert!(params.include_author);
     }
 }
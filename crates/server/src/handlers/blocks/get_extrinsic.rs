@@ -48,7 +48,8 @@ use super::types::{
         ("extrinsicDocs" = Option<bool>, Query, description = "Include documentation for extrinsics"),
         ("noFees" = Option<bool>, Query, description = "Skip fee calculation"),
         ("useRcBlock" = Option<bool>, Query, description = "When true, treat blockId as Relay Chain block and return Asset Hub extrinsics"),
-        ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events")
+        ("useEvmFormat" = Option<bool>, Query, description = "Convert AccountId32 addresses to EVM format for revive pallet events"),
+        ("feeBreakdown" = Option<bool>, Query, description = "Include baseFee, lenFee, adjustedWeightFee and tip in the fee info. Needs the queryFeeDetails runtime call even when the fee is read from the TransactionFeePaid event")
     ),
     responses(
         (status = 200, description = "Extrinsic details", body = Object),
@@ -178,7 +179,7 @@ async fn build_extrinsic_response(
     }
 
     let (_on_initialize, mut per_extrinsic_events, _on_finalize, extrinsic_outcomes) =
-        categorize_events(block_events, extrinsics.len());
+        categorize_events(block_events, extrinsics.len(), None);
 
     let mut extrinsics_with_events = extrinsics;
     associate_events_with_extrinsics(
@@ -200,10 +201,12 @@ async fn build_extrinsic_response(
             state,
             &client_at_parent,
             &extrinsic.raw_hex,
+            extrinsic.tip.as_deref(),
             &extrinsic.events,
             extrinsic_outcomes.get(extrinsic_index),
             spec_version,
             &state.chain_info.spec_name,
+            params.fee_breakdown,
         )
         .await;
 
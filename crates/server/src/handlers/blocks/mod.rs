@@ -6,6 +6,7 @@ pub mod decode;
 pub(crate) mod docs;
 pub(crate) mod evm_format;
 pub mod get_block;
+pub mod get_block_decoded_xcm_msgs;
 pub mod get_block_extrinsics_raw;
 pub mod get_block_head;
 pub mod get_block_header;
@@ -19,6 +20,7 @@ pub mod utils;
 
 pub use common::CommonBlockError;
 pub use get_block::get_block;
+pub use get_block_decoded_xcm_msgs::get_block_decoded_xcm_msgs;
 pub use get_block_extrinsics_raw::get_block_extrinsics_raw;
 pub use get_block_head::get_block_head;
 pub use get_block_header::get_block_header;
@@ -18,12 +18,12 @@ use serde_json::Value;
 
 use super::super::common::BlockClient;
 use super::super::decode::{
-    EventPhase as VisitorEventPhase, EventsVisitor, convert_bytes_to_hex, transform_json_unified,
-    try_convert_accountid_to_ss58,
+    EventPhase as VisitorEventPhase, EventsVisitor, convert_bytes_to_hex, resolve_module_errors,
+    transform_json_unified, try_convert_accountid_to_ss58,
 };
 use super::super::types::{
-    ActualWeight, Event, EventPhase, ExtrinsicOutcome, GetBlockError, MethodInfo, OnFinalize,
-    OnInitialize, ParsedEvent,
+    ActualWeight, Event, EventPhase, EventsPage, EventsPagination, ExtrinsicOutcome, GetBlockError,
+    MethodInfo, OnFinalize, OnInitialize, ParsedEvent,
 };
 use super::super::utils::extract_number_as_string;
 
@@ -103,6 +103,41 @@ pub fn extract_fee_from_transaction_paid_event(events: &[Event]) -> Option<Strin
     None
 }
 
+/// Fee paid in a non-native asset, as reported by the runtime
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetFeePaid {
+    /// Fee actually charged, denominated in `asset_id`
+    pub actual_fee: String,
+    pub tip: String,
+    /// Asset the fee was paid in: an asset index for `AssetTxPayment`, an XCM
+    /// location for `AssetConversionTxPayment`
+    pub asset_id: Value,
+}
+
+/// Extract the asset-denominated fee from an AssetTxFeePaid event if present
+///
+/// Emitted instead of TransactionFeePaid by `AssetTxPayment` and
+/// `AssetConversionTxPayment` when the fee is paid in an asset.
+/// AssetTxFeePaid event data: [who, actualFee, tip, assetId]
+pub fn extract_asset_fee_paid_event(events: &[Event]) -> Option<AssetFeePaid> {
+    events.iter().find_map(|event| {
+        let pallet_lower = event.method.pallet.to_lowercase();
+        let is_asset_fee_paid = (pallet_lower == "assettxpayment"
+            || pallet_lower == "assetconversiontxpayment")
+            && event.method.method == "AssetTxFeePaid";
+
+        if !is_asset_fee_paid || event.data.len() < 4 {
+            return None;
+        }
+
+        Some(AssetFeePaid {
+            actual_fee: extract_number_as_string(&event.data[1]),
+            tip: extract_number_as_string(&event.data[2]),
+            asset_id: event.data[3].clone(),
+        })
+    })
+}
+
 /// Extract actual weight from DispatchInfo in event data
 ///
 /// DispatchInfo contains: { weight, class, paysFee }
@@ -258,7 +293,7 @@ async fn fetch_block_events_impl(
 
         // Use the visitor's field values which have proper type-level enum serialization
         // (basic enums as strings, non-basic enums as objects)
-        let event_data: Vec<Value> = event_info
+        let mut event_data: Vec<Value> = event_info
             .fields
             .into_iter()
             .map(|event_field| {
@@ -292,6 +327,11 @@ async fn fetch_block_events_impl(
             })
             .collect();
 
+        // Name the pallet and error behind `DispatchError::Module` indices
+        for value in &mut event_data {
+            resolve_module_errors(value, &metadata);
+        }
+
         parsed_events.push(ParsedEvent {
             phase,
             pallet_name: event_info.pallet_name,
@@ -303,11 +343,60 @@ async fn fetch_block_events_impl(
     Ok(parsed_events)
 }
 
+/// Event filter parsed from the `eventsFilter` query parameter.
+///
+/// A comma-separated list of `Pallet.Event` patterns, where `Pallet.*` matches every
+/// event of a pallet (e.g. `Balances.Transfer,System.*`). Matching is case-insensitive.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(try_from = "String")]
+pub struct EventsFilter {
+    /// Lowercased `(pallet, event)` pairs, where a `None` event matches any event
+    patterns: Vec<(String, Option<String>)>,
+}
+
+impl EventsFilter {
+    pub fn matches(&self, pallet: &str, event: &str) -> bool {
+        self.patterns.iter().any(|(p, e)| {
+            p.eq_ignore_ascii_case(pallet)
+                && e.as_ref().is_none_or(|e| e.eq_ignore_ascii_case(event))
+        })
+    }
+}
+
+impl TryFrom<String> for EventsFilter {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let patterns = value
+            .split(',')
+            .map(str::trim)
+            .map(|pattern| match pattern.split_once('.') {
+                Some((pallet, event))
+                    if !pallet.is_empty() && !event.is_empty() && !event.contains('.') =>
+                {
+                    let event = (event != "*").then(|| event.to_lowercase());
+                    Ok((pallet.to_lowercase(), event))
+                }
+                _ => Err(format!(
+                    "Invalid eventsFilter pattern '{pattern}': expected 'Pallet.Event' or 'Pallet.*'"
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { patterns })
+    }
+}
+
 /// Categorize parsed events into onInitialize, per-extrinsic, and onFinalize arrays
 /// Also extracts extrinsic outcomes (success, paysFee) from System.ExtrinsicSuccess/ExtrinsicFailed events
+///
+/// When `events_filter` is set, onInitialize and onFinalize events that don't match it
+/// are dropped. Per-extrinsic events are kept, since fees are extracted from them;
+/// callers filter those afterwards if requested.
 pub fn categorize_events(
     parsed_events: Vec<ParsedEvent>,
     num_extrinsics: usize,
+    events_filter: Option<&EventsFilter>,
 ) -> (
     OnInitialize,
     Vec<Vec<Event>>,
@@ -363,6 +452,8 @@ pub fn categorize_events(
                     }
                 } else if is_failed_event {
                     // success stays false
+                    extrinsic_outcomes[idx].dispatch_error =
+                        parsed_event.event_data.first().cloned();
                     // Extract paysFee from DispatchInfo (second element in event data, after DispatchError)
                     if let Some(pays_fee) =
                         extract_pays_fee_from_event_data(&parsed_event.event_data, false)
@@ -385,6 +476,14 @@ pub fn categorize_events(
             }
         }
 
+        let is_extrinsic_event = matches!(parsed_event.phase, EventPhase::ApplyExtrinsic(_));
+        if !is_extrinsic_event
+            && let Some(filter) = events_filter
+            && !filter.matches(&parsed_event.pallet_name, &parsed_event.event_name)
+        {
+            continue;
+        }
+
         let event = Event {
             method: MethodInfo {
                 pallet: parsed_event.pallet_name,
@@ -426,3 +525,245 @@ pub fn categorize_events(
         extrinsic_outcomes,
     )
 }
+
+/// Keep only one page of the block's events.
+///
+/// `phases` are the block's event lists in order: onInitialize, each extrinsic's
+/// events, then onFinalize. They are paged as a single list, and lists outside the
+/// page are emptied without being removed.
+pub fn paginate_block_events<'a>(
+    phases: impl IntoIterator<Item = &'a mut Vec<Event>>,
+    events_page: EventsPage,
+) -> EventsPagination {
+    let mut phases: Vec<&mut Vec<Event>> = phases.into_iter().collect();
+    let total_events = phases.iter().map(|events| events.len()).sum();
+
+    let per_page = events_page.per_page as usize;
+    let start = (events_page.page as usize - 1)
+        .saturating_mul(per_page)
+        .min(total_events);
+    let end = start.saturating_add(per_page).min(total_events);
+
+    let mut offset = 0;
+    for events in phases.iter_mut() {
+        let len = events.len();
+        let from = start.saturating_sub(offset).min(len);
+        let to = end.saturating_sub(offset).min(len);
+        events.truncate(to);
+        events.drain(..from);
+        offset += len;
+    }
+
+    EventsPagination {
+        page: events_page.page,
+        per_page: events_page.per_page,
+        total_events,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(value: &str) -> Result<EventsFilter, String> {
+        EventsFilter::try_from(value.to_string())
+    }
+
+    fn parsed_event(phase: EventPhase, pallet: &str, event: &str) -> ParsedEvent {
+        ParsedEvent {
+            phase,
+            pallet_name: pallet.to_string(),
+            event_name: event.to_string(),
+            event_data: vec![],
+        }
+    }
+
+    #[test]
+    fn test_events_filter_matches_exact_and_wildcard() {
+        let filter = filter("Balances.Transfer, system.*").unwrap();
+
+        assert!(filter.matches("balances", "Transfer"));
+        assert!(!filter.matches("balances", "Deposit"));
+        assert!(filter.matches("system", "ExtrinsicSuccess"));
+        assert!(!filter.matches("assets", "Transferred"));
+    }
+
+    #[test]
+    fn test_events_filter_rejects_malformed_patterns() {
+        assert!(filter("Balances").is_err());
+        assert!(filter("Balances.").is_err());
+        assert!(filter(".Transfer").is_err());
+        assert!(filter("Balances.Transfer,").is_err());
+        assert!(filter("A.B.C").is_err());
+    }
+
+    #[test]
+    fn test_categorize_events_filters_only_block_phase_events() {
+        let events = vec![
+            parsed_event(EventPhase::Initialization, "balances", "Transfer"),
+            parsed_event(EventPhase::Initialization, "parachainsystem", "Upgrade"),
+            parsed_event(EventPhase::ApplyExtrinsic(0), "system", "ExtrinsicSuccess"),
+            parsed_event(EventPhase::Finalization, "treasury", "Deposit"),
+        ];
+        let filter = filter("Balances.*").unwrap();
+
+        let (on_initialize, per_extrinsic, on_finalize, outcomes) =
+            categorize_events(events, 1, Some(&filter));
+
+        assert_eq!(on_initialize.events.len(), 1);
+        assert_eq!(on_initialize.events[0].method.method, "Transfer");
+        assert_eq!(per_extrinsic[0].len(), 1);
+        assert!(outcomes[0].success);
+        assert!(on_finalize.events.is_empty());
+    }
+
+    fn events(count: usize, method: &str) -> Vec<Event> {
+        (0..count)
+            .map(|i| event("system", &format!("{method}{i}"), vec![]))
+            .collect()
+    }
+
+    /// Page over onInitialize (3 events), two extrinsics (1 and 2 events) and onFinalize (2 events)
+    fn paginate(page: u32, per_page: u32) -> (Vec<Vec<String>>, EventsPagination) {
+        let mut phases = vec![
+            events(3, "init"),
+            events(1, "ext0_"),
+            events(2, "ext1_"),
+            events(2, "final"),
+        ];
+        let pagination = paginate_block_events(phases.iter_mut(), EventsPage { page, per_page });
+        let names = phases
+            .into_iter()
+            .map(|events| events.into_iter().map(|e| e.method.method).collect())
+            .collect();
+        (names, pagination)
+    }
+
+    #[test]
+    fn test_paginate_block_events_spans_all_phases() {
+        let (phases, pagination) = paginate(1, 2);
+        assert_eq!(phases, [vec!["init0", "init1"], vec![], vec![], vec![]]);
+        assert_eq!(pagination.total_events, 8);
+
+        let (phases, _) = paginate(2, 2);
+        assert_eq!(phases, [vec!["init2"], vec!["ext0_0"], vec![], vec![]]);
+
+        let (phases, _) = paginate(3, 2);
+        assert_eq!(phases, [vec![], vec![], vec!["ext1_0", "ext1_1"], vec![]]);
+
+        let (phases, _) = paginate(2, 4);
+        assert_eq!(
+            phases,
+            [
+                vec![],
+                vec![],
+                vec!["ext1_0", "ext1_1"],
+                vec!["final0", "final1"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paginate_block_events_past_the_end_is_empty() {
+        let (phases, pagination) = paginate(5, 2);
+        assert!(phases.iter().all(Vec::is_empty));
+        assert_eq!(pagination.page, 5);
+        assert_eq!(pagination.total_events, 8);
+    }
+
+    #[test]
+    fn test_extract_weight_from_event_data_reads_both_components() {
+        let success = vec![serde_json::json!({
+            "weight": {"refTime": "1000", "proofSize": "64"}, "class": "Normal", "paysFee": "Yes"
+        })];
+        let weight = extract_weight_from_event_data(&success, true).unwrap();
+        assert_eq!(weight.ref_time.as_deref(), Some("1000"));
+        assert_eq!(weight.proof_size.as_deref(), Some("64"));
+
+        let failed = vec![
+            serde_json::json!({"other": null}),
+            serde_json::json!({"weight": {"ref_time": 5, "proof_size": 6}}),
+        ];
+        let weight = extract_weight_from_event_data(&failed, false).unwrap();
+        assert_eq!(weight.ref_time.as_deref(), Some("5"));
+        assert_eq!(weight.proof_size.as_deref(), Some("6"));
+
+        let legacy = vec![serde_json::json!({"weight": 42})];
+        let weight = extract_weight_from_event_data(&legacy, true).unwrap();
+        assert_eq!(weight.ref_time.as_deref(), Some("42"));
+        assert!(weight.proof_size.is_none());
+    }
+
+    #[test]
+    fn test_categorize_events_records_dispatch_error_of_failed_extrinsic() {
+        let dispatch_error = serde_json::json!({
+            "module": {"index": "10", "error": "0x02000000", "pallet": "Balances", "name": "InsufficientBalance"}
+        });
+        let mut failed = parsed_event(EventPhase::ApplyExtrinsic(1), "system", "ExtrinsicFailed");
+        failed.event_data = vec![dispatch_error.clone(), serde_json::json!({})];
+        let events = vec![
+            parsed_event(EventPhase::ApplyExtrinsic(0), "system", "ExtrinsicSuccess"),
+            failed,
+        ];
+
+        let (_, _, _, outcomes) = categorize_events(events, 2, None);
+
+        assert!(outcomes[0].success);
+        assert!(outcomes[0].dispatch_error.is_none());
+        assert!(!outcomes[1].success);
+        assert_eq!(outcomes[1].dispatch_error, Some(dispatch_error));
+    }
+
+    fn event(pallet: &str, method: &str, data: Vec<Value>) -> Event {
+        Event {
+            method: MethodInfo {
+                pallet: pallet.to_string(),
+                method: method.to_string(),
+            },
+            data,
+            docs: None,
+        }
+    }
+
+    #[test]
+    fn test_extract_asset_fee_paid_event() {
+        let location = serde_json::json!({"parents": "1", "interior": {"here": null}});
+        let events = vec![
+            event(
+                "balances",
+                "Withdraw",
+                vec![Value::from("who"), Value::from("5")],
+            ),
+            event(
+                "assetConversionTxPayment",
+                "AssetTxFeePaid",
+                vec![
+                    Value::from("who"),
+                    Value::from("1200"),
+                    Value::from("10"),
+                    location.clone(),
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            extract_asset_fee_paid_event(&events),
+            Some(AssetFeePaid {
+                actual_fee: "1200".to_string(),
+                tip: "10".to_string(),
+                asset_id: location,
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_asset_fee_paid_event_ignores_native_fees() {
+        let events = vec![event(
+            "transactionPayment",
+            "TransactionFeePaid",
+            vec![Value::from("who"), Value::from("1200"), Value::from("0")],
+        )];
+
+        assert_eq!(extract_asset_fee_paid_event(&events), None);
+    }
+}
@@ -8,17 +8,43 @@
 //! 1. TransactionFeePaid event (exact fee from runtime)
 //! 2. queryFeeDetails + calc_partial_fee (post-dispatch calculation)
 //! 3. queryInfo (pre-dispatch estimation)
+//!
+//! Fees paid in a non-native asset (Asset Hub's `AssetTxPayment` and
+//! `AssetConversionTxPayment`) are taken from the AssetTxFeePaid event instead,
+//! since the native fee calculations don't apply to them.
 
 use crate::state::AppState;
 use crate::utils::{self, decode_runtime_dispatch_info};
+use futures::StreamExt;
 use parity_scale_codec::{Decode, Encode};
 use serde_json::Value;
+use std::future::Future;
 use subxt::SubstrateConfig;
 use subxt::client::OnlineClientAtBlock;
 
 use super::super::types::{Event, ExtrinsicOutcome};
-use super::super::utils::{actual_weight_to_json, transform_fee_info};
-use super::events::extract_fee_from_transaction_paid_event;
+use super::super::utils::{
+    FeeBreakdown, actual_weight_to_json, insert_fee_breakdown, transform_fee_info,
+};
+use super::events::{extract_asset_fee_paid_event, extract_fee_from_transaction_paid_event};
+
+/// Run the per-extrinsic fee lookups with at most `max_concurrent` in flight.
+///
+/// Each lookup is an independent runtime call, so they don't need to wait on
+/// each other. Results come back in the order of `fee_futures`, so they can be
+/// zipped with the extrinsic indices the futures were built from.
+pub async fn collect_fee_infos<F>(
+    max_concurrent: usize,
+    fee_futures: impl IntoIterator<Item = F>,
+) -> Vec<serde_json::Map<String, Value>>
+where
+    F: Future<Output = serde_json::Map<String, Value>>,
+{
+    futures::stream::iter(fee_futures)
+        .buffered(max_concurrent.max(1))
+        .collect()
+        .await
+}
 
 /// Query fee info via runtime API using subxt's high-level API.
 ///
@@ -86,41 +112,55 @@ fn decode_fee_details(bytes: &[u8]) -> Option<Value> {
 /// 2. queryFeeDetails + calc_partial_fee (post-dispatch calculation)
 /// 3. queryInfo (pre-dispatch estimation)
 ///
+/// Extrinsics that paid their fee in an asset report the asset-denominated
+/// `partialFee` from the AssetTxFeePaid event, along with its `assetId` and `tip`.
+///
+/// With `fee_breakdown`, native fees also report their `baseFee`, `lenFee`,
+/// `adjustedWeightFee` and `tip` components. These come from queryFeeDetails, so
+/// the runtime calls are made even when the TransactionFeePaid event is present.
+/// The `tip` is the one decoded from the extrinsic's transaction extensions.
+#[allow(clippy::too_many_arguments)]
 pub async fn extract_fee_info_for_extrinsic(
     state: &AppState,
     client_at_parent: &OnlineClientAtBlock<SubstrateConfig>,
     extrinsic_hex: &str,
+    tip: Option<&str>,
     events: &[Event],
     outcome: Option<&ExtrinsicOutcome>,
     spec_version: u32,
     spec_name: &str,
+    fee_breakdown: bool,
 ) -> serde_json::Map<String, Value> {
-    // Priority 1: TransactionFeePaid event (exact fee from runtime)
-    // This avoids any RPC calls when the event is present.
-    if let Some(fee_from_event) = extract_fee_from_transaction_paid_event(events) {
+    // Fee paid in a non-native asset: the runtime APIs only know the native
+    // fee, so the event is the only correct source.
+    if let Some(asset_fee) = extract_asset_fee_paid_event(events) {
         let mut info = serde_json::Map::new();
-
-        if let Some(outcome) = outcome {
-            if let Some(ref actual_weight) = outcome.actual_weight
-                && let Some(weight_value) = actual_weight_to_json(actual_weight)
-            {
-                info.insert("weight".to_string(), weight_value);
-            }
-            if let Some(ref class) = outcome.class {
-                info.insert("class".to_string(), Value::String(class.clone()));
-            }
-        }
-
-        info.insert("partialFee".to_string(), Value::String(fee_from_event));
+        insert_outcome(&mut info, outcome);
+        info.insert(
+            "partialFee".to_string(),
+            Value::String(asset_fee.actual_fee),
+        );
+        info.insert("assetId".to_string(), asset_fee.asset_id);
+        info.insert("tip".to_string(), Value::String(asset_fee.tip));
         info.insert("kind".to_string(), Value::String("fromEvent".to_string()));
         return info;
     }
 
+    // Priority 1: TransactionFeePaid event (exact fee from runtime)
+    // This avoids any RPC calls when the event is present, unless the fee
+    // components were asked for.
+    let fee_from_event = extract_fee_from_transaction_paid_event(events);
+    if !fee_breakdown && let Some(fee_from_event) = fee_from_event {
+        return fee_info_from_event(fee_from_event, outcome, None);
+    }
+
     let extrinsic_bytes = match hex::decode(extrinsic_hex.trim_start_matches("0x")) {
         Ok(bytes) => bytes,
         Err(e) => {
             tracing::debug!("Failed to hex-decode extrinsic bytes: {e:?}");
-            return serde_json::Map::new();
+            return fee_from_event
+                .map(|fee| fee_info_from_event(fee, outcome, None))
+                .unwrap_or_default();
         }
     };
 
@@ -129,7 +169,7 @@ pub async fn extract_fee_info_for_extrinsic(
         .and_then(|o| o.actual_weight.as_ref())
         .and_then(|w| w.ref_time.clone());
 
-    let needs_fee_details = actual_weight_str.is_some()
+    let needs_fee_details = (actual_weight_str.is_some() || fee_breakdown)
         && state
             .fee_details_cache
             .is_available(spec_name, spec_version)
@@ -148,61 +188,226 @@ pub async fn extract_fee_info_for_extrinsic(
         (info, None)
     };
 
-    // Priority 2: queryFeeDetails + calc_partial_fee (post-dispatch calculation)
-    if let Some(ref actual_weight_str) = actual_weight_str
-        && let Some(fee_details_opt) = fee_details_result
-    {
-        if let Some(fee_details_response) = fee_details_opt {
+    let fee_details = match fee_details_result {
+        Some(Some(fee_details_response)) => {
             state.fee_details_cache.set_available(spec_version, true);
-
-            if let Some(fee_details) = utils::parse_fee_details(&fee_details_response)
-                && let Some((ref query_info, ref estimated_weight)) = query_info_result
-                && let Ok(partial_fee) =
-                    utils::calculate_accurate_fee(&fee_details, estimated_weight, actual_weight_str)
-            {
-                let mut info = transform_fee_info(query_info.clone());
-
-                if let Some(outcome) = outcome {
-                    if let Some(ref actual_weight) = outcome.actual_weight
-                        && let Some(weight_value) = actual_weight_to_json(actual_weight)
-                    {
-                        info.insert("weight".to_string(), weight_value);
-                    }
-                    if let Some(ref class) = outcome.class {
-                        info.insert("class".to_string(), Value::String(class.clone()));
-                    }
-                }
-
-                info.insert("partialFee".to_string(), Value::String(partial_fee));
-                info.insert(
-                    "kind".to_string(),
-                    Value::String("postDispatch".to_string()),
-                );
-                return info;
-            }
-        } else {
+            utils::parse_fee_details(&fee_details_response)
+        }
+        Some(None) => {
             state.fee_details_cache.set_available(spec_version, false);
+            None
         }
+        None => None,
+    };
+
+    let breakdown = if fee_breakdown
+        && let Some(ref fee_details) = fee_details
+        && let Some((_, ref estimated_weight)) = query_info_result
+    {
+        Some(build_fee_breakdown(
+            fee_details,
+            estimated_weight,
+            actual_weight_str.as_deref(),
+            tip,
+        ))
+    } else {
+        None
+    };
+
+    if let Some(fee_from_event) = fee_from_event {
+        return fee_info_from_event(fee_from_event, outcome, breakdown.as_ref());
+    }
+
+    // Priority 2: queryFeeDetails + calc_partial_fee (post-dispatch calculation)
+    if let Some(ref actual_weight_str) = actual_weight_str
+        && let Some(ref fee_details) = fee_details
+        && let Some((ref query_info, ref estimated_weight)) = query_info_result
+        && let Ok(partial_fee) =
+            utils::calculate_accurate_fee(fee_details, estimated_weight, actual_weight_str)
+    {
+        let mut info = transform_fee_info(query_info.clone(), breakdown.as_ref());
+        insert_outcome(&mut info, outcome);
+        info.insert("partialFee".to_string(), Value::String(partial_fee));
+        info.insert(
+            "kind".to_string(),
+            Value::String("postDispatch".to_string()),
+        );
+        return info;
     }
 
     // Priority 3: queryInfo (pre-dispatch estimation) - reuse cached result
     if let Some((query_info, _)) = query_info_result {
-        let mut info = transform_fee_info(query_info);
+        let mut info = transform_fee_info(query_info, breakdown.as_ref());
+        insert_outcome(&mut info, outcome);
+        info.insert("kind".to_string(), Value::String("preDispatch".to_string()));
+        return info;
+    }
 
-        if let Some(outcome) = outcome {
-            if let Some(ref actual_weight) = outcome.actual_weight
-                && let Some(weight_value) = actual_weight_to_json(actual_weight)
-            {
-                info.insert("weight".to_string(), weight_value);
-            }
-            if let Some(ref class) = outcome.class {
-                info.insert("class".to_string(), Value::String(class.clone()));
+    serde_json::Map::new()
+}
+
+/// Fee info for a fee taken from the TransactionFeePaid event.
+fn fee_info_from_event(
+    fee_from_event: String,
+    outcome: Option<&ExtrinsicOutcome>,
+    breakdown: Option<&FeeBreakdown>,
+) -> serde_json::Map<String, Value> {
+    let mut info = serde_json::Map::new();
+    insert_outcome(&mut info, outcome);
+    info.insert("partialFee".to_string(), Value::String(fee_from_event));
+    if let Some(breakdown) = breakdown {
+        insert_fee_breakdown(&mut info, breakdown);
+    }
+    info.insert("kind".to_string(), Value::String("fromEvent".to_string()));
+    info
+}
+
+/// Insert the actual weight and dispatch class from the extrinsic's outcome.
+///
+/// A legacy single-component event weight only carries `refTime`. When queryInfo
+/// already gave a `{ refTime, proofSize }` weight, the actual `refTime` replaces the
+/// estimated one and only `proofSize` is kept from queryInfo.
+fn insert_outcome(info: &mut serde_json::Map<String, Value>, outcome: Option<&ExtrinsicOutcome>) {
+    let Some(outcome) = outcome else {
+        return;
+    };
+    if let Some(ref actual_weight) = outcome.actual_weight
+        && let Some(weight_value) = actual_weight_to_json(actual_weight)
+    {
+        let weight = match (weight_value, info.get("weight")) {
+            (Value::String(ref_time), Some(Value::Object(query_info))) => {
+                let mut weight = serde_json::Map::new();
+                weight.insert("refTime".to_string(), Value::String(ref_time));
+                if let Some(proof_size) = query_info.get("proofSize") {
+                    weight.insert("proofSize".to_string(), proof_size.clone());
+                }
+                Value::Object(weight)
             }
+            (weight, _) => weight,
+        };
+        info.insert("weight".to_string(), weight);
+    }
+    if let Some(ref class) = outcome.class {
+        info.insert("class".to_string(), Value::String(class.clone()));
+    }
+}
+
+/// Fee components from queryFeeDetails. When the actual weight is known, the weight
+/// fee is scaled to it so the components add up to the post-dispatch fee.
+fn build_fee_breakdown(
+    fee_details: &utils::FeeDetails,
+    estimated_weight: &str,
+    actual_weight: Option<&str>,
+    tip: Option<&str>,
+) -> FeeBreakdown {
+    let fee_details = actual_weight
+        .and_then(|actual_weight| {
+            utils::post_dispatch_fee_details(fee_details, estimated_weight, actual_weight).ok()
+        })
+        .unwrap_or_else(|| fee_details.clone());
+
+    FeeBreakdown {
+        fee_details,
+        tip: tip.map(str::to_string),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::blocks::types::ActualWeight;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn outcome_with_weight(ref_time: &str, proof_size: Option<&str>) -> ExtrinsicOutcome {
+        ExtrinsicOutcome {
+            actual_weight: Some(ActualWeight {
+                ref_time: Some(ref_time.to_string()),
+                proof_size: proof_size.map(str::to_string),
+            }),
+            class: Some("Normal".to_string()),
+            ..Default::default()
         }
+    }
 
-        info.insert("kind".to_string(), Value::String("preDispatch".to_string()));
-        return info;
+    #[test]
+    fn test_insert_outcome_reports_both_weight_components() {
+        let mut info = serde_json::Map::new();
+        insert_outcome(&mut info, Some(&outcome_with_weight("1000", Some("64"))));
+
+        assert_eq!(
+            info["weight"],
+            json!({"refTime": "1000", "proofSize": "64"})
+        );
+        assert_eq!(info["class"], "Normal");
     }
 
-    serde_json::Map::new()
+    #[test]
+    fn test_insert_outcome_legacy_weight_keeps_actual_ref_time() {
+        let mut info = serde_json::Map::new();
+        info.insert(
+            "weight".to_string(),
+            json!({"refTime": "1200", "proofSize": "80"}),
+        );
+        insert_outcome(&mut info, Some(&outcome_with_weight("1000", None)));
+
+        assert_eq!(
+            info["weight"],
+            json!({"refTime": "1000", "proofSize": "80"})
+        );
+
+        let mut info = serde_json::Map::new();
+        info.insert("weight".to_string(), json!("1200"));
+        insert_outcome(&mut info, Some(&outcome_with_weight("1000", None)));
+
+        assert_eq!(info["weight"], json!("1000"));
+    }
+
+    #[test]
+    fn test_build_fee_breakdown_uses_decoded_tip() {
+        let fee_details = utils::FeeDetails {
+            base_fee: "100".to_string(),
+            len_fee: "20".to_string(),
+            adjusted_weight_fee: "30".to_string(),
+        };
+
+        let breakdown = build_fee_breakdown(&fee_details, "1000", None, Some("5"));
+        assert_eq!(breakdown.fee_details.base_fee, "100");
+        assert_eq!(breakdown.tip.as_deref(), Some("5"));
+
+        let breakdown = build_fee_breakdown(&fee_details, "1000", None, None);
+        assert_eq!(breakdown.tip, None);
+    }
+
+    #[tokio::test]
+    async fn test_collect_fee_infos_keeps_order_and_bounds_concurrency() {
+        let in_flight = AtomicUsize::new(0);
+        let max_seen = AtomicUsize::new(0);
+
+        let futures = (0..10u64).map(|i| {
+            let (in_flight, max_seen) = (&in_flight, &max_seen);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_seen.fetch_max(now, Ordering::SeqCst);
+                // Later extrinsics finish first
+                tokio::time::sleep(Duration::from_millis(20 - i)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let mut info = serde_json::Map::new();
+                info.insert("partialFee".to_string(), Value::String(i.to_string()));
+                info
+            }
+        });
+
+        let results = collect_fee_infos(3, futures).await;
+
+        let fees: Vec<_> = results
+            .iter()
+            .map(|info| info["partialFee"].clone())
+            .collect();
+        let expected: Vec<_> = (0..10).map(|i| Value::String(i.to_string())).collect();
+        assert_eq!(fees, expected);
+        assert!(max_seen.load(Ordering::SeqCst) <= 3);
+    }
 }
@@ -17,13 +17,16 @@
 pub mod events;
 pub mod extrinsics;
 pub mod fees;
+pub mod weight;
 
 pub use events::{
-    categorize_events, extract_class_from_event_data, extract_fee_from_transaction_paid_event,
-    extract_pays_fee_from_event_data, extract_weight_from_event_data, fetch_block_events,
-    fetch_block_events_with_client, fetch_block_events_with_prefix,
+    EventsFilter, categorize_events, extract_asset_fee_paid_event, extract_class_from_event_data,
+    extract_fee_from_transaction_paid_event, extract_pays_fee_from_event_data,
+    extract_weight_from_event_data, fetch_block_events, fetch_block_events_with_client,
+    fetch_block_events_with_prefix, paginate_block_events,
 };
 pub use extrinsics::{
     extract_extrinsics, extract_extrinsics_with_client, extract_extrinsics_with_prefix,
 };
-pub use fees::extract_fee_info_for_extrinsic;
+pub use fees::{collect_fee_infos, extract_fee_info_for_extrinsic};
+pub use weight::summarize_block_weight;
@@ -0,0 +1,249 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Block weight aggregation.
+//!
+//! Reports the weight consumed per dispatch class from `System::BlockWeight` and
+//! compares it with the limits of the `System::BlockWeights` constant. On runtimes
+//! where that storage can't be read, the weight each extrinsic reported in its
+//! ExtrinsicSuccess/ExtrinsicFailed event is summed instead, which leaves out hooks.
+
+use crate::handlers::runtime_queries::system::{BlockWeights, ConsumedWeight, Weight};
+
+use super::super::types::{
+    BlockWeightSummary, ExtrinsicOutcome, WeightUsage, WeightUtilization, WeightValue,
+};
+
+/// Weight consumed so far, summed without overflow
+#[derive(Debug, Default, Clone, Copy)]
+struct Consumed {
+    ref_time: u128,
+    proof_size: u128,
+}
+
+impl Consumed {
+    fn add(&mut self, other: Consumed) {
+        self.ref_time += other.ref_time;
+        self.proof_size += other.proof_size;
+    }
+}
+
+impl From<Weight> for Consumed {
+    fn from(weight: Weight) -> Self {
+        Consumed {
+            ref_time: weight.ref_time.into(),
+            proof_size: weight.proof_size.into(),
+        }
+    }
+}
+
+/// Summarize the weight consumed by a block.
+///
+/// `block_weight` is the block's `System::BlockWeight`. Without it, the extrinsics'
+/// event weights are summed instead: extrinsics without a known class or weight are
+/// skipped and legacy single-value weights count towards `refTime` only. Limits are
+/// omitted when `limits` is None.
+pub fn summarize_block_weight(
+    block_weight: Option<&ConsumedWeight>,
+    extrinsic_outcomes: &[ExtrinsicOutcome],
+    limits: Option<&BlockWeights>,
+) -> BlockWeightSummary {
+    let (normal, operational, mandatory) = match block_weight {
+        Some(weight) => (
+            weight.normal.into(),
+            weight.operational.into(),
+            weight.mandatory.into(),
+        ),
+        None => sum_extrinsic_weights(extrinsic_outcomes),
+    };
+
+    let mut total = normal;
+    total.add(operational);
+    total.add(mandatory);
+
+    let per_class = limits.map(|l| &l.per_class);
+    BlockWeightSummary {
+        normal: weight_usage(normal, per_class.and_then(|c| c.normal.max_total)),
+        operational: weight_usage(operational, per_class.and_then(|c| c.operational.max_total)),
+        mandatory: weight_usage(mandatory, per_class.and_then(|c| c.mandatory.max_total)),
+        total: weight_usage(total, limits.map(|l| l.max_block)),
+    }
+}
+
+/// Sum the weight reported by each extrinsic's outcome event, per dispatch class.
+fn sum_extrinsic_weights(
+    extrinsic_outcomes: &[ExtrinsicOutcome],
+) -> (Consumed, Consumed, Consumed) {
+    let mut normal = Consumed::default();
+    let mut operational = Consumed::default();
+    let mut mandatory = Consumed::default();
+
+    for outcome in extrinsic_outcomes {
+        let (Some(class), Some(weight)) = (&outcome.class, &outcome.actual_weight) else {
+            continue;
+        };
+        let parse = |value: &Option<String>| {
+            value
+                .as_deref()
+                .and_then(|v| v.parse::<u128>().ok())
+                .unwrap_or(0)
+        };
+        let consumed = Consumed {
+            ref_time: parse(&weight.ref_time),
+            proof_size: parse(&weight.proof_size),
+        };
+
+        match class.as_str() {
+            "Normal" => normal.add(consumed),
+            "Operational" => operational.add(consumed),
+            "Mandatory" => mandatory.add(consumed),
+            other => tracing::debug!("Unknown dispatch class: {other}"),
+        }
+    }
+
+    (normal, operational, mandatory)
+}
+
+fn weight_usage(consumed: Consumed, limit: Option<Weight>) -> WeightUsage {
+    let utilization = limit.and_then(|limit| {
+        Some(WeightUtilization {
+            ref_time: percentage(consumed.ref_time, limit.ref_time)?,
+            proof_size: percentage(consumed.proof_size, limit.proof_size)?,
+        })
+    });
+
+    WeightUsage {
+        consumed: WeightValue {
+            ref_time: consumed.ref_time.to_string(),
+            proof_size: consumed.proof_size.to_string(),
+        },
+        limit: limit.map(|limit| WeightValue {
+            ref_time: limit.ref_time.to_string(),
+            proof_size: limit.proof_size.to_string(),
+        }),
+        utilization,
+    }
+}
+
+/// Percentage of `limit` used, rounded to two decimals
+fn percentage(consumed: u128, limit: u64) -> Option<f64> {
+    if limit == 0 {
+        return None;
+    }
+    let percent = consumed as f64 / limit as f64 * 100.0;
+    Some((percent * 100.0).round() / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::blocks::types::ActualWeight;
+    use crate::handlers::runtime_queries::system::{PerDispatchClass, WeightsPerClass};
+
+    fn outcome(class: &str, ref_time: &str, proof_size: Option<&str>) -> ExtrinsicOutcome {
+        ExtrinsicOutcome {
+            success: true,
+            class: Some(class.to_string()),
+            actual_weight: Some(ActualWeight {
+                ref_time: Some(ref_time.to_string()),
+                proof_size: proof_size.map(str::to_string),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn limits() -> BlockWeights {
+        let weight = |ref_time, proof_size| Weight {
+            ref_time,
+            proof_size,
+        };
+        BlockWeights {
+            max_block: weight(2_000, 1_000),
+            per_class: PerDispatchClass {
+                normal: WeightsPerClass {
+                    max_total: Some(weight(1_500, 750)),
+                },
+                operational: WeightsPerClass {
+                    max_total: Some(weight(2_000, 1_000)),
+                },
+                mandatory: WeightsPerClass { max_total: None },
+            },
+        }
+    }
+
+    #[test]
+    fn test_summarize_block_weight_per_class_and_total() {
+        let outcomes = vec![
+            outcome("Mandatory", "100", Some("10")),
+            outcome("Normal", "300", Some("50")),
+            outcome("Normal", "150", Some("25")),
+            ExtrinsicOutcome::default(),
+        ];
+
+        let summary = summarize_block_weight(None, &outcomes, Some(&limits()));
+
+        assert_eq!(summary.normal.consumed.ref_time, "450");
+        assert_eq!(
+            summary.normal.utilization,
+            Some(WeightUtilization {
+                ref_time: 30.0,
+                proof_size: 10.0
+            })
+        );
+        assert_eq!(summary.operational.consumed.ref_time, "0");
+        assert!(summary.mandatory.limit.is_none());
+        assert!(summary.mandatory.utilization.is_none());
+        assert_eq!(summary.total.consumed.ref_time, "550");
+        assert_eq!(summary.total.consumed.proof_size, "85");
+        assert_eq!(
+            summary.total.utilization,
+            Some(WeightUtilization {
+                ref_time: 27.5,
+                proof_size: 8.5
+            })
+        );
+    }
+
+    #[test]
+    fn test_summarize_block_weight_without_limits() {
+        let outcomes = vec![outcome("Operational", "42", None)];
+
+        let summary = summarize_block_weight(None, &outcomes, None);
+
+        assert_eq!(summary.operational.consumed.ref_time, "42");
+        assert_eq!(summary.operational.consumed.proof_size, "0");
+        assert!(summary.total.limit.is_none());
+        assert!(summary.total.utilization.is_none());
+    }
+
+    #[test]
+    fn test_summarize_block_weight_prefers_block_weight_storage() {
+        let weight = |ref_time, proof_size| Weight {
+            ref_time,
+            proof_size,
+        };
+        // Includes 250 of hook weight that no extrinsic event reports
+        let block_weight = ConsumedWeight {
+            normal: weight(450, 75),
+            operational: weight(0, 0),
+            mandatory: weight(350, 10),
+        };
+        let outcomes = vec![
+            outcome("Mandatory", "100", Some("10")),
+            outcome("Normal", "450", Some("75")),
+        ];
+
+        let summary = summarize_block_weight(Some(&block_weight), &outcomes, Some(&limits()));
+
+        assert_eq!(summary.mandatory.consumed.ref_time, "350");
+        assert_eq!(summary.total.consumed.ref_time, "800");
+        assert_eq!(summary.total.consumed.proof_size, "85");
+        assert_eq!(
+            summary.total.utilization,
+            Some(WeightUtilization {
+                ref_time: 40.0,
+                proof_size: 8.5
+            })
+        );
+    }
+}
@@ -6,6 +6,7 @@
 //! This module contains all the types used by `/blocks/*` endpoints including
 //! request parameters, response structures, and internal types.
 
+use super::processing::EventsFilter;
 use crate::state::RelayChainError;
 use crate::utils::{self, EraInfo, RcBlockError};
 use axum::{Json, http::StatusCode, response::IntoResponse};
@@ -34,6 +35,30 @@ pub struct BlockBuildParams {
     pub para_id: Option<u32>,
     /// When true, convert AccountId32 addresses to EVM format for revive pallet events
     pub use_evm_format: bool,
+    /// Only return onInitialize/onFinalize events matching this filter
+    pub events_filter: Option<EventsFilter>,
+    /// Also apply `events_filter` to each extrinsic's events
+    pub filter_extrinsic_events: bool,
+    /// When true, include the fee components in each extrinsic's info
+    pub fee_breakdown: bool,
+    /// When true, include the digest logs with known consensus payloads decoded
+    pub decode_logs: bool,
+    /// When true, include the block's weight consumption against its limits
+    pub include_block_weight: bool,
+    /// Only return this page of the block's events
+    pub events_page: Option<EventsPage>,
+}
+
+/// Default page size when only `eventsPage` is given
+const DEFAULT_EVENTS_PER_PAGE: u32 = 100;
+/// Largest accepted `eventsPerPage`
+const MAX_EVENTS_PER_PAGE: u32 = 1000;
+
+/// A 1-based page of a block's events across all phases
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventsPage {
+    pub page: u32,
+    pub per_page: u32,
 }
 
 /// Query parameters for /blocks/{blockId} endpoint
@@ -64,6 +89,28 @@ pub struct BlockQueryParams {
     /// When true, convert AccountId32 addresses to EVM format (20 bytes) for revive pallet events
     #[serde(default)]
     pub use_evm_format: bool,
+    /// Comma-separated `Pallet.Event` / `Pallet.*` patterns. Non-matching onInitialize and
+    /// onFinalize events are excluded; extrinsic events are kept unless `filterExtrinsicEvents`
+    #[serde(default)]
+    pub events_filter: Option<EventsFilter>,
+    /// When true, also apply `eventsFilter` to the events listed under each extrinsic
+    #[serde(default)]
+    pub filter_extrinsic_events: bool,
+    /// When true, include `baseFee`, `lenFee`, `adjustedWeightFee` and `tip` in each extrinsic's info
+    #[serde(default)]
+    pub fee_breakdown: bool,
+    /// When true, include `decodedLogs` with PreRuntime, Consensus and Seal payloads decoded
+    #[serde(default)]
+    pub decode_logs: bool,
+    /// When true, include `blockWeight` with each dispatch class's consumed weight and utilization
+    #[serde(default)]
+    pub include_block_weight: bool,
+    /// 1-based page of the block's events to return
+    #[serde(default)]
+    pub events_page: Option<u32>,
+    /// Number of events per page (default 100, max 1000)
+    #[serde(default)]
+    pub events_per_page: Option<u32>,
 }
 
 fn default_true() -> bool {
@@ -88,6 +135,9 @@ pub struct ExtrinsicQueryParams {
     /// When true, convert AccountId32 addresses to EVM format (20 bytes) for revive pallet events
     #[serde(default)]
     pub use_evm_format: bool,
+    /// When true, include `baseFee`, `lenFee`, `adjustedWeightFee` and `tip` in the extrinsic's info
+    #[serde(default)]
+    pub fee_breakdown: bool,
 }
 
 /// Path parameters for /blocks/{blockId}/extrinsics/{extrinsicIndex} and
@@ -111,20 +161,49 @@ impl Default for BlockQueryParams {
             decoded_xcm_msgs: false,
             para_id: None,
             use_evm_format: false,
+            events_filter: None,
+            filter_extrinsic_events: false,
+            fee_breakdown: false,
+            decode_logs: false,
+            include_block_weight: false,
+            events_page: None,
+            events_per_page: None,
         }
     }
 }
 
 impl BlockQueryParams {
-    pub fn to_build_params(&self) -> BlockBuildParams {
-        BlockBuildParams {
+    pub fn to_build_params(&self) -> Result<BlockBuildParams, GetBlockError> {
+        Ok(BlockBuildParams {
             event_docs: self.event_docs,
             extrinsic_docs: self.extrinsic_docs,
             no_fees: self.no_fees,
             decoded_xcm_msgs: self.decoded_xcm_msgs,
             para_id: self.para_id,
             use_evm_format: self.use_evm_format,
+            events_filter: self.events_filter.clone(),
+            filter_extrinsic_events: self.filter_extrinsic_events,
+            fee_breakdown: self.fee_breakdown,
+            decode_logs: self.decode_logs,
+            include_block_weight: self.include_block_weight,
+            events_page: self.events_page()?,
+        })
+    }
+
+    /// Validate `eventsPage` and `eventsPerPage`. Returns None when neither is given.
+    pub fn events_page(&self) -> Result<Option<EventsPage>, GetBlockError> {
+        if self.events_page.is_none() && self.events_per_page.is_none() {
+            return Ok(None);
+        }
+        let page = self.events_page.unwrap_or(1);
+        if page == 0 {
+            return Err(GetBlockError::InvalidEventsPage);
         }
+        let per_page = self.events_per_page.unwrap_or(DEFAULT_EVENTS_PER_PAGE);
+        if per_page == 0 || per_page > MAX_EVENTS_PER_PAGE {
+            return Err(GetBlockError::InvalidEventsPerPage);
+        }
+        Ok(Some(EventsPage { page, per_page }))
     }
 }
 
@@ -135,6 +214,12 @@ pub struct BlockHeaderQueryParams {
     /// When true, treat block identifier as Relay Chain block and return Asset Hub blocks included in it
     #[serde(default, rename = "useRcBlock")]
     pub use_rc_block: bool,
+    /// When true, resolve the block author from the consensus digest and the validator set
+    #[serde(default, rename = "includeAuthor")]
+    pub include_author: bool,
+    /// When true, include `digest.decodedLogs` with known consensus payloads decoded
+    #[serde(default, rename = "decodeLogs")]
+    pub decode_logs: bool,
 }
 
 // ================================================================================================
@@ -232,6 +317,12 @@ pub enum GetBlockError {
 
     #[error("Inputted range is greater than the 500 range limit.")]
     RangeTooLarge,
+
+    #[error("eventsPage must be a positive integer.")]
+    InvalidEventsPage,
+
+    #[error("eventsPerPage must be an integer between 1 and 1000.")]
+    InvalidEventsPerPage,
 }
 
 impl From<utils::AtBlockError> for GetBlockError {
@@ -294,7 +385,9 @@ impl IntoResponse for GetBlockError {
             | GetBlockError::InvalidRangeMin
             | GetBlockError::InvalidRangeMax
             | GetBlockError::InvalidRangeMinMax
-            | GetBlockError::RangeTooLarge => (StatusCode::BAD_REQUEST, self.to_string()),
+            | GetBlockError::RangeTooLarge
+            | GetBlockError::InvalidEventsPage
+            | GetBlockError::InvalidEventsPerPage => (StatusCode::BAD_REQUEST, self.to_string()),
             GetBlockError::BlockResolveFailed(inner) => (inner.status_code(), inner.to_string()),
             GetBlockError::RelayChain(RelayChainError::NotConfigured) => {
                 (StatusCode::BAD_REQUEST, self.to_string())
@@ -534,6 +627,21 @@ pub fn convert_digest_logs_to_sidecar_format(logs: Vec<DigestLog>) -> Vec<serde_
         .collect()
 }
 
+/// A digest log with its consensus payload decoded (only produced when decodeLogs=true)
+#[derive(Debug, Serialize)]
+pub struct DecodedDigestLog {
+    /// Log type in camelCase, e.g. `preRuntime`, `consensus` or `seal`
+    #[serde(rename = "type")]
+    pub log_type: String,
+    /// Consensus engine ID as text (e.g. `BABE`, `aura`, `FRNK`), absent for untagged logs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engine: Option<String>,
+    /// Decoded payload, or the payload hex when the engine's format is unknown
+    pub data: Value,
+    /// The log's value in Sidecar's hex format
+    pub raw: Value,
+}
+
 /// Lightweight block header information (no author/logs decoding)
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -546,6 +654,9 @@ pub struct BlockHeaderResponse {
     /// Block hash (only present for /blocks/head/header, not for /blocks/{blockId}/header)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
+    /// Block author (only present when includeAuthor=true and the author can be determined)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_id: Option<String>,
     /// Relay Chain block hash (only present when useRcBlock=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rc_block_hash: Option<String>,
@@ -627,6 +738,9 @@ pub struct ExtrinsicInfo {
     pub events: Vec<Event>,
     /// Whether the extrinsic executed successfully (determined from System.ExtrinsicSuccess event)
     pub success: bool,
+    /// The DispatchError from System.ExtrinsicFailed, with module errors named (only present on failure)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_error: Option<Value>,
     /// Whether the extrinsic pays a fee (false for unsigned, value from DispatchInfo for signed)
     /// Extracted from DispatchInfo in System.ExtrinsicSuccess/ExtrinsicFailed events
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -678,6 +792,9 @@ pub struct BlockResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author_id: Option<String>,
     pub logs: Vec<DigestLog>,
+    /// Digest logs with their payloads decoded (only present when decodeLogs=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decoded_logs: Option<Vec<DecodedDigestLog>>,
     pub on_initialize: OnInitialize,
     pub extrinsics: Vec<ExtrinsicInfo>,
     pub on_finalize: OnFinalize,
@@ -696,6 +813,58 @@ pub struct BlockResponse {
     /// Decoded XCM messages (omitted when decodedXcmMsgs=false)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub decoded_xcm_msgs: Option<XcmMessages>,
+    /// Weight consumed per dispatch class (only present when includeBlockWeight=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_weight: Option<BlockWeightSummary>,
+    /// Which of the block's events were returned (only present with eventsPage/eventsPerPage)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events_pagination: Option<EventsPagination>,
+}
+
+/// Position of the returned events within all of the block's events
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventsPagination {
+    pub page: u32,
+    pub per_page: u32,
+    /// Number of events in all phases across all pages
+    pub total_events: usize,
+}
+
+/// Weight consumed by a block, per dispatch class and in total
+#[derive(Debug, Serialize)]
+pub struct BlockWeightSummary {
+    pub normal: WeightUsage,
+    pub operational: WeightUsage,
+    pub mandatory: WeightUsage,
+    pub total: WeightUsage,
+}
+
+/// Consumed weight against its limit, with utilization in percent
+#[derive(Debug, Serialize)]
+pub struct WeightUsage {
+    pub consumed: WeightValue,
+    /// Omitted when the limit is unknown or the class is unlimited
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<WeightValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utilization: Option<WeightUtilization>,
+}
+
+/// A `{ refTime, proofSize }` weight with numbers as strings
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightValue {
+    pub ref_time: String,
+    pub proof_size: String,
+}
+
+/// Percentage of the limit used by each weight component
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightUtilization {
+    pub ref_time: f64,
+    pub proof_size: f64,
 }
 
 // ================================================================================================
@@ -716,6 +885,9 @@ pub struct XcmMessages {
 #[serde(rename_all = "camelCase")]
 pub struct UpwardMessage {
     pub origin_para_id: String,
+    /// XCM version the message was decoded as; omitted when it couldn't be decoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xcm_version: Option<String>,
     pub data: Value,
 }
 
@@ -725,6 +897,9 @@ pub struct UpwardMessage {
 pub struct DownwardMessage {
     pub sent_at: String,
     pub msg: String,
+    /// XCM version the message was decoded as; omitted when it couldn't be decoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xcm_version: Option<String>,
     pub data: Value,
 }
 
@@ -738,6 +913,9 @@ pub struct HorizontalMessage {
     pub destination_para_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sent_at: Option<String>,
+    /// XCM version the message was decoded as; omitted when it couldn't be decoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub xcm_version: Option<String>,
     pub data: Value,
 }
 
@@ -774,6 +952,8 @@ pub struct ActualWeight {
 pub struct ExtrinsicOutcome {
     /// Whether the extrinsic succeeded (true if ExtrinsicSuccess event found)
     pub success: bool,
+    /// DispatchError of a failed extrinsic (first element of ExtrinsicFailed's data)
+    pub dispatch_error: Option<Value>,
     /// Whether the extrinsic pays a fee (extracted from DispatchInfo)
     /// None means we couldn't determine it from events
     pub pays_fee: Option<bool>,
@@ -816,7 +996,8 @@ mod tests {
             "useRcBlock": true,
             "decodedXcmMsgs": true,
             "paraId": 2000,
-            "useEvmFormat": true
+            "useEvmFormat": true,
+            "feeBreakdown": true
         }"#;
         let params: BlockQueryParams = serde_json::from_str(json).unwrap();
         assert!(params.event_docs);
@@ -827,6 +1008,36 @@ mod tests {
         assert!(params.decoded_xcm_msgs);
         assert_eq!(params.para_id, Some(2000));
         assert!(params.use_evm_format);
+        assert!(params.fee_breakdown);
+    }
+
+    #[test]
+    fn test_block_query_params_events_page() {
+        let parse = |json: &str| serde_json::from_str::<BlockQueryParams>(json).unwrap();
+
+        assert_eq!(parse("{}").events_page().unwrap(), None);
+        assert_eq!(
+            parse(r#"{"eventsPage": 3}"#).events_page().unwrap(),
+            Some(EventsPage {
+                page: 3,
+                per_page: 100
+            })
+        );
+        assert_eq!(
+            parse(r#"{"eventsPerPage": 20}"#).events_page().unwrap(),
+            Some(EventsPage {
+                page: 1,
+                per_page: 20
+            })
+        );
+        assert!(matches!(
+            parse(r#"{"eventsPage": 0}"#).events_page(),
+            Err(GetBlockError::InvalidEventsPage)
+        ));
+        assert!(matches!(
+            parse(r#"{"eventsPerPage": 1001}"#).events_page(),
+            Err(GetBlockError::InvalidEventsPerPage)
+        ));
     }
 
     #[test]
@@ -859,6 +1070,37 @@ mod tests {
         let json = r#"{"useRcBlock": true}"#;
         let params: BlockHeaderQueryParams = serde_json::from_str(json).unwrap();
         assert!(params.use_rc_block);
+        assert!(!params.include_author);
+
+        let json = r#"{"includeAuthor": true}"#;
+        let params: BlockHeaderQueryParams = serde_json::from_str(json).unwrap();
+        assert!(params.include_author);
+    }
+
+    #[test]
+    fn test_block_header_response_author_id() {
+        let mut response = BlockHeaderResponse {
+            parent_hash: "0x01".to_string(),
+            number: "100".to_string(),
+            state_root: "0x02".to_string(),
+            extrinsics_root: "0x03".to_string(),
+            digest: serde_json::json!({"logs": []}),
+            hash: None,
+            author_id: None,
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("authorId").is_none());
+
+        response.author_id = Some("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".to_string());
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json["authorId"],
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        );
     }
 
     #[test]
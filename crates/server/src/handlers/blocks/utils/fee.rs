@@ -10,6 +10,33 @@ use serde_json::Value;
 
 use super::super::types::ActualWeight;
 use super::numeric::extract_number_as_string;
+use crate::utils::FeeDetails;
+
+/// Components of an extrinsic's fee, reported when `feeBreakdown=true`
+#[derive(Debug, Clone)]
+pub struct FeeBreakdown {
+    /// Inclusion fee components from `queryFeeDetails`
+    pub fee_details: FeeDetails,
+    /// Tip from the extrinsic's signed extensions, if it could be read
+    pub tip: Option<String>,
+}
+
+/// Insert the `baseFee`, `lenFee`, `adjustedWeightFee` and `tip` components into `info`
+pub fn insert_fee_breakdown(info: &mut serde_json::Map<String, Value>, breakdown: &FeeBreakdown) {
+    let details = &breakdown.fee_details;
+    info.insert(
+        "baseFee".to_string(),
+        Value::String(details.base_fee.clone()),
+    );
+    info.insert("lenFee".to_string(), Value::String(details.len_fee.clone()));
+    info.insert(
+        "adjustedWeightFee".to_string(),
+        Value::String(details.adjusted_weight_fee.clone()),
+    );
+    if let Some(ref tip) = breakdown.tip {
+        info.insert("tip".to_string(), Value::String(tip.clone()));
+    }
+}
 
 /// Transform fee info from payment_queryInfo RPC response into the expected format
 ///
@@ -18,8 +45,12 @@ use super::numeric::extract_number_as_string;
 /// - class: "Normal", "Operational", or "Mandatory"
 /// - partialFee: fee amount (usually as hex string from RPC)
 ///
-/// We transform this to match sidecar's format with string values
-pub fn transform_fee_info(fee_info: Value) -> serde_json::Map<String, Value> {
+/// We transform this to match sidecar's format with string values, followed by the
+/// fee components when a `breakdown` is given
+pub fn transform_fee_info(
+    fee_info: Value,
+    breakdown: Option<&FeeBreakdown>,
+) -> serde_json::Map<String, Value> {
     let mut result = serde_json::Map::new();
 
     if let Some(weight) = fee_info.get("weight") {
@@ -65,6 +96,10 @@ pub fn transform_fee_info(fee_info: Value) -> serde_json::Map<String, Value> {
         );
     }
 
+    if let Some(breakdown) = breakdown {
+        insert_fee_breakdown(&mut result, breakdown);
+    }
+
     result
 }
 
@@ -79,3 +114,42 @@ pub fn actual_weight_to_json(actual_weight: &ActualWeight) -> Option<Value> {
         Value::String(ref_time.clone())
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transform_fee_info_with_breakdown() {
+        let query_info = json!({
+            "weight": { "refTime": 1000, "proofSize": 10 },
+            "class": "Normal",
+            "partialFee": "0x5e",
+        });
+        let breakdown = FeeBreakdown {
+            fee_details: FeeDetails {
+                base_fee: "40".to_string(),
+                len_fee: "4".to_string(),
+                adjusted_weight_fee: "50".to_string(),
+            },
+            tip: Some("7".to_string()),
+        };
+
+        let info = transform_fee_info(query_info.clone(), Some(&breakdown));
+
+        assert_eq!(
+            Value::Object(info),
+            json!({
+                "weight": { "refTime": "1000", "proofSize": "10" },
+                "class": "Normal",
+                "partialFee": "94",
+                "baseFee": "40",
+                "lenFee": "4",
+                "adjustedWeightFee": "50",
+                "tip": "7",
+            })
+        );
+        assert!(!transform_fee_info(query_info, None).contains_key("baseFee"));
+    }
+}
@@ -11,5 +11,5 @@ pub mod fee;
 pub mod numeric;
 
 // Re-export commonly used functions
-pub use fee::{actual_weight_to_json, transform_fee_info};
+pub use fee::{FeeBreakdown, actual_weight_to_json, insert_fee_breakdown, transform_fee_info};
 pub use numeric::{extract_number_as_string, extract_numeric_string};
@@ -5,8 +5,6 @@ use crate::extractors::JsonQuery;
 use crate::state::AppState;
 use crate::utils::BlockId;
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
-use frame_metadata::RuntimeMetadataPrefixed;
-use parity_scale_codec::Decode;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashSet;
@@ -18,12 +16,20 @@ use utoipa::ToSchema;
 pub struct CapabilitiesResponse {
     chain: String,
     pallets: Vec<String>,
+    /// Pallets from `expected` found in the runtime (only with `expected`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    present: Option<Vec<String>>,
+    /// Pallets from `expected` missing from the runtime (only with `expected`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct AtBlockParam {
     pub at: Option<String>,
+    /// Comma-separated pallet names to check for
+    pub expected: Option<String>,
 }
 
 #[derive(Debug, Error)]
@@ -64,7 +70,8 @@ impl IntoResponse for CapabilitiesError {
     summary = "API capabilities",
     description = "Returns the chain name and list of available pallets in the runtime metadata.",
     params(
-        ("at" = Option<String>, Query, description = "Block hash or number to query at")
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("expected" = Option<String>, Query, description = "Comma-separated pallet names; the response then lists which are `present` and `missing`")
     ),
     responses(
         (status = 200, description = "Chain capabilities", body = CapabilitiesResponse),
@@ -80,32 +87,53 @@ pub async fn get_capabilities(
     let block_id = params.at.map(|s| s.parse::<BlockId>()).transpose()?;
     let resolved = crate::utils::resolve_block(&state, block_id).await?;
 
-    let metadata_hex: String = state
-        .rpc_client
-        .request(
-            "state_getMetadata",
-            subxt_rpcs::client::rpc_params![&resolved.hash],
-        )
+    let to_error = |e| match e {
+        crate::utils::MetadataFetchError::RpcFailed(e) => CapabilitiesError::RpcFailed(e),
+        other => CapabilitiesError::DecodeFailed(other.to_string()),
+    };
+    let cached = state
+        .get_runtime_metadata_at_hash(&resolved.hash)
         .await
-        .map_err(CapabilitiesError::RpcFailed)?;
+        .map_err(to_error)?;
+    let metadata = cached.decoded().map_err(to_error)?;
+
+    let pallets_set: HashSet<String> = crate::utils::capabilities::pallets_in_metadata(metadata);
+    let (present, missing) = match params.expected.as_deref() {
+        Some(expected) => {
+            let (present, missing) = compare_pallets(&pallets_set, expected);
+            (Some(present), Some(missing))
+        }
+        None => (None, None),
+    };
 
-    let hex_str = metadata_hex.strip_prefix("0x").unwrap_or(&metadata_hex);
-    let metadata_bytes =
-        hex::decode(hex_str).map_err(|e| CapabilitiesError::DecodeFailed(e.to_string()))?;
-
-    let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata_bytes[..])
-        .map_err(|e| CapabilitiesError::DecodeFailed(e.to_string()))?;
-
-    let pallets_set: HashSet<String> = crate::utils::capabilities::pallets_in_metadata(&metadata);
     let mut pallets: Vec<String> = pallets_set.into_iter().collect();
     pallets.sort();
 
     Ok(Json(CapabilitiesResponse {
         chain: state.chain_info.spec_name.clone(),
         pallets,
+        present,
+        missing,
     }))
 }
 
+/// Split the comma-separated `expected` pallet names into those in `pallets` and those not.
+///
+/// Names match case-insensitively; present pallets use the runtime's spelling.
+fn compare_pallets(pallets: &HashSet<String>, expected: &str) -> (Vec<String>, Vec<String>) {
+    let mut present = Vec::new();
+    let mut missing = Vec::new();
+
+    for name in expected.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        match pallets.iter().find(|p| p.eq_ignore_ascii_case(name)) {
+            Some(pallet) => present.push(pallet.clone()),
+            None => missing.push(name.to_string()),
+        }
+    }
+
+    (present, missing)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +163,23 @@ mod tests {
         let params: AtBlockParam = serde_json::from_str(json).unwrap();
         assert_eq!(params.at, None);
     }
+
+    #[test]
+    fn test_compare_pallets() {
+        let pallets: HashSet<String> = ["System", "Balances", "Staking"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let (present, missing) = compare_pallets(&pallets, "balances, Staking,Assets,,");
+        assert_eq!(present, vec!["Balances", "Staking"]);
+        assert_eq!(missing, vec!["Assets"]);
+    }
+
+    #[test]
+    fn test_at_block_param_accepts_expected() {
+        let json = r#"{"expected": "System,Balances"}"#;
+        let params: AtBlockParam = serde_json::from_str(json).unwrap();
+        assert_eq!(params.expected.as_deref(), Some("System,Balances"));
+    }
 }
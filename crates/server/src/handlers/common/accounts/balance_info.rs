@@ -7,8 +7,11 @@
 //! that is shared between the regular accounts endpoint and the RC (relay chain) endpoint.
 
 use crate::handlers::runtime_queries::balances as balances_queries;
+use crate::handlers::runtime_queries::tokens as tokens_queries;
+use crate::state::AppState;
 use crate::utils::ResolvedBlock;
 use serde::Serialize;
+use serde_json::Value;
 use sp_core::crypto::AccountId32;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 
@@ -58,8 +61,8 @@ pub enum BalanceQueryError {
     #[error("Failed to decode storage value: {0}")]
     DecodeFailed(#[from] parity_scale_codec::Error),
 
-    #[error("Failed to fetch ExistentialDeposit constant from runtime")]
-    ExistentialDepositFetchFailed,
+    #[error(transparent)]
+    TokenQueryFailed(#[from] tokens_queries::TokensStorageError),
 }
 
 impl From<subxt::error::OnlineClientAtBlockError> for BalanceQueryError {
@@ -82,12 +85,17 @@ impl From<subxt::error::StorageError> for BalanceQueryError {
 ///
 /// This is the main shared function that queries account balance data.
 /// It returns raw data that can be formatted into either the regular or RC response format.
+///
+/// When `token` names a non-native token listed in `chain_tokens` and the runtime has an
+/// ORML Tokens pallet, the balance is read from `Tokens::Accounts`/`Tokens::Locks` and
+/// denominated with that token's decimals. Otherwise the native balance is returned.
 pub async fn query_balance_info(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     spec_name: &str,
     account: &AccountId32,
     block: &ResolvedBlock,
     token: Option<String>,
+    chain_tokens: Option<&ChainTokens>,
 ) -> Result<RawBalanceInfo, BalanceQueryError> {
     // Check if System pallet exists
     if client_at_block
@@ -98,14 +106,19 @@ pub async fn query_balance_info(
         return Err(BalanceQueryError::BalancesPalletNotAvailable);
     }
 
-    // Get token symbol (default based on chain type)
-    let token_symbol = token.unwrap_or_else(|| get_default_token_symbol(spec_name));
+    if let Some(token) = token.as_deref()
+        && let Some((symbol, decimals)) = chain_tokens.and_then(|t| t.non_native_token(token))
+        && tokens_queries::has_tokens_pallet(client_at_block)
+    {
+        return query_token_balance_info(client_at_block, account, block, symbol, decimals).await;
+    }
 
-    // Get token decimals
-    let token_decimals = get_default_token_decimals(spec_name);
+    // Native token: configured override, then chain properties, then the chain's default
+    let token_symbol = token.unwrap_or_else(|| native_token_symbol(spec_name, chain_tokens));
+    let token_decimals = native_token_decimals(spec_name, chain_tokens);
 
-    // Fetch existential deposit from runtime constants (sync - reads from metadata)
-    let existential_deposit = fetch_existential_deposit(client_at_block)?;
+    // Existential deposit at this block (sync - reads from metadata)
+    let existential_deposit = existential_deposit_at(client_at_block, spec_name);
 
     let (account_data, locks) = tokio::join!(
         balances_queries::get_account_data_or_default(client_at_block, account),
@@ -125,10 +138,173 @@ pub async fn query_balance_info(
     })
 }
 
+/// Query the balance of a non-native token held in the ORML Tokens pallet.
+///
+/// The nonce still comes from `System::Account`, since tokens share the account's nonce.
+async fn query_token_balance_info(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    account: &AccountId32,
+    block: &ResolvedBlock,
+    token: &str,
+    token_decimals: u8,
+) -> Result<RawBalanceInfo, BalanceQueryError> {
+    let (system_data, token_data, locks) = tokio::join!(
+        balances_queries::get_account_data_or_default(client_at_block, account),
+        tokens_queries::get_token_account_data(client_at_block, account, token),
+        tokens_queries::get_token_locks(client_at_block, account, token)
+    );
+    let token_data = token_data?.unwrap_or_default();
+    let locks = locks?;
+
+    let account_data = DecodedAccountData {
+        nonce: system_data.nonce,
+        free: token_data.free,
+        reserved: token_data.reserved,
+        misc_frozen: None,
+        fee_frozen: None,
+        frozen: Some(token_data.frozen),
+    };
+
+    // ORML frozen balances only restrict the free balance
+    let transferable = token_data
+        .free
+        .saturating_sub(token_data.frozen)
+        .to_string();
+
+    Ok(RawBalanceInfo {
+        block: block.clone(),
+        token_symbol: token.to_string(),
+        token_decimals,
+        account_data,
+        locks,
+        transferable,
+    })
+}
+
+// ================================================================================================
+// Chain Token Registry
+// ================================================================================================
+
+/// Token symbols and decimals advertised by the chain in `system_properties`.
+///
+/// The first entry is the native token; any further entries are additional
+/// native tokens (e.g. ORML multi-currency chains).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChainTokens {
+    pub symbols: Vec<String>,
+    pub decimals: Vec<u8>,
+}
+
+impl ChainTokens {
+    /// Build the registry from `system_properties`, where `tokenSymbol` and
+    /// `tokenDecimals` may each be a single value or an array.
+    pub fn from_properties(properties: &serde_json::Map<String, Value>) -> Self {
+        fn as_vec(value: Option<&Value>) -> Vec<&Value> {
+            match value {
+                Some(Value::Array(items)) => items.iter().collect(),
+                Some(Value::Null) | None => Vec::new(),
+                Some(other) => vec![other],
+            }
+        }
+
+        let symbols = as_vec(properties.get("tokenSymbol"))
+            .into_iter()
+            .filter_map(|v| v.as_str().map(str::to_string))
+            .collect();
+        let decimals = as_vec(properties.get("tokenDecimals"))
+            .into_iter()
+            .filter_map(|v| v.as_u64().and_then(|d| u8::try_from(d).ok()))
+            .collect();
+
+        Self { symbols, decimals }
+    }
+
+    /// Replace the native token's symbol and/or decimals with operator-configured values,
+    /// for chains whose `system_properties` are missing or wrong.
+    pub fn with_native_override(mut self, symbol: Option<&str>, decimals: Option<u8>) -> Self {
+        fn set_native<T>(values: &mut Vec<T>, value: T) {
+            match values.first_mut() {
+                Some(native) => *native = value,
+                None => values.push(value),
+            }
+        }
+
+        if let Some(symbol) = symbol {
+            set_native(&mut self.symbols, symbol.to_string());
+        }
+        if let Some(decimals) = decimals {
+            set_native(&mut self.decimals, decimals);
+        }
+        self
+    }
+
+    /// Canonical symbol and decimals for `token` if it is a recognized non-native token.
+    ///
+    /// Falls back to the native decimals when the chain lists fewer decimals than symbols.
+    pub fn non_native_token(&self, token: &str) -> Option<(&str, u8)> {
+        let index = self
+            .symbols
+            .iter()
+            .position(|s| s.eq_ignore_ascii_case(token))?;
+        if index == 0 {
+            return None;
+        }
+
+        let decimals = self
+            .decimals
+            .get(index)
+            .or_else(|| self.decimals.first())
+            .copied()?;
+        Some((self.symbols[index].as_str(), decimals))
+    }
+}
+
+/// Fetch the chain's token registry from `system_properties`, with the configured
+/// native token overrides (`SAS_SUBSTRATE_TOKEN_SYMBOL`/`SAS_SUBSTRATE_TOKEN_DECIMALS`)
+/// applied on top.
+///
+/// The properties aren't fetched when the overrides fully describe the native token
+/// and no specific `token` is requested. If they cannot be fetched, the native token
+/// falls back to the overrides and then the chain's defaults.
+pub async fn fetch_chain_tokens(state: &AppState, token: Option<&String>) -> ChainTokens {
+    let substrate = &state.config.substrate;
+    let overridden = substrate.token_symbol.is_some() && substrate.token_decimals.is_some();
+
+    let tokens = if token.is_none() && overridden {
+        ChainTokens::default()
+    } else {
+        match state.legacy_rpc.system_properties().await {
+            Ok(properties) => ChainTokens::from_properties(&properties),
+            Err(e) => {
+                tracing::debug!("Failed to fetch system properties for token lookup: {e:?}");
+                ChainTokens::default()
+            }
+        }
+    };
+
+    tokens.with_native_override(substrate.token_symbol.as_deref(), substrate.token_decimals)
+}
+
 // ================================================================================================
 // Token/Decimals Helpers
 // ================================================================================================
 
+/// Native token symbol from the chain tokens (including any configured override),
+/// falling back to the default for the spec name.
+pub fn native_token_symbol(spec_name: &str, chain_tokens: Option<&ChainTokens>) -> String {
+    chain_tokens
+        .and_then(|tokens| tokens.symbols.first().cloned())
+        .unwrap_or_else(|| get_default_token_symbol(spec_name))
+}
+
+/// Native token decimals from the chain tokens (including any configured override),
+/// falling back to the default for the spec name.
+pub fn native_token_decimals(spec_name: &str, chain_tokens: Option<&ChainTokens>) -> u8 {
+    chain_tokens
+        .and_then(|tokens| tokens.decimals.first().copied())
+        .unwrap_or_else(|| get_default_token_decimals(spec_name))
+}
+
 /// Get the default token symbol for a given spec name
 pub fn get_default_token_symbol(spec_name: &str) -> String {
     let spec_lower = spec_name.to_lowercase();
@@ -174,12 +350,30 @@ pub fn get_default_existential_deposit(spec_name: &str) -> u128 {
 /// ensuring accuracy across different chains and runtime upgrades.
 pub fn fetch_existential_deposit(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
-) -> Result<u128, BalanceQueryError> {
+) -> Result<u128, subxt::error::ConstantError> {
     let addr = subxt::dynamic::constant::<u128>("Balances", "ExistentialDeposit");
-    client_at_block
-        .constants()
-        .entry(addr)
-        .map_err(|_| BalanceQueryError::ExistentialDepositFetchFailed)
+    client_at_block.constants().entry(addr)
+}
+
+/// Existential deposit in effect at the queried block.
+///
+/// The ED can change across runtime upgrades, so it is read from the block's metadata.
+/// If the constant can't be read, the spec's default is used and a warning is logged.
+pub fn existential_deposit_at(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    spec_name: &str,
+) -> u128 {
+    fetch_existential_deposit(client_at_block).unwrap_or_else(|err| {
+        let fallback = get_default_existential_deposit(spec_name);
+        tracing::warn!(
+            "Failed to read Balances::ExistentialDeposit at block {}, using default {} for {}: {}",
+            client_at_block.block_number(),
+            fallback,
+            spec_name,
+            err
+        );
+        fallback
+    })
 }
 
 // ================================================================================================
@@ -192,6 +386,14 @@ pub fn fetch_existential_deposit(
 /// `transferable = free - max(maybeED, frozen - reserved)`
 /// where `maybeED = 0` if `frozen == 0 && reserved == 0`, else `existential_deposit`
 ///
+/// This mirrors `frame-balances`' `reducible_balance` for a polite, expendable withdrawal.
+/// Holds are counted in `reserved` and already lie outside `free`, and freezes may
+/// overlap them, so only the part of `frozen` not covered by `reserved` restricts `free`.
+/// An account with holds or freezes has consumers and can't be reaped, so it must also
+/// keep the existential deposit.
+///
+/// Older runtimes with `miscFrozen`/`feeFrozen` are not supported.
+///
 /// # Arguments
 /// * `existential_deposit` - The chain's existential deposit, fetched dynamically from runtime constants
 /// * `account_data` - The decoded account data containing free, reserved, and frozen balances
@@ -244,6 +446,8 @@ pub fn apply_denomination(amount: u128, decimals: usize) -> String {
 }
 
 /// Format a balance amount based on denomination settings
+///
+/// When `denominated` is false the raw planck amount is returned as an integer string.
 pub fn format_balance(amount: u128, denominated: bool, decimals: u8) -> String {
     if denominated && decimals > 0 {
         apply_denomination(amount, decimals as usize)
@@ -305,3 +509,188 @@ pub fn format_locks(
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn properties(value: Value) -> serde_json::Map<String, Value> {
+        value.as_object().cloned().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_existential_deposit_read_from_block_metadata() {
+        let rpc_client = crate::test_fixtures::create_rpc_client();
+        let client = subxt::OnlineClient::<SubstrateConfig>::from_rpc_client((*rpc_client).clone())
+            .await
+            .unwrap();
+        let client_at_block = client.at_current_block().await.unwrap();
+
+        let from_metadata = fetch_existential_deposit(&client_at_block).unwrap();
+        assert_eq!(
+            existential_deposit_at(&client_at_block, "unknown-chain"),
+            from_metadata
+        );
+        // The fixture is Asset Hub Polkadot, whose ED differs from the generic fallback
+        assert_ne!(
+            from_metadata,
+            get_default_existential_deposit("unknown-chain")
+        );
+    }
+
+    fn account(free: u128, reserved: u128, frozen: Option<u128>) -> DecodedAccountData {
+        DecodedAccountData {
+            nonce: 0,
+            free,
+            reserved,
+            misc_frozen: frozen.is_none().then_some(0),
+            fee_frozen: frozen.is_none().then_some(0),
+            frozen,
+        }
+    }
+
+    #[test]
+    fn test_calculate_transferable_holds_and_freezes() {
+        const ED: u128 = 100;
+
+        // Nothing held or frozen: the whole free balance can be moved
+        assert_eq!(
+            calculate_transferable(ED, &account(1_000, 0, Some(0))),
+            "1000"
+        );
+        // Held funds only: the account must keep the ED
+        assert_eq!(
+            calculate_transferable(ED, &account(1_000, 500, Some(0))),
+            "900"
+        );
+        // Frozen below the ED: the ED dominates
+        assert_eq!(
+            calculate_transferable(ED, &account(1_000, 0, Some(50))),
+            "900"
+        );
+        // Frozen above the ED and not covered by holds
+        assert_eq!(
+            calculate_transferable(ED, &account(1_000, 0, Some(400))),
+            "600"
+        );
+        // Freeze partly covered by held funds: only the uncovered part restricts free
+        assert_eq!(
+            calculate_transferable(ED, &account(1_000, 300, Some(700))),
+            "600"
+        );
+        // Freeze fully covered by held funds: only the ED remains
+        assert_eq!(
+            calculate_transferable(ED, &account(1_000, 800, Some(700))),
+            "900"
+        );
+        // More frozen than free: nothing is transferable
+        assert_eq!(calculate_transferable(ED, &account(200, 0, Some(500))), "0");
+    }
+
+    #[test]
+    fn test_calculate_transferable_legacy_runtime() {
+        assert_eq!(
+            calculate_transferable(100, &account(1_000, 0, None)),
+            "transferable formula not supported for this runtime"
+        );
+    }
+
+    #[test]
+    fn test_format_balance_raw_and_denominated() {
+        let amount = 12_345_678_901_234u128;
+        assert_eq!(format_balance(amount, false, 10), "12345678901234");
+        assert_eq!(format_balance(amount, true, 10), "1234.5678901234");
+        assert_eq!(format_transferable("500", false, 10), "500");
+        assert_eq!(format_transferable("500", true, 10), "0.0000000500");
+        // Without decimals the denominated value is the raw one
+        assert_eq!(format_balance(amount, true, 0), "12345678901234");
+    }
+
+    #[test]
+    fn test_chain_tokens_from_array_properties() {
+        let tokens = ChainTokens::from_properties(&properties(json!({
+            "tokenSymbol": ["ACA", "AUSD", "DOT", "LDOT"],
+            "tokenDecimals": [12, 12, 10, 10],
+        })));
+
+        assert_eq!(tokens.symbols, vec!["ACA", "AUSD", "DOT", "LDOT"]);
+        assert_eq!(tokens.non_native_token("dot"), Some(("DOT", 10)));
+        assert_eq!(tokens.non_native_token("AUSD"), Some(("AUSD", 12)));
+    }
+
+    #[test]
+    fn test_chain_tokens_native_and_unknown_are_not_non_native() {
+        let tokens = ChainTokens::from_properties(&properties(json!({
+            "tokenSymbol": ["ACA", "AUSD"],
+            "tokenDecimals": [12, 12],
+        })));
+
+        assert_eq!(tokens.non_native_token("ACA"), None);
+        assert_eq!(tokens.non_native_token("XYZ"), None);
+    }
+
+    #[test]
+    fn test_chain_tokens_from_single_value_properties() {
+        let tokens = ChainTokens::from_properties(&properties(json!({
+            "tokenSymbol": "DOT",
+            "tokenDecimals": 10,
+        })));
+
+        assert_eq!(tokens.symbols, vec!["DOT"]);
+        assert_eq!(tokens.decimals, vec![10]);
+        assert_eq!(tokens.non_native_token("DOT"), None);
+    }
+
+    #[test]
+    fn test_native_token_precedence() {
+        let from_chain = ChainTokens::from_properties(&properties(json!({
+            "tokenSymbol": "KAR",
+            "tokenDecimals": 12,
+        })));
+
+        // Config override wins over the chain properties
+        let overridden = from_chain
+            .clone()
+            .with_native_override(Some("XYZ"), Some(8));
+        assert_eq!(native_token_symbol("polkadot", Some(&overridden)), "XYZ");
+        assert_eq!(native_token_decimals("polkadot", Some(&overridden)), 8);
+
+        // Chain properties win over the hardcoded default
+        assert_eq!(native_token_symbol("polkadot", Some(&from_chain)), "KAR");
+        assert_eq!(native_token_decimals("polkadot", Some(&from_chain)), 12);
+
+        // Hardcoded default when neither is available
+        assert_eq!(native_token_symbol("polkadot", None), "DOT");
+        assert_eq!(native_token_decimals("polkadot", None), 10);
+        let empty = ChainTokens::default();
+        assert_eq!(native_token_decimals("polkadot", Some(&empty)), 10);
+
+        // A partial override keeps the other value from the chain or the default
+        let decimals_only = ChainTokens::default().with_native_override(None, Some(18));
+        assert_eq!(native_token_symbol("polkadot", Some(&decimals_only)), "DOT");
+        assert_eq!(native_token_decimals("polkadot", Some(&decimals_only)), 18);
+    }
+
+    #[test]
+    fn test_chain_tokens_override_keeps_non_native_tokens() {
+        let tokens = ChainTokens::from_properties(&properties(json!({
+            "tokenSymbol": ["ACA", "AUSD"],
+            "tokenDecimals": [12, 12],
+        })))
+        .with_native_override(Some("NEW"), None);
+
+        assert_eq!(tokens.symbols, vec!["NEW", "AUSD"]);
+        assert_eq!(tokens.non_native_token("AUSD"), Some(("AUSD", 12)));
+    }
+
+    #[test]
+    fn test_chain_tokens_falls_back_to_native_decimals() {
+        let tokens = ChainTokens::from_properties(&properties(json!({
+            "tokenSymbol": ["BNC", "KSM"],
+            "tokenDecimals": 12,
+        })));
+
+        assert_eq!(tokens.non_native_token("KSM"), Some(("KSM", 12)));
+    }
+}
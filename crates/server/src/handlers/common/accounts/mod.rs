@@ -10,22 +10,27 @@ mod staking_payouts;
 mod vesting_info;
 
 pub use balance_info::{
-    BalanceQueryError, DecodedAccountData, DecodedBalanceLock, FormattedBalanceLock,
-    FormattedBlockInfo, RawBalanceInfo, apply_denomination, calculate_transferable, format_balance,
-    format_frozen_fields, format_locks, format_transferable, get_default_existential_deposit,
-    get_default_token_decimals, get_default_token_symbol, query_balance_info,
+    BalanceQueryError, ChainTokens, DecodedAccountData, DecodedBalanceLock, FormattedBalanceLock,
+    FormattedBlockInfo, RawBalanceInfo, apply_denomination, calculate_transferable,
+    existential_deposit_at, fetch_chain_tokens, format_balance, format_frozen_fields, format_locks,
+    format_transferable, get_default_existential_deposit, get_default_token_decimals,
+    get_default_token_symbol, native_token_decimals, query_balance_info,
 };
 
-pub use proxy_info::{DecodedProxyDefinition, ProxyQueryError, RawProxyInfo, query_proxy_info};
+pub use proxy_info::{
+    DecodedProxyDefinition, DecodedProxyDelegator, ProxyQueryError, RawProxyDelegators,
+    RawProxyInfo, query_proxy_delegators, query_proxy_info,
+};
 
 pub use staking_info::{
-    ClaimStatus, DecodedNominationsInfo, DecodedRewardDestination, DecodedStakingLedger,
-    DecodedUnlockingChunk, EraClaimStatus, RawStakingInfo, StakingQueryError, query_staking_info,
+    ClaimStatus, ClaimedRewardsSource, DecodedLedgerClaimedRewards, DecodedNominationsInfo,
+    DecodedRewardDestination, DecodedStakingLedger, DecodedUnlockingChunk, EraClaimStatus,
+    RawStakingInfo, StakingQueryError, query_staking_info,
 };
 
 pub use staking_payouts::{
-    RawEraPayouts, RawEraPayoutsData, RawStakingPayouts, RawValidatorPayout, StakingPayoutsParams,
-    StakingPayoutsQueryError, query_staking_payouts,
+    RawEraPayouts, RawEraPayoutsData, RawStakingPayouts, RawValidatorPayout, StakingAccountType,
+    StakingPayoutsParams, StakingPayoutsQueryError, query_staking_payouts, resolve_stash,
 };
 
 pub use vesting_info::{
@@ -4,10 +4,13 @@
 //! Common proxy info utilities shared across handler modules.
 
 use crate::handlers::runtime_queries::balances as balances_queries;
-use crate::utils::ResolvedBlock;
-use sp_core::crypto::AccountId32;
+use crate::utils::{self, ResolvedBlock};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use std::time::Duration;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
+use subxt_rpcs::RpcClient;
 use thiserror::Error;
+use tokio::time::Instant;
 
 // ================================================================================================
 // Error Types
@@ -26,6 +29,9 @@ pub enum ProxyQueryError {
 
     #[error("Failed to decode storage value: {0}")]
     DecodeFailed(#[from] parity_scale_codec::Error),
+
+    #[error("Failed to scan proxy storage")]
+    ScanFailed(#[source] subxt_rpcs::Error),
 }
 
 impl From<subxt::error::OnlineClientAtBlockError> for ProxyQueryError {
@@ -73,6 +79,28 @@ pub struct DecodedProxyDefinition {
     pub delay: String,
 }
 
+/// Raw reverse proxy lookup data: accounts that delegated to an address
+#[derive(Debug)]
+pub struct RawProxyDelegators {
+    /// Block information
+    pub block: FormattedBlockInfo,
+    /// Accounts that added the address as a proxy, one entry per proxy definition
+    pub delegators: Vec<DecodedProxyDelegator>,
+    /// False if the scan was cut short by the time limit
+    pub scan_complete: bool,
+}
+
+/// A proxy definition pointing at the queried address, with the account that set it
+#[derive(Debug, Clone)]
+pub struct DecodedProxyDelegator {
+    /// The delegating account address (SS58 encoded)
+    pub delegator: String,
+    /// The type of proxy
+    pub proxy_type: String,
+    /// The announcement delay in blocks
+    pub delay: String,
+}
+
 /// Map proxy type variant index to common names
 /// These are the common proxy types across Polkadot/Kusama runtimes
 fn proxy_type_name(index: u8) -> String {
@@ -144,3 +172,154 @@ pub async fn query_proxy_info(
         deposit_held,
     })
 }
+
+// ================================================================================================
+// Reverse Lookup
+// ================================================================================================
+
+/// Length of the `Proxy::Proxies` prefix plus the `Twox64Concat` hash preceding the account.
+const PROXIES_KEY_ACCOUNT_OFFSET: usize = 32 + 8;
+
+/// Find all accounts that have added `delegate` as a proxy.
+///
+/// There is no index from delegates to delegators, so this pages through every
+/// `Proxy::Proxies` entry (`state_getKeysPaged` + `state_queryStorageAt`) and keeps the
+/// definitions whose delegate matches. The scan stops once `max_scan_time` has elapsed,
+/// in which case `scan_complete` is false and only the delegators found so far are returned.
+pub async fn query_proxy_delegators(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    rpc_client: &RpcClient,
+    delegate: &AccountId32,
+    block: &ResolvedBlock,
+    ss58_prefix: u16,
+    max_scan_time: Duration,
+) -> Result<RawProxyDelegators, ProxyQueryError> {
+    if client_at_block
+        .storage()
+        .entry(("Proxy", "Proxies"))
+        .is_err()
+    {
+        return Err(ProxyQueryError::ProxyPalletNotAvailable);
+    }
+
+    let prefix = utils::storage_map_prefix("Proxy", "Proxies");
+
+    let delegate = delegate.to_ss58check_with_version(ss58_prefix.into());
+    let deadline = Instant::now() + max_scan_time;
+    let mut delegators = Vec::new();
+    let mut start_key: Option<String> = None;
+    let mut scan_complete = true;
+
+    loop {
+        if Instant::now() >= deadline {
+            tracing::warn!("Proxy delegator scan for {delegate} stopped after {max_scan_time:?}");
+            scan_complete = false;
+            break;
+        }
+
+        let page =
+            utils::fetch_storage_page(rpc_client, &prefix, start_key.as_deref(), &block.hash)
+                .await
+                .map_err(ProxyQueryError::ScanFailed)?;
+
+        for (key, value) in &page.entries {
+            delegators.extend(match_delegate(key, value, &delegate, ss58_prefix));
+        }
+
+        match page.next_start_key {
+            Some(key) => start_key = Some(key),
+            None => break,
+        }
+    }
+
+    Ok(RawProxyDelegators {
+        block: FormattedBlockInfo {
+            hash: block.hash.clone(),
+            number: block.number,
+        },
+        delegators,
+        scan_complete,
+    })
+}
+
+/// Decode a `Proxy::Proxies` entry, returning its definitions whose delegate is `delegate`.
+fn match_delegate(
+    key: &str,
+    value: &str,
+    delegate: &str,
+    ss58_prefix: u16,
+) -> Vec<DecodedProxyDelegator> {
+    let key = hex::decode(key.trim_start_matches("0x")).unwrap_or_default();
+    let value = hex::decode(value.trim_start_matches("0x")).unwrap_or_default();
+
+    let Some(account) = key
+        .get(PROXIES_KEY_ACCOUNT_OFFSET..)
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+    else {
+        return Vec::new();
+    };
+    let Some((proxies, _)) = balances_queries::decode_proxy_definitions(&value, ss58_prefix) else {
+        return Vec::new();
+    };
+
+    let delegator = AccountId32::from(account).to_ss58check_with_version(ss58_prefix.into());
+    proxies
+        .into_iter()
+        .filter(|p| p.delegate == delegate)
+        .map(|p| DecodedProxyDelegator {
+            delegator: delegator.clone(),
+            proxy_type: proxy_type_name(p.proxy_type),
+            delay: p.delay.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+
+    fn proxies_key(account: [u8; 32]) -> String {
+        let mut key = sp_crypto_hashing::twox_128(b"Proxy").to_vec();
+        key.extend(sp_crypto_hashing::twox_128(b"Proxies"));
+        key.extend(sp_crypto_hashing::twox_64(&account));
+        key.extend(account);
+        format!("0x{}", hex::encode(key))
+    }
+
+    #[test]
+    fn test_match_delegate_keeps_matching_definitions() {
+        let delegate = [2u8; 32];
+        let other = [3u8; 32];
+        // (Vec<(delegate, proxy_type, delay)>, deposit)
+        let value = (
+            vec![
+                (delegate, 0u8, 0u32),
+                (other, 3u8, 0u32),
+                (delegate, 3u8, 10u32),
+            ],
+            100u128,
+        )
+            .encode();
+
+        let delegate_ss58 = AccountId32::from(delegate).to_ss58check_with_version(0u16.into());
+        let found = match_delegate(
+            &proxies_key([1u8; 32]),
+            &format!("0x{}", hex::encode(value)),
+            &delegate_ss58,
+            0,
+        );
+
+        let delegator = AccountId32::from([1u8; 32]).to_ss58check_with_version(0u16.into());
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().all(|d| d.delegator == delegator));
+        assert_eq!(found[0].proxy_type, "Any");
+        assert_eq!(found[1].proxy_type, "Staking");
+        assert_eq!(found[1].delay, "10");
+    }
+
+    #[test]
+    fn test_match_delegate_ignores_malformed_entries() {
+        assert!(match_delegate("0x00", "0x00", "5Grw", 0).is_empty());
+    }
+}
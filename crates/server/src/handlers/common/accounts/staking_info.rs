@@ -13,7 +13,8 @@ use thiserror::Error;
 
 // Re-export types from runtime_queries::staking for backwards compatibility
 pub use crate::handlers::runtime_queries::staking::{
-    DecodedNominationsInfo, DecodedRewardDestination, DecodedStakingLedger, DecodedUnlockingChunk,
+    ClaimedRewardsSource, DecodedLedgerClaimedRewards, DecodedNominationsInfo,
+    DecodedRewardDestination, DecodedStakingLedger, DecodedUnlockingChunk,
 };
 
 // ================================================================================================
@@ -83,6 +84,8 @@ pub struct RawStakingInfo {
     pub controller: String,
     /// Reward destination
     pub reward_destination: DecodedRewardDestination,
+    /// SS58 address that receives staking rewards (None when rewards are not paid out)
+    pub payee_account: Option<String>,
     /// Number of slashing spans
     pub num_slashing_spans: u32,
     /// Nominations info (None if not a nominator)
@@ -111,6 +114,8 @@ pub struct StakingLedgerWithClaims {
     pub unlocking: Vec<DecodedUnlockingChunk>,
     /// Claimed rewards per era (only populated when include_claimed_rewards=true)
     pub claimed_rewards: Option<Vec<EraClaimStatus>>,
+    /// Claimed-rewards eras recorded in the ledger itself
+    pub ledger_claimed_rewards: Option<DecodedLedgerClaimedRewards>,
 }
 
 impl From<DecodedStakingLedger> for StakingLedgerWithClaims {
@@ -121,6 +126,7 @@ impl From<DecodedStakingLedger> for StakingLedgerWithClaims {
             active: ledger.active,
             unlocking: ledger.unlocking,
             claimed_rewards: None,
+            ledger_claimed_rewards: ledger.claimed_rewards,
         }
     }
 }
@@ -222,6 +228,8 @@ pub async fn query_staking_info(
     let mut staking: StakingLedgerWithClaims = ledger.into();
     staking.claimed_rewards = claimed_rewards;
 
+    let payee_account = resolve_payee_account(&reward_destination, &staking.stash, &controller);
+
     Ok(RawStakingInfo {
         block: FormattedBlockInfo {
             hash: block.hash.clone(),
@@ -229,12 +237,32 @@ pub async fn query_staking_info(
         },
         controller,
         reward_destination,
+        payee_account,
         num_slashing_spans,
         nominations,
         staking,
     })
 }
 
+/// Resolve the account that receives staking rewards for a reward destination.
+///
+/// `Staked` and `Stash` pay out to the stash, `Controller` to the controller and
+/// `Account` to the SS58 address it carries. `None` means rewards are not paid out.
+pub fn resolve_payee_account(
+    reward_destination: &DecodedRewardDestination,
+    stash: &str,
+    controller: &str,
+) -> Option<String> {
+    match reward_destination {
+        DecodedRewardDestination::Simple(name) => match name.as_str() {
+            "Staked" | "Stash" => Some(stash.to_string()),
+            "Controller" => Some(controller.to_string()),
+            _ => None,
+        },
+        DecodedRewardDestination::Account { account } => Some(account.clone()),
+    }
+}
+
 // ================================================================================================
 // Claimed Rewards Query
 // ================================================================================================
@@ -366,3 +394,40 @@ async fn query_nominator_claim_status(
 
     ClaimStatus::Undefined
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STASH: &str = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+    const CONTROLLER: &str = "14E5nqKAp3oAJcmzgZhUD2RcptBeUBScxKHgJKU4HPNcKVf3";
+
+    #[test]
+    fn test_resolve_payee_account_simple_variants() {
+        let resolve = |name: &str| {
+            resolve_payee_account(
+                &DecodedRewardDestination::Simple(name.to_string()),
+                STASH,
+                CONTROLLER,
+            )
+        };
+
+        assert_eq!(resolve("Staked").as_deref(), Some(STASH));
+        assert_eq!(resolve("Stash").as_deref(), Some(STASH));
+        assert_eq!(resolve("Controller").as_deref(), Some(CONTROLLER));
+        assert_eq!(resolve("None"), None);
+    }
+
+    #[test]
+    fn test_resolve_payee_account_explicit_account() {
+        let payee = "1zugcag7cJVBtVRnFxv5Qftn7xKAnR6YJ9x4x3XLgGgmNnS";
+        let dest = DecodedRewardDestination::Account {
+            account: payee.to_string(),
+        };
+
+        assert_eq!(
+            resolve_payee_account(&dest, STASH, CONTROLLER).as_deref(),
+            Some(payee)
+        );
+    }
+}
@@ -6,6 +6,7 @@
 use crate::consts::{get_chain_display_name, get_migration_boundaries, is_bad_staking_block};
 use crate::handlers::runtime_queries::staking;
 use crate::utils::ResolvedBlock;
+use parity_scale_codec::Decode;
 use sp_core::crypto::{AccountId32, Ss58Codec};
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 use thiserror::Error;
@@ -24,11 +25,14 @@ pub enum StakingPayoutsQueryError {
     #[error("No active era found")]
     NoActiveEra,
 
-    #[error("Invalid era: requested era {0} is beyond history depth")]
-    InvalidEra(u32),
+    #[error("Invalid era: requested era {era} is not before the active era {active_era}")]
+    EraNotCompleted { era: u32, active_era: u32 },
 
-    #[error("Depth must be greater than 0 and less than history depth")]
-    InvalidDepth,
+    #[error("Invalid era: requested era {era} is older than history depth {history_depth}")]
+    EraBeyondHistoryDepth { era: u32, history_depth: u32 },
+
+    #[error("Depth must be greater than 0 and at most history depth {history_depth}")]
+    InvalidDepth { history_depth: u32 },
 
     #[error("Failed to get client at block: {0}")]
     ClientAtBlockFailed(Box<subxt::error::OnlineClientAtBlockError>),
@@ -67,7 +71,7 @@ impl From<subxt::error::StorageError> for StakingPayoutsQueryError {
 /// Query parameters for staking payouts
 #[derive(Debug, Clone)]
 pub struct StakingPayoutsParams {
-    /// Number of eras to query. Must be less than HISTORY_DEPTH. Defaults to 1.
+    /// Number of eras to query. Must be at most HISTORY_DEPTH. Defaults to 1.
     pub depth: u32,
     /// The era to query at. Defaults to active_era - 1.
     pub era: Option<u32>,
@@ -85,11 +89,33 @@ impl Default for StakingPayoutsParams {
     }
 }
 
+/// Whether the queried address is a stash or the controller of one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakingAccountType {
+    Stash,
+    Controller,
+}
+
+impl StakingAccountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StakingAccountType::Stash => "stash",
+            StakingAccountType::Controller => "controller",
+        }
+    }
+}
+
 /// Raw staking payouts data returned from query
 #[derive(Debug)]
 pub struct RawStakingPayouts {
     /// Block information
     pub block: FormattedBlockInfo,
+    /// Stash the payouts were computed for
+    pub stash: AccountId32,
+    /// What the queried address was detected as, `None` if it isn't bonded
+    pub account_type: Option<StakingAccountType>,
+    /// History depth the requested era range was validated against
+    pub history_depth: u32,
     /// Era payouts data
     pub eras_payouts: Vec<RawEraPayouts>,
 }
@@ -149,7 +175,8 @@ pub struct RawValidatorPayout {
 /// Query staking payouts from storage
 ///
 /// This is the shared function used by both `/accounts/:accountId/staking-payouts`
-/// and `/rc/accounts/:accountId/staking-payouts` endpoints.
+/// and `/rc/accounts/:accountId/staking-payouts` endpoints. `account` may be a stash
+/// or a controller; a controller is resolved to its stash before the eras are read.
 pub async fn query_staking_payouts(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
@@ -186,26 +213,18 @@ pub async fn query_staking_payouts(
     // Get history depth (default to 84 if not found)
     let history_depth = staking::get_history_depth(client_at_block).await;
 
-    // Validate depth parameter
-    if params.depth == 0 || params.depth > history_depth {
-        return Err(StakingPayoutsQueryError::InvalidDepth);
-    }
-
-    // Determine target era (default to active_era - 1, which is the last completed era)
-    let target_era = params.era.unwrap_or_else(|| active_era.saturating_sub(1));
+    let (start_era, target_era) =
+        validate_era_range(active_era, history_depth, params.depth, params.era)?;
 
-    // Validate era parameter
-    if target_era >= active_era {
-        return Err(StakingPayoutsQueryError::InvalidEra(target_era));
-    }
-
-    let min_era = active_era.saturating_sub(history_depth);
-    if target_era < min_era {
-        return Err(StakingPayoutsQueryError::InvalidEra(target_era));
+    let (mut stash, mut account_type) = resolve_stash(client_at_block, account).await?;
+    if account_type.is_none()
+        && let Some(rc_client) = relay_client_at_block
+        && rc_client.storage().entry(("Staking", "Bonded")).is_ok()
+    {
+        // Accounts that stopped staking before the migration are only bonded on the relay chain
+        (stash, account_type) = resolve_stash(rc_client, account).await?;
     }
-
-    // Calculate start era based on depth
-    let start_era = target_era.saturating_sub(params.depth - 1).max(min_era);
+    let account = &stash;
 
     // Check if migration-aware era splitting is needed
     let migration_boundaries = get_migration_boundaries(spec_name);
@@ -271,10 +290,89 @@ pub async fn query_staking_payouts(
             hash: block.hash.clone(),
             number: block.number,
         },
+        stash,
+        account_type,
+        history_depth,
         eras_payouts,
     })
 }
 
+/// Resolve `account` to the stash it stakes for.
+///
+/// A bonded stash is returned as-is. Otherwise, a controller is looked up in `Staking.Ledger`
+/// and its stash returned. Accounts that are neither are returned unchanged with no type.
+pub async fn resolve_stash(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    account: &AccountId32,
+) -> Result<(AccountId32, Option<StakingAccountType>), StakingPayoutsQueryError> {
+    let account_bytes: [u8; 32] = *account.as_ref();
+
+    let bonded_addr = subxt::dynamic::storage::<_, ()>("Staking", "Bonded");
+    if client_at_block
+        .storage()
+        .try_fetch(bonded_addr, (account_bytes,))
+        .await?
+        .is_some()
+    {
+        return Ok((account.clone(), Some(StakingAccountType::Stash)));
+    }
+
+    let ledger_addr = subxt::dynamic::storage::<_, ()>("Staking", "Ledger");
+    let Some(ledger) = client_at_block
+        .storage()
+        .try_fetch(ledger_addr, (account_bytes,))
+        .await?
+    else {
+        return Ok((account.clone(), None));
+    };
+
+    // Every ledger layout starts with the stash account
+    let stash = AccountId32::from(<[u8; 32]>::decode(&mut &ledger.into_bytes()[..])?);
+    let account_type = if &stash == account {
+        StakingAccountType::Stash
+    } else {
+        StakingAccountType::Controller
+    };
+    Ok((stash, Some(account_type)))
+}
+
+/// Validate the requested `depth` and `era` against the active era and history depth.
+///
+/// The target era defaults to `active_era - 1` (the last completed era) and must lie within
+/// `[active_era - history_depth, active_era)`. Returns the inclusive `(start_era, target_era)`
+/// range, with the start clamped to the oldest era still kept in history.
+pub fn validate_era_range(
+    active_era: u32,
+    history_depth: u32,
+    depth: u32,
+    era: Option<u32>,
+) -> Result<(u32, u32), StakingPayoutsQueryError> {
+    if depth == 0 || depth > history_depth {
+        return Err(StakingPayoutsQueryError::InvalidDepth { history_depth });
+    }
+
+    let target_era = era.unwrap_or_else(|| active_era.saturating_sub(1));
+
+    if target_era >= active_era {
+        return Err(StakingPayoutsQueryError::EraNotCompleted {
+            era: target_era,
+            active_era,
+        });
+    }
+
+    let min_era = active_era.saturating_sub(history_depth);
+    if target_era < min_era {
+        return Err(StakingPayoutsQueryError::EraBeyondHistoryDepth {
+            era: target_era,
+            history_depth,
+        });
+    }
+
+    let start_era = target_era.saturating_sub(depth - 1).max(min_era);
+
+    Ok((start_era, target_era))
+}
+
 // ================================================================================================
 // Era Processing
 // ================================================================================================
@@ -680,3 +778,163 @@ fn calculate_payout(
 }
 
 // Decoding functions have been moved to runtime_queries::staking module
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::mock_rpc_client_builder;
+    use parity_scale_codec::{Compact, Encode};
+    use serde_json::json;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+    const STASH: [u8; 32] = [1; 32];
+    const CONTROLLER: [u8; 32] = [2; 32];
+    const UNREACHABLE: [u8; 32] = [4; 32];
+
+    /// Client whose `Staking.Bonded` maps `STASH` to `CONTROLLER` and whose `Staking.Ledger`
+    /// is keyed by `CONTROLLER`.
+    async fn client_with_controller() -> OnlineClientAtBlock<SubstrateConfig> {
+        let bonded_key = storage_key("Bonded", &sp_crypto_hashing::twox_64(&STASH), &STASH);
+        let unreachable_key = storage_key(
+            "Bonded",
+            &sp_crypto_hashing::twox_64(&UNREACHABLE),
+            &UNREACHABLE,
+        );
+        let ledger_key = storage_key(
+            "Ledger",
+            &sp_crypto_hashing::blake2_128(&CONTROLLER),
+            &CONTROLLER,
+        );
+        let mut ledger = STASH.encode();
+        ledger.extend(Compact(100u128).encode());
+        ledger.extend(Compact(100u128).encode());
+        ledger.extend(Vec::<u8>::new().encode());
+
+        let mock_client = mock_rpc_client_builder()
+            .method_handler("state_getStorage", move |params| {
+                let bonded_key = bonded_key.clone();
+                let unreachable_key = unreachable_key.clone();
+                let ledger_key = ledger_key.clone();
+                let ledger = ledger.clone();
+                async move {
+                    let params: serde_json::Value =
+                        serde_json::from_str(params.unwrap().get()).unwrap();
+                    let value = match params[0].as_str().unwrap() {
+                        key if key == unreachable_key => {
+                            return Err(subxt_rpcs::Error::Client(Box::new(
+                                std::io::Error::other("connection reset"),
+                            )));
+                        }
+                        key if key == bonded_key => Some(hex_value(&CONTROLLER)),
+                        key if key == ledger_key => Some(hex_value(&ledger)),
+                        _ => None,
+                    };
+                    Ok(MockJson(json!(value)))
+                }
+            })
+            .build();
+        let client = subxt::OnlineClient::<SubstrateConfig>::from_rpc_client(
+            subxt_rpcs::RpcClient::new(mock_client),
+        )
+        .await
+        .unwrap();
+        client.at_current_block().await.unwrap()
+    }
+
+    /// `Bonded` is a `Twox64Concat` map and `Ledger` a `Blake2_128Concat` one
+    fn storage_key(entry: &str, hash: &[u8], account: &[u8; 32]) -> String {
+        let mut key = sp_crypto_hashing::twox_128(b"Staking").to_vec();
+        key.extend(sp_crypto_hashing::twox_128(entry.as_bytes()));
+        key.extend(hash);
+        key.extend(account);
+        hex_value(&key)
+    }
+
+    fn hex_value(bytes: &[u8]) -> String {
+        format!("0x{}", hex::encode(bytes))
+    }
+
+    #[tokio::test]
+    async fn test_resolve_stash_accepts_stash_and_controller() {
+        let client_at_block = client_with_controller().await;
+        let stash = AccountId32::from(STASH);
+
+        assert_eq!(
+            resolve_stash(&client_at_block, &stash).await.unwrap(),
+            (stash.clone(), Some(StakingAccountType::Stash))
+        );
+        assert_eq!(
+            resolve_stash(&client_at_block, &AccountId32::from(CONTROLLER))
+                .await
+                .unwrap(),
+            (stash, Some(StakingAccountType::Controller))
+        );
+
+        let unbonded = AccountId32::from([3; 32]);
+        assert_eq!(
+            resolve_stash(&client_at_block, &unbonded).await.unwrap(),
+            (unbonded, None)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_stash_propagates_storage_errors() {
+        let client_at_block = client_with_controller().await;
+
+        let result = resolve_stash(&client_at_block, &AccountId32::from(UNREACHABLE)).await;
+        assert!(matches!(
+            result,
+            Err(StakingPayoutsQueryError::StorageQueryFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_era_range_defaults_to_last_completed_era() {
+        assert_eq!(validate_era_range(100, 84, 1, None).unwrap(), (99, 99));
+        assert_eq!(validate_era_range(100, 84, 5, None).unwrap(), (95, 99));
+    }
+
+    #[test]
+    fn test_validate_era_range_clamps_start_to_history() {
+        // Oldest era in history is 16, so a deep request starting at 20 is clamped
+        assert_eq!(validate_era_range(100, 84, 10, Some(20)).unwrap(), (16, 20));
+        assert_eq!(validate_era_range(100, 84, 84, Some(16)).unwrap(), (16, 16));
+    }
+
+    #[test]
+    fn test_validate_era_range_rejects_era_older_than_history_depth() {
+        let err = validate_era_range(100, 84, 1, Some(15)).unwrap_err();
+        assert!(matches!(
+            err,
+            StakingPayoutsQueryError::EraBeyondHistoryDepth {
+                era: 15,
+                history_depth: 84
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Invalid era: requested era 15 is older than history depth 84"
+        );
+    }
+
+    #[test]
+    fn test_validate_era_range_rejects_incomplete_era() {
+        assert!(matches!(
+            validate_era_range(100, 84, 1, Some(100)),
+            Err(StakingPayoutsQueryError::EraNotCompleted {
+                era: 100,
+                active_era: 100
+            })
+        ));
+    }
+
+    #[test]
+    fn test_validate_era_range_rejects_invalid_depth() {
+        for depth in [0, 85] {
+            assert!(matches!(
+                validate_era_range(100, 84, depth, None),
+                Err(StakingPayoutsQueryError::InvalidDepth { history_depth: 84 })
+            ));
+        }
+    }
+}
@@ -4,6 +4,7 @@
 //! Common vesting info utilities shared across handler modules.
 
 use crate::handlers::runtime_queries::balances as balances_queries;
+use crate::handlers::runtime_queries::parachain_system::{self, ParachainStorageError};
 use crate::utils::ResolvedBlock;
 use sp_core::crypto::AccountId32;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
@@ -26,6 +27,9 @@ pub enum VestingQueryError {
 
     #[error("Failed to decode storage value: {0}")]
     DecodeFailed(#[from] parity_scale_codec::Error),
+
+    #[error("Failed to read the relay chain block number: {0}")]
+    RelayBlockNumberFailed(#[from] ParachainStorageError),
 }
 
 impl From<subxt::error::OnlineClientAtBlockError> for VestingQueryError {
@@ -51,6 +55,12 @@ pub struct RawVestingInfo {
     pub block: FormattedBlockInfo,
     /// Vesting schedules
     pub schedules: Vec<DecodedVestingSchedule>,
+    /// Sum of `locked` across all schedules
+    pub total_locked: String,
+    /// Sum of the amounts vested as of the queried block
+    pub total_vested: String,
+    /// Amount still locked as of the queried block
+    pub total_remaining: String,
 }
 
 /// Block information for response
@@ -69,14 +79,76 @@ pub struct DecodedVestingSchedule {
     pub per_block: String,
     /// Block when vesting begins
     pub starting_block: String,
+    /// Block at which the schedule is fully vested
+    pub unlocked_at: String,
+    /// Amount vested as of the queried block
+    pub currently_vested: String,
+}
+
+impl DecodedVestingSchedule {
+    /// Decode a schedule with `currently_vested` already derived by [`vested_amount`].
+    ///
+    /// Mirrors `pallet_vesting::VestingInfo::ending_block_as_balance`.
+    fn new(locked: u128, per_block: u128, starting_block: u32, currently_vested: u128) -> Self {
+        // The pallet treats a zero `per_block` as 1 so schedules always end
+        let per_block_nonzero = per_block.max(1);
+        let duration = if per_block_nonzero > locked {
+            1
+        } else {
+            locked.div_ceil(per_block_nonzero)
+        };
+        let unlocked_at = u128::from(starting_block).saturating_add(duration);
+
+        Self {
+            locked: locked.to_string(),
+            per_block: per_block.to_string(),
+            starting_block: starting_block.to_string(),
+            unlocked_at: unlocked_at.to_string(),
+            currently_vested: currently_vested.to_string(),
+        }
+    }
+}
+
+/// Amount of a schedule vested as of `block_number`.
+///
+/// Mirrors `pallet_vesting::VestingInfo::locked_at`.
+fn vested_amount(locked: u128, per_block: u128, starting_block: u32, block_number: u64) -> u128 {
+    let vested_blocks = u128::from(block_number.saturating_sub(u64::from(starting_block)));
+    per_block.max(1).saturating_mul(vested_blocks).min(locked)
 }
 
 // ================================================================================================
 // Core Query Function
 // ================================================================================================
 
+/// The block number vesting schedules are measured against at `block`.
+///
+/// Parachain runtimes such as Asset Hub configure `pallet_vesting` with a relay chain
+/// `BlockNumberProvider`, so their schedules start and end at relay chain blocks.
+/// When the runtime has `ParachainSystem`, its `LastRelayChainBlockNumber` is used;
+/// otherwise (e.g. on the relay chain) the block's own number is.
+async fn vesting_block_number(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    block: &ResolvedBlock,
+) -> Result<u64, VestingQueryError> {
+    if client_at_block
+        .storage()
+        .entry(("ParachainSystem", "LastRelayChainBlockNumber"))
+        .is_ok()
+        && let Some(number) = parachain_system::get_last_relay_block_number(client_at_block).await?
+    {
+        return Ok(u64::from(number));
+    }
+
+    Ok(block.number)
+}
+
 /// Query vesting info from storage
 ///
+/// Each schedule's `unlocked_at` and `currently_vested`, and the totals across
+/// schedules, are derived from the block number vesting is measured against,
+/// see [`vesting_block_number`].
+///
 /// This is the shared function used by both `/accounts/:accountId/vesting-info`
 /// and `/rc/accounts/:accountId/vesting-info` endpoints.
 ///
@@ -98,16 +170,22 @@ pub async fn query_vesting_info(
         return Err(VestingQueryError::VestingPalletNotAvailable);
     }
 
-    // Use centralized query function
-    let vesting_schedules = balances_queries::get_vesting_schedules(client_at_block, account).await;
+    let (vesting_schedules, block_number) = tokio::join!(
+        balances_queries::get_vesting_schedules(client_at_block, account),
+        vesting_block_number(client_at_block, block),
+    );
+    let block_number = block_number?;
 
-    // Convert to decoded schedules
+    // Convert to decoded schedules, summing the totals as we go
+    let mut total_locked = 0u128;
+    let mut total_vested = 0u128;
     let schedules: Vec<DecodedVestingSchedule> = vesting_schedules
         .iter()
-        .map(|s| DecodedVestingSchedule {
-            locked: s.locked.to_string(),
-            per_block: s.per_block.to_string(),
-            starting_block: s.starting_block.to_string(),
+        .map(|s| {
+            let vested = vested_amount(s.locked, s.per_block, s.starting_block, block_number);
+            total_locked = total_locked.saturating_add(s.locked);
+            total_vested = total_vested.saturating_add(vested);
+            DecodedVestingSchedule::new(s.locked, s.per_block, s.starting_block, vested)
         })
         .collect();
 
@@ -117,5 +195,65 @@ pub async fn query_vesting_info(
             number: block.number,
         },
         schedules,
+        total_locked: total_locked.to_string(),
+        total_vested: total_vested.to_string(),
+        total_remaining: total_locked.saturating_sub(total_vested).to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule_at(
+        locked: u128,
+        per_block: u128,
+        starting_block: u32,
+        block_number: u64,
+    ) -> DecodedVestingSchedule {
+        let vested = vested_amount(locked, per_block, starting_block, block_number);
+        DecodedVestingSchedule::new(locked, per_block, starting_block, vested)
+    }
+
+    #[test]
+    fn test_vesting_schedule_progress() {
+        // 1000 locked, 30 per block from block 100: ends at 100 + ceil(1000 / 30) = 134
+        let before = schedule_at(1000, 30, 100, 50);
+        assert_eq!(before.unlocked_at, "134");
+        assert_eq!(before.currently_vested, "0");
+
+        let during = schedule_at(1000, 30, 100, 110);
+        assert_eq!(during.currently_vested, "300");
+
+        let after = schedule_at(1000, 30, 100, 200);
+        assert_eq!(after.currently_vested, "1000");
+        assert_eq!(after.locked, "1000");
+        assert_eq!(after.per_block, "30");
+        assert_eq!(after.starting_block, "100");
+    }
+
+    #[test]
+    fn test_vested_amount_sums_across_schedules() {
+        let schedules = [(1000u128, 30u128, 100u32), (500, 100, 105)];
+        let total: u128 = schedules
+            .iter()
+            .map(|&(locked, per_block, start)| vested_amount(locked, per_block, start, 110))
+            .sum();
+
+        // 10 blocks * 30 + 5 blocks * 100
+        assert_eq!(total, 800);
+    }
+
+    #[test]
+    fn test_vesting_schedule_per_block_edge_cases() {
+        // Unlocking more than is locked per block vests after a single block
+        let fast = schedule_at(10, 50, 5, 6);
+        assert_eq!(fast.unlocked_at, "6");
+        assert_eq!(fast.currently_vested, "10");
+
+        // A zero per-block rate is treated as 1
+        let zero = schedule_at(10, 0, 5, 8);
+        assert_eq!(zero.unlocked_at, "15");
+        assert_eq!(zero.currently_vested, "3");
+    }
+}
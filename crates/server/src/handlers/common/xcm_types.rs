@@ -260,6 +260,9 @@ fn serialize_u64_as_string<S: Serializer>(n: &u64, serializer: S) -> Result<S::O
 
 /// XCM v4 NetworkId enum.
 ///
+/// V5 dropped `Westend`, `Rococo` and `Wococo` but kept the indices of the other
+/// variants, so V5 locations decode into this type unchanged.
+///
 /// Serde's default externally-tagged representation serializes unit variants
 /// as plain strings (e.g. `"Kusama"`) and data variants as objects
 /// (e.g. `{"Ethereum": {"chainId": "1"}}`), matching the Sidecar format.
@@ -139,6 +139,9 @@ pub enum CoretimeError {
     #[error("Invalid block hash format")]
     InvalidBlockHash,
 
+    #[error("{0}")]
+    InvalidSs58Format(#[from] crate::utils::InvalidSs58Format),
+
     #[error("Failed to get client at block")]
     ClientAtBlockFailed(#[source] subxt::error::OnlineClientAtBlockError),
 
@@ -229,6 +232,7 @@ impl IntoResponse for CoretimeError {
             CoretimeError::InvalidBlockParam(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             CoretimeError::BlockResolveFailed(inner) => (inner.status_code(), inner.to_string()),
             CoretimeError::InvalidBlockHash => (StatusCode::BAD_REQUEST, self.to_string()),
+            CoretimeError::InvalidSs58Format(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             CoretimeError::ClientAtBlockFailed(err) => {
                 if crate::utils::is_online_client_at_block_disconnected(err) {
                     (
@@ -304,6 +308,17 @@ pub struct CoretimeQueryParams {
     pub at: Option<String>,
 }
 
+/// Query parameters for `/coretime/regions`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CoretimeRegionsQueryParams {
+    /// Block number or 0x-prefixed block hash to query at.
+    /// If not provided, queries at the latest finalized block.
+    pub at: Option<String>,
+    /// SS58 prefix to encode region owners with, instead of the chain's
+    pub ss58_format: Option<u16>,
+}
+
 // ============================================================================
 // Utility Functions
 // ============================================================================
@@ -13,13 +13,13 @@ use crate::extractors::JsonQuery;
 use crate::handlers::coretime::common::{
     AtResponse,
     CoretimeError,
-    CoretimeQueryParams,
+    CoretimeRegionsQueryParams,
     // Shared functions
     has_broker_pallet,
 };
 use crate::handlers::runtime_queries::broker;
 use crate::state::AppState;
-use crate::utils::{BlockId, decode_address_to_ss58, resolve_block};
+use crate::utils::{BlockId, decode_address_to_ss58, resolve_block, response_ss58_prefix};
 use axum::{
     Json,
     extract::State,
@@ -84,6 +84,7 @@ pub struct CoretimeRegionsResponse {
 ///
 /// Query Parameters:
 /// - at: Optional block number or hash to query at (defaults to latest finalized)
+/// - ss58Format: Optional SS58 prefix to encode owners with (defaults to the chain's)
 #[utoipa::path(
     get,
     path = "/v1/coretime/regions",
@@ -91,7 +92,8 @@ pub struct CoretimeRegionsResponse {
     summary = "Get coretime regions",
     description = "Returns all regions on a coretime chain including begin/end timeslices, core, owner, and mask.",
     params(
-        ("at" = Option<String>, Query, description = "Block identifier (number or hash)")
+        ("at" = Option<String>, Query, description = "Block identifier (number or hash)"),
+        ("ss58Format" = Option<u16>, Query, description = "SS58 prefix to encode region owners with (defaults to the chain's)")
     ),
     responses(
         (status = 200, description = "Coretime regions", body = Object),
@@ -102,8 +104,10 @@ pub struct CoretimeRegionsResponse {
 )]
 pub async fn coretime_regions(
     State(state): State<AppState>,
-    JsonQuery(params): JsonQuery<CoretimeQueryParams>,
+    JsonQuery(params): JsonQuery<CoretimeRegionsQueryParams>,
 ) -> Result<Response, CoretimeError> {
+    let ss58_prefix = response_ss58_prefix(params.ss58_format, state.chain_info.ss58_prefix)?;
+
     // Parse the block ID if provided
     let block_id = match &params.at {
         None => None,
@@ -130,7 +134,7 @@ pub async fn coretime_regions(
     }
 
     // Fetch regions
-    let mut regions = fetch_regions(&client_at_block, state.chain_info.ss58_prefix).await?;
+    let mut regions = fetch_regions(&client_at_block, ss58_prefix).await?;
 
     // Sort by core ID
     regions.sort_by_key(|r| r.core);
@@ -262,7 +266,7 @@ mod tests {
     #[test]
     fn test_decode_region_record_invalid() {
         // Not enough bytes for a valid RegionRecord
-        let bytes = vec![0x00, 0x01];
+        let bytes = [0x00, 0x01];
         let result = RegionRecord::decode(&mut &bytes[..]);
         assert!(result.is_err());
     }
@@ -370,7 +374,7 @@ mod tests {
 
     #[test]
     fn test_regions_sorting_by_core() {
-        let mut regions = vec![
+        let mut regions = [
             RegionInfo {
                 core: 52,
                 begin: 100,
@@ -2,17 +2,24 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::state::AppState;
+use crate::utils::{self, parse_block_number_from_json, resolve_block_with_rpc};
 use axum::{extract::State, http::StatusCode, response::Json};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use subxt_rpcs::rpc_params;
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct HealthResponse {
+    /// "ok" when the node is reachable, "degraded" otherwise
     pub status: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connected_chains: Option<usize>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub uptime: Option<u64>,
+    pub best_block: Option<String>,
+    pub finalized_block: Option<String>,
+    /// Number of blocks between the best and the finalized block
+    pub lag: Option<String>,
+    /// Whether the node's RPC responded to the health check
+    pub connected: bool,
 }
 
 #[utoipa::path(
@@ -20,17 +27,181 @@ pub struct HealthResponse {
     path = "/v1/health",
     tag = "health",
     summary = "Health check",
-    description = "Returns the health status of the API server.",
+    description = "Returns the health status of the API server along with the node's best and finalized block. Responds with 503 and status \"degraded\" when the node cannot be reached, so it can be used as a readiness probe.",
     responses(
-        (status = 202, description = "API is healthy", body = HealthResponse)
+        (status = 202, description = "API is healthy", body = HealthResponse),
+        (status = 503, description = "Node RPC is unreachable", body = HealthResponse)
     )
 )]
-pub async fn get_health(State(_state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+pub async fn get_health(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    let Some((best, finalized)) = fetch_chain_heads(&state).await else {
+        return get_health_unavailable().await;
+    };
+
     let response = HealthResponse {
         status: "ok".to_string(),
-        connected_chains: None,
-        uptime: None,
+        best_block: Some(best.to_string()),
+        finalized_block: Some(finalized.to_string()),
+        lag: Some(best.saturating_sub(finalized).to_string()),
+        connected: true,
     };
 
     (StatusCode::ACCEPTED, Json(response))
 }
+
+/// Health check served while the node has not been reached since startup.
+pub async fn get_health_unavailable() -> (StatusCode, Json<HealthResponse>) {
+    let response = HealthResponse {
+        status: "degraded".to_string(),
+        best_block: None,
+        finalized_block: None,
+        lag: None,
+        connected: false,
+    };
+    (StatusCode::SERVICE_UNAVAILABLE, Json(response))
+}
+
+/// Fetch the best and finalized block numbers, or `None` if the node can't be reached.
+async fn fetch_chain_heads(state: &AppState) -> Option<(u64, u64)> {
+    let (best_header, finalized) = tokio::join!(
+        state
+            .rpc_client
+            .request::<Value>("chain_getHeader", rpc_params![]),
+        resolve_block_with_rpc(&state.rpc_client, &state.legacy_rpc, None)
+    );
+
+    let best_header = match best_header {
+        Ok(header) => header,
+        Err(e) => {
+            if utils::is_disconnected_error(&e) {
+                tracing::debug!("Health check: node RPC disconnected, waiting for reconnect");
+            } else {
+                tracing::warn!("Health check: failed to get best header: {e}");
+            }
+            return None;
+        }
+    };
+    let finalized = match finalized {
+        Ok(block) => block.number,
+        Err(e) => {
+            tracing::warn!("Health check: failed to get finalized block: {e}");
+            return None;
+        }
+    };
+
+    match best_header.get("number").map(parse_block_number_from_json) {
+        Some(Ok(best)) => Some((best, finalized)),
+        _ => {
+            tracing::warn!("Health check: best header without a valid block number");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::mock_rpc_client_builder;
+    use polkadot_rest_api_config::SidecarConfig;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+    use subxt_rpcs::client::{MockRpcClient, RpcClient};
+
+    async fn create_test_state_with_mock(mock_client: MockRpcClient) -> AppState {
+        let config = SidecarConfig::default();
+        let rpc_client = Arc::new(RpcClient::new(mock_client));
+        let legacy_rpc = Arc::new(subxt_rpcs::LegacyRpcMethods::new((*rpc_client).clone()));
+        let chain_info = crate::state::ChainInfo {
+            chain_type: polkadot_rest_api_config::ChainType::Relay,
+            spec_name: "test".to_string(),
+            spec_version: 1,
+            ss58_prefix: 42,
+        };
+
+        let client = subxt::OnlineClient::from_rpc_client((*rpc_client).clone())
+            .await
+            .expect("Failed to create test OnlineClient");
+
+        AppState {
+            config,
+            client: Arc::new(client),
+            legacy_rpc,
+            rpc_client,
+            chain_info,
+            relay_client: Arc::new(tokio::sync::OnceCell::new()),
+            relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
+            relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
+            fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
+            chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
+            chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
+                polkadot_rest_api_config::ChainConfig::default(),
+            )),
+            route_registry: crate::routes::RouteRegistry::new(),
+            relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
+        }
+    }
+
+    /// Serves headers until `disconnected` is set, then fails like a reconnecting client.
+    fn mock_with_heads(best: u64, finalized: u64, disconnected: Arc<AtomicBool>) -> MockRpcClient {
+        mock_rpc_client_builder()
+            .method_handler("chain_getHeader", move |params| {
+                let disconnected = disconnected.clone();
+                async move {
+                    if disconnected.load(Ordering::SeqCst) {
+                        return Err(subxt_rpcs::Error::DisconnectedWillReconnect(
+                            "Connection lost".to_string(),
+                        ));
+                    }
+                    // Requests without a hash ask for the best block
+                    let number = if params.is_none() { best } else { finalized };
+                    Ok(MockJson(json!({
+                        "number": format!("0x{:x}", number),
+                        "parentHash": crate::test_fixtures::TEST_GENESIS_HASH,
+                        "stateRoot": crate::test_fixtures::TEST_GENESIS_HASH,
+                        "extrinsicsRoot": crate::test_fixtures::TEST_GENESIS_HASH,
+                        "digest": { "logs": [] }
+                    })))
+                }
+            })
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_heads_and_lag() {
+        let mock_client = mock_with_heads(105, 100, Arc::new(AtomicBool::new(false)));
+        let state = create_test_state_with_mock(mock_client).await;
+
+        let (status, Json(response)) = get_health(State(state)).await;
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(response.status, "ok");
+        assert_eq!(response.best_block.as_deref(), Some("105"));
+        assert_eq!(response.finalized_block.as_deref(), Some("100"));
+        assert_eq!(response.lag.as_deref(), Some("5"));
+        assert!(response.connected);
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_when_disconnected() {
+        let disconnected = Arc::new(AtomicBool::new(false));
+        let mock_client = mock_with_heads(105, 100, disconnected.clone());
+        let state = create_test_state_with_mock(mock_client).await;
+        disconnected.store(true, Ordering::SeqCst);
+
+        let (status, Json(response)) = get_health(State(state)).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.status, "degraded");
+        assert!(!response.connected);
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json["bestBlock"].is_null());
+        assert!(json["lag"].is_null());
+    }
+}
@@ -3,4 +3,4 @@
 
 pub mod get_health;
 
-pub use get_health::get_health;
+pub use get_health::{get_health, get_health_unavailable};
@@ -58,6 +58,12 @@ pub async fn get_metrics_json() -> impl IntoResponse {
                             json!({
                                 "sample_count": h.get_sample_count(),
                                 "sample_sum": h.get_sample_sum(),
+                                "buckets": h.get_bucket().iter().map(|b| {
+                                    json!({
+                                        "upper_bound": b.get_upper_bound(),
+                                        "cumulative_count": b.get_cumulative_count()
+                                    })
+                                }).collect::<Vec<_>>(),
                             })
                         } else {
                             json!(null)
@@ -5,6 +5,7 @@ pub mod accounts;
 pub mod ahm;
 pub mod blocks;
 pub mod capabilities;
+pub mod chains;
 pub mod common;
 pub mod coretime;
 pub mod health;
@@ -15,5 +16,6 @@ pub mod paras;
 pub mod rc;
 pub mod runtime;
 pub mod runtime_queries;
+pub mod subscriptions;
 pub mod transaction;
 pub mod version;
@@ -7,6 +7,7 @@
 //! Asset Hub and Relay Chain queries. The handlers simply provide the appropriate
 //! RPC client and call these shared functions.
 
+use crate::utils::{self, RuntimeMetadataCache};
 use frame_metadata::RuntimeMetadataPrefixed;
 use parity_scale_codec::Decode;
 use scale_decode::DecodeAsType;
@@ -17,6 +18,8 @@ use subxt::SubstrateConfig;
 use subxt::config::RpcConfigFor;
 use subxt_rpcs::{LegacyRpcMethods, RpcClient, client::rpc_params};
 
+use super::NodePeer;
+
 // ================================================================================================
 // SCALE Decode Types for metadata constants
 // ================================================================================================
@@ -89,6 +92,18 @@ pub enum FetchError {
     TipExtraction(#[from] TipExtractionError),
 }
 
+impl From<utils::MetadataFetchError> for FetchError {
+    fn from(err: utils::MetadataFetchError) -> Self {
+        match err {
+            utils::MetadataFetchError::RpcFailed(e) => FetchError::RpcFailed(e),
+            utils::MetadataFetchError::HexDecodeFailed(_) => FetchError::MetadataDecodeFailed(
+                parity_scale_codec::Error::from("Failed to decode hex"),
+            ),
+            utils::MetadataFetchError::ScaleDecodeFailed(e) => FetchError::MetadataDecodeFailed(e),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum TipExtractionError {
     #[error("Empty extrinsic")]
@@ -130,9 +145,13 @@ pub async fn fetch_node_version(
         .to_string();
 
     Ok(NodeVersionResponse {
-        client_version,
+        at: None,
+        client_version: Some(client_version),
         client_impl_name,
         chain,
+        spec_name: None,
+        spec_version: None,
+        impl_version: None,
     })
 }
 
@@ -140,6 +159,14 @@ pub async fn fetch_node_version(
 // NETWORK
 // ============================================================================
 
+/// Serialize a `u64` as a decimal string, the way the node endpoints report counts
+pub fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_str(&value.to_string())
+}
+
 pub async fn fetch_node_network(rpc_client: &RpcClient) -> Result<NodeNetworkResponse, FetchError> {
     let (health_result, peer_id_result, roles_result, addresses_result) = tokio::join!(
         rpc_client.request::<Value>("system_health", rpc_params![]),
@@ -240,6 +267,37 @@ fn transform_peers_info(peers: Value) -> Value {
     Value::Array(transformed)
 }
 
+// ============================================================================
+// PEERS
+// ============================================================================
+
+/// Peer entry as returned by `system_peers`
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPeerInfo {
+    peer_id: String,
+    roles: String,
+    best_hash: String,
+    best_number: u64,
+}
+
+pub async fn fetch_node_peers(rpc_client: &RpcClient) -> Result<Vec<NodePeer>, FetchError> {
+    let peers: Vec<RawPeerInfo> = rpc_client
+        .request("system_peers", rpc_params![])
+        .await
+        .map_err(FetchError::RpcFailed)?;
+
+    Ok(peers
+        .into_iter()
+        .map(|peer| NodePeer {
+            peer_id: peer.peer_id,
+            roles: peer.roles,
+            best_hash: peer.best_hash,
+            best_number: peer.best_number.to_string(),
+        })
+        .collect())
+}
+
 // ============================================================================
 // TRANSACTION POOL
 // ============================================================================
@@ -268,6 +326,7 @@ pub async fn fetch_transaction_pool_simple(
                 tip: None,
                 priority: None,
                 partial_fee: None,
+                pallet: None,
             }
         })
         .collect();
@@ -277,6 +336,7 @@ pub async fn fetch_transaction_pool_simple(
 
 pub async fn fetch_transaction_pool_with_fees(
     rpc_client: &RpcClient,
+    metadata_cache: &RuntimeMetadataCache,
 ) -> Result<TransactionPoolResponse, FetchError> {
     let (extrinsics_result, latest_hash_result) = tokio::join!(
         rpc_client.request::<Vec<String>>("author_pendingExtrinsics", rpc_params![]),
@@ -309,6 +369,7 @@ pub async fn fetch_transaction_pool_with_fees(
         let priority = calculate_priority(
             &fee_info,
             rpc_client,
+            metadata_cache,
             &encoded_extrinsic,
             &latest_hash,
             encoded_length,
@@ -326,6 +387,7 @@ pub async fn fetch_transaction_pool_with_fees(
             tip,
             priority,
             partial_fee,
+            pallet: None,
         });
     }
 
@@ -497,6 +559,7 @@ async fn query_fee_details(
 async fn calculate_priority(
     fee_info: &Value,
     rpc_client: &RpcClient,
+    metadata_cache: &RuntimeMetadataCache,
     encoded_extrinsic: &str,
     latest_hash: &str,
     encoded_length: usize,
@@ -523,13 +586,14 @@ async fn calculate_priority(
                 .ok_or_else(|| FetchError::ConstantNotFound("weight".to_string()))?
         };
 
-    let metadata = get_runtime_metadata(rpc_client, latest_hash).await?;
+    let cached = utils::fetch_runtime_metadata(rpc_client, metadata_cache, latest_hash).await?;
+    let metadata = cached.decoded()?;
 
-    let max_block_weight = extract_max_block_weight(&metadata).ok_or_else(|| {
+    let max_block_weight = extract_max_block_weight(metadata).ok_or_else(|| {
         FetchError::ConstantNotFound("System::BlockWeights::maxBlock::refTime".to_string())
     })?;
 
-    let max_length = extract_max_block_length(&metadata, &class_str).ok_or_else(|| {
+    let max_length = extract_max_block_length(metadata, &class_str).ok_or_else(|| {
         FetchError::ConstantNotFound(format!("System::BlockLength::max[{}]", class_str))
     })?;
 
@@ -575,7 +639,7 @@ async fn calculate_priority(
                         let final_fee = computed_inclusion_fee.saturating_add(tip);
 
                         let operational_fee_multiplier =
-                            extract_operational_fee_multiplier(&metadata).ok_or_else(|| {
+                            extract_operational_fee_multiplier(metadata).ok_or_else(|| {
                                 FetchError::ConstantNotFound(
                                     "TransactionPayment::operationalFeeMultiplier".to_string(),
                                 )
@@ -602,24 +666,6 @@ async fn calculate_priority(
     Ok(Some(priority))
 }
 
-async fn get_runtime_metadata(
-    rpc_client: &RpcClient,
-    block_hash: &str,
-) -> Result<RuntimeMetadataPrefixed, FetchError> {
-    let metadata_hex: String = rpc_client
-        .request("state_getMetadata", rpc_params![block_hash])
-        .await
-        .map_err(FetchError::RpcFailed)?;
-
-    let hex_str = metadata_hex.strip_prefix("0x").unwrap_or(&metadata_hex);
-    let metadata_bytes = hex::decode(hex_str).map_err(|_| {
-        FetchError::MetadataDecodeFailed(parity_scale_codec::Error::from("Failed to decode hex"))
-    })?;
-
-    RuntimeMetadataPrefixed::decode(&mut &metadata_bytes[..])
-        .map_err(FetchError::MetadataDecodeFailed)
-}
-
 fn extract_max_block_weight(metadata: &RuntimeMetadataPrefixed) -> Option<u64> {
     use frame_metadata::RuntimeMetadata;
 
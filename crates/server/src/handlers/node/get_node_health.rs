@@ -0,0 +1,126 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::state::AppState;
+use crate::utils;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use subxt_rpcs::{RpcClient, rpc_params};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+use super::common::serialize_u64_as_string;
+
+#[derive(Debug, Error)]
+pub enum GetNodeHealthError {
+    #[error("Failed to get system health")]
+    SystemHealthFailed(#[source] subxt_rpcs::Error),
+}
+
+impl IntoResponse for GetNodeHealthError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, message) = match &self {
+            GetNodeHealthError::SystemHealthFailed(err) => utils::rpc_error_to_status(err),
+        };
+
+        let body = Json(json!({
+            "error": message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+/// The node's `system_health` as reported by the node itself
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealthResponse {
+    /// Number of connected peers
+    #[serde(serialize_with = "serialize_u64_as_string")]
+    pub peers: u64,
+    /// Whether the node is still syncing
+    pub is_syncing: bool,
+    /// Whether the node is expected to be connected to peers
+    pub should_have_peers: bool,
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/node/health",
+    tag = "node",
+    summary = "Node health",
+    description = "Returns the connected node's `system_health`: its peer count, whether it is syncing and whether it should have peers. Unlike `/v1/health`, which reports this service's own state, this reports the node's values as-is. A syncing node answers 503 with the same body, so clients can avoid querying state until it has caught up.",
+    responses(
+        (status = 200, description = "Node is synced", body = NodeHealthResponse),
+        (status = 503, description = "Node is syncing, or service unavailable", body = NodeHealthResponse),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_node_health(
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<NodeHealthResponse>), GetNodeHealthError> {
+    let health = fetch_node_health(&state.rpc_client).await?;
+    Ok((health_status(&health), Json(health)))
+}
+
+async fn fetch_node_health(
+    rpc_client: &RpcClient,
+) -> Result<NodeHealthResponse, GetNodeHealthError> {
+    rpc_client
+        .request::<NodeHealthResponse>("system_health", rpc_params![])
+        .await
+        .map_err(GetNodeHealthError::SystemHealthFailed)
+}
+
+/// A syncing node's state lags the chain, so it isn't ready to serve queries.
+fn health_status(health: &NodeHealthResponse) -> StatusCode {
+    if health.is_syncing {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::mock_rpc_client_builder;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+    fn rpc_client_with_health(is_syncing: bool) -> RpcClient {
+        let mock_client = mock_rpc_client_builder()
+            .method_handler("system_health", move |_params| async move {
+                MockJson(json!({
+                    "peers": 7,
+                    "isSyncing": is_syncing,
+                    "shouldHavePeers": true
+                }))
+            })
+            .build();
+        RpcClient::new(mock_client)
+    }
+
+    #[tokio::test]
+    async fn test_node_health_reports_node_values() {
+        let health = fetch_node_health(&rpc_client_with_health(false))
+            .await
+            .unwrap();
+
+        assert_eq!(health_status(&health), StatusCode::OK);
+        assert_eq!(
+            serde_json::to_value(&health).unwrap(),
+            json!({ "peers": "7", "isSyncing": false, "shouldHavePeers": true })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_syncing_node_is_unavailable() {
+        let health = fetch_node_health(&rpc_client_with_health(true))
+            .await
+            .unwrap();
+
+        assert!(health.is_syncing);
+        assert_eq!(health_status(&health), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 use thiserror::Error;
 
-use super::common::{FetchError, fetch_node_network};
+use super::common::{FetchError, fetch_node_network, serialize_u64_as_string};
 
 #[derive(Debug, Error)]
 pub enum GetNodeNetworkError {
@@ -66,13 +66,6 @@ pub struct NodeNetworkResponse {
     pub peers_info: Value,
 }
 
-fn serialize_u64_as_string<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    serializer.serialize_str(&value.to_string())
-}
-
 #[utoipa::path(
     get,
     path = "/v1/node/network",
@@ -128,12 +121,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
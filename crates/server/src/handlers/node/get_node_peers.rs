@@ -0,0 +1,189 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::state::AppState;
+use crate::utils;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+use super::common::{FetchError, fetch_node_peers};
+
+#[derive(Debug, Error)]
+pub enum GetNodePeersError {
+    #[error(
+        "system_peers is not available on this node. It is an unsafe RPC method; start the node with `--rpc-methods unsafe` to enable it"
+    )]
+    PeersUnavailable(#[source] subxt_rpcs::Error),
+
+    #[error("Failed to get system peers")]
+    SystemPeersFailed(#[source] subxt_rpcs::Error),
+
+    #[error("Failed to fetch node peers")]
+    FetchFailed(#[source] FetchError),
+}
+
+impl From<FetchError> for GetNodePeersError {
+    fn from(err: FetchError) -> Self {
+        match err {
+            FetchError::RpcFailed(e) if utils::is_method_unavailable_error(&e) => {
+                GetNodePeersError::PeersUnavailable(e)
+            }
+            FetchError::RpcFailed(e) => GetNodePeersError::SystemPeersFailed(e),
+            other => GetNodePeersError::FetchFailed(other),
+        }
+    }
+}
+
+impl IntoResponse for GetNodePeersError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, message) = match &self {
+            GetNodePeersError::PeersUnavailable(_) => {
+                (StatusCode::NOT_IMPLEMENTED, self.to_string())
+            }
+            GetNodePeersError::SystemPeersFailed(err) => utils::rpc_error_to_status(err),
+            GetNodePeersError::FetchFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            }
+        };
+
+        let body = Json(json!({
+            "error": message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NodePeer {
+    pub peer_id: String,
+    pub roles: String,
+    pub best_hash: String,
+    pub best_number: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/node/peers",
+    tag = "node",
+    summary = "Node peers",
+    description = "Returns the peers currently connected to the node. Requires the node to expose the unsafe `system_peers` RPC method.",
+    responses(
+        (status = 200, description = "Connected peers", body = Vec<NodePeer>),
+        (status = 501, description = "system_peers is not exposed by the node"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_node_peers(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<NodePeer>>, GetNodePeersError> {
+    let peers = fetch_node_peers(&state.rpc_client).await?;
+    Ok(Json(peers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AppState;
+    use crate::test_fixtures::mock_rpc_client_builder;
+    use axum::extract::State;
+    use polkadot_rest_api_config::SidecarConfig;
+    use std::sync::Arc;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+    use subxt_rpcs::client::{MockRpcClient, RpcClient};
+
+    async fn create_test_state_with_mock(mock_client: MockRpcClient) -> AppState {
+        let config = SidecarConfig::default();
+        let rpc_client = Arc::new(RpcClient::new(mock_client));
+        let legacy_rpc = Arc::new(subxt_rpcs::LegacyRpcMethods::new((*rpc_client).clone()));
+        let chain_info = crate::state::ChainInfo {
+            chain_type: polkadot_rest_api_config::ChainType::Relay,
+            spec_name: "test".to_string(),
+            spec_version: 1,
+            ss58_prefix: 42,
+        };
+
+        let client = subxt::OnlineClient::from_rpc_client((*rpc_client).clone())
+            .await
+            .expect("Failed to create test OnlineClient");
+
+        AppState {
+            config,
+            client: Arc::new(client),
+            legacy_rpc,
+            rpc_client,
+            chain_info,
+            relay_client: Arc::new(tokio::sync::OnceCell::new()),
+            relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
+            relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
+            fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
+            chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
+            chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
+                polkadot_rest_api_config::ChainConfig::default(),
+            )),
+            route_registry: crate::routes::RouteRegistry::new(),
+            relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_node_peers_success() {
+        let mock_client = mock_rpc_client_builder()
+            .method_handler("system_peers", async |_params| {
+                MockJson(json!([
+                    {
+                        "peerId": "12D3KooWFBkZwKye8pKvnG3KH5TN6UNf146Ciz1hCJUZ6mwtE5Qw",
+                        "roles": "FULL",
+                        "bestHash": "0x9e9c9e87c875a5e5c9296e50d4eca8eb8dc8513ac4fad29756ce9e65066f6525",
+                        "bestNumber": 29522680
+                    }
+                ]))
+            })
+            .build();
+
+        let state = create_test_state_with_mock(mock_client).await;
+        let response = get_node_peers(State(state)).await.unwrap().0;
+
+        assert_eq!(response.len(), 1);
+        assert_eq!(
+            response[0].peer_id,
+            "12D3KooWFBkZwKye8pKvnG3KH5TN6UNf146Ciz1hCJUZ6mwtE5Qw"
+        );
+        assert_eq!(response[0].roles, "FULL");
+        assert_eq!(response[0].best_number, "29522680");
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json[0]["bestHash"], response[0].best_hash);
+    }
+
+    #[tokio::test]
+    async fn test_get_node_peers_unavailable_returns_501() {
+        let mock_client = mock_rpc_client_builder().build();
+
+        let state = create_test_state_with_mock(mock_client).await;
+        let err = get_node_peers(State(state)).await.unwrap_err();
+
+        assert!(matches!(err, GetNodePeersError::PeersUnavailable(_)));
+        assert_eq!(err.into_response().status(), StatusCode::NOT_IMPLEMENTED);
+    }
+
+    #[test]
+    fn test_non_rpc_fetch_error_returns_500() {
+        let err = GetNodePeersError::from(FetchError::ConstantNotFound("Peers".to_string()));
+
+        assert!(matches!(err, GetNodePeersError::FetchFailed(_)));
+        assert_eq!(
+            err.into_response().status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
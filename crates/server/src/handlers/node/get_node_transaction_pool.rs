@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::extractors::JsonQuery;
+use crate::handlers::transaction::decode::decode_extrinsic_pallet;
 use crate::state::AppState;
 use crate::utils;
 use axum::{Json, extract::State, response::IntoResponse};
@@ -43,6 +44,9 @@ pub enum GetNodeTransactionPoolError {
 
     #[error("Tip extraction failed: {0}")]
     TipExtractionFailed(#[from] TipExtractionError),
+
+    #[error("Failed to get client at current block")]
+    ClientAtBlockFailed(#[source] Box<subxt::error::OnlineClientAtBlockError>),
 }
 
 impl From<FetchError> for GetNodeTransactionPoolError {
@@ -73,6 +77,16 @@ impl IntoResponse for GetNodeTransactionPoolError {
             | GetNodeTransactionPoolError::TipExtractionFailed(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
             }
+            GetNodeTransactionPoolError::ClientAtBlockFailed(err) => {
+                if utils::is_online_client_at_block_disconnected(err) {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "Service temporarily unavailable".to_string(),
+                    )
+                } else {
+                    (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+                }
+            }
         };
 
         let body = Json(json!({
@@ -88,6 +102,8 @@ impl IntoResponse for GetNodeTransactionPoolError {
 pub struct TransactionPoolQueryParams {
     #[serde(default)]
     pub include_fee: bool,
+    /// Only return extrinsics calling into this pallet (case-insensitive)
+    pub pallet: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -101,6 +117,11 @@ pub struct TransactionPoolEntry {
     pub priority: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub partial_fee: Option<String>,
+    /// Pallet of the extrinsic's call, only present when filtering by pallet.
+    /// Null if the extrinsic could not be decoded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>)]
+    pub pallet: Option<Option<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -114,9 +135,10 @@ pub struct TransactionPoolResponse {
     path = "/v1/node/transaction-pool",
     tag = "node",
     summary = "Transaction pool",
-    description = "Returns the node's transaction pool with optional fee information.",
+    description = "Returns the node's transaction pool with optional fee information. When filtering by pallet, extrinsics that cannot be decoded are kept with a null pallet.",
     params(
-        ("includeFee" = Option<bool>, Query, description = "Include fee details for each transaction")
+        ("includeFee" = Option<bool>, Query, description = "Include fee details for each transaction"),
+        ("pallet" = Option<String>, Query, description = "Only return extrinsics calling into this pallet")
     ),
     responses(
         (status = 200, description = "Transaction pool entries", body = TransactionPoolResponse),
@@ -128,15 +150,49 @@ pub async fn get_node_transaction_pool(
     State(state): State<AppState>,
     JsonQuery(params): JsonQuery<TransactionPoolQueryParams>,
 ) -> Result<Json<TransactionPoolResponse>, GetNodeTransactionPoolError> {
-    let response = if params.include_fee {
-        fetch_transaction_pool_with_fees(&state.rpc_client).await?
+    let mut response = if params.include_fee {
+        fetch_transaction_pool_with_fees(&state.rpc_client, &state.metadata_cache).await?
     } else {
         fetch_transaction_pool_simple(&state.rpc_client).await?
     };
 
+    if let Some(pallet) = params.pallet.as_deref() {
+        filter_pool_by_pallet(&state, &mut response.pool, pallet).await?;
+    }
+
     Ok(Json(response))
 }
 
+/// Decode the call pallet of each entry and keep those matching `pallet`.
+///
+/// Entries that fail to decode are kept, with a null pallet, so nothing is silently hidden.
+async fn filter_pool_by_pallet(
+    state: &AppState,
+    pool: &mut Vec<TransactionPoolEntry>,
+    pallet: &str,
+) -> Result<(), GetNodeTransactionPoolError> {
+    let client_at = state
+        .client
+        .at_current_block()
+        .await
+        .map_err(|e| GetNodeTransactionPoolError::ClientAtBlockFailed(Box::new(e)))?;
+
+    for entry in pool.iter_mut() {
+        let decoded = match hex::decode(entry.encoded_extrinsic.trim_start_matches("0x")) {
+            Ok(bytes) => decode_extrinsic_pallet(&client_at, bytes).await,
+            Err(_) => None,
+        };
+        entry.pallet = Some(decoded);
+    }
+
+    pool.retain(|entry| match &entry.pallet {
+        Some(Some(name)) => name.eq_ignore_ascii_case(pallet),
+        _ => true,
+    });
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,12 +230,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
@@ -235,7 +295,10 @@ mod tests {
             .build();
 
         let state = create_test_state_with_mock(mock_client).await;
-        let params = TransactionPoolQueryParams { include_fee: false };
+        let params = TransactionPoolQueryParams {
+            include_fee: false,
+            pallet: None,
+        };
 
         let result = get_node_transaction_pool(State(state), JsonQuery(params)).await;
         assert!(result.is_ok());
@@ -259,7 +322,10 @@ mod tests {
             .build();
 
         let state = create_test_state_with_mock(mock_client).await;
-        let params = TransactionPoolQueryParams { include_fee: false };
+        let params = TransactionPoolQueryParams {
+            include_fee: false,
+            pallet: None,
+        };
 
         let result = get_node_transaction_pool(State(state), JsonQuery(params)).await;
         assert!(result.is_ok());
@@ -306,7 +372,10 @@ mod tests {
             .build();
 
         let state = create_test_state_with_mock(mock_client).await;
-        let params = TransactionPoolQueryParams { include_fee: true };
+        let params = TransactionPoolQueryParams {
+            include_fee: true,
+            pallet: None,
+        };
 
         let result = get_node_transaction_pool(State(state), JsonQuery(params)).await;
         if let Ok(response) = result {
@@ -319,6 +388,43 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_transaction_pool_filtered_by_pallet() {
+        let mock_client = mock_rpc_client_builder()
+            .method_handler("author_pendingExtrinsics", async |_params| {
+                MockJson(serde_json::json!([
+                    real_asset_hub_extrinsic_transfer(),
+                    real_asset_hub_extrinsic_assets(),
+                    "0x1234"
+                ]))
+            })
+            .build();
+
+        let state = create_test_state_with_mock(mock_client).await;
+        let params = TransactionPoolQueryParams {
+            include_fee: false,
+            pallet: Some("Balances".to_string()),
+        };
+
+        let response = get_node_transaction_pool(State(state), JsonQuery(params))
+            .await
+            .unwrap()
+            .0;
+
+        assert_eq!(response.pool.len(), 2);
+        assert_eq!(
+            response.pool[0].encoded_extrinsic,
+            real_asset_hub_extrinsic_transfer()
+        );
+        assert_eq!(response.pool[0].pallet, Some(Some("balances".to_string())));
+        assert_eq!(response.pool[1].encoded_extrinsic, "0x1234");
+        assert_eq!(response.pool[1].pallet, Some(None));
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["pool"][0]["pallet"], "balances");
+        assert!(json["pool"][1]["pallet"].is_null());
+    }
+
     #[test]
     fn test_extract_tip_real_extrinsics() {
         let test_cases = [
@@ -1,11 +1,14 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use crate::extractors::JsonQuery;
 use crate::state::AppState;
 use crate::utils;
-use axum::{Json, extract::State, response::IntoResponse};
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{Value, json};
+use subxt::error::OnlineClientAtBlockError;
+use subxt_rpcs::rpc_params;
 use thiserror::Error;
 use utoipa::ToSchema;
 
@@ -21,6 +24,31 @@ pub enum GetNodeVersionError {
 
     #[error("Failed to get system version")]
     SystemVersionFailed(#[source] subxt_rpcs::Error),
+
+    #[error("Invalid block parameter")]
+    InvalidBlockParam(#[from] utils::BlockIdParseError),
+
+    #[error("Block resolution failed")]
+    BlockResolveFailed(#[from] utils::BlockResolveError),
+
+    #[error("Failed to get client at block")]
+    ClientAtBlockFailed(#[source] Box<OnlineClientAtBlockError>),
+}
+
+impl From<utils::ResolveClientAtBlockError> for GetNodeVersionError {
+    fn from(err: utils::ResolveClientAtBlockError) -> Self {
+        match err {
+            utils::ResolveClientAtBlockError::ParseError(e) => {
+                GetNodeVersionError::InvalidBlockParam(e)
+            }
+            utils::ResolveClientAtBlockError::BlockNotFound(msg) => {
+                GetNodeVersionError::BlockResolveFailed(utils::BlockResolveError::NotFound(msg))
+            }
+            utils::ResolveClientAtBlockError::SubxtError(e) => {
+                GetNodeVersionError::ClientAtBlockFailed(Box::new(e))
+            }
+        }
+    }
 }
 
 impl From<FetchError> for GetNodeVersionError {
@@ -38,6 +66,22 @@ impl IntoResponse for GetNodeVersionError {
             GetNodeVersionError::RuntimeVersionFailed(err)
             | GetNodeVersionError::SystemChainFailed(err)
             | GetNodeVersionError::SystemVersionFailed(err) => utils::rpc_error_to_status(err),
+            GetNodeVersionError::InvalidBlockParam(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            GetNodeVersionError::BlockResolveFailed(inner) => {
+                (inner.status_code(), inner.to_string())
+            }
+            GetNodeVersionError::ClientAtBlockFailed(err) => {
+                if utils::is_online_client_at_block_disconnected(err) {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "Service temporarily unavailable".to_string(),
+                    )
+                } else {
+                    (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+                }
+            }
         };
 
         let body = Json(json!({
@@ -48,12 +92,37 @@ impl IntoResponse for GetNodeVersionError {
     }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeVersionQueryParams {
+    pub at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct At {
+    pub hash: String,
+    pub height: String,
+}
+
+/// Node version information.
+///
+/// When queried with `at`, the runtime fields describe the runtime at that block and
+/// `clientVersion` is omitted, since the node binary version is not historical.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeVersionResponse {
-    pub client_version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at: Option<At>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_version: Option<String>,
     pub client_impl_name: String,
     pub chain: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impl_version: Option<String>,
 }
 
 #[utoipa::path(
@@ -61,18 +130,58 @@ pub struct NodeVersionResponse {
     path = "/v1/node/version",
     tag = "node",
     summary = "Node version",
-    description = "Returns the node's version information including client version, implementation name, and chain name.",
+    description = "Returns the node's version information including client version, implementation name, and chain name. With `at`, returns the runtime's specName, specVersion and implVersion as of that block instead of the node client version.",
+    params(
+        ("at" = Option<String>, Query, description = "Block hash or number to read the runtime version at")
+    ),
     responses(
         (status = 200, description = "Node version information", body = NodeVersionResponse),
+        (status = 400, description = "Invalid block parameter"),
+        (status = 404, description = "Block not found"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_node_version(
     State(state): State<AppState>,
+    JsonQuery(params): JsonQuery<NodeVersionQueryParams>,
 ) -> Result<Json<NodeVersionResponse>, GetNodeVersionError> {
-    let response = fetch_node_version(&state.rpc_client, &state.legacy_rpc).await?;
-    Ok(Json(response))
+    let Some(at) = params.at else {
+        let response = fetch_node_version(&state.rpc_client, &state.legacy_rpc).await?;
+        return Ok(Json(response));
+    };
+
+    let client_at_block = utils::resolve_client_at_block(&state.client, Some(&at)).await?;
+    let block_hash = format!("{:#x}", client_at_block.block_hash());
+
+    let (runtime_version_result, chain_result) = tokio::join!(
+        state.get_runtime_version_at_hash(&block_hash),
+        state
+            .rpc_client
+            .request::<String>("system_chain", rpc_params![]),
+    );
+    let runtime_version =
+        runtime_version_result.map_err(GetNodeVersionError::RuntimeVersionFailed)?;
+    let chain = chain_result.map_err(GetNodeVersionError::SystemChainFailed)?;
+
+    let string_field = |name: &str| match runtime_version.get(name) {
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(Value::Number(n)) => Some(n.to_string()),
+        _ => None,
+    };
+
+    Ok(Json(NodeVersionResponse {
+        at: Some(At {
+            hash: block_hash,
+            height: client_at_block.block_number().to_string(),
+        }),
+        client_version: None,
+        client_impl_name: string_field("implName").unwrap_or_else(|| "unknown".to_string()),
+        chain,
+        spec_name: string_field("specName"),
+        spec_version: string_field("specVersion"),
+        impl_version: string_field("implVersion"),
+    }))
 }
 
 #[cfg(test)]
@@ -111,12 +220,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
@@ -144,12 +257,14 @@ mod tests {
             .build();
 
         let state = create_test_state_with_mock(mock_client).await;
-        let result = get_node_version(State(state)).await;
+        let result =
+            get_node_version(State(state), JsonQuery(NodeVersionQueryParams { at: None })).await;
 
         assert!(result.is_ok());
         let response = result.unwrap().0;
         assert_eq!(response.chain, "Polkadot Asset Hub");
-        assert_eq!(response.client_version, "1.16.0-xyz9876");
+        assert_eq!(response.client_version.as_deref(), Some("1.16.0-xyz9876"));
+        assert!(response.spec_version.is_none());
         assert_eq!(response.client_impl_name, "asset-hub-polkadot");
     }
 
@@ -176,12 +291,70 @@ mod tests {
             .build();
 
         let state = create_test_state_with_mock(mock_client).await;
-        let result = get_node_version(State(state)).await;
+        let result =
+            get_node_version(State(state), JsonQuery(NodeVersionQueryParams { at: None })).await;
 
         assert!(result.is_ok());
         let response = result.unwrap().0;
         assert_eq!(response.chain, "Westend Asset Hub");
-        assert_eq!(response.client_version, "1.0.0");
+        assert_eq!(response.client_version.as_deref(), Some("1.0.0"));
         assert_eq!(response.client_impl_name, "unknown");
     }
+
+    #[tokio::test]
+    async fn test_get_node_version_at_block() {
+        let mock_client = mock_rpc_client_builder()
+            .method_handler("state_getRuntimeVersion", async |_params| {
+                MockJson(serde_json::json!({
+                    "specName": "asset-hub-polkadot",
+                    "implName": "asset-hub-polkadot",
+                    "authoringVersion": 0,
+                    "specVersion": 1003000,
+                    "implVersion": 2,
+                    "apis": [],
+                    "transactionVersion": 26,
+                    "stateVersion": 1
+                }))
+            })
+            .method_handler("system_chain", async |_params| {
+                MockJson("Polkadot Asset Hub".to_string())
+            })
+            .build();
+
+        let state = create_test_state_with_mock(mock_client).await;
+        let params = NodeVersionQueryParams {
+            at: Some(crate::test_fixtures::TEST_BLOCK_NUMBER.to_string()),
+        };
+        let response = get_node_version(State(state), JsonQuery(params))
+            .await
+            .unwrap()
+            .0;
+
+        let at = response.at.as_ref().expect("at should be set");
+        assert_eq!(at.hash, crate::test_fixtures::TEST_BLOCK_HASH);
+        assert_eq!(
+            at.height,
+            crate::test_fixtures::TEST_BLOCK_NUMBER.to_string()
+        );
+        assert_eq!(response.spec_name.as_deref(), Some("asset-hub-polkadot"));
+        assert_eq!(response.spec_version.as_deref(), Some("1003000"));
+        assert_eq!(response.impl_version.as_deref(), Some("2"));
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("clientVersion").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_node_version_invalid_at() {
+        let state = create_test_state_with_mock(mock_rpc_client_builder().build()).await;
+        let params = NodeVersionQueryParams {
+            at: Some("not-a-block".to_string()),
+        };
+        let err = get_node_version(State(state), JsonQuery(params))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, GetNodeVersionError::InvalidBlockParam(_)));
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
 }
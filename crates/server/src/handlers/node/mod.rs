@@ -2,13 +2,19 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 pub mod common;
+pub mod get_node_health;
 pub mod get_node_network;
+pub mod get_node_peers;
 pub mod get_node_transaction_pool;
 pub mod get_node_version;
 
+pub use get_node_health::{GetNodeHealthError, NodeHealthResponse, get_node_health};
 pub use get_node_network::{GetNodeNetworkError, NodeNetworkResponse, get_node_network};
+pub use get_node_peers::{GetNodePeersError, NodePeer, get_node_peers};
 pub use get_node_transaction_pool::{
     GetNodeTransactionPoolError, TransactionPoolEntry, TransactionPoolQueryParams,
     TransactionPoolResponse, get_node_transaction_pool,
 };
-pub use get_node_version::{GetNodeVersionError, NodeVersionResponse, get_node_version};
+pub use get_node_version::{
+    GetNodeVersionError, NodeVersionQueryParams, NodeVersionResponse, get_node_version,
+};
@@ -6,24 +6,35 @@
 //! This module provides endpoints for querying the AssetConversion pallet:
 //! - `/pallets/asset-conversion/liquidity-pools` - List all liquidity pools
 //! - `/pallets/asset-conversion/next-available-id` - Get the next available pool asset ID
+//! - `/pallets/assetconversion/nextPoolAssetId` - Alias of `next-available-id`
+//! - `/pallets/asset-conversion/pools/{poolId}/reserves` - Get a pool's reserves and LP supply
 
 use crate::extractors::JsonQuery;
+use crate::handlers::common::accounts::{
+    fetch_chain_tokens, format_balance, native_token_decimals,
+};
+use crate::handlers::common::xcm_types::{Junction, Junctions, Location};
 use crate::handlers::pallets::common::{AtResponse, PalletError, resolve_block_for_pallet};
 use crate::handlers::runtime_queries::asset_conversion as asset_conversion_queries;
+use crate::handlers::runtime_queries::assets as assets_queries;
+use crate::handlers::runtime_queries::foreign_assets as foreign_assets_queries;
+use crate::handlers::runtime_queries::pool_assets as pool_assets_queries;
 use crate::handlers::runtime_queries::staking as staking_queries;
 use crate::state::AppState;
 use crate::utils;
 use crate::utils::rc_block::find_ah_blocks_in_rc_block;
 use axum::{
     Json,
-    extract::State,
+    extract::{Path, State},
     http::StatusCode,
     response::{IntoResponse, Response},
 };
 use heck::ToLowerCamelCase;
+use parity_scale_codec::{Decode, DecodeAll};
 use polkadot_rest_api_config::ChainType;
 use scale_decode::DecodeAsType;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use subxt::{SubstrateConfig, client::OnlineClientAtBlock};
 
 // ============================================================================
@@ -39,6 +50,14 @@ struct PoolInfo {
     lp_token: u32,
 }
 
+/// Pool asset identifier used by runtimes that key pools by `NativeOrWithId`
+/// rather than by XCM location.
+#[derive(Debug, Decode)]
+enum NativeOrWithId {
+    Native,
+    WithId(u32),
+}
+
 /// Converts a `scale_value::Value` to `serde_json::Value`, matching Sidecar's JSON format.
 ///
 /// The pool key type varies across chains (older chains used `NativeOrWithId`,
@@ -127,6 +146,12 @@ pub struct LiquidityPoolInfo {
     /// The LP token info for this pool
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lp_token: Option<serde_json::Value>,
+    /// The pool's LP token ID, for looking pools up by LP token
+    #[serde(skip)]
+    pub lp_token_id: Option<u32>,
+    /// The SCALE-encoded asset pair from the pool's storage key
+    #[serde(skip)]
+    pub pair_bytes: Vec<u8>,
 }
 
 #[derive(Debug, Serialize)]
@@ -142,6 +167,64 @@ pub struct LiquidityPoolsResponse {
     pub ah_timestamp: Option<String>,
 }
 
+// --- Pool Reserves Response ---
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PoolReservesQueryParams {
+    pub at: Option<String>,
+    #[serde(default)]
+    pub use_rc_block: bool,
+    /// When true, also return each amount shifted by its asset's decimals
+    #[serde(default)]
+    pub denominated: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolReserve {
+    /// The asset, as listed in the pool's `reserves` pair
+    pub asset: serde_json::Value,
+    /// The pool account's balance of the asset
+    pub balance: String,
+    /// `balance` denominated by the asset's decimals, when requested and known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denominated: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolLpTokenSupply {
+    pub lp_token: String,
+    pub supply: String,
+    /// `supply` denominated by the LP token's decimals, when requested and known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denominated: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolReservesResponse {
+    pub at: AtResponse,
+    pub pool_id: String,
+    pub reserves: Vec<PoolReserve>,
+    pub lp_token_supply: PoolLpTokenSupply,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ah_timestamp: Option<String>,
+}
+
+/// Where a pool asset is registered, and so where its decimals are found.
+#[derive(Debug, PartialEq)]
+enum PoolAssetKind {
+    Native,
+    Asset(u32),
+    Foreign(Box<Location>),
+}
+
 // ============================================================================
 // Next Available ID Handler
 // ============================================================================
@@ -151,7 +234,7 @@ pub struct LiquidityPoolsResponse {
     path = "/v1/pallets/asset-conversion/next-available-id",
     tag = "pallets",
     summary = "Next available pool ID",
-    description = "Returns the next available pool asset ID from the AssetConversion pallet.",
+    description = "Returns the next available pool asset ID from the AssetConversion pallet, i.e. the LP token ID the next created pool will receive.",
     params(
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
@@ -188,6 +271,29 @@ pub async fn get_next_available_id(
         .into_response())
 }
 
+#[utoipa::path(
+    get,
+    path = "/v1/pallets/assetconversion/nextPoolAssetId",
+    tag = "pallets",
+    summary = "Next pool asset ID",
+    description = "Returns the LP token ID the next created AssetConversion pool will receive, from `AssetConversion::NextPoolAssetId`. Same response as `/v1/pallets/asset-conversion/next-available-id`.",
+    params(
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
+    ),
+    responses(
+        (status = 200, description = "Next pool asset ID", body = Object),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_next_pool_asset_id(
+    state: State<AppState>,
+    params: JsonQuery<AssetConversionQueryParams>,
+) -> Result<Response, PalletError> {
+    get_next_available_id(state, params).await
+}
+
 /// Handles the next-available-id request with RC block resolution.
 async fn handle_next_id_with_rc_block(
     state: AppState,
@@ -346,6 +452,138 @@ async fn handle_pools_with_rc_block(
     Ok((StatusCode::OK, Json(results)).into_response())
 }
 
+// ============================================================================
+// Pool Reserves Handler
+// ============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/v1/pallets/asset-conversion/pools/{poolId}/reserves",
+    tag = "pallets",
+    summary = "Liquidity pool reserves",
+    description = "Returns the current reserves of both assets of an AssetConversion pool, read via the AssetConversionApi runtime API, and the total supply of its LP token. Pools are identified by their LP token ID, as listed by `/pallets/asset-conversion/liquidity-pools`. Since pools are stored by asset pair, each request scans all pools to find the one with this LP token, so its cost grows with the number of pools. `denominated=true` adds each amount shifted by its asset's decimals where the asset has metadata.",
+    params(
+        ("poolId" = String, Path, description = "The pool's LP token ID"),
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("denominated" = Option<bool>, Query, description = "Also return amounts denominated by asset decimals (default: false)")
+    ),
+    responses(
+        (status = 200, description = "Pool reserves", body = Object),
+        (status = 404, description = "Liquidity pool not found"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_pool_reserves(
+    State(state): State<AppState>,
+    Path(pool_id): Path<String>,
+    JsonQuery(params): JsonQuery<PoolReservesQueryParams>,
+) -> Result<Response, PalletError> {
+    let pool_id: u32 = pool_id
+        .parse()
+        .map_err(|_| PalletError::LiquidityPoolNotFound(format!("Invalid pool ID: {}", pool_id)))?;
+
+    if params.use_rc_block {
+        return handle_reserves_with_rc_block(state, pool_id, params).await;
+    }
+
+    let resolved = resolve_block_for_pallet(&state.client, params.at.as_ref()).await?;
+
+    let native_decimals = if params.denominated {
+        Some(chain_native_decimals(&state).await)
+    } else {
+        None
+    };
+    let (reserves, lp_token_supply) =
+        fetch_pool_reserves(&resolved.client_at_block, pool_id, native_decimals)
+            .await?
+            .ok_or_else(|| PalletError::LiquidityPoolNotFound(pool_id.to_string()))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(PoolReservesResponse {
+            at: resolved.at,
+            pool_id: pool_id.to_string(),
+            reserves,
+            lp_token_supply,
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+        }),
+    )
+        .into_response())
+}
+
+/// Handles the pool reserves request with RC block resolution.
+async fn handle_reserves_with_rc_block(
+    state: AppState,
+    pool_id: u32,
+    params: PoolReservesQueryParams,
+) -> Result<Response, PalletError> {
+    if state.chain_info.chain_type != ChainType::AssetHub {
+        return Err(PalletError::UseRcBlockNotSupported);
+    }
+
+    let rc_rpc_client = state.get_relay_chain_rpc_client().await?;
+    let rc_rpc = state.get_relay_chain_rpc().await?;
+
+    let rc_block_id = params
+        .at
+        .as_ref()
+        .ok_or(PalletError::AtParameterRequired)?
+        .parse::<utils::BlockId>()?;
+
+    let rc_resolved_block =
+        utils::resolve_block_with_rpc(&rc_rpc_client, &rc_rpc, Some(rc_block_id)).await?;
+
+    let ah_blocks = find_ah_blocks_in_rc_block(&state, &rc_resolved_block).await?;
+
+    // Return empty array when no AH blocks found (matching Sidecar behavior)
+    if ah_blocks.is_empty() {
+        return Ok((StatusCode::OK, Json(serde_json::json!([]))).into_response());
+    }
+
+    let rc_block_number = rc_resolved_block.number.to_string();
+    let rc_block_hash = rc_resolved_block.hash.clone();
+    let native_decimals = if params.denominated {
+        Some(chain_native_decimals(&state).await)
+    } else {
+        None
+    };
+
+    let mut results = Vec::new();
+    for ah_block in ah_blocks {
+        let client_at_block = state.client.at_block(ah_block.number).await?;
+
+        // Skip blocks where the pool doesn't exist
+        let Some((reserves, lp_token_supply)) =
+            fetch_pool_reserves(&client_at_block, pool_id, native_decimals).await?
+        else {
+            continue;
+        };
+
+        let at = AtResponse {
+            hash: ah_block.hash.clone(),
+            height: ah_block.number.to_string(),
+        };
+
+        let ah_timestamp = fetch_timestamp(&client_at_block).await;
+
+        results.push(PoolReservesResponse {
+            at,
+            pool_id: pool_id.to_string(),
+            reserves,
+            lp_token_supply,
+            rc_block_hash: Some(rc_block_hash.clone()),
+            rc_block_number: Some(rc_block_number.clone()),
+            ah_timestamp,
+        });
+    }
+
+    Ok((StatusCode::OK, Json(results)).into_response())
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -368,6 +606,24 @@ async fn fetch_next_pool_asset_id(
 async fn fetch_liquidity_pools(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
 ) -> Result<Vec<LiquidityPoolInfo>, PalletError> {
+    scan_liquidity_pools(client_at_block)
+        .await
+        .map_err(|e| PalletError::LiquidityPoolsFetchFailed(Box::new(e)))?
+        .ok_or_else(|| PalletError::PalletNotFound("AssetConversion".to_string()))
+}
+
+/// Iterates AssetConversion::Pools storage, or returns `None` if the pallet doesn't exist.
+async fn scan_liquidity_pools(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+) -> Result<Option<Vec<LiquidityPoolInfo>>, subxt::error::StorageError> {
+    if client_at_block
+        .metadata()
+        .pallet_by_name("AssetConversion")
+        .is_none()
+    {
+        return Ok(None);
+    }
+
     let mut pools = Vec::new();
 
     // Iterate all entries in AssetConversion::Pools storage.
@@ -380,54 +636,215 @@ async fn fetch_liquidity_pools(
     let mut iter = client_at_block
         .storage()
         .iter(storage_addr, key_parts)
-        .await
-        .map_err(|e| {
-            tracing::error!("Failed to iterate AssetConversion::Pools storage: {:?}", e);
-            PalletError::PalletNotFound("AssetConversion".to_string())
-        })?;
+        .await?;
 
     while let Some(result) = iter.next().await {
-        match result {
-            Ok(kv) => {
-                // Decode the key dynamically using scale_value::Value<()>.
-                // The key contains the asset pair (reserves) — which could be
-                // (NativeOrWithId, NativeOrWithId) or (Location, Location) depending on the chain.
-                let reserves = match kv.key() {
-                    Ok(storage_key) => match storage_key.decode() {
-                        Ok(key_values) => {
-                            if key_values.len() == 1 {
-                                scale_value_to_json(&key_values[0])
-                            } else if key_values.is_empty() {
-                                serde_json::Value::Null
-                            } else {
-                                serde_json::Value::Array(
-                                    key_values.iter().map(scale_value_to_json).collect(),
-                                )
-                            }
-                        }
-                        Err(_) => serde_json::Value::Null,
-                    },
-                    Err(_) => serde_json::Value::Null,
-                };
-
-                // Decode value as typed PoolInfo (lp_token)
-                let lp_token = match kv.value().decode_as::<PoolInfo>() {
-                    Ok(pool_info) => {
-                        Some(serde_json::json!({"lpToken": pool_info.lp_token.to_string()}))
+        let kv = result?;
+        // Decode the key dynamically using scale_value::Value<()>.
+        // The key contains the asset pair (reserves) — which could be
+        // (NativeOrWithId, NativeOrWithId) or (Location, Location) depending on the chain.
+        let reserves = match kv.key() {
+            Ok(storage_key) => match storage_key.decode() {
+                Ok(key_values) => {
+                    if key_values.len() == 1 {
+                        scale_value_to_json(&key_values[0])
+                    } else if key_values.is_empty() {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::Value::Array(
+                            key_values.iter().map(scale_value_to_json).collect(),
+                        )
                     }
-                    Err(_) => None,
-                };
+                }
+                Err(_) => serde_json::Value::Null,
+            },
+            Err(_) => serde_json::Value::Null,
+        };
 
-                pools.push(LiquidityPoolInfo { reserves, lp_token });
-            }
-            Err(e) => {
-                tracing::warn!("Error iterating pools: {}", e);
-                continue;
-            }
+        // Decode value as typed PoolInfo (lp_token)
+        let lp_token_id = kv
+            .value()
+            .decode_as::<PoolInfo>()
+            .ok()
+            .map(|pool_info| pool_info.lp_token);
+        let lp_token = lp_token_id.map(|id| serde_json::json!({"lpToken": id.to_string()}));
+
+        let pair_bytes = kv
+            .key()
+            .ok()
+            .and_then(|storage_key| storage_key.part(0))
+            .map(|part| part.value_bytes().to_vec())
+            .unwrap_or_default();
+
+        pools.push(LiquidityPoolInfo {
+            reserves,
+            lp_token,
+            lp_token_id,
+            pair_bytes,
+        });
+    }
+
+    Ok(Some(pools))
+}
+
+/// Maps each LP token ID to the asset pair of its pool, as listed by
+/// `/pallets/asset-conversion/liquidity-pools`. Without the AssetConversion pallet
+/// there are no pools, so the map is empty.
+pub(crate) async fn fetch_pool_pairs_by_lp_token(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+) -> Result<HashMap<u32, serde_json::Value>, subxt::error::StorageError> {
+    Ok(scan_liquidity_pools(client_at_block)
+        .await?
+        .map(pools_by_lp_token)
+        .unwrap_or_default())
+}
+
+fn pools_by_lp_token(pools: Vec<LiquidityPoolInfo>) -> HashMap<u32, serde_json::Value> {
+    pools
+        .into_iter()
+        .filter_map(|pool| Some((pool.lp_token_id?, pool.reserves)))
+        .collect()
+}
+
+/// Fetches the reserves and LP token supply of the pool whose LP token is `lp_token`.
+///
+/// Pools are keyed by their asset pair, so finding the pool scans every
+/// `AssetConversion::Pools` entry. Returns `None` if no such pool exists. Amounts are denominated only when
+/// `native_decimals` is given, i.e. when the caller asked for denominated values.
+async fn fetch_pool_reserves(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    lp_token: u32,
+    native_decimals: Option<u8>,
+) -> Result<Option<(Vec<PoolReserve>, PoolLpTokenSupply)>, PalletError> {
+    let Some(pool) = fetch_liquidity_pools(client_at_block)
+        .await?
+        .into_iter()
+        .find(|pool| pool.lp_token_id == Some(lp_token))
+    else {
+        return Ok(None);
+    };
+
+    // The runtime reports no reserves for a pool without liquidity
+    let (balance1, balance2) =
+        asset_conversion_queries::get_pool_reserves(client_at_block, &pool.pair_bytes)
+            .await?
+            .unwrap_or_default();
+
+    let supply = pool_assets_queries::get_pool_asset_supply(client_at_block, lp_token)
+        .await?
+        .unwrap_or_default();
+
+    let (decimals, lp_decimals) = match native_decimals {
+        Some(native_decimals) => {
+            let assets_pallet_index = client_at_block
+                .metadata()
+                .pallet_by_name("Assets")
+                .map(|pallet| pallet.call_index());
+            let decimals = match classify_pool_pair(&pool.pair_bytes, assets_pallet_index) {
+                Some([asset1, asset2]) => {
+                    let (decimals1, decimals2) = tokio::try_join!(
+                        pool_asset_decimals(client_at_block, asset1, native_decimals),
+                        pool_asset_decimals(client_at_block, asset2, native_decimals)
+                    )?;
+                    [decimals1, decimals2]
+                }
+                None => [None, None],
+            };
+            let lp_decimals =
+                pool_assets_queries::try_fetch_pool_asset_metadata(client_at_block, lp_token)
+                    .await?
+                    .map(|metadata| metadata.decimals);
+            (decimals, lp_decimals)
+        }
+        None => ([None, None], None),
+    };
+
+    let reserves = [balance1, balance2]
+        .into_iter()
+        .zip(decimals)
+        .enumerate()
+        .map(|(i, (balance, decimals))| PoolReserve {
+            asset: pool
+                .reserves
+                .get(i)
+                .cloned()
+                .unwrap_or(serde_json::Value::Null),
+            balance: balance.to_string(),
+            denominated: decimals.map(|decimals| format_balance(balance, true, decimals)),
+        })
+        .collect();
+
+    let lp_token_supply = PoolLpTokenSupply {
+        lp_token: lp_token.to_string(),
+        supply: supply.to_string(),
+        denominated: lp_decimals.map(|decimals| format_balance(supply, true, decimals)),
+    };
+
+    Ok(Some((reserves, lp_token_supply)))
+}
+
+/// Classifies both assets of an encoded pool pair, whether the runtime keys
+/// pools by XCM location or by `NativeOrWithId`.
+fn classify_pool_pair(pair: &[u8], assets_pallet_index: Option<u8>) -> Option<[PoolAssetKind; 2]> {
+    if let Ok((asset1, asset2)) = <(Location, Location)>::decode_all(&mut &pair[..]) {
+        return Some([
+            classify_location(asset1, assets_pallet_index),
+            classify_location(asset2, assets_pallet_index),
+        ]);
+    }
+
+    let (asset1, asset2) = <(NativeOrWithId, NativeOrWithId)>::decode_all(&mut &pair[..]).ok()?;
+    Some([asset1, asset2].map(|asset| match asset {
+        NativeOrWithId::Native => PoolAssetKind::Native,
+        NativeOrWithId::WithId(id) => PoolAssetKind::Asset(id),
+    }))
+}
+
+/// Asset Hub's native token is the relay chain's (`{parents: 1, interior: Here}`)
+/// and its local assets live under the Assets pallet instance; any other
+/// location is a foreign asset.
+fn classify_location(location: Location, assets_pallet_index: Option<u8>) -> PoolAssetKind {
+    match (location.parents, &location.interior) {
+        (0 | 1, Junctions::Here) => PoolAssetKind::Native,
+        (
+            0,
+            Junctions::X2(
+                [
+                    Junction::PalletInstance(pallet),
+                    Junction::GeneralIndex(index),
+                ],
+            ),
+        ) if Some(*pallet) == assets_pallet_index && u32::try_from(*index).is_ok() => {
+            PoolAssetKind::Asset(*index as u32)
         }
+        _ => PoolAssetKind::Foreign(Box::new(location)),
     }
+}
+
+/// Looks up a pool asset's decimals, or `None` if it has no metadata.
+async fn pool_asset_decimals(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    asset: PoolAssetKind,
+    native_decimals: u8,
+) -> Result<Option<u8>, PalletError> {
+    let metadata = match asset {
+        PoolAssetKind::Native => return Ok(Some(native_decimals)),
+        PoolAssetKind::Asset(id) => {
+            assets_queries::try_fetch_asset_metadata(client_at_block, id).await?
+        }
+        PoolAssetKind::Foreign(location) => {
+            foreign_assets_queries::try_fetch_foreign_asset_metadata(client_at_block, &location)
+                .await
+        }
+    };
+    Ok(metadata.map(|metadata| metadata.decimals))
+}
 
-    Ok(pools)
+/// The native token's decimals, resolved the same way as for balance responses:
+/// the configured override, then `system_properties`, then the chain's default.
+async fn chain_native_decimals(state: &AppState) -> u8 {
+    let chain_tokens = fetch_chain_tokens(state, None).await;
+    native_token_decimals(&state.chain_info.spec_name, Some(&chain_tokens))
 }
 
 /// Fetches timestamp from Timestamp::Now storage.
@@ -549,6 +966,8 @@ mod tests {
             pools: vec![LiquidityPoolInfo {
                 reserves: serde_json::json!([{"native": null}, {"asset": "1984"}]),
                 lp_token: Some(serde_json::json!({"lpToken": "30"})),
+                lp_token_id: Some(30),
+                pair_bytes: Vec::new(),
             }],
             rc_block_hash: None,
             rc_block_number: None,
@@ -569,12 +988,129 @@ mod tests {
         let pool = LiquidityPoolInfo {
             reserves: serde_json::json!({"test": "value"}),
             lp_token: None,
+            lp_token_id: None,
+            pair_bytes: Vec::new(),
         };
 
         let json = serde_json::to_value(&pool).unwrap();
         assert!(json.get("lpToken").is_none());
     }
 
+    #[test]
+    fn test_pools_by_lp_token() {
+        let pools = vec![
+            LiquidityPoolInfo {
+                reserves: serde_json::json!([{"native": null}, {"asset": "1984"}]),
+                lp_token: Some(serde_json::json!({"lpToken": "30"})),
+                lp_token_id: Some(30),
+                pair_bytes: Vec::new(),
+            },
+            LiquidityPoolInfo {
+                reserves: serde_json::json!([{"native": null}, {"asset": "1337"}]),
+                lp_token: None,
+                lp_token_id: None,
+                pair_bytes: Vec::new(),
+            },
+        ];
+
+        let pairs = pools_by_lp_token(pools);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[&30][1]["asset"], "1984");
+    }
+
+    #[test]
+    fn test_classify_pool_pair_locations() {
+        use parity_scale_codec::Encode;
+
+        let dot = Location {
+            parents: 1,
+            interior: Junctions::Here,
+        };
+        let usdt = Location {
+            parents: 0,
+            interior: Junctions::X2([Junction::PalletInstance(50), Junction::GeneralIndex(1984)]),
+        };
+        let pair = (dot, usdt.clone()).encode();
+
+        assert_eq!(
+            classify_pool_pair(&pair, Some(50)),
+            Some([PoolAssetKind::Native, PoolAssetKind::Asset(1984)])
+        );
+        // Without an Assets pallet at that index, the location is treated as foreign
+        assert_eq!(
+            classify_pool_pair(&pair, None),
+            Some([
+                PoolAssetKind::Native,
+                PoolAssetKind::Foreign(Box::new(usdt))
+            ])
+        );
+    }
+
+    #[test]
+    fn test_classify_pool_pair_native_or_with_id() {
+        // (Native, WithId(1984))
+        let mut pair = vec![0u8, 1u8];
+        pair.extend_from_slice(&1984u32.to_le_bytes());
+
+        assert_eq!(
+            classify_pool_pair(&pair, Some(50)),
+            Some([PoolAssetKind::Native, PoolAssetKind::Asset(1984)])
+        );
+        assert_eq!(classify_pool_pair(&[0xff, 0xff], Some(50)), None);
+    }
+
+    #[test]
+    fn test_pool_reserves_response_serialization() {
+        let response = PoolReservesResponse {
+            at: AtResponse {
+                hash: "0xabc".to_string(),
+                height: "200".to_string(),
+            },
+            pool_id: "30".to_string(),
+            reserves: vec![
+                PoolReserve {
+                    asset: serde_json::json!({"parents": "1", "interior": {"here": null}}),
+                    balance: "12345000000".to_string(),
+                    denominated: Some("1.2345".to_string()),
+                },
+                PoolReserve {
+                    asset: serde_json::json!({"parents": "0", "interior": {"x2": []}}),
+                    balance: "500".to_string(),
+                    denominated: None,
+                },
+            ],
+            lp_token_supply: PoolLpTokenSupply {
+                lp_token: "30".to_string(),
+                supply: "1000".to_string(),
+                denominated: None,
+            },
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["poolId"], "30");
+        assert_eq!(json["reserves"][0]["balance"], "12345000000");
+        assert_eq!(json["reserves"][0]["denominated"], "1.2345");
+        assert!(json["reserves"][1].get("denominated").is_none());
+        assert_eq!(json["lpTokenSupply"]["lpToken"], "30");
+        assert_eq!(json["lpTokenSupply"]["supply"], "1000");
+        assert!(json.get("rcBlockHash").is_none());
+    }
+
+    #[test]
+    fn test_pool_reserves_query_params_deserialization() {
+        let params: PoolReservesQueryParams =
+            serde_json::from_str(r#"{"at": "100", "denominated": true}"#).unwrap();
+        assert_eq!(params.at, Some("100".to_string()));
+        assert!(params.denominated);
+        assert!(!params.use_rc_block);
+
+        let params: PoolReservesQueryParams = serde_json::from_str(r#"{}"#).unwrap();
+        assert!(!params.denominated);
+    }
+
     #[test]
     fn test_query_params_deserialization() {
         // Test with all fields
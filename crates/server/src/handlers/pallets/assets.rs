@@ -6,6 +6,7 @@
 use crate::extractors::JsonQuery;
 use crate::handlers::pallets::common::{AtResponse, PalletError, resolve_block_for_pallet};
 use crate::handlers::runtime_queries::assets as assets_queries;
+use crate::handlers::runtime_queries::assets_common::format_account_id;
 use crate::state::AppState;
 use crate::utils::{
     BlockId, DEFAULT_CONCURRENCY, fetch_block_timestamp, rc_block::find_ah_blocks_in_rc_block,
@@ -19,6 +20,7 @@ use axum::{
 };
 use polkadot_rest_api_config::ChainType;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 // ============================================================================
 // Request/Response Types
@@ -30,6 +32,23 @@ pub struct AssetsQueryParams {
     pub at: Option<String>,
     #[serde(default)]
     pub use_rc_block: bool,
+    /// Number of largest holders to include (at most 100)
+    pub include_holders: Option<u32>,
+}
+
+/// Largest accepted `includeHolders`
+const MAX_HOLDERS_COUNT: u32 = 100;
+
+/// Longest time spent scanning `Assets::Account` for an asset's largest holders.
+/// The scan stops early and reports `holdersComplete: false`.
+const MAX_HOLDERS_SCAN_TIME: Duration = Duration::from_secs(20);
+
+/// An account holding the asset
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetHolder {
+    pub address: String,
+    pub balance: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -67,6 +86,12 @@ pub struct PalletsAssetsInfoResponse {
     pub asset_info: Option<AssetInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub asset_meta_data: Option<AssetMetadata>,
+    /// Largest holders, largest balance first (only present when includeHolders is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holders: Option<Vec<AssetHolder>>,
+    /// False when `holders` was ranked from a partial scan of the asset's accounts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holders_complete: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rc_block_hash: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -84,14 +109,16 @@ pub struct PalletsAssetsInfoResponse {
     path = "/v1/pallets/assets/{assetId}/asset-info",
     tag = "pallets",
     summary = "Asset info",
-    description = "Returns details for a specific asset including supply, admin, and metadata.",
+    description = "Returns details for a specific asset including supply, admin, and metadata. `includeHolders=N` adds the asset's N largest holders, ranked from its `Assets::Account` entries; `holdersComplete` is false when the scan ran out of time.",
     params(
         ("assetId" = String, Path, description = "Asset ID"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("includeHolders" = Option<u32>, Query, description = "Include the N largest holders (1-100)")
     ),
     responses(
         (status = 200, description = "Asset information", body = Object),
+        (status = 400, description = "Invalid parameters"),
         (status = 404, description = "Asset not found"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -106,6 +133,14 @@ pub async fn pallets_assets_asset_info(
         .parse()
         .map_err(|_| PalletError::AssetNotFound(format!("Invalid asset ID: {}", asset_id)))?;
 
+    if let Some(count) = params.include_holders
+        && (count == 0 || count > MAX_HOLDERS_COUNT)
+    {
+        return Err(PalletError::InvalidHoldersCount {
+            max: MAX_HOLDERS_COUNT,
+        });
+    }
+
     if params.use_rc_block {
         return handle_use_rc_block(state, asset_id, params).await;
     }
@@ -121,12 +156,22 @@ pub async fn pallets_assets_asset_info(
         return Err(PalletError::AssetNotFound(asset_id.to_string()));
     }
 
+    let holders = fetch_asset_holders(
+        &resolved.client_at_block,
+        asset_id,
+        params.include_holders,
+        ss58_prefix,
+    )
+    .await?;
+
     Ok((
         StatusCode::OK,
         Json(PalletsAssetsInfoResponse {
             at: resolved.at,
             asset_info,
             asset_meta_data,
+            holders: holders.as_ref().map(|(holders, _)| holders.clone()),
+            holders_complete: holders.map(|(_, complete)| complete),
             rc_block_hash: None,
             rc_block_number: None,
             ah_timestamp: None,
@@ -173,6 +218,7 @@ async fn handle_use_rc_block(
     }
 
     let ss58_prefix = state.chain_info.ss58_prefix;
+    let include_holders = params.include_holders;
     let rc_hash = rc_resolved_block.hash.clone();
     let rc_number = rc_resolved_block.number.to_string();
 
@@ -204,10 +250,16 @@ async fn handle_use_rc_block(
                 });
             }
 
+            let holders =
+                fetch_asset_holders(&client_at_block, asset_id, include_holders, ss58_prefix)
+                    .await?;
+
             Ok(PalletsAssetsInfoResponse {
                 at,
                 asset_info,
                 asset_meta_data,
+                holders: holders.as_ref().map(|(holders, _)| holders.clone()),
+                holders_complete: holders.map(|(_, complete)| complete),
                 rc_block_hash: Some(rc_hash),
                 rc_block_number: Some(rc_number),
                 ah_timestamp,
@@ -268,6 +320,43 @@ async fn fetch_asset_metadata(
     })
 }
 
+/// Fetches the `count` largest holders of an asset, if requested, along with whether the
+/// scan visited every holder.
+async fn fetch_asset_holders(
+    client_at_block: &subxt::OnlineClientAtBlock<subxt::SubstrateConfig>,
+    asset_id: u32,
+    count: Option<u32>,
+    ss58_prefix: u16,
+) -> Result<Option<(Vec<AssetHolder>, bool)>, PalletError> {
+    let Some(count) = count else {
+        return Ok(None);
+    };
+
+    let ranked = assets_queries::get_top_asset_holders(
+        client_at_block,
+        asset_id,
+        count as usize,
+        MAX_HOLDERS_SCAN_TIME,
+    )
+    .await
+    .map_err(|e| PalletError::StorageEntryFetchFailed {
+        pallet: "Assets",
+        entry: "Account",
+        error: e.to_string(),
+    })?;
+
+    let holders = ranked
+        .holders
+        .iter()
+        .map(|(account, balance)| AssetHolder {
+            address: format_account_id(account, ss58_prefix),
+            balance: balance.to_string(),
+        })
+        .collect();
+
+    Ok(Some((holders, ranked.complete)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,4 +368,24 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
+
+    #[test]
+    fn test_holders_omitted_unless_requested() {
+        let response = PalletsAssetsInfoResponse::default();
+        let json = serde_json::to_value(&response).unwrap();
+        assert!(json.get("holders").is_none());
+        assert!(json.get("holdersComplete").is_none());
+
+        let response = PalletsAssetsInfoResponse {
+            holders: Some(vec![AssetHolder {
+                address: "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5".to_string(),
+                balance: "1000".to_string(),
+            }]),
+            holders_complete: Some(true),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["holders"][0]["balance"], "1000");
+        assert_eq!(json["holdersComplete"], true);
+    }
 }
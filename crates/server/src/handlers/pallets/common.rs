@@ -97,9 +97,55 @@ pub enum PalletError {
     #[error("Nomination pool not found: {0}")]
     PoolNotFound(String),
 
+    #[error("Failed to fetch nomination pool {pool_id}")]
+    PoolFetchFailed {
+        pool_id: u32,
+        #[source]
+        source: Box<subxt::error::StorageError>,
+    },
+
     #[error("Pool asset not found: {0}")]
     PoolAssetNotFound(String),
 
+    #[error("Liquidity pool not found: {0}")]
+    LiquidityPoolNotFound(String),
+
+    #[error("Failed to fetch AssetConversion::Pools storage")]
+    LiquidityPoolsFetchFailed(#[source] Box<subxt::error::StorageError>),
+
+    #[error("Referendum not found: {0}")]
+    ReferendumNotFound(String),
+
+    #[error(transparent)]
+    ReferendumFetchFailed(
+        #[from] crate::handlers::runtime_queries::referenda::ReferendaStorageError,
+    ),
+
+    #[error(transparent)]
+    PreimageFetchFailed(#[from] crate::handlers::runtime_queries::preimage::PreimageStorageError),
+
+    #[error(transparent)]
+    AssetFetchFailed(#[from] crate::handlers::runtime_queries::assets::AssetsStorageError),
+
+    #[error(transparent)]
+    PoolAssetFetchFailed(
+        #[from] crate::handlers::runtime_queries::pool_assets::PoolAssetsStorageError,
+    ),
+
+    #[error(transparent)]
+    AssetConversionApiFailed(
+        #[from] crate::handlers::runtime_queries::asset_conversion::AssetConversionApiError,
+    ),
+
+    #[error("includeHolders must be between 1 and {max}")]
+    InvalidHoldersCount { max: u32 },
+
+    #[error("count must be between 1 and {max}")]
+    InvalidMembersCount { max: u32 },
+
+    #[error("startKey '{0}' is not a hex-encoded storage key")]
+    InvalidStartKey(String),
+
     #[error(
         "Could not find event item (\"{0}\") in metadata. Event item names are expected to be in PascalCase, e.g. 'Transfer'"
     )]
@@ -141,6 +187,12 @@ pub enum PalletError {
     )]
     StorageItemNotFound { pallet: String, item: String },
 
+    #[error("Invalid storage keys: {0}")]
+    InvalidStorageKeys(String),
+
+    #[error("Failed to fetch storage keys")]
+    StorageKeysFetchFailed(#[source] subxt_rpcs::Error),
+
     #[error("Unsupported metadata version")]
     UnsupportedMetadataVersion,
 
@@ -248,6 +300,8 @@ impl IntoResponse for PalletError {
             PalletError::StorageDecodeFailed { .. } => {
                 (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
             }
+            PalletError::InvalidStorageKeys(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            PalletError::StorageKeysFetchFailed(err) => crate::utils::rpc_error_to_status(err),
             PalletError::PalletNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             PalletError::PalletNotAvailable(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             PalletError::PalletNotAvailableAtBlock { .. } => {
@@ -256,7 +310,41 @@ impl IntoResponse for PalletError {
             PalletError::AssetNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             PalletError::AssetNotFoundAtBlock { .. } => (StatusCode::NOT_FOUND, self.to_string()),
             PalletError::PoolNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            PalletError::PoolFetchFailed { source, .. } => {
+                if crate::utils::is_storage_disconnected_error(source) {
+                    (StatusCode::SERVICE_UNAVAILABLE, self.to_string())
+                } else {
+                    (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+                }
+            }
             PalletError::PoolAssetNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            PalletError::LiquidityPoolNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            PalletError::LiquidityPoolsFetchFailed(source) => {
+                if crate::utils::is_storage_disconnected_error(source) {
+                    (StatusCode::SERVICE_UNAVAILABLE, self.to_string())
+                } else {
+                    (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+                }
+            }
+            PalletError::ReferendumNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            PalletError::ReferendumFetchFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            }
+            PalletError::PreimageFetchFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            }
+            PalletError::AssetFetchFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            }
+            PalletError::PoolAssetFetchFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            }
+            PalletError::AssetConversionApiFailed(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            }
+            PalletError::InvalidHoldersCount { .. } => (StatusCode::BAD_REQUEST, self.to_string()),
+            PalletError::InvalidMembersCount { .. } => (StatusCode::BAD_REQUEST, self.to_string()),
+            PalletError::InvalidStartKey(_) => (StatusCode::BAD_REQUEST, self.to_string()),
             PalletError::EventNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             PalletError::NoEventsInPallet(_) => (StatusCode::BAD_REQUEST, self.to_string()),
 
@@ -644,4 +732,85 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
+
+    #[test]
+    fn test_referendum_errors_status() {
+        use crate::handlers::runtime_queries::referenda::ReferendaStorageError;
+
+        let not_found = PalletError::ReferendumNotFound("7".to_string()).into_response();
+        assert_eq!(not_found.status(), StatusCode::NOT_FOUND);
+
+        let fetch_failed = PalletError::from(ReferendaStorageError::FetchFailed {
+            id: 7,
+            details: "connection closed".to_string(),
+        })
+        .into_response();
+        assert_eq!(fetch_failed.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        let decode_failed = PalletError::from(ReferendaStorageError::DecodeFailed {
+            id: 7,
+            details: "unexpected variant".to_string(),
+        })
+        .into_response();
+        assert_eq!(decode_failed.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_preimage_fetch_failed_status() {
+        use crate::handlers::runtime_queries::preimage::PreimageStorageError;
+
+        let response = PalletError::from(PreimageStorageError::FetchFailed {
+            entry: "PreimageFor",
+            details: "connection reset".to_string(),
+        })
+        .into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_pool_members_error_statuses() {
+        let count = PalletError::InvalidMembersCount { max: 1000 }.into_response();
+        assert_eq!(count.status(), StatusCode::BAD_REQUEST);
+
+        let start_key = PalletError::InvalidStartKey("0xzz".to_string()).into_response();
+        assert_eq!(start_key.status(), StatusCode::BAD_REQUEST);
+
+        let fetch_failed = PalletError::PoolFetchFailed {
+            pool_id: 1,
+            source: Box::new(subxt::error::StorageError::NoValueFound),
+        }
+        .into_response();
+        assert_eq!(fetch_failed.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_liquidity_pools_fetch_failed_status() {
+        let response = PalletError::LiquidityPoolsFetchFailed(Box::new(
+            subxt::error::StorageError::NoValueFound,
+        ))
+        .into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_asset_fetch_failed_status() {
+        use crate::handlers::runtime_queries::assets::AssetsStorageError;
+
+        let response = PalletError::from(AssetsStorageError::DecodeFailed(
+            "unexpected end of input".to_string(),
+        ))
+        .into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_pool_asset_fetch_failed_status() {
+        use crate::handlers::runtime_queries::pool_assets::PoolAssetsStorageError;
+
+        let response = PalletError::from(PoolAssetsStorageError::StorageFetchFailed(
+            "connection closed".to_string(),
+        ))
+        .into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
 }
@@ -9,6 +9,7 @@
 #![allow(clippy::result_large_err)]
 
 use crate::extractors::JsonQuery;
+use crate::handlers::blocks::decode::JsonVisitor;
 use crate::handlers::pallets::common::{
     AtResponse, PalletError, RcPalletItemQueryParams, RcPalletQueryParams,
 };
@@ -23,8 +24,10 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use polkadot_rest_api_config::ChainType;
+use scale_decode::visitor::decode_with_visitor;
 use serde::{Deserialize, Serialize};
 use subxt::Metadata;
+
 // ============================================================================
 // Request/Response Types
 // ============================================================================
@@ -113,6 +116,10 @@ pub struct PalletConstantItemResponse {
     pub pallet_index: String,
     #[serde(rename = "constantsItem")]
     pub constants_item: String,
+    /// Constant value decoded using the type registry
+    pub value: serde_json::Value,
+    /// SCALE-encoded constant value as hex
+    pub value_raw: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ConstantItemMetadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -267,6 +274,8 @@ pub async fn pallets_constant_item(
         height: resolved.number.to_string(),
     };
 
+    let value = decode_constant_value(constant, &metadata, state.chain_info.ss58_prefix);
+
     let metadata_field = if params.metadata {
         Some(constant.clone())
     } else {
@@ -280,6 +289,8 @@ pub async fn pallets_constant_item(
             pallet: pallet_id.to_lowercase(),
             pallet_index: pallet_info.index.to_string(),
             constants_item: to_camel_case(&constant_item_id),
+            value,
+            value_raw: constant.value.clone(),
             metadata: metadata_field,
             rc_block_hash: None,
             rc_block_number: None,
@@ -425,6 +436,8 @@ async fn handle_constant_item_use_rc_block(
 
         let ah_timestamp = utils::fetch_block_timestamp(&client_at_block).await;
 
+        let value = decode_constant_value(constant, &metadata, state.chain_info.ss58_prefix);
+
         let metadata_field = if params.metadata {
             Some(constant.clone())
         } else {
@@ -436,6 +449,8 @@ async fn handle_constant_item_use_rc_block(
             pallet: pallet_id.to_lowercase(),
             pallet_index: pallet_info.index.to_string(),
             constants_item: to_camel_case(&constant_item_id),
+            value,
+            value_raw: constant.value.clone(),
             metadata: metadata_field,
             rc_block_hash: Some(rc_resolved_block.hash.clone()),
             rc_block_number: Some(rc_resolved_block.number.to_string()),
@@ -489,6 +504,38 @@ fn extract_pallet_constants(
     })
 }
 
+/// Decode a constant's SCALE-encoded value against its type in the registry,
+/// falling back to the raw hex if decoding fails.
+fn decode_constant_value(
+    constant: &ConstantItemMetadata,
+    metadata: &Metadata,
+    ss58_prefix: u16,
+) -> serde_json::Value {
+    let raw = serde_json::Value::String(constant.value.clone());
+    let (Ok(type_id), Ok(bytes)) = (
+        constant.ty.parse::<u32>(),
+        hex::decode(constant.value.trim_start_matches("0x")),
+    ) else {
+        return raw;
+    };
+
+    let registry = metadata.types();
+    let visitor = JsonVisitor::new(ss58_prefix, registry);
+
+    match decode_with_visitor(&mut &bytes[..], type_id, registry, visitor) {
+        Ok(value) => value,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to decode constant {} (type_id={}): {}. Falling back to raw hex.",
+                constant.name,
+                type_id,
+                e
+            );
+            raw
+        }
+    }
+}
+
 // ============================================================================
 // RC (Relay Chain) Handlers
 // ============================================================================
@@ -624,6 +671,13 @@ pub async fn rc_pallets_constant_item(
         height: resolved.number.to_string(),
     };
 
+    let rc_ss58_prefix = if state.chain_info.chain_type == ChainType::Relay {
+        state.chain_info.ss58_prefix
+    } else {
+        state.get_relay_chain_info().await?.ss58_prefix
+    };
+    let value = decode_constant_value(constant, &metadata, rc_ss58_prefix);
+
     let metadata_field = if params.metadata {
         Some(constant.clone())
     } else {
@@ -637,6 +691,8 @@ pub async fn rc_pallets_constant_item(
             pallet: pallet_id.to_lowercase(),
             pallet_index: pallet_info.index.to_string(),
             constants_item: to_camel_case(&constant_item_id),
+            value,
+            value_raw: constant.value.clone(),
             metadata: metadata_field,
             rc_block_hash: None,
             rc_block_number: None,
@@ -734,6 +790,8 @@ mod tests {
             pallet: "balances".to_string(),
             pallet_index: "5".to_string(),
             constants_item: "existentialDeposit".to_string(),
+            value: serde_json::json!("100000000"),
+            value_raw: "0x00e1f505000000000000000000000000".to_string(),
             metadata: None,
             rc_block_hash: Some("0xrc123".to_string()),
             rc_block_number: Some("1000".to_string()),
@@ -744,6 +802,35 @@ mod tests {
         assert!(json.contains("\"constantsItem\":\"existentialDeposit\""));
         assert!(json.contains("\"rcBlockHash\":\"0xrc123\""));
         assert!(json.contains("\"rcBlockNumber\":\"1000\""));
+        assert!(json.contains("\"value\":\"100000000\""));
+        assert!(json.contains("\"valueRaw\":\"0x00e1f505000000000000000000000000\""));
+    }
+
+    #[test]
+    fn test_decode_constant_value() {
+        use crate::test_fixtures::ASSET_HUB_METADATA;
+        use parity_scale_codec::Decode;
+
+        let metadata = Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let constants = extract_pallet_constants(&metadata, "System")
+            .unwrap()
+            .constants;
+        let ss58_prefix = constants.iter().find(|c| c.name == "SS58Prefix").unwrap();
+
+        assert_eq!(
+            decode_constant_value(ss58_prefix, &metadata, 0),
+            serde_json::json!("0")
+        );
+
+        // Bytes that don't fit the type fall back to the raw hex
+        let truncated = ConstantItemMetadata {
+            value: "0x00".to_string(),
+            ..ss58_prefix.clone()
+        };
+        assert_eq!(
+            decode_constant_value(&truncated, &metadata, 0),
+            serde_json::json!("0x00")
+        );
     }
 
     #[test]
@@ -155,6 +155,12 @@ pub struct PalletErrorItemResponse {
     /// The error name (camelCase).
     pub error_item: String,
 
+    /// The index of the error in the pallet's error enum.
+    pub index: String,
+
+    /// Documentation for the error.
+    pub docs: Vec<String>,
+
     /// Full metadata for the error (only when `metadata=true`).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<ErrorItemMetadata>,
@@ -501,7 +507,8 @@ fn extract_error_item_from_metadata(
         .find(|v| v.name.to_lowercase() == error_id_lower)
         .ok_or_else(|| PalletError::ErrorItemNotFound(error_id.to_string()))?;
 
-    let error_name = error_variant.name.clone();
+    let error_info = utils::lookup_module_error(metadata, pallet_index, error_variant.index)
+        .ok_or_else(|| PalletError::ErrorItemNotFound(error_id.to_string()))?;
 
     let error_metadata = if include_metadata {
         Some(variant_to_error_metadata(error_variant, metadata.types()))
@@ -513,7 +520,9 @@ fn extract_error_item_from_metadata(
         at,
         pallet: pallet_name.to_lowercase(),
         pallet_index: pallet_index.to_string(),
-        error_item: error_name.to_lower_camel_case(),
+        error_item: error_info.name.to_lower_camel_case(),
+        index: error_info.index.to_string(),
+        docs: error_info.docs,
         metadata: error_metadata,
         rc_block_hash: rc_fields.rc_block_hash,
         rc_block_number: rc_fields.rc_block_number,
@@ -700,6 +709,103 @@ pub async fn rc_pallet_error_item(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::Decode;
+
+    fn asset_hub_metadata() -> Metadata {
+        Metadata::decode(&mut &ASSET_HUB_METADATA[..]).expect("fixture metadata decodes")
+    }
+
+    fn errors_json(metadata: &Metadata, pallet_id: &str, only_ids: bool) -> serde_json::Value {
+        let response = extract_errors_from_metadata(
+            metadata,
+            pallet_id,
+            AtResponse::default(),
+            only_ids,
+            RcBlockFields::default(),
+        )
+        .unwrap();
+        serde_json::to_value(&response).unwrap()
+    }
+
+    #[test]
+    fn test_extract_errors_only_ids_returns_names() {
+        let metadata = asset_hub_metadata();
+        let json = errors_json(&metadata, "Balances", true);
+
+        let items = json["items"].as_array().unwrap();
+        assert!(!items.is_empty());
+        assert!(items.iter().all(|item| item.is_string()));
+        assert!(items.contains(&serde_json::json!("InsufficientBalance")));
+    }
+
+    #[test]
+    fn test_extract_errors_full_returns_metadata() {
+        let metadata = asset_hub_metadata();
+        let json = errors_json(&metadata, "Balances", false);
+
+        let first = &json["items"][0];
+        assert!(first["name"].is_string());
+        assert!(first["index"].is_string());
+        assert!(first["docs"].is_array());
+    }
+
+    #[test]
+    fn test_extract_errors_resolves_pallet_by_name_or_index() {
+        let metadata = asset_hub_metadata();
+        let index = metadata.pallet_by_name("Balances").unwrap().error_index();
+
+        let by_name = errors_json(&metadata, "Balances", true);
+        let by_lowercase_name = errors_json(&metadata, "balances", true);
+        let by_index = errors_json(&metadata, &index.to_string(), true);
+
+        assert_eq!(by_name["pallet"], "balances");
+        assert_eq!(by_name["palletIndex"], index.to_string());
+        assert_eq!(by_lowercase_name, by_name);
+        assert_eq!(by_index, by_name);
+    }
+
+    #[test]
+    fn test_extract_error_item_includes_index_and_docs() {
+        let metadata = asset_hub_metadata();
+        let variant = metadata
+            .pallet_by_name("Balances")
+            .unwrap()
+            .error_variants()
+            .unwrap()
+            .iter()
+            .find(|v| v.name == "InsufficientBalance")
+            .unwrap();
+
+        let response = extract_error_item_from_metadata(
+            &metadata,
+            "balances",
+            "insufficientBalance",
+            AtResponse::default(),
+            false,
+            RcBlockFields::default(),
+        )
+        .unwrap();
+
+        assert_eq!(response.error_item, "insufficientBalance");
+        assert_eq!(response.index, variant.index.to_string());
+        assert_eq!(response.docs, variant.docs);
+        assert!(response.metadata.is_none());
+    }
+
+    #[test]
+    fn test_extract_errors_unknown_pallet_is_not_found() {
+        let metadata = asset_hub_metadata();
+
+        let result = extract_errors_from_metadata(
+            &metadata,
+            "NoSuchPallet",
+            AtResponse::default(),
+            true,
+            RcBlockFields::default(),
+        );
+        assert!(matches!(result, Err(PalletError::PalletNotFound(_))));
+    }
 
     #[test]
     fn test_simplify_type_name() {
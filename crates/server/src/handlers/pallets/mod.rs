@@ -18,11 +18,14 @@ pub mod foreign_assets;
 pub mod nomination_pools;
 pub mod on_going_referenda;
 pub mod pool_assets;
+pub mod referenda;
 pub mod staking_progress;
 pub mod staking_validators;
 pub mod storage;
 
-pub use asset_conversion::{get_liquidity_pools, get_next_available_id};
+pub use asset_conversion::{
+    get_liquidity_pools, get_next_available_id, get_next_pool_asset_id, get_pool_reserves,
+};
 pub use assets::pallets_assets_asset_info;
 pub use consts::{pallets_constant_item, pallets_constants};
 pub use consts::{rc_pallets_constant_item, rc_pallets_constants};
@@ -33,13 +36,16 @@ pub use errors::{rc_pallet_error_item, rc_pallet_errors};
 pub use events::{get_pallet_event_item, get_pallet_events};
 pub use events::{rc_pallet_event_item, rc_pallet_events};
 pub use foreign_assets::pallets_foreign_assets;
-pub use nomination_pools::{pallets_nomination_pools_info, pallets_nomination_pools_pool};
+pub use nomination_pools::{
+    pallets_nomination_pools_info, pallets_nomination_pools_members, pallets_nomination_pools_pool,
+};
 pub use on_going_referenda::pallets_on_going_referenda;
 pub use on_going_referenda::rc_pallets_on_going_referenda;
 pub use pool_assets::pallets_pool_assets_asset_info;
+pub use referenda::pallets_referendum;
 pub use staking_progress::pallets_staking_progress;
 pub use staking_progress::rc_pallets_staking_progress;
 pub use staking_validators::pallets_staking_validators;
 pub use staking_validators::rc_pallets_staking_validators;
-pub use storage::{get_pallets_storage, get_pallets_storage_item};
+pub use storage::{get_pallets_storage, get_pallets_storage_item, get_pallets_storage_item_keys};
 pub use storage::{rc_get_pallets_storage, rc_get_pallets_storage_item};
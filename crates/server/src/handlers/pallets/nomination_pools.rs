@@ -20,11 +20,14 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use parity_scale_codec::Decode;
 use polkadot_rest_api_config::ChainType;
 use scale_decode::DecodeAsType;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value as JsonValue, json};
+use std::time::{Duration, Instant};
 use subxt::{SubstrateConfig, client::OnlineClientAtBlock};
+use subxt_rpcs::RpcClient;
 
 // ============================================================================
 // Request/Response Types
@@ -63,6 +66,48 @@ pub struct NominationPoolsInfoResponse {
     pub ah_timestamp: Option<String>,
 }
 
+/// Members returned per page when `count` isn't given
+const DEFAULT_MEMBERS_COUNT: u32 = 100;
+
+/// Largest accepted `count` for pool members
+const MAX_MEMBERS_COUNT: u32 = 1000;
+
+/// Longest time spent scanning `PoolMembers` for one page of a pool's members.
+/// The scan stops early with a `nextStartKey` to continue from.
+const MAX_MEMBERS_SCAN_TIME: Duration = Duration::from_secs(20);
+
+/// Length of the `PoolMembers` prefix plus the `Twox64Concat` hash preceding the account
+const POOL_MEMBERS_KEY_ACCOUNT_OFFSET: usize = 32 + 8;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PoolMembersQueryParams {
+    pub at: Option<String>,
+    /// Number of members to return (default 100, at most 1000)
+    pub count: Option<u32>,
+    /// Continue after this `PoolMembers` storage key, as given by `nextStartKey`
+    pub start_key: Option<String>,
+}
+
+/// A member of a nomination pool
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolMember {
+    pub account_id: String,
+    pub points: String,
+}
+
+/// Response for `/pallets/nomination-pools/{poolId}/members`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolMembersResponse {
+    pub at: AtResponse,
+    pub pool_id: String,
+    pub members: Vec<PoolMember>,
+    /// `startKey` for the next page, `null` once all members were returned
+    pub next_start_key: Option<String>,
+}
+
 /// Response for `/pallets/nomination-pools/{poolId}`
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -190,6 +235,90 @@ pub async fn pallets_nomination_pools_pool(
         .into_response())
 }
 
+#[utoipa::path(
+    get,
+    path = "/v1/pallets/nomination-pools/{poolId}/members",
+    tag = "pallets",
+    summary = "Nomination pool members",
+    description = "Lists the members of a nomination pool with their points. `PoolMembers` is keyed by account, so members are found by paging through all pool members via `state_getKeysPaged`; each call scans for at most 20 seconds. Pass the returned `nextStartKey` as `startKey` to get the next page; a page may hold fewer than `count` members while `nextStartKey` is set.",
+    params(
+        ("poolId" = String, Path, description = "Pool ID"),
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("count" = Option<u32>, Query, description = "Number of members to return (default 100, max 1000)"),
+        ("startKey" = Option<String>, Query, description = "Storage key to continue after, as returned in `nextStartKey`")
+    ),
+    responses(
+        (status = 200, description = "Page of pool members", body = Object),
+        (status = 400, description = "Invalid parameters or not supported on this chain"),
+        (status = 404, description = "Invalid pool ID or pool not found"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pallets_nomination_pools_members(
+    State(state): State<AppState>,
+    Path(pool_id): Path<String>,
+    JsonQuery(params): JsonQuery<PoolMembersQueryParams>,
+) -> Result<Response, PalletError> {
+    let pool_id: u32 = pool_id
+        .parse()
+        .map_err(|_| PalletError::PoolNotFound(format!("Invalid pool ID: {}", pool_id)))?;
+
+    let count = params.count.unwrap_or(DEFAULT_MEMBERS_COUNT);
+    if count == 0 || count > MAX_MEMBERS_COUNT {
+        return Err(PalletError::InvalidMembersCount {
+            max: MAX_MEMBERS_COUNT,
+        });
+    }
+    if let Some(start_key) = &params.start_key
+        && hex::decode(start_key.strip_prefix("0x").unwrap_or(start_key)).is_err()
+    {
+        return Err(PalletError::InvalidStartKey(start_key.clone()));
+    }
+
+    // Check if chain supports nomination pools (not Asset Hub)
+    if state.chain_info.chain_type == ChainType::AssetHub {
+        return Err(PalletError::UnsupportedChainForStaking(
+            "Nomination pools are not available on Asset Hub".to_string(),
+        ));
+    }
+
+    let resolved = resolve_block_for_pallet(&state.client, params.at.as_ref()).await?;
+
+    let pool_exists =
+        nomination_pools_queries::bonded_pool_exists(&resolved.client_at_block, pool_id)
+            .await
+            .map_err(|e| PalletError::PoolFetchFailed {
+                pool_id,
+                source: Box::new(e),
+            })?;
+    if !pool_exists {
+        return Err(PalletError::PoolNotFound(pool_id.to_string()));
+    }
+
+    let (members, next_start_key) = scan_pool_members(
+        &state.rpc_client,
+        &resolved.at.hash,
+        pool_id,
+        count as usize,
+        params.start_key,
+        state.chain_info.ss58_prefix,
+    )
+    .await
+    .map_err(PalletError::StorageKeysFetchFailed)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(PoolMembersResponse {
+            at: resolved.at,
+            pool_id: pool_id.to_string(),
+            members,
+            next_start_key,
+        }),
+    )
+        .into_response())
+}
+
 async fn handle_info_use_rc_block(
     state: AppState,
     params: NominationPoolsQueryParams,
@@ -395,6 +524,78 @@ async fn build_nomination_pools_info(
     }
 }
 
+// ============================================================================
+// Helper Functions - Pool Members Scan
+// ============================================================================
+
+/// Page through `NominationPools::PoolMembers` from `start_key`, collecting up to
+/// `count` members of `pool_id`.
+///
+/// Returns the members found and the key to resume from: the last returned member's
+/// key once `count` is reached, or the last scanned key when the scan runs out of time.
+/// `None` means the end of the map was reached.
+async fn scan_pool_members(
+    rpc_client: &RpcClient,
+    block_hash: &str,
+    pool_id: u32,
+    count: usize,
+    mut start_key: Option<String>,
+    ss58_prefix: u16,
+) -> Result<(Vec<PoolMember>, Option<String>), subxt_rpcs::Error> {
+    let prefix = utils::storage_map_prefix("NominationPools", "PoolMembers");
+
+    let deadline = Instant::now() + MAX_MEMBERS_SCAN_TIME;
+    let mut members = Vec::new();
+
+    loop {
+        if Instant::now() >= deadline {
+            tracing::warn!("Pool {pool_id} members scan stopped after {MAX_MEMBERS_SCAN_TIME:?}");
+            return Ok((members, start_key));
+        }
+
+        let page = utils::fetch_storage_page(rpc_client, &prefix, start_key.as_deref(), block_hash)
+            .await?;
+
+        for (key, value) in &page.entries {
+            if let Some((member_pool_id, member)) = decode_pool_member(key, value, ss58_prefix)
+                && member_pool_id == pool_id
+            {
+                members.push(member);
+                if members.len() == count {
+                    return Ok((members, Some(key.clone())));
+                }
+            }
+        }
+
+        match page.next_start_key {
+            Some(key) => start_key = Some(key),
+            None => return Ok((members, None)),
+        }
+    }
+}
+
+/// Decode a `PoolMembers` entry into its pool id and the member.
+///
+/// Only the leading `pool_id: u32` and `points: Balance` fields of `PoolMember` are read.
+fn decode_pool_member(key: &str, value: &str, ss58_prefix: u16) -> Option<(u32, PoolMember)> {
+    let key = hex::decode(key.trim_start_matches("0x")).ok()?;
+    let value = hex::decode(value.trim_start_matches("0x")).ok()?;
+
+    let account: [u8; 32] = key
+        .get(POOL_MEMBERS_KEY_ACCOUNT_OFFSET..)?
+        .try_into()
+        .ok()?;
+    let (pool_id, points) = <(u32, u128)>::decode(&mut &value[..]).ok()?;
+
+    Some((
+        pool_id,
+        PoolMember {
+            account_id: format_account_id(&account, ss58_prefix),
+            points: points.to_string(),
+        },
+    ))
+}
+
 // ============================================================================
 // Helper Functions - Storage Value Fetchers
 // ============================================================================
@@ -531,6 +732,82 @@ mod tests {
     use super::*;
     use nomination_pools_queries::PoolState;
 
+    fn pool_member_entry(account: [u8; 32], pool_id: u32, points: u128) -> (String, String) {
+        use parity_scale_codec::Encode;
+
+        let mut key = sp_crypto_hashing::twox_128(b"NominationPools").to_vec();
+        key.extend(sp_crypto_hashing::twox_128(b"PoolMembers"));
+        key.extend(sp_crypto_hashing::twox_64(&account));
+        key.extend(account);
+
+        // PoolMember { pool_id, points, last_recorded_reward_counter, unbonding_eras }
+        let mut value = (pool_id, points, 0u128).encode();
+        value.push(0); // empty unbonding_eras
+
+        (
+            format!("0x{}", hex::encode(key)),
+            format!("0x{}", hex::encode(value)),
+        )
+    }
+
+    #[test]
+    fn test_decode_pool_member() {
+        let account = [7u8; 32];
+        let (key, value) = pool_member_entry(account, 12, 5_000_000_000);
+
+        let (pool_id, member) = decode_pool_member(&key, &value, 0).unwrap();
+        assert_eq!(pool_id, 12);
+        assert_eq!(member.account_id, format_account_id(&account, 0));
+        assert_eq!(member.points, "5000000000");
+
+        assert!(decode_pool_member("0x00", &value, 0).is_none());
+        assert!(decode_pool_member(&key, "0x0c", 0).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_pool_members_filters_and_pages() {
+        use subxt_rpcs::client::MockRpcClient;
+        use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+        let entries = [
+            pool_member_entry([1u8; 32], 1, 100),
+            pool_member_entry([2u8; 32], 2, 200),
+            pool_member_entry([3u8; 32], 1, 300),
+            pool_member_entry([4u8; 32], 1, 400),
+        ];
+        let keys: Vec<String> = entries.iter().map(|(k, _)| k.clone()).collect();
+        let changes: Vec<(String, Option<String>)> = entries
+            .iter()
+            .map(|(k, v)| (k.clone(), Some(v.clone())))
+            .collect();
+
+        let mock_client = MockRpcClient::builder()
+            .method_handler("state_getKeysPaged", move |_params| {
+                let keys = keys.clone();
+                async move { MockJson(keys) }
+            })
+            .method_handler("state_queryStorageAt", move |_params| {
+                let changes = changes.clone();
+                async move { MockJson(json!([{ "block": "0x00", "changes": changes }])) }
+            })
+            .build();
+        let rpc_client = RpcClient::new(mock_client);
+
+        let (members, next) = scan_pool_members(&rpc_client, "0x00", 1, 2, None, 0)
+            .await
+            .unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].points, "100");
+        assert_eq!(members[1].points, "300");
+        assert_eq!(next, Some(entries[2].0.clone()));
+
+        let (members, next) = scan_pool_members(&rpc_client, "0x00", 1, 10, None, 0)
+            .await
+            .unwrap();
+        assert_eq!(members.len(), 3);
+        assert_eq!(next, None);
+    }
+
     #[test]
     fn test_pool_state_as_str() {
         assert_eq!(PoolState::Open.as_str(), "Open");
@@ -8,6 +8,7 @@
 //! as parachains don't have governance.
 
 use crate::extractors::JsonQuery;
+use crate::handlers::blocks::decode::JsonVisitor;
 use crate::handlers::common::xcm_types::format_number_with_commas;
 use crate::handlers::pallets::common::{
     AtResponse, ClientAtBlock, PalletError, format_account_id, resolve_block_for_pallet,
@@ -24,7 +25,10 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use polkadot_rest_api_config::ChainType;
+use scale_decode::visitor::decode_with_visitor;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use subxt::Metadata;
 
 // ============================================================================
 // Query Parameters
@@ -38,6 +42,9 @@ pub struct OnGoingReferendaQueryParams {
     /// Use relay chain block (for Asset Hub)
     #[serde(default)]
     pub use_rc_block: bool,
+    /// Add each referendum's track name and parameters
+    #[serde(default)]
+    pub include_track: bool,
 }
 
 // ============================================================================
@@ -66,6 +73,9 @@ pub struct ReferendumInfo {
     pub enactment: EnactmentInfo,
     pub submitted: String,
     pub deciding: Option<DecidingStatus>,
+    /// The referendum's track, when requested with `includeTrack=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track: Option<TrackInfo>,
 }
 
 /// Enactment info matching Sidecar's format: {"after": "14400"} or {"at": "12345"}
@@ -92,6 +102,23 @@ pub struct DecidingStatus {
     pub confirming: Option<String>,
 }
 
+/// A track's parameters, as decoded from the `Referenda::Tracks` constant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackInfo {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub max_deciding: String,
+    pub decision_deposit: String,
+    pub prepare_period: String,
+    pub decision_period: String,
+    pub confirm_period: String,
+    pub min_enactment_period: String,
+    pub min_approval: serde_json::Value,
+    pub min_support: serde_json::Value,
+}
+
 // ============================================================================
 // Main Handler
 // ============================================================================
@@ -104,7 +131,8 @@ pub struct DecidingStatus {
     description = "Returns all currently active referenda from the Referenda pallet.",
     params(
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("includeTrack" = Option<bool>, Query, description = "Add each referendum's track name and parameters (default: false)")
     ),
     responses(
         (status = 200, description = "Active referenda", body = Object),
@@ -128,6 +156,7 @@ pub async fn pallets_on_going_referenda(
         &resolved.client_at_block,
         state.chain_info.ss58_prefix,
         &resolved.at.height,
+        params.include_track,
     )
     .await?;
 
@@ -186,9 +215,13 @@ async fn handle_use_rc_block(
 
         let ah_timestamp = utils::fetch_block_timestamp(&client_at_block).await;
 
-        let referenda =
-            fetch_ongoing_referenda(&client_at_block, state.chain_info.ss58_prefix, &at.height)
-                .await?;
+        let referenda = fetch_ongoing_referenda(
+            &client_at_block,
+            state.chain_info.ss58_prefix,
+            &at.height,
+            params.include_track,
+        )
+        .await?;
 
         results.push(OnGoingReferendaResponse {
             at,
@@ -211,8 +244,14 @@ async fn fetch_ongoing_referenda(
     client_at_block: &ClientAtBlock,
     ss58_prefix: u16,
     block_height: &str,
+    include_track: bool,
 ) -> Result<Vec<ReferendumInfo>, PalletError> {
     let mut referenda = Vec::new();
+    let tracks = if include_track {
+        decode_tracks(&client_at_block.metadata(), ss58_prefix)
+    } else {
+        HashMap::new()
+    };
 
     // First, get the ReferendumCount to know how many referenda have been created
     let referendum_count: u32 =
@@ -252,7 +291,8 @@ async fn fetch_ongoing_referenda(
             {
                 // Filter to only include track 0 (Root) and track 1 (WhitelistedCaller)
                 if track == 0 || track == 1 {
-                    referenda.push(convert_to_referendum_info(ongoing, ss58_prefix));
+                    let track_info = tracks.get(&track).cloned();
+                    referenda.push(convert_to_referendum_info(ongoing, ss58_prefix, track_info));
                 }
             }
         }
@@ -274,6 +314,7 @@ async fn fetch_ongoing_referenda(
 fn convert_to_referendum_info(
     decoded: referenda_queries::DecodedOngoingReferendum,
     ss58_prefix: u16,
+    track: Option<TrackInfo>,
 ) -> ReferendumInfo {
     use referenda_queries::DecodedEnactment;
 
@@ -304,9 +345,65 @@ fn convert_to_referendum_info(
         enactment,
         submitted: decoded.submitted.to_string(),
         deciding,
+        track,
     }
 }
 
+/// Decode the `Referenda::Tracks` constant against the type registry, keyed by track ID.
+///
+/// Returns an empty map if the pallet or constant is missing or fails to decode.
+fn decode_tracks(metadata: &Metadata, ss58_prefix: u16) -> HashMap<u16, TrackInfo> {
+    let Some(constant) = metadata
+        .pallet_by_name("Referenda")
+        .and_then(|pallet| pallet.constant_by_name("Tracks"))
+    else {
+        return HashMap::new();
+    };
+
+    let registry = metadata.types();
+    let visitor = JsonVisitor::new(ss58_prefix, registry);
+
+    match decode_with_visitor(&mut constant.value(), constant.ty(), registry, visitor) {
+        Ok(tracks) => parse_tracks(tracks),
+        Err(e) => {
+            tracing::warn!("Failed to decode Referenda::Tracks constant: {}", e);
+            HashMap::new()
+        }
+    }
+}
+
+/// Parse decoded tracks, either `(id, info)` tuples or `{id, info}` structs.
+///
+/// Runtimes that store track names as fixed-size byte arrays pad them with
+/// NULs, which are trimmed here.
+fn parse_tracks(tracks: serde_json::Value) -> HashMap<u16, TrackInfo> {
+    let serde_json::Value::Array(tracks) = tracks else {
+        return HashMap::new();
+    };
+
+    tracks
+        .into_iter()
+        .filter_map(|track| {
+            let (id, info) = match track {
+                serde_json::Value::Array(pair) => {
+                    let [id, info]: [serde_json::Value; 2] = pair.try_into().ok()?;
+                    (id, info)
+                }
+                serde_json::Value::Object(mut fields) => {
+                    (fields.remove("id")?, fields.remove("info")?)
+                }
+                _ => return None,
+            };
+
+            let id: u16 = id.as_str()?.parse().ok()?;
+            let mut info: TrackInfo = serde_json::from_value(info).ok()?;
+            info.id = id.to_string();
+            info.name = info.name.trim_end_matches('\0').to_string();
+            Some((id, info))
+        })
+        .collect()
+}
+
 // ============================================================================
 // RC (Relay Chain) Handler
 // ============================================================================
@@ -315,6 +412,8 @@ fn convert_to_referendum_info(
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RcOnGoingReferendaQueryParams {
     pub at: Option<String>,
+    #[serde(default)]
+    pub include_track: bool,
 }
 
 /// Handler for GET `/rc/pallets/on-going-referenda`
@@ -327,7 +426,8 @@ pub struct RcOnGoingReferendaQueryParams {
     summary = "RC on-going referenda",
     description = "Returns all currently active referenda from the relay chain's Referenda pallet.",
     params(
-        ("at" = Option<String>, Query, description = "Block hash or number to query at")
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("includeTrack" = Option<bool>, Query, description = "Add each referendum's track name and parameters (default: false)")
     ),
     responses(
         (status = 200, description = "Active referenda from relay chain", body = Object),
@@ -358,8 +458,13 @@ pub async fn rc_pallets_on_going_referenda(
         height: resolved.number.to_string(),
     };
 
-    let referenda =
-        fetch_ongoing_referenda(&client_at_block, relay_chain_info.ss58_prefix, &at.height).await?;
+    let referenda = fetch_ongoing_referenda(
+        &client_at_block,
+        relay_chain_info.ss58_prefix,
+        &at.height,
+        params.include_track,
+    )
+    .await?;
 
     Ok((
         StatusCode::OK,
@@ -447,6 +552,7 @@ mod tests {
                 since: "23687165".to_string(),
                 confirming: None,
             }),
+            track: None,
         };
 
         let json = serde_json::to_value(&referendum).unwrap();
@@ -469,12 +575,72 @@ mod tests {
             },
             submitted: "23810220".to_string(),
             deciding: None,
+            track: None,
+        };
+
+        let json = serde_json::to_value(&referendum).unwrap();
+        assert!(json.get("track").is_none());
+
+        let referendum = ReferendumInfo {
+            track: decode_tracks(&asset_hub_metadata(), 0).remove(&1),
+            ..referendum
         };
 
         let json = serde_json::to_value(&referendum).unwrap();
         assert_eq!(json["id"], "1,349");
         assert!(json["decisionDeposit"].is_null());
         assert!(json["deciding"].is_null());
+        assert_eq!(json["track"]["id"], "1");
+        assert_eq!(json["track"]["name"], "whitelisted_caller");
+    }
+
+    // ========================================================================
+    // Track decoding tests
+    // ========================================================================
+
+    fn asset_hub_metadata() -> Metadata {
+        use crate::test_fixtures::ASSET_HUB_METADATA;
+        use parity_scale_codec::Decode;
+
+        Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap()
+    }
+
+    #[test]
+    fn test_decode_tracks() {
+        let tracks = decode_tracks(&asset_hub_metadata(), 0);
+
+        let root = &tracks[&0];
+        assert_eq!(root.id, "0");
+        assert_eq!(root.name, "root");
+        assert_eq!(root.max_deciding, "1");
+        assert_eq!(root.decision_period, "403200");
+        assert_eq!(root.confirm_period, "14400");
+        assert!(root.min_approval.get("reciprocal").is_some());
+
+        assert_eq!(tracks[&1].name, "whitelisted_caller");
+        assert_eq!(tracks[&1].confirm_period, "100");
+    }
+
+    #[test]
+    fn test_parse_tracks_struct_form() {
+        let tracks = parse_tracks(serde_json::json!([{
+            "id": "2",
+            "info": {
+                "name": "wish_for_change",
+                "maxDeciding": "20",
+                "decisionDeposit": "200000000000000",
+                "preparePeriod": "1200",
+                "decisionPeriod": "403200",
+                "confirmPeriod": "14400",
+                "minEnactmentPeriod": "100",
+                "minApproval": {},
+                "minSupport": {}
+            }
+        }]));
+
+        assert_eq!(tracks[&2].id, "2");
+        assert_eq!(tracks[&2].name, "wish_for_change");
+        assert!(parse_tracks(serde_json::json!("0x00")).is_empty());
     }
 
     #[test]
@@ -508,6 +674,17 @@ mod tests {
         assert!(!params.use_rc_block);
     }
 
+    #[test]
+    fn test_query_params_with_include_track() {
+        let params: OnGoingReferendaQueryParams =
+            serde_json::from_str(r#"{"includeTrack": true}"#).unwrap();
+        assert!(params.include_track);
+
+        let params: RcOnGoingReferendaQueryParams =
+            serde_json::from_str(r#"{"includeTrack": true}"#).unwrap();
+        assert!(params.include_track);
+    }
+
     #[test]
     fn test_query_params_with_use_rc_block() {
         let params: OnGoingReferendaQueryParams =
@@ -8,6 +8,7 @@
 //! LP (liquidity pool) tokens created by the AssetConversion pallet.
 
 use crate::extractors::JsonQuery;
+use crate::handlers::pallets::asset_conversion::fetch_pool_pairs_by_lp_token;
 use crate::handlers::pallets::common::{
     AtResponse, ClientAtBlock, PalletError, resolve_block_for_pallet,
 };
@@ -35,6 +36,9 @@ pub struct PoolAssetsQueryParams {
     pub at: Option<String>,
     #[serde(default)]
     pub use_rc_block: bool,
+    /// Include the asset pair of the LP token's AssetConversion pool
+    #[serde(default)]
+    pub include_pool: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -72,6 +76,9 @@ pub struct PalletsPoolAssetsInfoResponse {
     pub pool_asset_info: Option<PoolAssetInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pool_asset_meta_data: Option<PoolAssetMetadata>,
+    /// Asset pair of the LP token's pool (only present when includePool=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pool: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rc_block_hash: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,11 +96,12 @@ pub struct PalletsPoolAssetsInfoResponse {
     path = "/v1/pallets/pool-assets/{assetId}/asset-info",
     tag = "pallets",
     summary = "Pool asset info",
-    description = "Returns details for a specific pool asset including supply, admin, and metadata.",
+    description = "Returns details for a specific pool asset including supply, admin, and metadata. `includePool=true` adds `pool`, the asset pair of the AssetConversion pool the LP token belongs to.",
     params(
         ("assetId" = String, Path, description = "Pool asset ID"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("includePool" = Option<bool>, Query, description = "Include the asset pair of the LP token's pool (default: false)")
     ),
     responses(
         (status = 200, description = "Pool asset information", body = Object),
@@ -128,12 +136,15 @@ pub async fn pallets_pool_assets_asset_info(
         return Err(PalletError::PoolAssetNotFound(asset_id.to_string()));
     }
 
+    let pool = fetch_pool_pair(&resolved.client_at_block, asset_id, params.include_pool).await?;
+
     Ok((
         StatusCode::OK,
         Json(PalletsPoolAssetsInfoResponse {
             at: resolved.at,
             pool_asset_info,
             pool_asset_meta_data,
+            pool,
             rc_block_hash: None,
             rc_block_number: None,
             ah_timestamp: None,
@@ -199,10 +210,13 @@ async fn handle_use_rc_block(
             continue;
         }
 
+        let pool = fetch_pool_pair(&client_at_block, asset_id, params.include_pool).await?;
+
         results.push(PalletsPoolAssetsInfoResponse {
             at,
             pool_asset_info,
             pool_asset_meta_data,
+            pool,
             rc_block_hash: Some(rc_block_hash.clone()),
             rc_block_number: Some(rc_block_number.clone()),
             ah_timestamp,
@@ -242,6 +256,21 @@ async fn fetch_pool_asset_info(
     })
 }
 
+/// Fetches the asset pair of the pool whose LP token is `asset_id`, if requested.
+async fn fetch_pool_pair(
+    client_at_block: &ClientAtBlock,
+    asset_id: u32,
+    include_pool: bool,
+) -> Result<Option<serde_json::Value>, PalletError> {
+    if !include_pool {
+        return Ok(None);
+    }
+    let mut pairs = fetch_pool_pairs_by_lp_token(client_at_block)
+        .await
+        .map_err(|e| PalletError::LiquidityPoolsFetchFailed(Box::new(e)))?;
+    Ok(pairs.remove(&asset_id))
+}
+
 /// Fetches pool asset metadata from PoolAssets::Metadata storage.
 async fn fetch_pool_asset_meta_data(
     client_at_block: &ClientAtBlock,
@@ -325,6 +354,7 @@ mod tests {
             },
             pool_asset_info: None,
             pool_asset_meta_data: None,
+            pool: Some(serde_json::json!([{"native": null}, {"asset": "1984"}])),
             rc_block_hash: Some("0xdef456".to_string()),
             rc_block_number: Some("28500000".to_string()),
             ah_timestamp: Some("1700000000000".to_string()),
@@ -334,6 +364,7 @@ mod tests {
         assert!(json.contains("\"rcBlockHash\":\"0xdef456\""));
         assert!(json.contains("\"rcBlockNumber\":\"28500000\""));
         assert!(json.contains("\"ahTimestamp\":\"1700000000000\""));
+        assert!(json.contains("\"pool\":[{\"native\":null},{\"asset\":\"1984\"}]"));
     }
 
     #[test]
@@ -345,6 +376,7 @@ mod tests {
             },
             pool_asset_info: None,
             pool_asset_meta_data: None,
+            pool: None,
             rc_block_hash: None,
             rc_block_number: None,
             ah_timestamp: None,
@@ -355,6 +387,7 @@ mod tests {
         assert!(!json.contains("rcBlockHash"));
         assert!(!json.contains("rcBlockNumber"));
         assert!(!json.contains("ahTimestamp"));
+        assert!(!json.contains("pool\""));
     }
 
     #[test]
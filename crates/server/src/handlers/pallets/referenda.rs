@@ -0,0 +1,618 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for the `/pallets/referenda/{referendumId}` endpoint.
+//!
+//! This endpoint returns a single referendum from the Referenda pallet,
+//! whatever its status: ongoing, approved, rejected, cancelled, timed out
+//! or killed.
+
+use crate::extractors::JsonQuery;
+use crate::handlers::blocks::decode::JsonVisitor;
+use crate::handlers::pallets::common::{
+    AtResponse, ClientAtBlock, PalletError, format_account_id, resolve_block_for_pallet,
+};
+use crate::handlers::pallets::on_going_referenda::{DecidingStatus, Deposit, EnactmentInfo};
+use crate::handlers::runtime_queries::governance as governance_queries;
+use crate::handlers::runtime_queries::preimage as preimage_queries;
+use crate::handlers::runtime_queries::referenda as referenda_queries;
+use crate::state::AppState;
+use crate::utils;
+use crate::utils::rc_block::find_ah_blocks_in_rc_block;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use polkadot_rest_api_config::ChainType;
+use scale_decode::visitor::decode_with_visitor;
+use serde::{Deserialize, Serialize};
+use sp_core::hashing::blake2_256;
+use subxt::Metadata;
+
+// ============================================================================
+// Query Parameters
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReferendumQueryParams {
+    /// Block height (number) or hash (0x-prefixed hex string)
+    pub at: Option<String>,
+    /// Use relay chain block (for Asset Hub)
+    #[serde(default)]
+    pub use_rc_block: bool,
+    /// Fetch the proposal's preimage and decode its call
+    #[serde(default)]
+    pub resolve_preimage: bool,
+}
+
+// ============================================================================
+// Response Types
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferendumResponse {
+    pub at: AtResponse,
+    pub referendum_id: String,
+    #[serde(flatten)]
+    pub info: ReferendumDetailsInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rc_block_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ah_timestamp: Option<String>,
+}
+
+/// The referendum's status and the details stored for it
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum ReferendumDetailsInfo {
+    Ongoing(Box<OngoingReferendum>),
+    Approved(ConcludedReferendum),
+    Rejected(ConcludedReferendum),
+    Cancelled(ConcludedReferendum),
+    TimedOut(ConcludedReferendum),
+    Killed { concluded: String },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OngoingReferendum {
+    pub track: String,
+    pub proposal: Proposal,
+    pub enactment: EnactmentInfo,
+    pub submitted: String,
+    pub submission_deposit: Deposit,
+    pub decision_deposit: Option<Deposit>,
+    pub deciding: Option<DecidingStatus>,
+    pub tally: Tally,
+    pub in_queue: bool,
+}
+
+/// A referendum that is no longer ongoing
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConcludedReferendum {
+    /// Block at which the referendum concluded
+    pub concluded: String,
+    pub submission_deposit: Option<Deposit>,
+    pub decision_deposit: Option<Deposit>,
+}
+
+/// The proposal's preimage hash and length. Inline proposals also carry the
+/// encoded call; legacy proposals don't record a length.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Proposal {
+    pub hash: String,
+    pub len: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline: Option<String>,
+    /// The decoded call, with `resolvePreimage=true` when its preimage is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tally {
+    pub ayes: String,
+    pub nays: String,
+    pub support: String,
+}
+
+// ============================================================================
+// Main Handler
+// ============================================================================
+
+#[utoipa::path(
+    get,
+    path = "/v1/pallets/referenda/{referendumId}",
+    tag = "pallets",
+    summary = "Referendum",
+    description = "Returns a single referendum from the Referenda pallet with its status. Ongoing referenda include the track, proposal hash and length, deposits, deciding status and tally; concluded ones include the block they concluded at and the refundable deposits.",
+    params(
+        ("referendumId" = String, Path, description = "The referendum's index"),
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("resolvePreimage" = Option<bool>, Query, description = "Fetch an ongoing referendum's proposal preimage and decode its call (default: false)")
+    ),
+    responses(
+        (status = 200, description = "Referendum", body = Object),
+        (status = 404, description = "Referendum not found"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn pallets_referendum(
+    State(state): State<AppState>,
+    Path(referendum_id): Path<String>,
+    JsonQuery(params): JsonQuery<ReferendumQueryParams>,
+) -> Result<Response, PalletError> {
+    let referendum_id: u32 = referendum_id.parse().map_err(|_| {
+        PalletError::ReferendumNotFound(format!("Invalid referendum ID: {}", referendum_id))
+    })?;
+
+    if params.use_rc_block {
+        return handle_use_rc_block(state, referendum_id, params).await;
+    }
+
+    let resolved = resolve_block_for_pallet(&state.client, params.at.as_ref()).await?;
+
+    let info = fetch_referendum(
+        &resolved.client_at_block,
+        referendum_id,
+        state.chain_info.ss58_prefix,
+        &resolved.at.height,
+        params.resolve_preimage,
+    )
+    .await?
+    .ok_or_else(|| PalletError::ReferendumNotFound(referendum_id.to_string()))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ReferendumResponse {
+            at: resolved.at,
+            referendum_id: referendum_id.to_string(),
+            info,
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+        }),
+    )
+        .into_response())
+}
+
+async fn handle_use_rc_block(
+    state: AppState,
+    referendum_id: u32,
+    params: ReferendumQueryParams,
+) -> Result<Response, PalletError> {
+    if state.chain_info.chain_type != ChainType::AssetHub {
+        return Err(PalletError::UseRcBlockNotSupported);
+    }
+
+    let rc_block_id = params
+        .at
+        .as_ref()
+        .ok_or(PalletError::AtParameterRequired)?
+        .parse::<utils::BlockId>()?;
+
+    let rc_rpc_client = state.get_relay_chain_rpc_client().await?;
+    let rc_rpc = state.get_relay_chain_rpc().await?;
+
+    let rc_resolved_block =
+        utils::resolve_block_with_rpc(&rc_rpc_client, &rc_rpc, Some(rc_block_id)).await?;
+
+    let ah_blocks = find_ah_blocks_in_rc_block(&state, &rc_resolved_block).await?;
+
+    if ah_blocks.is_empty() {
+        return Ok((StatusCode::OK, Json(Vec::<ReferendumResponse>::new())).into_response());
+    }
+
+    let mut results = Vec::new();
+    let rc_block_hash = rc_resolved_block.hash.clone();
+    let rc_block_number = rc_resolved_block.number.to_string();
+
+    for ah_block in &ah_blocks {
+        let client_at_block = state.client.at_block(ah_block.number).await?;
+
+        let at = AtResponse {
+            hash: ah_block.hash.clone(),
+            height: ah_block.number.to_string(),
+        };
+
+        // Skip blocks where the referendum doesn't exist yet
+        let Some(info) = fetch_referendum(
+            &client_at_block,
+            referendum_id,
+            state.chain_info.ss58_prefix,
+            &at.height,
+            params.resolve_preimage,
+        )
+        .await?
+        else {
+            continue;
+        };
+
+        let ah_timestamp = utils::fetch_block_timestamp(&client_at_block).await;
+
+        results.push(ReferendumResponse {
+            at,
+            referendum_id: referendum_id.to_string(),
+            info,
+            rc_block_hash: Some(rc_block_hash.clone()),
+            rc_block_number: Some(rc_block_number.clone()),
+            ah_timestamp,
+        });
+    }
+
+    Ok((StatusCode::OK, Json(results)).into_response())
+}
+
+// ============================================================================
+// Storage Fetching
+// ============================================================================
+
+/// Fetch a referendum from `Referenda::ReferendumInfoFor`, or `None` if it doesn't exist.
+async fn fetch_referendum(
+    client_at_block: &ClientAtBlock,
+    referendum_id: u32,
+    ss58_prefix: u16,
+    block_height: &str,
+    resolve_preimage: bool,
+) -> Result<Option<ReferendumDetailsInfo>, PalletError> {
+    if governance_queries::get_referendum_count(client_at_block)
+        .await
+        .is_none()
+    {
+        return Err(PalletError::PalletNotAvailableAtBlock {
+            module: "api.query.referenda".to_string(),
+            block_height: block_height.to_string(),
+        });
+    }
+
+    let Some(details) =
+        referenda_queries::get_referendum_details(client_at_block, referendum_id).await?
+    else {
+        return Ok(None);
+    };
+
+    let preimage = match &details {
+        referenda_queries::ReferendumDetails::Ongoing(ongoing) if resolve_preimage => {
+            Some(fetch_proposal_preimage(client_at_block, &ongoing.proposal).await?)
+        }
+        _ => None,
+    };
+
+    let mut info = convert_referendum_details(details, ss58_prefix);
+    if let (ReferendumDetailsInfo::Ongoing(ongoing), Some((len, call))) = (&mut info, preimage) {
+        if ongoing.proposal.len.is_none() {
+            ongoing.proposal.len = len.map(|len| len.to_string());
+        }
+        ongoing.proposal.call =
+            call.and_then(|call| decode_call(&client_at_block.metadata(), &call, ss58_prefix));
+    }
+
+    Ok(Some(info))
+}
+
+/// Fetch a proposal's length and encoded call from the Preimage pallet.
+///
+/// Inline proposals carry their call; legacy proposals have their length
+/// looked up from the preimage's request status. Either is `None` when the
+/// preimage isn't stored.
+async fn fetch_proposal_preimage(
+    client_at_block: &ClientAtBlock,
+    proposal: &referenda_queries::BoundedProposal,
+) -> Result<(Option<u32>, Option<Vec<u8>>), PalletError> {
+    use referenda_queries::BoundedProposal;
+
+    let (hash, len) = match proposal {
+        BoundedProposal::Inline(call) => {
+            return Ok((Some(call.len() as u32), Some(call.clone())));
+        }
+        BoundedProposal::Lookup { hash, len } => (*hash, Some(*len)),
+        BoundedProposal::Legacy { hash } => (
+            *hash,
+            preimage_queries::get_preimage_len(client_at_block, *hash).await?,
+        ),
+    };
+
+    let call = match len {
+        Some(len) => preimage_queries::get_preimage(client_at_block, hash, len).await?,
+        None => None,
+    };
+
+    Ok((len, call))
+}
+
+/// Decode an encoded `RuntimeCall` into its pallet, method and args.
+/// Returns `None` if the bytes aren't exactly one call.
+fn decode_call(metadata: &Metadata, call: &[u8], ss58_prefix: u16) -> Option<serde_json::Value> {
+    let registry = metadata.types();
+    let visitor = JsonVisitor::new(ss58_prefix, registry);
+    let cursor = &mut &call[..];
+
+    match decode_with_visitor(
+        cursor,
+        metadata.outer_enums().call_enum_ty(),
+        registry,
+        visitor,
+    ) {
+        Ok(value) if cursor.is_empty() => Some(value),
+        Ok(_) => {
+            tracing::debug!("Preimage has trailing bytes after the call");
+            None
+        }
+        Err(e) => {
+            tracing::debug!("Failed to decode preimage call: {}", e);
+            None
+        }
+    }
+}
+
+/// Convert a decoded referendum to the handler's response format
+fn convert_referendum_details(
+    details: referenda_queries::ReferendumDetails,
+    ss58_prefix: u16,
+) -> ReferendumDetailsInfo {
+    use referenda_queries::ReferendumDetails;
+
+    let deposit = |d: referenda_queries::DepositDetails| Deposit {
+        who: format_account_id(&d.who, ss58_prefix),
+        amount: d.amount.to_string(),
+    };
+    let concluded =
+        |block: u32,
+         submission_deposit: Option<referenda_queries::DepositDetails>,
+         decision_deposit: Option<referenda_queries::DepositDetails>| {
+            ConcludedReferendum {
+                concluded: block.to_string(),
+                submission_deposit: submission_deposit.map(deposit),
+                decision_deposit: decision_deposit.map(deposit),
+            }
+        };
+
+    match details {
+        ReferendumDetails::Ongoing(ongoing) => {
+            let ongoing = *ongoing;
+
+            let enactment = match ongoing.enactment {
+                referenda_queries::EnactmentType::After(blocks) => EnactmentInfo {
+                    after: Some(blocks.to_string()),
+                    at: None,
+                },
+                referenda_queries::EnactmentType::At(block) => EnactmentInfo {
+                    after: None,
+                    at: Some(block.to_string()),
+                },
+            };
+
+            ReferendumDetailsInfo::Ongoing(Box::new(OngoingReferendum {
+                track: ongoing.track.to_string(),
+                proposal: convert_proposal(ongoing.proposal),
+                enactment,
+                submitted: ongoing.submitted.to_string(),
+                submission_deposit: deposit(ongoing.submission_deposit),
+                decision_deposit: ongoing.decision_deposit.map(deposit),
+                deciding: ongoing.deciding.map(|d| DecidingStatus {
+                    since: d.since.to_string(),
+                    confirming: d.confirming.map(|c| c.to_string()),
+                }),
+                tally: Tally {
+                    ayes: ongoing.tally.ayes.to_string(),
+                    nays: ongoing.tally.nays.to_string(),
+                    support: ongoing.tally.support.to_string(),
+                },
+                in_queue: ongoing.in_queue,
+            }))
+        }
+        ReferendumDetails::Approved(block, submission, decision) => {
+            ReferendumDetailsInfo::Approved(concluded(block, submission, decision))
+        }
+        ReferendumDetails::Rejected(block, submission, decision) => {
+            ReferendumDetailsInfo::Rejected(concluded(block, submission, decision))
+        }
+        ReferendumDetails::Cancelled(block, submission, decision) => {
+            ReferendumDetailsInfo::Cancelled(concluded(block, submission, decision))
+        }
+        ReferendumDetails::TimedOut(block, submission, decision) => {
+            ReferendumDetailsInfo::TimedOut(concluded(block, submission, decision))
+        }
+        ReferendumDetails::Killed(block) => ReferendumDetailsInfo::Killed {
+            concluded: block.to_string(),
+        },
+    }
+}
+
+/// Convert a `Bounded<Call>` proposal to its preimage hash and length.
+/// Inline calls aren't noted as preimages, so their hash is computed here.
+fn convert_proposal(proposal: referenda_queries::BoundedProposal) -> Proposal {
+    use referenda_queries::BoundedProposal;
+
+    match proposal {
+        BoundedProposal::Legacy { hash } => Proposal {
+            hash: format!("0x{}", hex::encode(hash)),
+            len: None,
+            inline: None,
+            call: None,
+        },
+        BoundedProposal::Inline(call) => Proposal {
+            hash: format!("0x{}", hex::encode(blake2_256(&call))),
+            len: Some(call.len().to_string()),
+            inline: Some(format!("0x{}", hex::encode(&call))),
+            call: None,
+        },
+        BoundedProposal::Lookup { hash, len } => Proposal {
+            hash: format!("0x{}", hex::encode(hash)),
+            len: Some(len.to_string()),
+            inline: None,
+            call: None,
+        },
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use referenda_queries::{BoundedProposal, DepositDetails, ReferendumDetails};
+
+    fn sample_deposit() -> DepositDetails {
+        DepositDetails {
+            who: [1u8; 32],
+            amount: 1_000_000_000_000,
+        }
+    }
+
+    #[test]
+    fn test_convert_proposal_lookup() {
+        let proposal = convert_proposal(BoundedProposal::Lookup {
+            hash: [0xab; 32],
+            len: 42,
+        });
+        assert_eq!(proposal.hash, format!("0x{}", "ab".repeat(32)));
+        assert_eq!(proposal.len, Some("42".to_string()));
+        assert!(proposal.inline.is_none());
+    }
+
+    #[test]
+    fn test_convert_proposal_inline() {
+        let call = vec![0x00, 0x07, 0x04, 0x00];
+        let proposal = convert_proposal(BoundedProposal::Inline(call.clone()));
+        assert_eq!(
+            proposal.hash,
+            format!("0x{}", hex::encode(blake2_256(&call)))
+        );
+        assert_eq!(proposal.len, Some("4".to_string()));
+        assert_eq!(proposal.inline, Some("0x00070400".to_string()));
+    }
+
+    #[test]
+    fn test_concluded_referendum_serialization() {
+        let info = convert_referendum_details(
+            ReferendumDetails::Approved(23_500_000, Some(sample_deposit()), None),
+            0,
+        );
+        let response = ReferendumResponse {
+            at: AtResponse {
+                hash: "0xabc".to_string(),
+                height: "24000000".to_string(),
+            },
+            referendum_id: "1308".to_string(),
+            info,
+            rc_block_hash: None,
+            rc_block_number: None,
+            ah_timestamp: None,
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["referendumId"], "1308");
+        assert_eq!(json["status"], "approved");
+        assert_eq!(json["concluded"], "23500000");
+        assert_eq!(json["submissionDeposit"]["amount"], "1000000000000");
+        assert!(json["decisionDeposit"].is_null());
+        assert!(json.get("rcBlockHash").is_none());
+    }
+
+    #[test]
+    fn test_killed_and_timed_out_status() {
+        let json = serde_json::to_value(convert_referendum_details(
+            ReferendumDetails::Killed(100),
+            0,
+        ))
+        .unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"status": "killed", "concluded": "100"})
+        );
+
+        let json = serde_json::to_value(convert_referendum_details(
+            ReferendumDetails::TimedOut(200, None, None),
+            0,
+        ))
+        .unwrap();
+        assert_eq!(json["status"], "timedOut");
+    }
+
+    #[test]
+    fn test_ongoing_referendum_serialization() {
+        let details = ReferendumDetails::Ongoing(Box::new(referenda_queries::OngoingFullDetails {
+            track: 1,
+            proposal: BoundedProposal::Lookup {
+                hash: [0x11; 32],
+                len: 100,
+            },
+            enactment: referenda_queries::EnactmentType::After(14400),
+            submitted: 23_496_576,
+            submission_deposit: sample_deposit(),
+            decision_deposit: None,
+            deciding: Some(referenda_queries::DecidingDetails {
+                since: 23_687_165,
+                confirming: None,
+            }),
+            tally: referenda_queries::TallyDetails {
+                ayes: 300,
+                nays: 200,
+                support: 100,
+            },
+            in_queue: false,
+        }));
+
+        let json = serde_json::to_value(convert_referendum_details(details, 0)).unwrap();
+        assert_eq!(json["status"], "ongoing");
+        assert_eq!(json["track"], "1");
+        assert_eq!(json["proposal"]["len"], "100");
+        assert_eq!(json["enactment"]["after"], "14400");
+        assert_eq!(json["deciding"]["since"], "23687165");
+        assert_eq!(json["tally"]["ayes"], "300");
+        assert_eq!(json["inQueue"], false);
+    }
+
+    #[test]
+    fn test_decode_call() {
+        use crate::test_fixtures::ASSET_HUB_METADATA;
+        use parity_scale_codec::{Decode, Encode};
+
+        let metadata = Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let system = metadata.pallet_by_name("System").unwrap();
+        let remark_index = system.call_variant_by_name("remark").unwrap().index;
+        let mut call = vec![system.call_index(), remark_index];
+        b"hello".to_vec().encode_to(&mut call);
+
+        let decoded = decode_call(&metadata, &call, 0).unwrap();
+        assert_eq!(decoded["method"]["pallet"], "system");
+        assert_eq!(decoded["method"]["method"], "remark");
+        assert_eq!(decoded["args"]["remark"], "0x68656c6c6f");
+
+        let mut trailing = call.clone();
+        trailing.push(0);
+        assert!(decode_call(&metadata, &trailing, 0).is_none());
+        assert!(decode_call(&metadata, &[0xff, 0xff], 0).is_none());
+    }
+
+    #[test]
+    fn test_query_params_resolve_preimage() {
+        let params: ReferendumQueryParams =
+            serde_json::from_str(r#"{"resolvePreimage": true}"#).unwrap();
+        assert!(params.resolve_preimage);
+
+        let params: ReferendumQueryParams = serde_json::from_str("{}").unwrap();
+        assert!(!params.resolve_preimage);
+    }
+
+    #[test]
+    fn test_query_params_rejects_unknown_fields() {
+        let result: Result<ReferendumQueryParams, _> =
+            serde_json::from_str(r#"{"at": "12345", "unknownField": true}"#);
+        assert!(result.is_err());
+    }
+}
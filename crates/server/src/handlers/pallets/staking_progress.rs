@@ -33,6 +33,9 @@ pub struct StakingProgressQueryParams {
     pub at: Option<String>,
     #[serde(default)]
     pub use_rc_block: bool,
+    /// Report how many unapplied slashes are scheduled for the active era
+    #[serde(default)]
+    pub include_unapplied_slashes_count: bool,
 }
 
 #[derive(Debug, Default, Serialize)]
@@ -40,11 +43,26 @@ pub struct StakingProgressQueryParams {
 pub struct StakingProgressResponse {
     pub at: AtResponse,
     pub active_era: Option<String>,
+    /// Era being planned. Runs one ahead of `active_era` between the election and
+    /// the era rotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_era: Option<String>,
+    /// Session index at which the active era started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eras_start_session_index: Option<String>,
     pub force_era: serde_json::Value,
     pub next_session_estimate: Option<String>,
     pub unapplied_slashes: Vec<UnappliedSlash>,
+    /// Number of `unapplied_slashes` due in the active era, only set when
+    /// `includeUnappliedSlashesCount` is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unapplied_slashes_count: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_active_era_estimate: Option<String>,
+    /// Estimated unix time in milliseconds at which the next era becomes active.
+    /// Omitted when the slot duration or block timestamp can't be read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_active_era_timestamp_estimate: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub election_status: Option<ElectionStatusResponse>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,8 +188,28 @@ struct SessionEraProgress {
     session_length: u64,
     session_progress: u64,
     active_era: u32,
+    active_era_start_session: u32,
     #[allow(dead_code)]
     current_session_index: u32,
+    /// Block timestamp in milliseconds
+    timestamp_ms: Option<u64>,
+    /// Duration of the slots `era_length` and `session_length` are measured in
+    slot_duration_ms: Option<u64>,
+}
+
+impl SessionEraProgress {
+    /// Projects the wall-clock time at which the next era becomes active, given the
+    /// number of slots still to go.
+    fn next_active_era_timestamp(&self, remaining_slots: u64) -> Option<u64> {
+        let elapsed = remaining_slots.checked_mul(self.slot_duration_ms?)?;
+        self.timestamp_ms?.checked_add(elapsed)
+    }
+}
+
+/// Counts the unapplied slashes that are due to be applied in `era`.
+fn unapplied_slashes_in_era(slashes: &[UnappliedSlash], era: u32) -> usize {
+    let era = era.to_string();
+    slashes.iter().filter(|slash| slash.era == era).count()
 }
 
 #[utoipa::path(
@@ -179,10 +217,11 @@ struct SessionEraProgress {
     path = "/v1/pallets/staking/progress",
     tag = "pallets",
     summary = "Staking progress",
-    description = "Returns staking progress including era, session info, and validator counts.",
+    description = "Returns staking progress including era, session info, and validator counts. `activeEra` is the era currently paying out, while `currentEra` is the latest planned era and runs one ahead of it between the election and the era rotation. `erasStartSessionIndex` is the session at which the active era started. `nextActiveEraEstimate` is a block number, and `nextActiveEraTimestampEstimate` is the same estimate as unix time in milliseconds.",
     params(
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
+        ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier"),
+        ("includeUnappliedSlashesCount" = Option<bool>, Query, description = "Include `unappliedSlashesCount`, the number of unapplied slashes due in the active era")
     ),
     responses(
         (status = 200, description = "Staking progress information", body = Object),
@@ -270,14 +309,23 @@ pub async fn pallets_staking_progress(
         .saturating_sub(progress.session_progress)
         .saturating_add(current_block_number);
 
+    let current_era = fetch_current_era(&resolved.client_at_block).await;
+    let unapplied_slashes_count = params
+        .include_unapplied_slashes_count
+        .then(|| unapplied_slashes_in_era(&unapplied_slashes, progress.active_era).to_string());
+
     // Build base response (always included fields)
     let mut response = StakingProgressResponse {
         at: resolved.at,
         active_era: Some(progress.active_era.to_string()),
+        current_era,
+        eras_start_session_index: Some(progress.active_era_start_session.to_string()),
         force_era: force_era.to_json(),
         next_session_estimate: Some(next_session.to_string()),
         unapplied_slashes,
+        unapplied_slashes_count,
         next_active_era_estimate: None,
+        next_active_era_timestamp_estimate: None,
         election_status: None,
         ideal_validator_count: None,
         validator_set: Some(validators),
@@ -335,6 +383,9 @@ pub async fn pallets_staking_progress(
 
     // Add extended fields
     response.next_active_era_estimate = Some(next_active_era.to_string());
+    response.next_active_era_timestamp_estimate = progress
+        .next_active_era_timestamp(next_active_era.saturating_sub(current_block_number))
+        .map(|t| t.to_string());
     response.ideal_validator_count = Some(validator_count.to_string());
     response.election_status = Some(match election_status {
         Some(status) => ElectionStatusResponse::Active {
@@ -354,7 +405,8 @@ pub async fn pallets_staking_progress(
     summary = "RC staking progress",
     description = "Returns staking progress from the relay chain.",
     params(
-        ("at" = Option<String>, Query, description = "Block hash or number to query at")
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("includeUnappliedSlashesCount" = Option<bool>, Query, description = "Include `unappliedSlashesCount`, the number of unapplied slashes due in the active era")
     ),
     responses(
         (status = 200, description = "Relay chain staking progress", body = Object),
@@ -420,14 +472,23 @@ pub async fn rc_pallets_staking_progress(
         .saturating_sub(progress.session_progress)
         .saturating_add(current_block_number);
 
+    let current_era = fetch_current_era(&client_at_block).await;
+    let unapplied_slashes_count = params
+        .include_unapplied_slashes_count
+        .then(|| unapplied_slashes_in_era(&unapplied_slashes, progress.active_era).to_string());
+
     // Build base response
     let mut response = StakingProgressResponse {
         at,
         active_era: Some(progress.active_era.to_string()),
+        current_era,
+        eras_start_session_index: Some(progress.active_era_start_session.to_string()),
         force_era: force_era.to_json(),
         next_session_estimate: Some(next_session.to_string()),
         unapplied_slashes,
+        unapplied_slashes_count,
         next_active_era_estimate: None,
+        next_active_era_timestamp_estimate: None,
         election_status: None,
         ideal_validator_count: None,
         validator_set: Some(validators),
@@ -485,6 +546,9 @@ pub async fn rc_pallets_staking_progress(
 
     // Add extended fields
     response.next_active_era_estimate = Some(next_active_era.to_string());
+    response.next_active_era_timestamp_estimate = progress
+        .next_active_era_timestamp(next_active_era.saturating_sub(current_block_number))
+        .map(|t| t.to_string());
     response.ideal_validator_count = Some(validator_count.to_string());
     response.election_status = Some(match election_status {
         Some(status) => ElectionStatusResponse::Active {
@@ -502,6 +566,8 @@ pub async fn rc_pallets_staking_progress(
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RcStakingProgressQueryParams {
     pub at: Option<String>,
+    #[serde(default)]
+    pub include_unapplied_slashes_count: bool,
 }
 
 async fn handle_use_rc_block(
@@ -536,130 +602,142 @@ async fn handle_use_rc_block(
     let rc_number = rc_resolved_block.number.to_string();
     let spec_name = state.chain_info.spec_name.clone();
     let ss58_prefix = state.chain_info.ss58_prefix;
+    let include_unapplied_slashes_count = params.include_unapplied_slashes_count;
+
+    let futures = ah_blocks.iter().map(|ah_block| {
+        let state = state.clone();
+        let rc_hash = rc_hash.clone();
+        let rc_number = rc_number.clone();
+        let spec_name = spec_name.clone();
+        let ah_block_hash = ah_block.hash.clone();
+        let ah_block_number = ah_block.number;
+
+        async move {
+            let client_at_block = state.client.at_block(ah_block_number).await?;
+
+            let at = AtResponse {
+                hash: ah_block_hash,
+                height: ah_block_number.to_string(),
+            };
 
-    let futures =
-        ah_blocks.iter().map(|ah_block| {
-            let state = state.clone();
-            let rc_hash = rc_hash.clone();
-            let rc_number = rc_number.clone();
-            let spec_name = spec_name.clone();
-            let ah_block_hash = ah_block.hash.clone();
-            let ah_block_number = ah_block.number;
-
-            async move {
-                let client_at_block = state.client.at_block(ah_block_number).await?;
-
-                let at = AtResponse {
-                    hash: ah_block_hash,
-                    height: ah_block_number.to_string(),
-                };
-
-                // Check for bad staking blocks
-                if is_bad_staking_block(&spec_name, ah_block_number) {
-                    return Err(PalletError::BadStakingBlock(format!(
-                        "Block {} is a known bad staking block for {}",
-                        ah_block_number, spec_name
-                    )));
-                }
-
-                // Fetch data in parallel where possible
-                let (
-                    ah_timestamp,
-                    validator_count,
-                    force_era,
-                    validators,
-                    unapplied_slashes,
-                    progress,
-                ) = tokio::try_join!(
-                    async { Ok::<_, PalletError>(fetch_block_timestamp(&client_at_block).await) },
-                    fetch_validator_count(&client_at_block),
-                    fetch_force_era(&client_at_block),
-                    fetch_staking_validators(&client_at_block, ss58_prefix),
-                    async {
-                        Ok::<_, PalletError>(
-                            fetch_unapplied_slashes(&client_at_block, ss58_prefix).await,
-                        )
-                    },
-                    derive_session_era_progress_asset_hub(&state, &client_at_block)
-                )?;
-
-                // Calculate estimates
-                let current_block_number = ah_block_number;
-                let next_session = progress
-                    .session_length
-                    .saturating_sub(progress.session_progress)
-                    .saturating_add(current_block_number);
-
-                let mut response = StakingProgressResponse {
-                    at,
-                    active_era: Some(progress.active_era.to_string()),
-                    force_era: force_era.to_json(),
-                    next_session_estimate: Some(next_session.to_string()),
-                    unapplied_slashes,
-                    next_active_era_estimate: None,
-                    election_status: None,
-                    ideal_validator_count: None,
-                    validator_set: Some(validators),
-                    rc_block_hash: Some(rc_hash),
-                    rc_block_number: Some(rc_number),
-                    ah_timestamp,
-                };
-
-                if force_era.is_force_none() {
-                    response.validator_set = None;
-                    return Ok(response);
-                }
-
-                let next_active_era = if force_era.is_force_always() {
-                    next_session
-                } else {
-                    progress
-                        .era_length
-                        .saturating_sub(progress.era_progress)
-                        .saturating_add(current_block_number)
-                };
-
-                let election_status = fetch_election_status(&client_at_block).await;
-                let election_lookahead =
-                    derive_election_lookahead(&spec_name, progress.session_length);
-
-                let next_current_era = if next_active_era
-                    .saturating_sub(current_block_number)
-                    .saturating_sub(progress.session_length)
-                    > 0
-                {
-                    next_active_era.saturating_sub(progress.session_length)
-                } else {
-                    next_active_era
-                        .saturating_add(progress.era_length)
-                        .saturating_sub(progress.session_length)
-                };
-
-                let toggle_estimate = if election_lookahead == 0 {
-                    None
-                } else if election_status
-                    .as_ref()
-                    .map(|s| s.is_close())
-                    .unwrap_or(true)
-                {
-                    Some(next_current_era.saturating_sub(election_lookahead))
-                } else {
-                    Some(next_current_era)
-                };
-
-                response.next_active_era_estimate = Some(next_active_era.to_string());
-                response.ideal_validator_count = Some(validator_count.to_string());
-                response.election_status = Some(match election_status {
-                    Some(status) => ElectionStatusResponse::Active {
-                        status: status.to_json(),
-                        toggle_estimate: toggle_estimate.map(|t| t.to_string()),
-                    },
-                    None => ElectionStatusResponse::Deprecated("Deprecated, see docs".to_string()),
-                });
-
-                Ok(response)
+            // Check for bad staking blocks
+            if is_bad_staking_block(&spec_name, ah_block_number) {
+                return Err(PalletError::BadStakingBlock(format!(
+                    "Block {} is a known bad staking block for {}",
+                    ah_block_number, spec_name
+                )));
+            }
+
+            // Fetch data in parallel where possible
+            let (
+                ah_timestamp,
+                validator_count,
+                force_era,
+                validators,
+                unapplied_slashes,
+                progress,
+                current_era,
+            ) = tokio::try_join!(
+                async { Ok::<_, PalletError>(fetch_block_timestamp(&client_at_block).await) },
+                fetch_validator_count(&client_at_block),
+                fetch_force_era(&client_at_block),
+                fetch_staking_validators(&client_at_block, ss58_prefix),
+                async {
+                    Ok::<_, PalletError>(
+                        fetch_unapplied_slashes(&client_at_block, ss58_prefix).await,
+                    )
+                },
+                derive_session_era_progress_asset_hub(&state, &client_at_block),
+                async { Ok::<_, PalletError>(fetch_current_era(&client_at_block).await) }
+            )?;
+
+            // Calculate estimates
+            let current_block_number = ah_block_number;
+            let next_session = progress
+                .session_length
+                .saturating_sub(progress.session_progress)
+                .saturating_add(current_block_number);
+
+            let unapplied_slashes_count = include_unapplied_slashes_count.then(|| {
+                unapplied_slashes_in_era(&unapplied_slashes, progress.active_era).to_string()
+            });
+
+            let mut response = StakingProgressResponse {
+                at,
+                active_era: Some(progress.active_era.to_string()),
+                current_era,
+                eras_start_session_index: Some(progress.active_era_start_session.to_string()),
+                force_era: force_era.to_json(),
+                next_session_estimate: Some(next_session.to_string()),
+                unapplied_slashes,
+                unapplied_slashes_count,
+                next_active_era_estimate: None,
+                next_active_era_timestamp_estimate: None,
+                election_status: None,
+                ideal_validator_count: None,
+                validator_set: Some(validators),
+                rc_block_hash: Some(rc_hash),
+                rc_block_number: Some(rc_number),
+                ah_timestamp,
+            };
+
+            if force_era.is_force_none() {
+                response.validator_set = None;
+                return Ok(response);
             }
-        });
+
+            let next_active_era = if force_era.is_force_always() {
+                next_session
+            } else {
+                progress
+                    .era_length
+                    .saturating_sub(progress.era_progress)
+                    .saturating_add(current_block_number)
+            };
+
+            let election_status = fetch_election_status(&client_at_block).await;
+            let election_lookahead = derive_election_lookahead(&spec_name, progress.session_length);
+
+            let next_current_era = if next_active_era
+                .saturating_sub(current_block_number)
+                .saturating_sub(progress.session_length)
+                > 0
+            {
+                next_active_era.saturating_sub(progress.session_length)
+            } else {
+                next_active_era
+                    .saturating_add(progress.era_length)
+                    .saturating_sub(progress.session_length)
+            };
+
+            let toggle_estimate = if election_lookahead == 0 {
+                None
+            } else if election_status
+                .as_ref()
+                .map(|s| s.is_close())
+                .unwrap_or(true)
+            {
+                Some(next_current_era.saturating_sub(election_lookahead))
+            } else {
+                Some(next_current_era)
+            };
+
+            response.next_active_era_estimate = Some(next_active_era.to_string());
+            response.next_active_era_timestamp_estimate = progress
+                .next_active_era_timestamp(next_active_era.saturating_sub(current_block_number))
+                .map(|t| t.to_string());
+            response.ideal_validator_count = Some(validator_count.to_string());
+            response.election_status = Some(match election_status {
+                Some(status) => ElectionStatusResponse::Active {
+                    status: status.to_json(),
+                    toggle_estimate: toggle_estimate.map(|t| t.to_string()),
+                },
+                None => ElectionStatusResponse::Deprecated("Deprecated, see docs".to_string()),
+            });
+
+            Ok(response)
+        }
+    });
 
     let responses = run_with_concurrency_collect(DEFAULT_CONCURRENCY, futures).await?;
 
@@ -698,6 +776,16 @@ async fn fetch_active_era(
         .ok_or(PalletError::ActiveEraNotFound)
 }
 
+/// `CurrentEra` is optional in storage, so a missing value is reported as absent
+/// rather than failing the request.
+async fn fetch_current_era(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+) -> Option<String> {
+    staking_queries::get_current_era(client_at_block)
+        .await
+        .map(|era| era.to_string())
+}
+
 async fn fetch_bonded_eras(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
 ) -> Result<Vec<(u32, u32)>, PalletError> {
@@ -786,6 +874,8 @@ async fn derive_session_era_progress_relay(
         },
     )?;
     let epoch_duration = get_babe_epoch_duration(spec_name);
+    let slot_duration_ms = get_slot_duration_from_metadata(&client_at_block.metadata());
+    let timestamp_ms = staking_queries::get_timestamp(client_at_block).await;
 
     // Find active era start session index
     let active_era_start_session = bonded_eras
@@ -807,7 +897,10 @@ async fn derive_session_era_progress_relay(
         session_length,
         session_progress,
         active_era: active_era_info.index,
+        active_era_start_session,
         current_session_index: current_index,
+        timestamp_ms,
+        slot_duration_ms,
     })
 }
 
@@ -878,7 +971,10 @@ async fn derive_session_era_progress_asset_hub(
         session_length: babe_params.epoch_duration,
         session_progress,
         active_era: active_era_info.index,
+        active_era_start_session,
         current_session_index: current_index,
+        timestamp_ms: Some(timestamp),
+        slot_duration_ms: Some(babe_params.slot_duration_ms),
     })
 }
 
@@ -964,6 +1060,18 @@ async fn fetch_session_current_index(
         })
 }
 
+/// Reads the BABE slot duration, falling back to twice `Timestamp.MinimumPeriod`,
+/// which is how the slot duration is derived from it.
+fn get_slot_duration_from_metadata(metadata: &subxt::Metadata) -> Option<u64> {
+    let constant_u64 = |pallet: &str, name: &str| {
+        let constant = metadata.pallet_by_name(pallet)?.constant_by_name(name)?;
+        u64::decode(&mut &constant.value()[..]).ok()
+    };
+    constant_u64("Babe", "ExpectedBlockTime")
+        .or_else(|| constant_u64("Timestamp", "MinimumPeriod").and_then(|p| p.checked_mul(2)))
+        .filter(|duration| *duration > 0)
+}
+
 fn get_sessions_per_era_from_metadata(metadata: &subxt::Metadata) -> Option<u32> {
     let pallet = metadata.pallet_by_name("Staking")?;
     let constant = pallet.constant_by_name("SessionsPerEra")?;
@@ -973,6 +1081,7 @@ fn get_sessions_per_era_from_metadata(metadata: &subxt::Metadata) -> Option<u32>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
 
     #[test]
     fn test_staking_progress_query_params_rejects_unknown_fields() {
@@ -989,4 +1098,94 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
+
+    fn progress_with_clock(
+        timestamp_ms: Option<u64>,
+        slot_duration_ms: Option<u64>,
+    ) -> SessionEraProgress {
+        SessionEraProgress {
+            era_length: 14_400,
+            era_progress: 14_000,
+            session_length: 2_400,
+            session_progress: 2_000,
+            active_era: 1000,
+            active_era_start_session: 9000,
+            current_session_index: 9005,
+            timestamp_ms,
+            slot_duration_ms,
+        }
+    }
+
+    #[test]
+    fn test_next_active_era_timestamp() {
+        let progress = progress_with_clock(Some(1_700_000_000_000), Some(6_000));
+        assert_eq!(
+            progress.next_active_era_timestamp(400),
+            Some(1_700_000_000_000 + 400 * 6_000)
+        );
+        assert_eq!(
+            progress.next_active_era_timestamp(0),
+            Some(1_700_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_next_active_era_timestamp_omitted_without_clock() {
+        assert_eq!(
+            progress_with_clock(None, Some(6_000)).next_active_era_timestamp(400),
+            None
+        );
+        assert_eq!(
+            progress_with_clock(Some(1_700_000_000_000), None).next_active_era_timestamp(400),
+            None
+        );
+    }
+
+    #[test]
+    fn test_slot_duration_from_metadata() {
+        // Asset Hub has no Babe pallet, so the duration comes from Timestamp.MinimumPeriod
+        let metadata = subxt::Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let minimum_period = metadata
+            .pallet_by_name("Timestamp")
+            .and_then(|p| p.constant_by_name("MinimumPeriod"))
+            .map(|c| u64::decode(&mut &c.value()[..]).unwrap());
+
+        assert!(metadata.pallet_by_name("Babe").is_none());
+        assert_eq!(
+            get_slot_duration_from_metadata(&metadata),
+            minimum_period.map(|p| p * 2).filter(|d| *d > 0)
+        );
+    }
+
+    #[test]
+    fn test_unapplied_slashes_in_era_counts_only_that_era() {
+        let slash = |era: &str| UnappliedSlash {
+            era: era.to_string(),
+            ..Default::default()
+        };
+        let slashes = [slash("1000"), slash("1001"), slash("1000"), slash("999")];
+
+        assert_eq!(unapplied_slashes_in_era(&slashes, 1000), 2);
+        assert_eq!(unapplied_slashes_in_era(&slashes, 1002), 0);
+    }
+
+    #[test]
+    fn test_staking_progress_response_era_fields() {
+        let response = StakingProgressResponse {
+            active_era: Some("1000".to_string()),
+            current_era: Some("1001".to_string()),
+            eras_start_session_index: Some("9000".to_string()),
+            ..Default::default()
+        };
+        let value = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(value["activeEra"], "1000");
+        assert_eq!(value["currentEra"], "1001");
+        assert_eq!(value["erasStartSessionIndex"], "9000");
+        assert!(value.get("unappliedSlashesCount").is_none());
+
+        let params: StakingProgressQueryParams =
+            serde_json::from_str(r#"{"includeUnappliedSlashesCount": true}"#).unwrap();
+        assert!(params.include_unapplied_slashes_count);
+    }
 }
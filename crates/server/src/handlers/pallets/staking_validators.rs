@@ -59,8 +59,11 @@ pub struct StakingValidatorsRcResponse {
 pub struct ValidatorInfo {
     pub address: String,
     pub status: String,
+    /// Commission from the validator's `ValidatorPrefs`, in parts per billion (Perbill).
+    /// Omitted for validators being chilled, which no longer have prefs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commission: Option<String>,
+    /// Whether the validator blocks new nominations, from its `ValidatorPrefs`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blocked: Option<bool>,
 }
@@ -70,7 +73,7 @@ pub struct ValidatorInfo {
     path = "/v1/pallets/staking/validators",
     tag = "pallets",
     summary = "Staking validators",
-    description = "Returns the list of active validators and their info.",
+    description = "Returns the list of active validators and their info. Validators registered in `Staking::Validators` include their `commission` (Perbill, parts per billion, as a string) and whether they are `blocked` from new nominations.",
     params(
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
@@ -23,6 +23,7 @@ use axum::{Json, extract::Path, extract::State, response::IntoResponse, response
 use parity_scale_codec::Decode;
 use polkadot_rest_api_config::ChainType;
 use scale_decode::visitor::decode_with_visitor;
+use scale_info::PortableRegistry;
 use serde::Serialize;
 use serde_json::json;
 use subxt::Metadata;
@@ -135,18 +136,68 @@ pub enum DeprecationInfo {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct StorageItemQueryParams {
     pub at: Option<String>,
-    /// Storage keys for map types (format: ?keys[]=key1&keys[]=key2)
-    /// Note: serde_qs handles bracket notation automatically, no rename needed
-    #[serde(default)]
+    /// Storage keys for map types, one per hasher for double maps and NMaps.
+    /// Accepts `?keys[]=key1&keys[]=key2` or a comma-separated `?keys=key1,key2`
+    #[serde(default, deserialize_with = "deserialize_storage_keys")]
     pub keys: Vec<String>,
     /// When true, include storage item metadata in response
     #[serde(default)]
     pub metadata: bool,
+    /// When true, include the full hashed storage key in response
+    #[serde(default)]
+    pub compute_key: bool,
     /// When true, treat `at` as a relay chain block and find Asset Hub blocks within it
     #[serde(default)]
     pub use_rc_block: bool,
 }
 
+/// Deserialize storage keys from either a list or a comma-separated string.
+///
+/// Keys are SS58 addresses, hex or numbers, none of which contain commas.
+fn deserialize_storage_keys<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct StorageKeysVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for StorageKeysVisitor {
+        type Value = Vec<String>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a list or comma-separated string of storage keys")
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(vec![v.to_string()])
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(v.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect())
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(
+            self,
+            mut seq: A,
+        ) -> Result<Self::Value, A::Error> {
+            let mut keys = Vec::new();
+            while let Some(raw) = seq.next_element::<serde_json::Value>()? {
+                let raw = match raw {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                keys.push(raw.trim().to_string());
+            }
+            Ok(keys)
+        }
+    }
+
+    deserializer.deserialize_any(StorageKeysVisitor)
+}
+
 /// Response for /pallets/{palletId}/storage/{storageItemId} endpoint
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -156,6 +207,9 @@ pub struct PalletsStorageItemResponse {
     pub pallet_index: String,
     pub storage_item: String,
     pub keys: Vec<String>,
+    /// Full storage key passed to `state_getStorage` (only present when computeKey=true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
     pub value: serde_json::Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<StorageItemMetadata>,
@@ -302,8 +356,9 @@ async fn handle_use_rc_block(
         ("palletId" = String, Path, description = "Name or index of the pallet"),
         ("storageItemId" = String, Path, description = "Name of the storage item"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("keys[]" = Option<Vec<String>>, Query, description = "Storage key arguments"),
+        ("keys[]" = Option<Vec<String>>, Query, description = "Storage key arguments, one per key of a double map or NMap, in order (also accepts comma-separated keys=a,b)"),
         ("metadata" = Option<bool>, Query, description = "Include metadata for the storage item"),
+        ("computeKey" = Option<bool>, Query, description = "Include the full hashed storage key used for the query"),
         ("useRcBlock" = Option<bool>, Query, description = "Treat 'at' as relay chain block identifier")
     ),
     responses(
@@ -340,6 +395,7 @@ pub async fn get_pallets_storage_item(
         &params.keys,
         &resolved_block,
         params.metadata,
+        params.compute_key,
         block_hash,
         state.chain_info.ss58_prefix,
     )
@@ -400,6 +456,7 @@ async fn handle_storage_item_use_rc_block(
             &params.keys,
             &ah_resolved_block,
             params.metadata,
+            params.compute_key,
             &ah_block.hash,
             state.chain_info.ss58_prefix,
         )
@@ -576,18 +633,59 @@ fn decode_key_value_auto(key: &str) -> Result<Vec<u8>, PalletError> {
         return Ok(num.to_le_bytes().to_vec());
     }
 
-    Err(PalletError::PalletNotFound(format!(
+    Err(PalletError::InvalidStorageKeys(format!(
         "Unable to encode key '{}'",
         key
     )))
 }
 
+/// Resolve a storage key type ID to the primitive it encodes as (e.g. `u16`), so
+/// numeric keys get the right width. Single-field newtypes are unwrapped; anything
+/// else returns the type ID unchanged and the key is encoded by auto-detection.
+fn key_type_hint(registry: &PortableRegistry, type_id: &str) -> String {
+    use scale_info::{TypeDef, TypeDefPrimitive};
+
+    let mut id = match type_id.trim().parse::<u32>() {
+        Ok(id) => id,
+        Err(_) => return type_id.to_string(),
+    };
+
+    loop {
+        let Some(ty) = registry.resolve(id) else {
+            return type_id.to_string();
+        };
+
+        match &ty.type_def {
+            TypeDef::Composite(composite) if composite.fields.len() == 1 => {
+                id = composite.fields[0].ty.id;
+            }
+            TypeDef::Primitive(primitive) => {
+                let name = match primitive {
+                    TypeDefPrimitive::Bool => "bool",
+                    TypeDefPrimitive::U8 => "u8",
+                    TypeDefPrimitive::U16 => "u16",
+                    TypeDefPrimitive::U32 => "u32",
+                    TypeDefPrimitive::U64 => "u64",
+                    TypeDefPrimitive::U128 => "u128",
+                    _ => return type_id.to_string(),
+                };
+                return name.to_string();
+            }
+            _ => return type_id.to_string(),
+        }
+    }
+}
+
 /// Build storage key from pallet name, storage item name, and optional keys
+///
+/// Double maps and NMaps take one key per hasher, in order; each key is encoded
+/// according to its own type and hashed with its own hasher.
 fn build_storage_key(
     pallet_name: &str,
     storage_name: &str,
     keys: &[String],
     storage_type: &StorageTypeInfo,
+    registry: &PortableRegistry,
 ) -> Result<String, PalletError> {
     use sp_crypto_hashing::twox_128;
 
@@ -604,7 +702,7 @@ fn build_storage_key(
         let (hashers, key_type) = match storage_type {
             StorageTypeInfo::Map { map } => (&map.hashers, &map.key),
             StorageTypeInfo::Plain { .. } => {
-                return Err(PalletError::PalletNotFound(
+                return Err(PalletError::InvalidStorageKeys(
                     "Keys provided for plain storage type".to_string(),
                 ));
             }
@@ -612,16 +710,20 @@ fn build_storage_key(
 
         // Validate key count matches hasher count
         if keys.len() != hashers.len() {
-            return Err(PalletError::PalletNotFound(format!(
+            return Err(PalletError::InvalidStorageKeys(format!(
                 "Expected {} key(s) but got {}",
                 hashers.len(),
                 keys.len()
             )));
         }
 
+        // Multi-key maps list their key type IDs comma-separated
+        let key_types: Vec<&str> = key_type.split(',').collect();
+
         // Hash each key with its corresponding hasher
-        for (key_str, hasher) in keys.iter().zip(hashers.iter()) {
-            let key_bytes = encode_key_value(key_str, key_type)?;
+        for (i, (key_str, hasher)) in keys.iter().zip(hashers.iter()).enumerate() {
+            let type_id = key_types.get(i).copied().unwrap_or(key_type);
+            let key_bytes = encode_key_value(key_str, &key_type_hint(registry, type_id))?;
             let hashed_key = hash_key(&key_bytes, hasher);
             key.extend_from_slice(&hashed_key);
         }
@@ -683,6 +785,207 @@ fn decode_storage_value(
     }
 }
 
+// ============================================================================
+// Storage Keys Handler
+// ============================================================================
+
+/// Page size used when listing storage keys without an explicit `count`.
+const DEFAULT_KEYS_COUNT: u32 = 100;
+
+/// Largest accepted `count` when listing storage keys.
+const MAX_KEYS_COUNT: u32 = 1000;
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StorageKeysQueryParams {
+    pub at: Option<String>,
+    /// Number of keys to return (default 100, at most 1000)
+    pub count: Option<u32>,
+    /// Return keys after this full storage key, as given by `nextStartKey`
+    pub start_key: Option<String>,
+}
+
+/// A storage key along with the map key(s) decoded from it
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageKeyEntry {
+    pub key: String,
+    /// Decoded map keys, in order. `null` for keys hashed with a non-concat hasher,
+    /// which can't be recovered
+    pub args: Vec<serde_json::Value>,
+}
+
+/// Response for /pallets/{palletId}/storage/{storageItemId}/keys endpoint
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PalletsStorageKeysResponse {
+    pub at: AtResponse,
+    pub pallet: String,
+    pub pallet_index: String,
+    pub storage_item: String,
+    pub keys: Vec<StorageKeyEntry>,
+    /// `startKey` for the next page, `null` once all keys were returned
+    pub next_start_key: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/pallets/{palletId}/storage/{storageItemId}/keys",
+    tag = "pallets",
+    summary = "Pallet storage map keys",
+    description = "Pages through the keys of a storage map via `state_getKeysPaged`, decoding each storage key back into its map key arguments. Pass the returned `nextStartKey` as `startKey` to get the next page.",
+    params(
+        ("palletId" = String, Path, description = "Name or index of the pallet"),
+        ("storageItemId" = String, Path, description = "Name of the storage map"),
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("count" = Option<u32>, Query, description = "Number of keys to return (default 100, max 1000)"),
+        ("startKey" = Option<String>, Query, description = "Full storage key to start after, as returned in `nextStartKey`")
+    ),
+    responses(
+        (status = 200, description = "Page of storage keys", body = Object),
+        (status = 400, description = "Invalid parameters or not a storage map"),
+        (status = 404, description = "Storage item not found"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_pallets_storage_item_keys(
+    State(state): State<AppState>,
+    Path((pallet_id, storage_item_id)): Path<(String, String)>,
+    JsonQuery(params): JsonQuery<StorageKeysQueryParams>,
+) -> Result<Json<PalletsStorageKeysResponse>, PalletError> {
+    let count = params.count.unwrap_or(DEFAULT_KEYS_COUNT);
+    if count == 0 || count > MAX_KEYS_COUNT {
+        return Err(PalletError::InvalidStorageKeys(format!(
+            "count must be between 1 and {MAX_KEYS_COUNT}"
+        )));
+    }
+    if let Some(start_key) = &params.start_key
+        && hex::decode(start_key.strip_prefix("0x").unwrap_or(start_key)).is_err()
+    {
+        return Err(PalletError::InvalidStorageKeys(format!(
+            "startKey '{start_key}' is not a hex-encoded storage key"
+        )));
+    }
+
+    let resolved = resolve_block_for_pallet(&state.client, params.at.as_ref()).await?;
+    let resolved_block = utils::ResolvedBlock {
+        hash: resolved.at.hash.clone(),
+        number: resolved.client_at_block.block_number(),
+    };
+    let metadata = resolved.client_at_block.metadata();
+
+    let (storage_response, storage_item) =
+        find_storage_item(&metadata, &pallet_id, &storage_item_id, &resolved_block)?;
+    let StorageTypeInfo::Map { map } = &storage_item.ty else {
+        return Err(PalletError::InvalidStorageKeys(format!(
+            "{} is a plain storage item and has no keys",
+            storage_item.name
+        )));
+    };
+
+    let original_pallet_name = get_original_pallet_name_subxt(&metadata, &pallet_id)?;
+    let prefix = build_storage_key(
+        &original_pallet_name,
+        &storage_item.name,
+        &[],
+        &storage_item.ty,
+        metadata.types(),
+    )?;
+
+    let raw_keys: Vec<String> = state
+        .rpc_client
+        .request(
+            "state_getKeysPaged",
+            rpc_params![&prefix, count, &params.start_key, &resolved.at.hash],
+        )
+        .await
+        .map_err(PalletError::StorageKeysFetchFailed)?;
+
+    let next_start_key = if raw_keys.len() == count as usize {
+        raw_keys.last().cloned()
+    } else {
+        None
+    };
+
+    let prefix_len = (prefix.len() - 2) / 2;
+    let keys = raw_keys
+        .into_iter()
+        .map(|key| {
+            let bytes = hex::decode(key.strip_prefix("0x").unwrap_or(&key)).unwrap_or_default();
+            let args = decode_storage_key_args(
+                bytes.get(prefix_len..).unwrap_or_default(),
+                map,
+                metadata.types(),
+                state.chain_info.ss58_prefix,
+            );
+            StorageKeyEntry { key, args }
+        })
+        .collect();
+
+    Ok(Json(PalletsStorageKeysResponse {
+        at: AtResponse {
+            hash: resolved_block.hash,
+            height: resolved_block.number.to_string(),
+        },
+        pallet: storage_response.pallet,
+        pallet_index: storage_response.pallet_index,
+        storage_item: to_camel_case(&storage_item.name),
+        keys,
+        next_start_key,
+    }))
+}
+
+/// Decode the map keys following the pallet/item prefix of a storage key.
+///
+/// Concat hashers (`Blake2_128Concat`, `Twox64Concat`, `Identity`) keep the encoded key
+/// after the hash, so it is decoded by its type. Other hashers only have a fixed-size
+/// hash, yielding `null`. Once a key can't be located, the remaining ones are `null`.
+fn decode_storage_key_args(
+    mut bytes: &[u8],
+    map: &MapTypeInfo,
+    registry: &PortableRegistry,
+    ss58_prefix: u16,
+) -> Vec<serde_json::Value> {
+    let key_types: Vec<&str> = map.key.split(',').collect();
+    let mut args = Vec::with_capacity(map.hashers.len());
+
+    for (i, hasher) in map.hashers.iter().enumerate() {
+        let (hash_len, concat) = match hasher.as_str() {
+            "Blake2_128Concat" => (16, true),
+            "Twox64Concat" => (8, true),
+            "Identity" => (0, true),
+            "Blake2_128" | "Twox128" => (16, false),
+            "Blake2_256" | "Twox256" => (32, false),
+            _ => break,
+        };
+        let Some(rest) = bytes.get(hash_len..) else {
+            break;
+        };
+        bytes = rest;
+
+        if !concat {
+            args.push(serde_json::Value::Null);
+            continue;
+        }
+
+        let Ok(type_id) = key_types.get(i).copied().unwrap_or(&map.key).trim().parse() else {
+            break;
+        };
+        let visitor = JsonVisitor::new(ss58_prefix, registry);
+        match decode_with_visitor(&mut bytes, type_id, registry, visitor) {
+            Ok(value) => args.push(value),
+            Err(e) => {
+                tracing::debug!("Failed to decode storage key (type_id={type_id}): {e}");
+                break;
+            }
+        }
+    }
+
+    args.resize(map.hashers.len(), serde_json::Value::Null);
+    args
+}
+
 // ============================================================================
 // RC (Relay Chain) Handlers
 // ============================================================================
@@ -691,12 +994,15 @@ fn decode_storage_value(
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct RcStorageItemQueryParams {
     pub at: Option<String>,
-    /// Storage keys for map types (format: ?keys[]=key1&keys[]=key2)
-    /// Note: serde_qs handles bracket notation automatically, no rename needed
-    #[serde(default)]
+    /// Storage keys for map types, one per hasher for double maps and NMaps.
+    /// Accepts `?keys[]=key1&keys[]=key2` or a comma-separated `?keys=key1,key2`
+    #[serde(default, deserialize_with = "deserialize_storage_keys")]
     pub keys: Vec<String>,
     #[serde(default)]
     pub metadata: bool,
+    /// When true, include the full hashed storage key in response
+    #[serde(default)]
+    pub compute_key: bool,
 }
 
 /// Handler for GET `/rc/pallets/{palletId}/storage`
@@ -757,8 +1063,9 @@ pub async fn rc_get_pallets_storage(
         ("palletId" = String, Path, description = "Name or index of the pallet"),
         ("storageItemId" = String, Path, description = "Name of the storage item"),
         ("at" = Option<String>, Query, description = "Block hash or number to query at"),
-        ("keys[]" = Option<Vec<String>>, Query, description = "Storage key arguments"),
-        ("metadata" = Option<bool>, Query, description = "Include metadata for the storage item")
+        ("keys[]" = Option<Vec<String>>, Query, description = "Storage key arguments, one per key of a double map or NMap, in order (also accepts comma-separated keys=a,b)"),
+        ("metadata" = Option<bool>, Query, description = "Include metadata for the storage item"),
+        ("computeKey" = Option<bool>, Query, description = "Include the full hashed storage key used for the query")
     ),
     responses(
         (status = 200, description = "Relay chain storage item value", body = Object),
@@ -803,6 +1110,7 @@ pub async fn rc_get_pallets_storage_item(
         &params.keys,
         &resolved,
         params.metadata,
+        params.compute_key,
         &block_hash,
         rc_ss58_prefix,
     )
@@ -904,6 +1212,32 @@ fn build_storage_response(
     }
 }
 
+/// Look up a storage item (case-insensitively) in a pallet's storage metadata.
+///
+/// Returns the pallet's storage response (for its name and index) along with the item.
+fn find_storage_item(
+    metadata: &Metadata,
+    pallet_id: &str,
+    storage_item_id: &str,
+    resolved_block: &utils::ResolvedBlock,
+) -> Result<(PalletsStorageResponse, StorageItemMetadata), PalletError> {
+    let storage_response = build_storage_response(metadata, pallet_id, resolved_block, false)?;
+
+    let storage_item = match &storage_response.items {
+        StorageItems::Full(items) => items
+            .iter()
+            .find(|item| item.name.eq_ignore_ascii_case(storage_item_id))
+            .cloned(),
+        StorageItems::OnlyIds(_) => None,
+    }
+    .ok_or_else(|| PalletError::StorageItemNotFound {
+        pallet: pallet_id.to_string(),
+        item: storage_item_id.to_string(),
+    })?;
+
+    Ok((storage_response, storage_item))
+}
+
 /// Build storage item response using subxt's Metadata (all versions V9-V16 via cached metadata).
 #[allow(clippy::too_many_arguments)]
 async fn build_storage_item_response(
@@ -914,28 +1248,12 @@ async fn build_storage_item_response(
     keys: &[String],
     resolved_block: &utils::ResolvedBlock,
     include_metadata: bool,
+    compute_key: bool,
     block_hash: &str,
     ss58_prefix: u16,
 ) -> Result<PalletsStorageItemResponse, PalletError> {
-    let storage_response = build_storage_response(metadata, pallet_id, resolved_block, false)?;
-
-    let storage_items = match &storage_response.items {
-        StorageItems::Full(items) => items,
-        StorageItems::OnlyIds(_) => {
-            return Err(PalletError::StorageItemNotFound {
-                pallet: pallet_id.to_string(),
-                item: storage_item_id.to_string(),
-            });
-        }
-    };
-
-    let storage_item = storage_items
-        .iter()
-        .find(|item| item.name.eq_ignore_ascii_case(storage_item_id))
-        .ok_or_else(|| PalletError::StorageItemNotFound {
-            pallet: pallet_id.to_string(),
-            item: storage_item_id.to_string(),
-        })?;
+    let (storage_response, storage_item) =
+        find_storage_item(metadata, pallet_id, storage_item_id, resolved_block)?;
 
     let original_pallet_name = get_original_pallet_name_subxt(metadata, pallet_id)?;
 
@@ -944,6 +1262,7 @@ async fn build_storage_item_response(
         &storage_item.name,
         keys,
         &storage_item.ty,
+        metadata.types(),
     )?;
 
     let value_hex: Option<String> = rpc_client
@@ -968,6 +1287,7 @@ async fn build_storage_item_response(
         pallet_index: storage_response.pallet_index,
         storage_item: to_camel_case(&storage_item.name),
         keys: keys.to_vec(),
+        key: compute_key.then_some(storage_key),
         value,
         metadata: metadata_field,
         rc_block_hash: None,
@@ -1048,4 +1368,206 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
+
+    /// Newtype key, like `EraIndex` wrappers in some runtimes
+    #[derive(scale_info::TypeInfo)]
+    #[allow(dead_code)]
+    struct Wrapped(u64);
+
+    /// Registry with a `u16` and a `Wrapped` key type, and their type IDs.
+    fn key_registry() -> (PortableRegistry, u32, u32) {
+        let mut registry = scale_info::Registry::new();
+        let u16_id = registry
+            .register_type(&scale_info::MetaType::new::<u16>())
+            .id;
+        let wrapped_id = registry
+            .register_type(&scale_info::MetaType::new::<Wrapped>())
+            .id;
+        (registry.into(), u16_id, wrapped_id)
+    }
+
+    fn double_map(key: String) -> StorageTypeInfo {
+        StorageTypeInfo::Map {
+            map: MapTypeInfo {
+                hashers: vec!["Twox64Concat".to_string(), "Blake2_128Concat".to_string()],
+                key,
+                value: "0".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_key_type_hint_resolves_primitives_and_newtypes() {
+        let (registry, u16_id, wrapped_id) = key_registry();
+
+        assert_eq!(key_type_hint(&registry, &u16_id.to_string()), "u16");
+        assert_eq!(key_type_hint(&registry, &format!(" {wrapped_id}")), "u64");
+        assert_eq!(key_type_hint(&registry, "9999"), "9999");
+    }
+
+    #[test]
+    fn test_build_storage_key_double_map() {
+        let (registry, u16_id, wrapped_id) = key_registry();
+        let storage_type = double_map(format!("{u16_id}, {wrapped_id}"));
+
+        let key = build_storage_key(
+            "Pallet",
+            "Item",
+            &["7".to_string(), "9".to_string()],
+            &storage_type,
+            &registry,
+        )
+        .unwrap();
+
+        let mut expected = sp_crypto_hashing::twox_128(b"Pallet").to_vec();
+        expected.extend(sp_crypto_hashing::twox_128(b"Item"));
+        expected.extend(hash_key(&7u16.to_le_bytes(), "Twox64Concat"));
+        expected.extend(hash_key(&9u64.to_le_bytes(), "Blake2_128Concat"));
+        assert_eq!(key, format!("0x{}", hex::encode(expected)));
+    }
+
+    #[test]
+    fn test_build_storage_key_wrong_arity_is_bad_request() {
+        let (registry, u16_id, wrapped_id) = key_registry();
+        let storage_type = double_map(format!("{u16_id}, {wrapped_id}"));
+
+        let err = build_storage_key(
+            "Pallet",
+            "Item",
+            &["7".to_string()],
+            &storage_type,
+            &registry,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, PalletError::InvalidStorageKeys(_)));
+        assert_eq!(
+            err.into_response().status(),
+            axum::http::StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[test]
+    fn test_decode_storage_key_args_double_map() {
+        let (registry, u16_id, wrapped_id) = key_registry();
+        let storage_type = double_map(format!("{u16_id}, {wrapped_id}"));
+        let StorageTypeInfo::Map { map } = &storage_type else {
+            unreachable!()
+        };
+
+        let key = build_storage_key(
+            "Pallet",
+            "Item",
+            &["7".to_string(), "9".to_string()],
+            &storage_type,
+            &registry,
+        )
+        .unwrap();
+        let bytes = hex::decode(&key[2..]).unwrap();
+
+        let args = decode_storage_key_args(&bytes[32..], map, &registry, 0);
+        assert_eq!(args, vec![serde_json::json!("7"), serde_json::json!("9")]);
+    }
+
+    #[test]
+    fn test_decode_storage_key_args_opaque_hasher_is_null() {
+        let (registry, u16_id, wrapped_id) = key_registry();
+        let map = MapTypeInfo {
+            hashers: vec!["Blake2_128".to_string(), "Twox64Concat".to_string()],
+            key: format!("{u16_id}, {wrapped_id}"),
+            value: "0".to_string(),
+        };
+
+        let mut bytes = hash_key(&7u16.to_le_bytes(), "Blake2_128");
+        bytes.extend(hash_key(&9u64.to_le_bytes(), "Twox64Concat"));
+
+        let args = decode_storage_key_args(&bytes, &map, &registry, 0);
+        assert_eq!(args, vec![serde_json::Value::Null, serde_json::json!("9")]);
+
+        // Truncated keys yield nulls instead of failing
+        let args = decode_storage_key_args(&bytes[..20], &map, &registry, 0);
+        assert_eq!(args, vec![serde_json::Value::Null, serde_json::Value::Null]);
+    }
+
+    #[test]
+    fn test_storage_keys_query_params() {
+        let params: StorageKeysQueryParams =
+            serde_json::from_str(r#"{"count": 10, "startKey": "0x00"}"#).unwrap();
+        assert_eq!(params.count, Some(10));
+        assert_eq!(params.start_key.as_deref(), Some("0x00"));
+
+        let result: Result<StorageKeysQueryParams, _> =
+            serde_json::from_str(r#"{"unknownField": true}"#);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_compute_key_returned_for_absent_value() {
+        use crate::test_fixtures::{ASSET_HUB_METADATA, TEST_BLOCK_HASH, mock_rpc_client_builder};
+        use parity_scale_codec::Decode;
+        use sp_core::crypto::Ss58Codec;
+        use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+        let metadata = Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let rpc_client = RpcClient::new(
+            mock_rpc_client_builder()
+                .method_handler("state_getStorage", async |_params| {
+                    MockJson(serde_json::Value::Null)
+                })
+                .build(),
+        );
+        let block = utils::ResolvedBlock {
+            hash: TEST_BLOCK_HASH.to_string(),
+            number: 1,
+        };
+        let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".to_string();
+
+        let query = |compute_key| {
+            build_storage_item_response(
+                &rpc_client,
+                &metadata,
+                "System",
+                "Account",
+                std::slice::from_ref(&alice),
+                &block,
+                false,
+                compute_key,
+                TEST_BLOCK_HASH,
+                0,
+            )
+        };
+
+        let account = sp_core::crypto::AccountId32::from_ss58check(&alice).unwrap();
+        let account: &[u8] = account.as_ref();
+        let mut expected = sp_crypto_hashing::twox_128(b"System").to_vec();
+        expected.extend(sp_crypto_hashing::twox_128(b"Account"));
+        expected.extend(hash_key(account, "Blake2_128Concat"));
+
+        let response = query(true).await.unwrap();
+        assert_eq!(response.key, Some(format!("0x{}", hex::encode(expected))));
+
+        let response = query(false).await.unwrap();
+        assert!(response.key.is_none());
+        assert!(
+            serde_json::to_value(&response)
+                .unwrap()
+                .get("key")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_keys_accept_brackets_and_csv() {
+        let config = serde_qs::Config::new(5, false);
+
+        let params: StorageItemQueryParams =
+            config.deserialize_str("keys[]=100&keys[]=0xabcd").unwrap();
+        assert_eq!(params.keys, vec!["100", "0xabcd"]);
+
+        let params: StorageItemQueryParams = config.deserialize_str("keys=100,%200xabcd").unwrap();
+        assert_eq!(params.keys, vec!["100", "0xabcd"]);
+
+        let params: StorageItemQueryParams = config.deserialize_str("at=1").unwrap();
+        assert!(params.keys.is_empty());
+    }
 }
@@ -3,7 +3,9 @@
 
 //! Handlers for parachain-related endpoints.
 
+pub mod paras_head;
 pub mod paras_inclusion;
 mod relay_parent_visitor;
 
+pub use paras_head::get_paras_header;
 pub use paras_inclusion::get_paras_inclusion;
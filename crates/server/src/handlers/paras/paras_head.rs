@@ -0,0 +1,282 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::extractors::JsonQuery;
+use crate::handlers::runtime_queries::paras::{self as paras_storage, ParasStorageError};
+use crate::state::{AppState, RelayChainError, SubstrateLegacyRpc};
+use crate::utils::{self, BlockId};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use parity_scale_codec::{Compact, Decode};
+use polkadot_rest_api_config::ChainType;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use subxt::{OnlineClient, SubstrateConfig};
+use subxt_rpcs::RpcClient;
+use thiserror::Error;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ParasHeaderQueryParams {
+    /// Relay chain block at which to read the head (hash or number)
+    pub at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct At {
+    pub hash: String,
+    pub height: String,
+}
+
+/// Header decoded from the head data stored on the relay chain.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParaHeader {
+    pub parent_hash: String,
+    pub number: String,
+    pub state_root: String,
+    pub extrinsics_root: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParasHeaderResponse {
+    pub at: At,
+    pub para_id: String,
+    pub header: ParaHeader,
+}
+
+#[derive(Debug, Error)]
+pub enum ParasHeaderError {
+    #[error("Invalid paraId: {0}")]
+    InvalidParaId(String),
+
+    #[error("Invalid block parameter: {0}")]
+    InvalidBlockParam(#[from] utils::BlockIdParseError),
+
+    #[error("{0}")]
+    BlockResolveFailed(#[from] utils::BlockResolveError),
+
+    #[error("Failed to get client at block: {0}")]
+    ClientAtBlockFailed(#[from] subxt::error::OnlineClientAtBlockError),
+
+    #[error(transparent)]
+    RelayChain(#[from] RelayChainError),
+
+    #[error("Paras pallet not available at this block")]
+    PalletNotAvailable,
+
+    #[error("Parachain {0} is not registered")]
+    ParaNotFound(u32),
+
+    #[error("{0}")]
+    StorageFailed(ParasStorageError),
+
+    #[error("Failed to decode head data of parachain {0}")]
+    HeadDecodeFailed(u32),
+}
+
+impl From<ParasStorageError> for ParasHeaderError {
+    fn from(err: ParasStorageError) -> Self {
+        match err {
+            ParasStorageError::PalletNotAvailable => ParasHeaderError::PalletNotAvailable,
+            other => ParasHeaderError::StorageFailed(other),
+        }
+    }
+}
+
+impl IntoResponse for ParasHeaderError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ParasHeaderError::InvalidParaId(_)
+            | ParasHeaderError::InvalidBlockParam(_)
+            | ParasHeaderError::PalletNotAvailable => StatusCode::BAD_REQUEST,
+
+            ParasHeaderError::ParaNotFound(_) => StatusCode::NOT_FOUND,
+
+            ParasHeaderError::BlockResolveFailed(err) => err.status_code(),
+
+            ParasHeaderError::ClientAtBlockFailed(err) => {
+                if utils::is_online_client_at_block_disconnected(err) {
+                    StatusCode::SERVICE_UNAVAILABLE
+                } else {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            }
+
+            ParasHeaderError::RelayChain(RelayChainError::NotConfigured) => StatusCode::BAD_REQUEST,
+            ParasHeaderError::RelayChain(RelayChainError::ConnectionFailed(_)) => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
+
+            ParasHeaderError::StorageFailed(_) | ParasHeaderError::HeadDecodeFailed(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/paras/{paraId}/header",
+    tag = "paras",
+    summary = "Parachain head from relay state",
+    description = "Returns the latest included head of a parachain, read from `Paras::Heads` in relay chain state and decoded into a header. Works without a connection to the parachain itself.",
+    params(
+        ("paraId" = String, Path, description = "Parachain ID"),
+        ("at" = Option<String>, Query, description = "Relay chain block hash or number to query at (defaults to latest finalized)")
+    ),
+    responses(
+        (status = 200, description = "Decoded parachain header", body = Object),
+        (status = 400, description = "Invalid parameters"),
+        (status = 404, description = "Parachain is not registered"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_paras_header(
+    State(state): State<AppState>,
+    Path(para_id): Path<String>,
+    JsonQuery(params): JsonQuery<ParasHeaderQueryParams>,
+) -> Result<Json<ParasHeaderResponse>, ParasHeaderError> {
+    let para_id: u32 = para_id
+        .parse()
+        .map_err(|_| ParasHeaderError::InvalidParaId(para_id.clone()))?;
+
+    let block_id = params
+        .at
+        .as_ref()
+        .map(|s| s.parse::<BlockId>())
+        .transpose()?;
+
+    let (relay_client, relay_rpc_client, relay_rpc) = get_relay_chain_access(&state).await?;
+    let resolved_block =
+        utils::resolve_block_with_rpc(&relay_rpc_client, relay_rpc.as_ref(), block_id).await?;
+    let block_hash = resolved_block
+        .hash
+        .parse::<subxt::utils::H256>()
+        .map_err(|e| utils::BlockResolveError::NotFound(e.to_string()))?;
+    let client_at_block = relay_client.at_block(block_hash).await?;
+
+    let head_data = paras_storage::get_para_head(&client_at_block, para_id)
+        .await?
+        .ok_or(ParasHeaderError::ParaNotFound(para_id))?;
+    let header =
+        decode_para_header(&head_data).ok_or(ParasHeaderError::HeadDecodeFailed(para_id))?;
+
+    Ok(Json(ParasHeaderResponse {
+        at: At {
+            hash: resolved_block.hash,
+            height: resolved_block.number.to_string(),
+        },
+        para_id: para_id.to_string(),
+        header,
+    }))
+}
+
+/// Relay chain state lives on the primary client when connected to a relay chain,
+/// otherwise on the configured relay chain connection.
+async fn get_relay_chain_access(
+    state: &AppState,
+) -> Result<
+    (
+        Arc<OnlineClient<SubstrateConfig>>,
+        Arc<RpcClient>,
+        Arc<SubstrateLegacyRpc>,
+    ),
+    ParasHeaderError,
+> {
+    if state.chain_info.chain_type == ChainType::Relay {
+        return Ok((
+            state.client.clone(),
+            state.rpc_client.clone(),
+            state.legacy_rpc.clone(),
+        ));
+    }
+
+    Ok((
+        state.get_relay_chain_client().await?,
+        state.get_relay_chain_rpc_client().await?,
+        state.get_relay_chain_rpc().await?,
+    ))
+}
+
+/// Decode the leading fields of a SCALE-encoded parachain header.
+///
+/// Head data starts with `parent_hash`, a compact `number`, `state_root` and
+/// `extrinsics_root`; the trailing digest is not needed here.
+fn decode_para_header(head_data: &[u8]) -> Option<ParaHeader> {
+    let mut cursor = head_data;
+    let parent_hash = <[u8; 32]>::decode(&mut cursor).ok()?;
+    let number = Compact::<u32>::decode(&mut cursor).ok()?;
+    let state_root = <[u8; 32]>::decode(&mut cursor).ok()?;
+    let extrinsics_root = <[u8; 32]>::decode(&mut cursor).ok()?;
+
+    Some(ParaHeader {
+        parent_hash: format!("0x{}", hex::encode(parent_hash)),
+        number: number.0.to_string(),
+        state_root: format!("0x{}", hex::encode(state_root)),
+        extrinsics_root: format!("0x{}", hex::encode(extrinsics_root)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+
+    fn encoded_header(number: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&[0x11; 32]);
+        Compact(number).encode_to(&mut bytes);
+        bytes.extend_from_slice(&[0x22; 32]);
+        bytes.extend_from_slice(&[0x33; 32]);
+        // Empty digest
+        Compact(0u32).encode_to(&mut bytes);
+        bytes
+    }
+
+    #[test]
+    fn test_decode_para_header() {
+        let header = decode_para_header(&encoded_header(1_234_567)).unwrap();
+
+        assert_eq!(header.number, "1234567");
+        assert_eq!(header.parent_hash, format!("0x{}", "11".repeat(32)));
+        assert_eq!(header.state_root, format!("0x{}", "22".repeat(32)));
+        assert_eq!(header.extrinsics_root, format!("0x{}", "33".repeat(32)));
+    }
+
+    #[test]
+    fn test_decode_para_header_truncated() {
+        let bytes = encoded_header(1);
+        assert!(decode_para_header(&bytes[..40]).is_none());
+        assert!(decode_para_header(&[]).is_none());
+    }
+
+    #[test]
+    fn test_unregistered_para_is_not_found() {
+        let response = ParasHeaderError::ParaNotFound(2000).into_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_missing_pallet_is_bad_request() {
+        let err = ParasHeaderError::from(ParasStorageError::PalletNotAvailable);
+        assert!(matches!(err, ParasHeaderError::PalletNotAvailable));
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_query_params_reject_unknown_fields() {
+        let result: Result<ParasHeaderQueryParams, _> =
+            serde_json::from_str(r#"{"at": "10", "depth": "5"}"#);
+        assert!(result.is_err());
+    }
+}
@@ -5,7 +5,10 @@ use crate::extractors::JsonQuery;
 use crate::handlers::common::candidate_types::CandidateIncludedEvent;
 use crate::handlers::runtime_queries::parachain_info;
 use crate::state::{AppState, RelayChainError};
-use crate::utils::{self, extract_block_number_from_header, run_with_concurrency};
+use crate::utils::{
+    self, BlockId, ResolvedBlock, extract_block_number_from_header, resolve_block,
+    run_with_concurrency,
+};
 use axum::{
     Json,
     extract::{Path, State},
@@ -15,7 +18,7 @@ use axum::{
 use futures::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use subxt::{OnlineClient, SubstrateConfig};
+use subxt::{OnlineClient, OnlineClientAtBlock, SubstrateConfig};
 use thiserror::Error;
 use tracing::warn;
 
@@ -29,9 +32,16 @@ pub struct ParasInclusionQueryParams {
     pub depth: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct At {
+    pub hash: String,
+    pub height: String,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParasInclusionResponse {
+    pub at: At,
     pub parachain_block: u64,
     pub parachain_block_hash: String,
     pub parachain_id: u32,
@@ -54,8 +64,8 @@ pub enum ParasInclusionError {
     #[error("Invalid block parameter: {0}")]
     InvalidBlockParam(String),
 
-    #[error("Block {0} not found")]
-    BlockNotFound(u64),
+    #[error("{0}")]
+    BlockResolveFailed(#[from] utils::BlockResolveError),
 
     #[error(
         "Block does not contain setValidationData extrinsic. Cannot determine relay parent number."
@@ -92,6 +102,12 @@ impl From<utils::AtBlockError> for ParasInclusionError {
     }
 }
 
+impl From<utils::BlockIdParseError> for ParasInclusionError {
+    fn from(err: utils::BlockIdParseError) -> Self {
+        ParasInclusionError::InvalidBlockParam(err.to_string())
+    }
+}
+
 impl From<subxt::error::OnlineClientAtBlockError> for ParasInclusionError {
     fn from(err: subxt::error::OnlineClientAtBlockError) -> Self {
         ParasInclusionError::from(utils::AtBlockError::from(err))
@@ -105,7 +121,6 @@ impl IntoResponse for ParasInclusionError {
             | ParasInclusionError::DepthTooLarge
             | ParasInclusionError::InvalidBlockParam(_)
             | ParasInclusionError::DepthNotOptimal
-            | ParasInclusionError::BlockNotFound(_)
             | ParasInclusionError::NotAParachain => StatusCode::BAD_REQUEST,
 
             ParasInclusionError::RelayChain(RelayChainError::NotConfigured) => {
@@ -120,6 +135,8 @@ impl IntoResponse for ParasInclusionError {
             | ParasInclusionError::ClientAtBlockFailed(_)
             | ParasInclusionError::EventsFetchFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
 
+            ParasInclusionError::BlockResolveFailed(err) => err.status_code(),
+
             ParasInclusionError::RpcFailed(err) => {
                 let (status, message) = crate::utils::rpc_error_to_status(err);
                 return (status, Json(json!({ "error": message }))).into_response();
@@ -143,9 +160,9 @@ fn default_depth() -> String {
     path = "/v1/paras/{number}/inclusion",
     tag = "paras",
     summary = "Parachain inclusion data",
-    description = "Returns inclusion information for a given parachain block, searching relay chain blocks for when the parachain block was included.",
+    description = "Returns inclusion information for a given parachain block, identified by number or hash, searching relay chain blocks for when the parachain block was included.",
     params(
-        ("number" = String, Path, description = "Parachain block number"),
+        ("number" = String, Path, description = "Parachain block number or hash"),
         ("depth" = Option<String>, Query, description = "Search depth for relay chain blocks (max 100, default 10, must be divisible by 5)")
     ),
     responses(
@@ -162,18 +179,20 @@ pub async fn get_paras_inclusion(
 ) -> Result<Json<ParasInclusionResponse>, ParasInclusionError> {
     let search_depth = validate_depth(params.depth)?;
 
-    let block_number: u64 = number
-        .parse()
-        .map_err(|_| ParasInclusionError::InvalidBlockParam(number.clone()))?;
+    let block_id = number.parse::<BlockId>()?;
+    let ResolvedBlock {
+        hash: block_hash,
+        number: block_number,
+    } = resolve_block(&state, Some(block_id)).await?;
 
-    let block_hash = state
-        .get_block_hash_at_number(block_number)
-        .await
-        .map_err(ParasInclusionError::RpcFailed)?
-        .ok_or(ParasInclusionError::BlockNotFound(block_number))?;
+    // Read everything from the resolved block so a hash pins the exact state
+    let block_hash_h256 = block_hash
+        .parse::<subxt::utils::H256>()
+        .map_err(|e| ParasInclusionError::InvalidBlockParam(e.to_string()))?;
+    let client_at_block = state.client.at_block(block_hash_h256).await?;
 
-    let para_id = get_parachain_id(&state, block_number).await?;
-    let relay_parent_number = extract_relay_parent_number(&state, &block_hash).await?;
+    let para_id = get_parachain_id(&client_at_block).await?;
+    let relay_parent_number = extract_relay_parent_number(&client_at_block, &block_hash).await?;
 
     let relay_client = state.get_relay_chain_client().await?;
 
@@ -187,6 +206,10 @@ pub async fn get_paras_inclusion(
     .await;
 
     Ok(Json(ParasInclusionResponse {
+        at: At {
+            hash: block_hash.clone(),
+            height: block_number.to_string(),
+        },
         parachain_block: block_number,
         parachain_block_hash: block_hash,
         parachain_id: para_id,
@@ -209,24 +232,18 @@ fn validate_depth(depth: String) -> Result<u32, ParasInclusionError> {
     }
 }
 
-async fn get_parachain_id(state: &AppState, block_number: u64) -> Result<u32, ParasInclusionError> {
-    let client_at_block = state.client.at_block(block_number).await?;
-
-    parachain_info::get_parachain_id(&client_at_block)
+async fn get_parachain_id(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+) -> Result<u32, ParasInclusionError> {
+    parachain_info::get_parachain_id(client_at_block)
         .await
         .map_err(|_| ParasInclusionError::NotAParachain)
 }
 
 async fn extract_relay_parent_number(
-    state: &AppState,
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     block_hash: &str,
 ) -> Result<u64, ParasInclusionError> {
-    let block_hash_h256 = block_hash
-        .parse::<subxt::utils::H256>()
-        .map_err(|e| ParasInclusionError::InvalidBlockParam(e.to_string()))?;
-
-    let client_at_block = state.client.at_block(block_hash_h256).await?;
-
     let extrinsics = client_at_block
         .extrinsics()
         .fetch()
@@ -420,6 +437,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_block_param_accepts_number_and_hash() {
+        assert!(matches!(
+            "12345".parse::<BlockId>(),
+            Ok(BlockId::Number(12345))
+        ));
+        assert!(matches!(
+            "0x1234567890123456789012345678901234567890123456789012345678901234".parse::<BlockId>(),
+            Ok(BlockId::Hash(_))
+        ));
+
+        let err: ParasInclusionError = "0xnothex".parse::<BlockId>().unwrap_err().into();
+        assert!(matches!(err, ParasInclusionError::InvalidBlockParam(_)));
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_block_not_found_is_bad_request() {
+        let err = ParasInclusionError::from(utils::BlockResolveError::NotFound(
+            "Block at height 42 not found".to_string(),
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Block not found: Block at height 42 not found"
+        );
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_response_includes_at() {
+        let response = ParasInclusionResponse {
+            at: At {
+                hash: "0xabc".to_string(),
+                height: "7".to_string(),
+            },
+            parachain_block: 7,
+            parachain_block_hash: "0xabc".to_string(),
+            parachain_id: 1000,
+            relay_parent_number: 100,
+            inclusion_number: None,
+            found: false,
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["at"]["hash"], "0xabc");
+        assert_eq!(json["at"]["height"], "7");
+    }
+
     #[test]
     fn test_paras_inclusion_query_params_rejects_unknown_fields() {
         let json = r#"{"depth": "10", "unknownField": true}"#;
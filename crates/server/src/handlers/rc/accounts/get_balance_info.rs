@@ -31,7 +31,8 @@ use polkadot_rest_api_config::ChainType;
 /// Query Parameters:
 /// - `at` (optional): Block identifier (hash or height) - defaults to latest finalized
 /// - `token` (optional): Token symbol (defaults to native token)
-/// - `denominated` (optional): When true, denominate balances using chain decimals
+/// - `denominated` (optional): Denominate balances using chain decimals (default: true).
+///   When false, raw planck values are returned as integer strings.
 #[utoipa::path(
     get,
     path = "/v1/rc/accounts/{accountId}/balance-info",
@@ -42,7 +43,7 @@ use polkadot_rest_api_config::ChainType;
         ("accountId" = String, Path, description = "SS58-encoded account address"),
         ("at" = Option<String>, Query, description = "Block identifier (number or hash)"),
         ("token" = Option<String>, Query, description = "Token symbol (defaults to native token)"),
-        ("denominated" = Option<bool>, Query, description = "Denominate balances using chain decimals")
+        ("denominated" = Option<bool>, Query, description = "Denominate balances using chain decimals (default: true). When false, raw planck values are returned")
     ),
     responses(
         (status = 200, description = "Balance information", body = RcBalanceInfoResponse),
@@ -79,6 +80,7 @@ pub async fn get_balance_info(
         &account,
         &resolved_block,
         params.token.clone(),
+        None,
     )
     .await?;
 
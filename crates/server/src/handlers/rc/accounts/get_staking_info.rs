@@ -187,6 +187,16 @@ fn format_response(raw: &RawStakingInfo) -> RcStakingInfoResponse {
         active: raw.staking.active.clone(),
         unlocking,
         claimed_rewards,
+        ledger_claimed_rewards: raw
+            .staking
+            .ledger_claimed_rewards
+            .as_ref()
+            .map(|claimed| claimed.eras.iter().map(|era| era.to_string()).collect()),
+        ledger_claimed_rewards_source: raw
+            .staking
+            .ledger_claimed_rewards
+            .as_ref()
+            .map(|claimed| claimed.source.as_str().to_string()),
     };
 
     RcStakingInfoResponse {
@@ -196,6 +206,7 @@ fn format_response(raw: &RawStakingInfo) -> RcStakingInfoResponse {
         },
         controller: raw.controller.clone(),
         reward_destination,
+        payee_account: raw.payee_account.clone(),
         num_slashing_spans: raw.num_slashing_spans.to_string(),
         nominations,
         staking,
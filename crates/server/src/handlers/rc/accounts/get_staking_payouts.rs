@@ -18,6 +18,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use polkadot_rest_api_config::ChainType;
+use sp_core::crypto::Ss58Codec;
 
 // ================================================================================================
 // Main Handler
@@ -38,9 +39,9 @@ use polkadot_rest_api_config::ChainType;
     path = "/v1/rc/accounts/{accountId}/staking-payouts",
     tag = "rc",
     summary = "RC get staking payouts",
-    description = "Returns staking payout information for a given account on the relay chain.",
+    description = "Returns staking payout information for a given account on the relay chain. The account may be a stash or a controller; a controller is resolved to its stash, and `accountType` reports which one was given.",
     params(
-        ("accountId" = String, Path, description = "SS58-encoded account address"),
+        ("accountId" = String, Path, description = "SS58-encoded stash or controller account address"),
         ("at" = Option<String>, Query, description = "Block identifier (number or hash)"),
         ("depth" = Option<u32>, Query, description = "Number of eras to query (default: 1)"),
         ("era" = Option<u32>, Query, description = "The era to query at (default: active_era - 1)"),
@@ -107,7 +108,7 @@ pub async fn get_staking_payouts(
     )
     .await?;
 
-    let response = format_response(&raw_payouts);
+    let response = format_response(&raw_payouts, rc_ss58_prefix);
 
     Ok(Json(response).into_response())
 }
@@ -154,7 +155,7 @@ async fn get_relay_chain_access(state: &AppState) -> Result<RelayChainAccess, Ac
 // Response Formatting
 // ================================================================================================
 
-fn format_response(raw: &RawStakingPayouts) -> RcStakingPayoutsResponse {
+fn format_response(raw: &RawStakingPayouts, ss58_prefix: u16) -> RcStakingPayoutsResponse {
     let eras_payouts = raw
         .eras_payouts
         .iter()
@@ -188,6 +189,11 @@ fn format_response(raw: &RawStakingPayouts) -> RcStakingPayoutsResponse {
             hash: raw.block.hash.clone(),
             height: raw.block.number.to_string(),
         },
+        account_type: raw.account_type.map(|t| t.as_str().to_string()),
+        stash: raw
+            .account_type
+            .map(|_| raw.stash.to_ss58check_with_version(ss58_prefix.into())),
+        history_depth: raw.history_depth.to_string(),
         eras_payouts,
     }
 }
@@ -125,6 +125,8 @@ fn format_response(raw: &RawVestingInfo) -> RcVestingInfoResponse {
             locked: s.locked.clone(),
             per_block: s.per_block.clone(),
             starting_block: s.starting_block.clone(),
+            unlocked_at: s.unlocked_at.clone(),
+            currently_vested: s.currently_vested.clone(),
         })
         .collect();
 
@@ -134,5 +136,8 @@ fn format_response(raw: &RawVestingInfo) -> RcVestingInfoResponse {
             height: raw.block.number.to_string(),
         },
         vesting: schedules,
+        total_locked: raw.total_locked.clone(),
+        total_vested: raw.total_vested.clone(),
+        total_remaining: raw.total_remaining.clone(),
     }
 }
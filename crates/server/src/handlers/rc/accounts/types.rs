@@ -48,11 +48,15 @@ pub struct RcBalanceInfoQueryParams {
     #[serde(default)]
     pub token: Option<String>,
 
-    /// When true, denominate balances using chain decimals
-    #[serde(default)]
+    /// Denominate balances using chain decimals (default: true)
+    #[serde(default = "default_denominated")]
     pub denominated: bool,
 }
 
+fn default_denominated() -> bool {
+    true
+}
+
 /// Response for GET /rc/accounts/{accountId}/balance-info
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -136,6 +140,15 @@ pub struct RcVestingInfoResponse {
 
     /// Array of vesting schedules (empty array if no vesting)
     pub vesting: Vec<VestingSchedule>,
+
+    /// Sum of `locked` across all schedules
+    pub total_locked: String,
+
+    /// Sum of the amounts vested as of the queried block
+    pub total_vested: String,
+
+    /// Amount still locked as of the queried block
+    pub total_remaining: String,
 }
 
 // ================================================================================================
@@ -167,6 +180,10 @@ pub struct RcStakingInfoResponse {
     /// Where rewards are paid to
     pub reward_destination: RewardDestination,
 
+    /// SS58 address that receives staking rewards (absent when rewards are not paid out)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payee_account: Option<String>,
+
     /// Number of slashing spans
     pub num_slashing_spans: String,
 
@@ -189,7 +206,7 @@ pub struct RcStakingPayoutsQueryParams {
     #[serde(default)]
     pub at: Option<String>,
 
-    /// Number of eras to query. Must be less than HISTORY_DEPTH. Defaults to 1.
+    /// Number of eras to query. Must be at most HISTORY_DEPTH. Defaults to 1.
     #[serde(default = "default_depth")]
     pub depth: u32,
 
@@ -216,6 +233,18 @@ fn default_unclaimed_only() -> bool {
 pub struct RcStakingPayoutsResponse {
     pub at: BlockInfo,
 
+    /// Whether the queried address was detected as a `stash` or a `controller`.
+    /// Omitted when the address isn't bonded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+
+    /// Stash the payouts were computed for, resolved from the controller if needed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stash: Option<String>,
+
+    /// History depth the requested era range was validated against
+    pub history_depth: String,
+
     /// Array of era payouts
     pub eras_payouts: Vec<EraPayouts>,
 }
@@ -232,6 +261,16 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
 
+    #[test]
+    fn test_rc_balance_info_query_params_denominated_defaults_to_true() {
+        let params: RcBalanceInfoQueryParams = serde_json::from_str("{}").unwrap();
+        assert!(params.denominated);
+
+        let params: RcBalanceInfoQueryParams =
+            serde_json::from_str(r#"{"denominated": false}"#).unwrap();
+        assert!(!params.denominated);
+    }
+
     #[test]
     fn test_rc_proxy_info_query_params_rejects_unknown_fields() {
         let json = r#"{"at": "123", "unknownField": true}"#;
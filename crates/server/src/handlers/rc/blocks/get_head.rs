@@ -13,8 +13,8 @@ use crate::handlers::blocks::common::{
 use crate::handlers::blocks::decode::XcmDecoder;
 use crate::handlers::blocks::docs::Docs;
 use crate::handlers::blocks::processing::{
-    categorize_events, extract_extrinsics_with_prefix, extract_fee_info_for_extrinsic,
-    fetch_block_events_with_prefix,
+    categorize_events, collect_fee_infos, extract_extrinsics_with_prefix,
+    extract_fee_info_for_extrinsic, fetch_block_events_with_prefix,
 };
 use crate::handlers::blocks::types::{BlockResponse, GetBlockError};
 use crate::state::{AppState, RelayChainError};
@@ -278,7 +278,7 @@ pub async fn get_rc_blocks_head(
     let finalized = Some(is_finalized);
 
     let (on_initialize, mut per_extrinsic_events, on_finalize, extrinsic_outcomes) =
-        categorize_events(block_events, extrinsics.len());
+        categorize_events(block_events, extrinsics.len(), None);
 
     let mut extrinsics_with_events = extrinsics;
     for (i, outcome) in extrinsic_outcomes.iter().enumerate() {
@@ -287,6 +287,7 @@ pub async fn get_rc_blocks_head(
                 extrinsic.events = std::mem::take(events);
             }
             extrinsic.success = outcome.success;
+            extrinsic.dispatch_error = outcome.dispatch_error.clone();
             if extrinsic.signature.is_some() && outcome.pays_fee.is_some() {
                 extrinsic.pays_fee = outcome.pays_fee;
             }
@@ -313,15 +314,18 @@ pub async fn get_rc_blocks_head(
                         &state,
                         &client_at_parent,
                         &extrinsic.raw_hex,
+                        extrinsic.tip.as_deref(),
                         &extrinsic.events,
                         extrinsic_outcomes.get(i),
                         spec_version,
                         &relay_chain_info.spec_name,
+                        false,
                     )
                 })
                 .collect();
 
-            let fee_results = futures::future::join_all(fee_futures).await;
+            let fee_results =
+                collect_fee_infos(state.config.express.fee_fetch_concurrency, fee_futures).await;
 
             for (idx, fee_info) in fee_indices.into_iter().zip(fee_results) {
                 extrinsics_with_events[idx].info = fee_info;
@@ -367,12 +371,15 @@ pub async fn get_rc_blocks_head(
         state_root,
         extrinsics_root,
         author_id,
+        decoded_logs: None,
         logs,
         on_initialize,
         extrinsics: extrinsics_with_events,
         on_finalize,
         finalized,
         decoded_xcm_msgs,
+        block_weight: None,
+        events_pagination: None,
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
@@ -439,6 +446,7 @@ mod tests {
             extrinsics_root: "0xefbdd47ab4826ccd29911e04f8b93df56ef43241c3c75f610f0171152a48c6b1"
                 .to_string(),
             author_id: Some("1zugcag7cJVBtVRnFxv5Qftn7xKGLXqR4VEy4Hzir2u5f5X".to_string()),
+            decoded_logs: None,
             logs: vec![DigestLog {
                 log_type: "PreRuntime".to_string(),
                 index: "6".to_string(),
@@ -462,6 +470,7 @@ mod tests {
                 },
                 events: vec![],
                 success: true,
+                dispatch_error: None,
                 pays_fee: None,
                 docs: None,
                 raw_hex: "0x".to_string(),
@@ -469,6 +478,8 @@ mod tests {
             on_finalize: OnFinalize { events: vec![] },
             finalized: Some(true),
             decoded_xcm_msgs: None,
+            block_weight: None,
+            events_pagination: None,
             rc_block_hash: None,
             rc_block_number: None,
             ah_timestamp: None,
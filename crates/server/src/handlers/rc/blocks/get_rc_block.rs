@@ -56,6 +56,12 @@ impl RcBlockQueryParams {
             decoded_xcm_msgs: self.decoded_xcm_msgs,
             para_id: self.para_id,
             use_evm_format: false,
+            events_filter: None,
+            filter_extrinsic_events: false,
+            fee_breakdown: false,
+            decode_logs: false,
+            include_block_weight: false,
+            events_page: None,
         }
     }
 }
@@ -237,6 +243,7 @@ mod tests {
             extrinsics_root: "0x88f053bffb861277ed565f932f90a6518135436bd554f3172c03f934be74d9b7"
                 .to_string(),
             author_id: Some("16hwkvDGzdLLyaZ9CyPfwg85ijEAJUoHKxKSu6oSfDVyZm9j".to_string()),
+            decoded_logs: None,
             logs: vec![DigestLog {
                 log_type: "PreRuntime".to_string(),
                 index: "6".to_string(),
@@ -264,6 +271,7 @@ mod tests {
                 },
                 events: vec![],
                 success: true,
+                dispatch_error: None,
                 pays_fee: None,
                 docs: None,
                 raw_hex: "0x".to_string(),
@@ -271,6 +279,8 @@ mod tests {
             on_finalize: OnFinalize { events: vec![] },
             finalized: Some(true),
             decoded_xcm_msgs: None,
+            block_weight: None,
+            events_pagination: None,
             rc_block_hash: None,
             rc_block_number: None,
             ah_timestamp: None,
@@ -8,7 +8,9 @@
 //! this queries the relay chain connection instead of the primary chain.
 
 use crate::handlers::blocks::common::convert_digest_items_to_logs;
-use crate::handlers::blocks::get_block_extrinsics_raw::{BlockRawDigest, BlockRawResponse};
+use crate::handlers::blocks::get_block_extrinsics_raw::{
+    BlockRawDigest, BlockRawResponse, extract_raw_extrinsics_from_json,
+};
 use crate::handlers::blocks::types::GetBlockError;
 use crate::state::AppState;
 use crate::utils;
@@ -104,19 +106,3 @@ async fn fetch_raw_extrinsics_via_rpc(
 
     extract_raw_extrinsics_from_json(&block_json)
 }
-
-/// Extract raw extrinsics from the chain_getBlock JSON response
-fn extract_raw_extrinsics_from_json(block_json: &Value) -> Result<Vec<String>, GetBlockError> {
-    let extrinsics = block_json
-        .get("block")
-        .and_then(|b| b.get("extrinsics"))
-        .and_then(|e| e.as_array())
-        .ok_or_else(|| GetBlockError::HeaderFieldMissing("extrinsics".to_string()))?;
-
-    let raw_extrinsics: Vec<String> = extrinsics
-        .iter()
-        .filter_map(|e| e.as_str().map(|s| s.to_string()))
-        .collect();
-
-    Ok(raw_extrinsics)
-}
@@ -9,8 +9,8 @@ use crate::handlers::blocks::common::{
 use crate::handlers::blocks::decode::XcmDecoder;
 use crate::handlers::blocks::docs::Docs;
 use crate::handlers::blocks::processing::{
-    categorize_events, extract_extrinsics_with_prefix, extract_fee_info_for_extrinsic,
-    fetch_block_events_with_prefix,
+    categorize_events, collect_fee_infos, extract_extrinsics_with_prefix,
+    extract_fee_info_for_extrinsic, fetch_block_events_with_prefix,
 };
 use crate::handlers::blocks::types::{BlockQueryParams, BlockResponse, GetBlockError};
 use crate::state::AppState;
@@ -187,7 +187,7 @@ async fn build_rc_block_response(
     let block_events = events_result?;
 
     let (on_initialize, mut per_extrinsic_events, on_finalize, extrinsic_outcomes) =
-        categorize_events(block_events, extrinsics.len());
+        categorize_events(block_events, extrinsics.len(), None);
 
     let mut extrinsics_with_events = extrinsics;
     for (i, outcome) in extrinsic_outcomes.iter().enumerate() {
@@ -196,6 +196,7 @@ async fn build_rc_block_response(
                 extrinsic.events = std::mem::take(events);
             }
             extrinsic.success = outcome.success;
+            extrinsic.dispatch_error = outcome.dispatch_error.clone();
             if extrinsic.signature.is_some() && outcome.pays_fee.is_some() {
                 extrinsic.pays_fee = outcome.pays_fee;
             }
@@ -222,15 +223,18 @@ async fn build_rc_block_response(
                         state,
                         &client_at_parent,
                         &extrinsic.raw_hex,
+                        extrinsic.tip.as_deref(),
                         &extrinsic.events,
                         extrinsic_outcomes.get(i),
                         spec_version,
                         &relay_chain_info.spec_name,
+                        false,
                     )
                 })
                 .collect();
 
-            let fee_results = futures::future::join_all(fee_futures).await;
+            let fee_results =
+                collect_fee_infos(state.config.express.fee_fetch_concurrency, fee_futures).await;
 
             for (idx, fee_info) in fee_indices.into_iter().zip(fee_results) {
                 extrinsics_with_events[idx].info = fee_info;
@@ -280,12 +284,15 @@ async fn build_rc_block_response(
         state_root,
         extrinsics_root,
         author_id,
+        decoded_logs: None,
         logs,
         on_initialize,
         extrinsics: extrinsics_with_events,
         on_finalize,
         finalized: Some(is_finalized),
         decoded_xcm_msgs,
+        block_weight: None,
+        events_pagination: None,
         rc_block_hash: None,
         rc_block_number: None,
         ah_timestamp: None,
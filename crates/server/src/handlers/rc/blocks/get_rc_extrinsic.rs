@@ -45,7 +45,8 @@ use axum::{
         ("extrinsicIndex" = String, Path, description = "Index of the extrinsic in the block"),
         ("eventDocs" = Option<bool>, Query, description = "Include event documentation"),
         ("extrinsicDocs" = Option<bool>, Query, description = "Include extrinsic documentation"),
-        ("noFees" = Option<bool>, Query, description = "Skip fee calculation")
+        ("noFees" = Option<bool>, Query, description = "Skip fee calculation"),
+        ("feeBreakdown" = Option<bool>, Query, description = "Include baseFee, lenFee, adjustedWeightFee and tip in the fee info. Needs the queryFeeDetails runtime call even when the fee is read from the TransactionFeePaid event")
     ),
     responses(
         (status = 200, description = "Extrinsic details", body = Object),
@@ -93,7 +94,7 @@ pub async fn get_rc_extrinsic(
     }
 
     let (_on_initialize, mut per_extrinsic_events, _on_finalize, extrinsic_outcomes) =
-        categorize_events(block_events, extrinsics.len());
+        categorize_events(block_events, extrinsics.len(), None);
 
     let mut extrinsics_with_events = extrinsics;
     associate_events_with_extrinsics(
@@ -115,10 +116,12 @@ pub async fn get_rc_extrinsic(
             &state,
             &client_at_parent,
             &extrinsic.raw_hex,
+            extrinsic.tip.as_deref(),
             &extrinsic.events,
             extrinsic_outcomes.get(extrinsic_index),
             spec_version,
             &relay_chain_info.spec_name,
+            params.fee_breakdown,
         )
         .await;
 
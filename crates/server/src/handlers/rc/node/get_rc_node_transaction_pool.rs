@@ -7,12 +7,13 @@ use crate::handlers::node::common::{
 };
 
 // Re-export for tests
+use crate::handlers::node::TransactionPoolResponse;
 #[cfg(test)]
 use crate::handlers::node::common::extract_tip_from_extrinsic_bytes;
-use crate::handlers::node::{TransactionPoolQueryParams, TransactionPoolResponse};
 use crate::state::{AppState, RelayChainError};
 use crate::utils;
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::Deserialize;
 use serde_json::json;
 use thiserror::Error;
 
@@ -88,6 +89,13 @@ impl IntoResponse for GetRcNodeTransactionPoolError {
     }
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RcTransactionPoolQueryParams {
+    #[serde(default)]
+    pub include_fee: bool,
+}
+
 /// Handler for GET /rc/node/transaction-pool
 ///
 /// Returns the relay chain's transaction pool with optional fee information.
@@ -110,12 +118,12 @@ impl IntoResponse for GetRcNodeTransactionPoolError {
 )]
 pub async fn get_rc_node_transaction_pool(
     State(state): State<AppState>,
-    JsonQuery(params): JsonQuery<TransactionPoolQueryParams>,
+    JsonQuery(params): JsonQuery<RcTransactionPoolQueryParams>,
 ) -> Result<Json<TransactionPoolResponse>, GetRcNodeTransactionPoolError> {
     let relay_rpc_client = state.get_relay_chain_rpc_client().await?;
 
     let response = if params.include_fee {
-        fetch_transaction_pool_with_fees(&relay_rpc_client).await?
+        fetch_transaction_pool_with_fees(&relay_rpc_client, &state.metadata_cache).await?
     } else {
         fetch_transaction_pool_simple(&relay_rpc_client).await?
     };
@@ -173,7 +181,11 @@ mod tests {
                 cell
             },
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
@@ -216,7 +228,7 @@ mod tests {
             .build();
 
         let state = create_test_state_with_relay_mock(relay_mock).await;
-        let params = TransactionPoolQueryParams { include_fee: false };
+        let params = RcTransactionPoolQueryParams { include_fee: false };
 
         let result = get_rc_node_transaction_pool(State(state), JsonQuery(params)).await;
         assert!(result.is_ok());
@@ -236,7 +248,7 @@ mod tests {
             .build();
 
         let state = create_test_state_with_relay_mock(relay_mock).await;
-        let params = TransactionPoolQueryParams { include_fee: false };
+        let params = RcTransactionPoolQueryParams { include_fee: false };
 
         let result = get_rc_node_transaction_pool(State(state), JsonQuery(params)).await;
         assert!(result.is_ok());
@@ -274,7 +286,7 @@ mod tests {
             .build();
 
         let state = create_test_state_with_relay_mock(relay_mock).await;
-        let params = TransactionPoolQueryParams { include_fee: true };
+        let params = RcTransactionPoolQueryParams { include_fee: true };
 
         let result = get_rc_node_transaction_pool(State(state), JsonQuery(params)).await;
         if let Ok(response) = result {
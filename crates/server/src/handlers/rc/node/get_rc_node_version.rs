@@ -130,7 +130,11 @@ mod tests {
                 cell
             },
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
@@ -168,7 +172,7 @@ mod tests {
         assert!(result.is_ok());
         let response = result.unwrap().0;
         assert_eq!(response.chain, "Polkadot");
-        assert_eq!(response.client_version, "1.15.2-abcdef12");
+        assert_eq!(response.client_version.as_deref(), Some("1.15.2-abcdef12"));
         assert_eq!(response.client_impl_name, "parity-polkadot");
     }
 
@@ -200,7 +204,7 @@ mod tests {
         assert!(result.is_ok());
         let response = result.unwrap().0;
         assert_eq!(response.chain, "Westend");
-        assert_eq!(response.client_version, "1.0.0");
+        assert_eq!(response.client_version.as_deref(), Some("1.0.0"));
         assert_eq!(response.client_impl_name, "unknown");
     }
 }
@@ -7,6 +7,6 @@ pub mod get_rc_node_version;
 
 pub use get_rc_node_network::{GetRcNodeNetworkError, get_rc_node_network};
 pub use get_rc_node_transaction_pool::{
-    GetRcNodeTransactionPoolError, get_rc_node_transaction_pool,
+    GetRcNodeTransactionPoolError, RcTransactionPoolQueryParams, get_rc_node_transaction_pool,
 };
 pub use get_rc_node_version::{GetRcNodeVersionError, get_rc_node_version};
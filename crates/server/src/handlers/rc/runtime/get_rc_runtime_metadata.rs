@@ -53,6 +53,18 @@ pub enum GetRcMetadataError {
     BlockNotFound(String),
 }
 
+impl From<utils::MetadataFetchError> for GetRcMetadataError {
+    fn from(err: utils::MetadataFetchError) -> Self {
+        match err {
+            utils::MetadataFetchError::RpcFailed(e) => GetRcMetadataError::RpcFailed(e),
+            utils::MetadataFetchError::HexDecodeFailed(e) => GetRcMetadataError::HexDecodeFailed(e),
+            utils::MetadataFetchError::ScaleDecodeFailed(e) => {
+                GetRcMetadataError::ScaleDecodeFailed(e)
+            }
+        }
+    }
+}
+
 impl IntoResponse for GetRcMetadataError {
     fn into_response(self) -> axum::response::Response {
         let (status, message) = match &self {
@@ -153,26 +165,13 @@ pub async fn get_rc_runtime_metadata(
         resolve_relay_block_hash(&relay_rpc_client, &relay_legacy_rpc, params.at.as_deref())
             .await?;
 
-    let metadata_hex: String = relay_rpc_client
-        .request("state_getMetadata", rpc_params![&block_hash])
-        .await
-        .map_err(GetRcMetadataError::RpcFailed)?;
-
-    let hex_str = metadata_hex.strip_prefix("0x").unwrap_or(&metadata_hex);
-    let metadata_bytes = hex::decode(hex_str).map_err(GetRcMetadataError::HexDecodeFailed)?;
-
-    if metadata_bytes.len() < 4 {
-        return Err(GetRcMetadataError::MetadataTooShort);
-    }
-
-    let magic_number = u32::from_le_bytes(
-        metadata_bytes[0..4]
-            .try_into()
-            .map_err(|_| GetRcMetadataError::MetadataTooShort)?,
-    );
+    let cached =
+        utils::fetch_runtime_metadata(&relay_rpc_client, &state.metadata_cache, &block_hash)
+            .await?;
+    let metadata_prefixed = cached.decoded()?;
 
-    let metadata_prefixed = RuntimeMetadataPrefixed::decode(&mut &metadata_bytes[..])
-        .map_err(GetRcMetadataError::ScaleDecodeFailed)?;
+    // Magic number is the first 4 bytes as little-endian u32
+    let magic_number = metadata_prefixed.0;
 
     let metadata = convert_metadata(&metadata_prefixed.1)
         .map_err(|_| GetRcMetadataError::UnsupportedVersion)?;
@@ -0,0 +1,415 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for `/runtime/call/{api}/{method}` endpoint.
+//!
+//! Invokes any runtime API method exposed in the metadata via `state_call`,
+//! taking SCALE-encoded input. The output is decoded into JSON against the
+//! method's return type in the metadata, falling back to hex when it can't be.
+//! This covers runtime APIs that have no dedicated endpoint, at the cost of
+//! leaving argument encoding to the caller.
+
+use crate::extractors::JsonQuery;
+use crate::handlers::blocks::decode::JsonVisitor;
+use crate::state::AppState;
+use crate::utils;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use scale_decode::visitor::decode_with_visitor;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use subxt::Metadata;
+use subxt::error::OnlineClientAtBlockError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum RuntimeCallError {
+    #[error("Invalid block parameter")]
+    InvalidBlockParam(#[from] crate::utils::BlockIdParseError),
+
+    #[error("Block resolution failed")]
+    BlockResolveFailed(#[from] utils::BlockResolveError),
+
+    #[error("Failed to get client at block")]
+    ClientAtBlockFailed(#[source] Box<OnlineClientAtBlockError>),
+
+    #[error("Runtime API '{0}' not found in metadata")]
+    ApiNotFound(String),
+
+    #[error("Method '{method}' not found in runtime API '{api}'")]
+    MethodNotFound { api: String, method: String },
+
+    #[error("Invalid params: expected a 0x-prefixed hex string")]
+    InvalidParams,
+
+    #[error("Runtime API call failed")]
+    CallFailed(#[source] subxt_rpcs::Error),
+}
+
+impl From<utils::ResolveClientAtBlockError> for RuntimeCallError {
+    fn from(err: utils::ResolveClientAtBlockError) -> Self {
+        match err {
+            utils::ResolveClientAtBlockError::ParseError(e) => {
+                RuntimeCallError::InvalidBlockParam(e)
+            }
+            utils::ResolveClientAtBlockError::BlockNotFound(msg) => {
+                RuntimeCallError::BlockResolveFailed(utils::BlockResolveError::NotFound(msg))
+            }
+            utils::ResolveClientAtBlockError::SubxtError(e) => {
+                RuntimeCallError::ClientAtBlockFailed(Box::new(e))
+            }
+        }
+    }
+}
+
+impl IntoResponse for RuntimeCallError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, message) = match &self {
+            RuntimeCallError::InvalidBlockParam(_) | RuntimeCallError::InvalidParams => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            RuntimeCallError::BlockResolveFailed(inner) => (inner.status_code(), inner.to_string()),
+            RuntimeCallError::ApiNotFound(_) | RuntimeCallError::MethodNotFound { .. } => {
+                (StatusCode::NOT_FOUND, self.to_string())
+            }
+            RuntimeCallError::ClientAtBlockFailed(err) => {
+                if utils::is_online_client_at_block_disconnected(err) {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "Service temporarily unavailable".to_string(),
+                    )
+                } else {
+                    (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+                }
+            }
+            RuntimeCallError::CallFailed(err) => utils::rpc_error_to_status(err),
+        };
+
+        let body = Json(json!({
+            "error": message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockInfo {
+    pub hash: String,
+    pub height: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuntimeCallResponse {
+    pub at: BlockInfo,
+    pub api: String,
+    pub method: String,
+    /// Whether `result` is decoded JSON rather than the raw hex output
+    pub decoded: bool,
+    pub result: Value,
+}
+
+/// Query parameters for the runtime call endpoint
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeCallQueryParams {
+    pub at: Option<String>,
+    /// SCALE-encoded call arguments as a hex string. Defaults to no arguments.
+    pub params: Option<String>,
+    /// Set to `false` to skip decoding and return the raw hex output.
+    pub decoded: Option<bool>,
+}
+
+/// Handler for GET /runtime/call/{api}/{method}
+///
+/// Calls a runtime API method via `state_call` and returns its result.
+///
+/// Path parameters:
+/// - `api`: Runtime API trait name as it appears in the metadata (e.g. `NominationPoolsApi`)
+/// - `method`: Method name within that API (e.g. `points_to_balance`)
+///
+/// Query parameters:
+/// - `at` (optional): Block identifier (block number or block hash). Defaults to latest block.
+/// - `params` (optional): SCALE-encoded arguments as a 0x-prefixed hex string. Defaults to `0x`.
+/// - `decoded` (optional): Decode the result using the metadata. Defaults to `true`.
+///
+/// Returns:
+/// - `at`: Block number and hash at which the call was made
+/// - `decoded`: Whether `result` was decoded
+/// - `result`: Return value as JSON, or as a hex string when not decoded
+#[utoipa::path(
+    get,
+    path = "/v1/runtime/call/{api}/{method}",
+    tag = "runtime",
+    summary = "Call a runtime API",
+    description = "Invokes a runtime API method via `state_call` with SCALE-encoded arguments. The result is decoded into JSON using the method's return type from the metadata, or returned as hex when it can't be decoded or `decoded=false`. The API and method must be present in the runtime metadata (V15 or later); runtimes that only expose V14 metadata list no runtime APIs.",
+    params(
+        ("api" = String, Path, description = "Runtime API name (e.g. 'NominationPoolsApi')"),
+        ("method" = String, Path, description = "Runtime API method name (e.g. 'points_to_balance')"),
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("params" = Option<String>, Query, description = "SCALE-encoded call arguments as a 0x-prefixed hex string"),
+        ("decoded" = Option<bool>, Query, description = "Decode the result using the metadata (default: true)")
+    ),
+    responses(
+        (status = 200, description = "Runtime API call result", body = Object),
+        (status = 400, description = "Invalid block or params"),
+        (status = 404, description = "Runtime API or method not found"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn runtime_call(
+    State(state): State<AppState>,
+    Path((api, method)): Path<(String, String)>,
+    JsonQuery(params): JsonQuery<RuntimeCallQueryParams>,
+) -> Result<Json<RuntimeCallResponse>, RuntimeCallError> {
+    let call_parameters = parse_call_params(params.params.as_deref())?;
+
+    let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
+    let block_hash = format!("{:#x}", client_at_block.block_hash());
+    let block_number = client_at_block.block_number();
+
+    let metadata = client_at_block.metadata();
+    let output_ty = find_runtime_api_method(&metadata, &api, &method)?;
+
+    let raw_result = state
+        .state_call(&format!("{api}_{method}"), &call_parameters, &block_hash)
+        .await
+        .map_err(RuntimeCallError::CallFailed)?;
+
+    let decoded_result = if params.decoded.unwrap_or(true) {
+        decode_call_result(
+            &raw_result,
+            output_ty,
+            &metadata,
+            state.chain_info.ss58_prefix,
+        )
+    } else {
+        None
+    };
+    let decoded = decoded_result.is_some();
+    let result = decoded_result.unwrap_or(Value::String(raw_result));
+
+    Ok(Json(RuntimeCallResponse {
+        at: BlockInfo {
+            hash: block_hash,
+            height: block_number.to_string(),
+        },
+        api,
+        method,
+        decoded,
+        result,
+    }))
+}
+
+/// Check that `api` and `method` are declared in the metadata's runtime API section,
+/// returning the method's output type ID.
+fn find_runtime_api_method(
+    metadata: &Metadata,
+    api: &str,
+    method: &str,
+) -> Result<u32, RuntimeCallError> {
+    let api_metadata = metadata
+        .runtime_api_trait_by_name(api)
+        .ok_or_else(|| RuntimeCallError::ApiNotFound(api.to_string()))?;
+
+    api_metadata
+        .method_by_name(method)
+        .map(|method| method.output_ty())
+        .ok_or_else(|| RuntimeCallError::MethodNotFound {
+            api: api.to_string(),
+            method: method.to_string(),
+        })
+}
+
+/// Decode a hex `state_call` result against the method's output type.
+///
+/// Returns `None` if the bytes don't decode as exactly one value of that type,
+/// so the caller can fall back to the raw hex.
+fn decode_call_result(
+    result_hex: &str,
+    output_ty: u32,
+    metadata: &Metadata,
+    ss58_prefix: u16,
+) -> Option<Value> {
+    let bytes = hex::decode(result_hex.strip_prefix("0x").unwrap_or(result_hex)).ok()?;
+    let registry = metadata.types();
+    let mut data = &bytes[..];
+
+    match decode_with_visitor(
+        &mut data,
+        output_ty,
+        registry,
+        JsonVisitor::new(ss58_prefix, registry),
+    ) {
+        Ok(value) if data.is_empty() => Some(value),
+        Ok(_) => {
+            tracing::warn!(
+                "Runtime API result left {} undecoded bytes (type_id={}). Falling back to raw hex.",
+                data.len(),
+                output_ty
+            );
+            None
+        }
+        Err(e) => {
+            tracing::warn!(
+                "Failed to decode runtime API result (type_id={}): {}. Falling back to raw hex.",
+                output_ty,
+                e
+            );
+            None
+        }
+    }
+}
+
+/// Validate the hex call arguments, defaulting to empty input.
+fn parse_call_params(params: Option<&str>) -> Result<String, RuntimeCallError> {
+    let Some(params) = params else {
+        return Ok("0x".to_string());
+    };
+
+    let bytes = params
+        .strip_prefix("0x")
+        .and_then(|hex_str| hex::decode(hex_str).ok())
+        .ok_or(RuntimeCallError::InvalidParams)?;
+
+    Ok(format!("0x{}", hex::encode(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use frame_metadata::v15::{
+        CustomMetadata, ExtrinsicMetadata, OuterEnums, RuntimeApiMetadata,
+        RuntimeApiMethodMetadata, RuntimeMetadataV15,
+    };
+    use parity_scale_codec::Decode;
+    use scale_info::meta_type;
+
+    /// Minimal V15 metadata declaring only `Core_version`.
+    fn metadata_with_core_api() -> Metadata {
+        let metadata = RuntimeMetadataV15::new(
+            vec![],
+            ExtrinsicMetadata {
+                version: 4,
+                address_ty: meta_type::<()>(),
+                call_ty: meta_type::<()>(),
+                signature_ty: meta_type::<()>(),
+                extra_ty: meta_type::<()>(),
+                signed_extensions: vec![],
+            },
+            meta_type::<()>(),
+            vec![RuntimeApiMetadata {
+                name: "Core",
+                methods: vec![RuntimeApiMethodMetadata {
+                    name: "version",
+                    inputs: vec![],
+                    output: meta_type::<u32>(),
+                    docs: vec![],
+                }],
+                docs: vec![],
+            }],
+            OuterEnums {
+                call_enum_ty: meta_type::<()>(),
+                event_enum_ty: meta_type::<()>(),
+                error_enum_ty: meta_type::<()>(),
+            },
+            CustomMetadata {
+                map: Default::default(),
+            },
+        );
+        Metadata::try_from(metadata).expect("V15 metadata converts")
+    }
+
+    #[test]
+    fn test_find_runtime_api_method_accepts_known_method() {
+        let metadata = metadata_with_core_api();
+
+        let output_ty = find_runtime_api_method(&metadata, "Core", "version").unwrap();
+        assert_eq!(
+            metadata.types().resolve(output_ty).unwrap().type_def,
+            scale_info::TypeDef::Primitive(scale_info::TypeDefPrimitive::U32)
+        );
+    }
+
+    #[test]
+    fn test_decode_call_result_uses_output_type() {
+        let metadata = metadata_with_core_api();
+        let output_ty = find_runtime_api_method(&metadata, "Core", "version").unwrap();
+
+        assert_eq!(
+            decode_call_result("0x2a000000", output_ty, &metadata, 0),
+            Some(json!("42"))
+        );
+    }
+
+    #[test]
+    fn test_decode_call_result_falls_back_on_mismatch() {
+        let metadata = metadata_with_core_api();
+        let output_ty = find_runtime_api_method(&metadata, "Core", "version").unwrap();
+
+        // Too short, trailing bytes, and not hex at all
+        assert_eq!(decode_call_result("0x2a00", output_ty, &metadata, 0), None);
+        assert_eq!(
+            decode_call_result("0x2a00000001", output_ty, &metadata, 0),
+            None
+        );
+        assert_eq!(decode_call_result("0xzz", output_ty, &metadata, 0), None);
+    }
+
+    #[test]
+    fn test_find_runtime_api_method_rejects_unknown_api_and_method() {
+        let metadata = metadata_with_core_api();
+
+        assert!(matches!(
+            find_runtime_api_method(&metadata, "NoSuchApi", "version"),
+            Err(RuntimeCallError::ApiNotFound(api)) if api == "NoSuchApi"
+        ));
+        assert!(matches!(
+            find_runtime_api_method(&metadata, "Core", "no_such_method"),
+            Err(RuntimeCallError::MethodNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_v14_metadata_has_no_runtime_apis() {
+        let metadata =
+            Metadata::decode(&mut &ASSET_HUB_METADATA[..]).expect("fixture metadata decodes");
+
+        assert!(matches!(
+            find_runtime_api_method(&metadata, "Core", "version"),
+            Err(RuntimeCallError::ApiNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_call_params() {
+        assert_eq!(parse_call_params(None).unwrap(), "0x");
+        assert_eq!(parse_call_params(Some("0x")).unwrap(), "0x");
+        assert_eq!(parse_call_params(Some("0xABcd")).unwrap(), "0xabcd");
+        assert!(matches!(
+            parse_call_params(Some("abcd")),
+            Err(RuntimeCallError::InvalidParams)
+        ));
+        assert!(matches!(
+            parse_call_params(Some("0xabc")),
+            Err(RuntimeCallError::InvalidParams)
+        ));
+    }
+
+    #[test]
+    fn test_not_found_errors_map_to_404() {
+        let response = RuntimeCallError::MethodNotFound {
+            api: "Core".to_string(),
+            method: "nope".to_string(),
+        }
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -83,6 +83,14 @@ pub struct RuntimeCodeResponse {
     pub code: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeCodeHashResponse {
+    pub at: BlockInfo,
+    pub code_hash: String,
+    pub size_bytes: String,
+}
+
 /// Query parameters for the runtime code endpoint
 #[derive(Debug, serde::Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -146,6 +154,60 @@ pub async fn runtime_code(
         code,
     }))
 }
+
+/// Handler for GET /runtime/code/hash
+///
+/// Returns the Blake2-256 hash and size of the runtime Wasm code at a given block,
+/// without sending the code itself.
+///
+/// Query parameters:
+/// - `at` (optional): Block identifier (block number or block hash). Defaults to latest block.
+///
+/// Returns:
+/// - `at`: Block number and hash at which the call was made
+/// - `codeHash`: Blake2-256 hash of the `:code` storage value
+/// - `sizeBytes`: Size of the runtime code in bytes
+#[utoipa::path(
+    get,
+    path = "/v1/runtime/code/hash",
+    tag = "runtime",
+    summary = "Runtime code hash",
+    description = "Returns the Blake2-256 hash and size in bytes of the runtime Wasm code at a given block, without returning the code itself. Useful for monitoring runtime upgrades.",
+    params(
+        ("at" = Option<String>, Query, description = "Block hash or number to query at")
+    ),
+    responses(
+        (status = 200, description = "Runtime code hash and size", body = Object),
+        (status = 400, description = "Invalid block parameter"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn runtime_code_hash(
+    State(state): State<AppState>,
+    JsonQuery(params): JsonQuery<AtBlockParam>,
+) -> Result<Json<RuntimeCodeHashResponse>, GetCodeError> {
+    let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
+
+    let block_hash = format!("{:#x}", client_at_block.block_hash());
+    let block_number = client_at_block.block_number();
+
+    let wasm_blob: Vec<u8> = client_at_block
+        .storage()
+        .runtime_wasm_code()
+        .await
+        .map_err(GetCodeError::GetCodeFailed)?;
+
+    Ok(Json(RuntimeCodeHashResponse {
+        at: BlockInfo {
+            hash: block_hash,
+            height: block_number.to_string(),
+        },
+        code_hash: utils::blake2_256_hex(&wasm_blob),
+        size_bytes: wasm_blob.len().to_string(),
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,12 +245,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
@@ -257,6 +323,28 @@ mod tests {
         assert_eq!(response.code, TEST_WASM_CODE);
     }
 
+    #[tokio::test]
+    async fn test_runtime_code_hash() {
+        let mock_client = mock_rpc_client_builder()
+            .method_handler("state_getStorage", async |_params| MockJson(TEST_WASM_CODE))
+            .build();
+
+        let state = create_test_state_with_mock(mock_client).await;
+        let params = AtBlockParam {
+            at: Some(TEST_BLOCK_HASH.to_string()),
+        };
+
+        let response = runtime_code_hash(State(state), JsonQuery(params))
+            .await
+            .unwrap()
+            .0;
+
+        let code = hex::decode(TEST_WASM_CODE.trim_start_matches("0x")).unwrap();
+        assert_eq!(response.at.height, TEST_BLOCK_NUMBER.to_string());
+        assert_eq!(response.code_hash, utils::blake2_256_hex(&code));
+        assert_eq!(response.size_bytes, code.len().to_string());
+    }
+
     #[test]
     fn test_at_block_param_rejects_unknown_fields() {
         let json = r#"{"at": "123", "unknownField": true}"#;
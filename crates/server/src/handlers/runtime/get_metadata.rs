@@ -3,17 +3,29 @@
 
 //! Handler for `/runtime/metadata` endpoint.
 //!
-//! Returns the decoded runtime metadata in JSON format matching sidecar's output.
+//! Returns the decoded runtime metadata in JSON format matching sidecar's output,
+//! or the raw SCALE bytes (hex or base64) when requested via `format`.
+//!
+//! Metadata only changes with the runtime, so responses carry an `ETag` keyed by
+//! the spec version at the queried block and honor `If-None-Match`.
 
 use crate::extractors::JsonQuery;
 use crate::state::AppState;
 use crate::utils;
-use axum::{Json, extract::Path, extract::State, http::StatusCode, response::IntoResponse};
+use crate::utils::http_cache::{self, IMMUTABLE_CACHE_CONTROL, REVALIDATE_CACHE_CONTROL};
+use axum::{
+    Json,
+    extract::Path,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use base64::Engine;
 use frame_metadata::v14 as v14_types;
 use frame_metadata::v15 as v15_types;
 use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
 use lazy_static::lazy_static;
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Compact, Decode};
 use regex::Regex;
 use scale_info::{PortableRegistry, form::PortableForm};
 use serde::Serialize;
@@ -64,6 +76,18 @@ pub enum GetMetadataError {
     BlockNotFound(String),
 }
 
+impl From<utils::MetadataFetchError> for GetMetadataError {
+    fn from(err: utils::MetadataFetchError) -> Self {
+        match err {
+            utils::MetadataFetchError::RpcFailed(e) => GetMetadataError::RpcFailed(e),
+            utils::MetadataFetchError::HexDecodeFailed(e) => GetMetadataError::HexDecodeFailed(e),
+            utils::MetadataFetchError::ScaleDecodeFailed(e) => {
+                GetMetadataError::ScaleDecodeFailed(e)
+            }
+        }
+    }
+}
+
 impl IntoResponse for GetMetadataError {
     fn into_response(self) -> axum::response::Response {
         let (status, message) = match &self {
@@ -135,6 +159,39 @@ pub struct AtBlockParam {
     pub at: Option<String>,
 }
 
+/// Representation of the metadata returned by `/runtime/metadata`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataFormat {
+    /// Decoded metadata as JSON
+    #[default]
+    Json,
+    /// `0x`-prefixed SCALE-encoded `RuntimeMetadataPrefixed`
+    Hex,
+    /// Base64 SCALE-encoded `RuntimeMetadataPrefixed`
+    Raw,
+}
+
+impl MetadataFormat {
+    fn as_str(self) -> &'static str {
+        match self {
+            MetadataFormat::Json => "json",
+            MetadataFormat::Hex => "hex",
+            MetadataFormat::Raw => "raw",
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeMetadataQueryParams {
+    pub at: Option<String>,
+    #[serde(default)]
+    pub format: MetadataFormat,
+    /// Metadata version to fetch via `Metadata_metadata_at_version`
+    pub version: Option<u32>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RuntimeMetadataResponse {
@@ -147,12 +204,15 @@ pub struct RuntimeMetadataResponse {
     path = "/v1/runtime/metadata",
     tag = "runtime",
     summary = "Runtime metadata",
-    description = "Returns the decoded runtime metadata in JSON format.",
+    description = "Returns the runtime metadata. By default it is decoded to JSON; `format=hex` or `format=raw` return the SCALE-encoded bytes as a hex or base64 string instead, skipping the decode. `version` selects a specific metadata version.",
     params(
-        ("at" = Option<String>, Query, description = "Block hash or number to query at")
+        ("at" = Option<String>, Query, description = "Block hash or number to query at"),
+        ("format" = Option<String>, Query, description = "Output format: 'json' (default), 'hex' or 'raw' (base64)"),
+        ("version" = Option<u32>, Query, description = "Metadata version to return (e.g. 15); 400 if the runtime doesn't support it")
     ),
     responses(
-        (status = 200, description = "Runtime metadata", body = Object),
+        (status = 200, description = "Runtime metadata, with an ETag keyed by the spec version", body = Object),
+        (status = 304, description = "If-None-Match matched the ETag"),
         (status = 400, description = "Invalid block parameter"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -160,40 +220,51 @@ pub struct RuntimeMetadataResponse {
 )]
 pub async fn runtime_metadata(
     State(state): State<AppState>,
-    JsonQuery(params): JsonQuery<AtBlockParam>,
-) -> Result<Json<RuntimeMetadataResponse>, GetMetadataError> {
+    headers: HeaderMap,
+    JsonQuery(params): JsonQuery<RuntimeMetadataQueryParams>,
+) -> Result<Response, GetMetadataError> {
     // Get block hash - only fetch what we need (saves RPC call when hash provided)
     let block_hash = resolve_block_hash(&state, params.at.as_deref()).await?;
 
-    let metadata_hex: String = state
-        .rpc_client
-        .request("state_getMetadata", rpc_params![&block_hash])
-        .await
-        .map_err(GetMetadataError::RpcFailed)?;
+    let variant = match params.version {
+        Some(version) => format!("v{version}-{}", params.format.as_str()),
+        None => params.format.as_str().to_string(),
+    };
+    let cache = MetadataCache::new(&state, &block_hash, params.at.as_deref(), &variant).await;
+    if let Some(response) = cache.not_modified(&headers) {
+        return Ok(response);
+    }
+
+    let response = build_runtime_metadata(&state, &block_hash, &params).await?;
+    Ok(cache.apply(Json(response).into_response()))
+}
 
-    let hex_str = metadata_hex.strip_prefix("0x").unwrap_or(&metadata_hex);
-    let metadata_bytes = hex::decode(hex_str).map_err(GetMetadataError::HexDecodeFailed)?;
+async fn build_runtime_metadata(
+    state: &AppState,
+    block_hash: &str,
+    params: &RuntimeMetadataQueryParams,
+) -> Result<RuntimeMetadataResponse, GetMetadataError> {
+    if let Some(version) = params.version {
+        let metadata_hex = fetch_metadata_at_version(state, block_hash, version).await?;
+        return encoded_metadata_response(&metadata_hex, params.format);
+    }
 
-    if metadata_bytes.len() < 4 {
-        return Err(GetMetadataError::MetadataTooShort);
+    let cached = state.get_runtime_metadata_at_hash(block_hash).await?;
+    if params.format != MetadataFormat::Json {
+        return encoded_metadata_response(cached.hex(), params.format);
     }
 
-    // Magic number is the first 4 bytes as little-endian u32
-    let magic_number = u32::from_le_bytes(
-        metadata_bytes[0..4]
-            .try_into()
-            .map_err(|_| GetMetadataError::MetadataTooShort)?,
-    );
+    let metadata_prefixed = cached.decoded()?;
 
-    let metadata_prefixed = RuntimeMetadataPrefixed::decode(&mut &metadata_bytes[..])
-        .map_err(GetMetadataError::ScaleDecodeFailed)?;
+    // Magic number is the first 4 bytes as little-endian u32
+    let magic_number = metadata_prefixed.0;
 
     let metadata = convert_metadata(&metadata_prefixed.1)?;
 
-    Ok(Json(RuntimeMetadataResponse {
+    Ok(RuntimeMetadataResponse {
         magic_number: magic_number.to_string(),
         metadata,
-    }))
+    })
 }
 
 #[utoipa::path(
@@ -206,7 +277,8 @@ pub async fn runtime_metadata(
         ("at" = Option<String>, Query, description = "Block hash or number to query at")
     ),
     responses(
-        (status = 200, description = "List of available metadata versions", body = Vec<String>),
+        (status = 200, description = "List of available metadata versions, with an ETag keyed by the spec version", body = Vec<String>),
+        (status = 304, description = "If-None-Match matched the ETag"),
         (status = 400, description = "Invalid block parameter"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -214,11 +286,17 @@ pub async fn runtime_metadata(
 )]
 pub async fn runtime_metadata_versions(
     State(state): State<AppState>,
+    headers: HeaderMap,
     JsonQuery(params): JsonQuery<AtBlockParam>,
-) -> Result<Json<Vec<String>>, GetMetadataError> {
+) -> Result<Response, GetMetadataError> {
     // Get block hash - only fetch what we need (saves RPC call when hash provided)
     let block_hash = resolve_block_hash(&state, params.at.as_deref()).await?;
 
+    let cache = MetadataCache::new(&state, &block_hash, params.at.as_deref(), "versions").await;
+    if let Some(response) = cache.not_modified(&headers) {
+        return Ok(response);
+    }
+
     // Call state_call with Metadata_metadata_versions
     // The call takes no parameters, so we just encode empty bytes
     let call_data = "0x".to_string();
@@ -250,7 +328,7 @@ pub async fn runtime_metadata_versions(
     // Convert to "vX" format matching Sidecar output
     let version_strings: Vec<String> = versions.iter().map(|v| format!("{}", v)).collect();
 
-    Ok(Json(version_strings))
+    Ok(cache.apply(Json(version_strings).into_response()))
 }
 
 #[utoipa::path(
@@ -264,7 +342,8 @@ pub async fn runtime_metadata_versions(
         ("at" = Option<String>, Query, description = "Block hash or number to query at")
     ),
     responses(
-        (status = 200, description = "Runtime metadata at specified version", body = Object),
+        (status = 200, description = "Runtime metadata at specified version, with an ETag keyed by the spec version", body = Object),
+        (status = 304, description = "If-None-Match matched the ETag"),
         (status = 400, description = "Invalid version format or block parameter"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
@@ -273,8 +352,9 @@ pub async fn runtime_metadata_versions(
 pub async fn runtime_metadata_versioned(
     State(state): State<AppState>,
     Path(version): Path<String>,
+    headers: HeaderMap,
     JsonQuery(params): JsonQuery<AtBlockParam>,
-) -> Result<Json<RuntimeMetadataResponse>, GetMetadataError> {
+) -> Result<Response, GetMetadataError> {
     let version_num: u32 = match VERSION_REGEX.captures(&version) {
         Some(caps) => {
             // Safe unwrap: regex guarantees capture group 1 exists when there's a match
@@ -290,6 +370,78 @@ pub async fn runtime_metadata_versioned(
     // Get block hash - only fetch what we need (saves RPC call when hash provided)
     let block_hash = resolve_block_hash(&state, params.at.as_deref()).await?;
 
+    let variant = format!("v{version_num}-{}", MetadataFormat::Json.as_str());
+    let cache = MetadataCache::new(&state, &block_hash, params.at.as_deref(), &variant).await;
+    if let Some(response) = cache.not_modified(&headers) {
+        return Ok(response);
+    }
+
+    let metadata_hex = fetch_metadata_at_version(&state, &block_hash, version_num).await?;
+
+    let response = encoded_metadata_response(&metadata_hex, MetadataFormat::Json)?;
+    Ok(cache.apply(Json(response).into_response()))
+}
+
+/// Cache headers for a metadata response, keyed by the runtime's spec version.
+///
+/// Responses for an explicit `at` are immutable. Responses for the latest block
+/// must be revalidated, since a runtime upgrade changes them.
+struct MetadataCache {
+    etag: Option<String>,
+    cache_control: &'static str,
+}
+
+impl MetadataCache {
+    /// `variant` distinguishes representations of the same metadata, e.g. `hex` or `v15-json`
+    async fn new(state: &AppState, block_hash: &str, at: Option<&str>, variant: &str) -> Self {
+        let spec_version = state
+            .get_runtime_version_at_hash(block_hash)
+            .await
+            .inspect_err(|e| tracing::debug!("Failed to fetch runtime version: {e:?}"))
+            .ok()
+            .and_then(|version| version.get("specVersion").and_then(Value::as_u64));
+
+        Self {
+            etag: spec_version.map(|spec_version| {
+                metadata_etag(&state.chain_info.spec_name, spec_version, variant)
+            }),
+            cache_control: if at.is_some() {
+                IMMUTABLE_CACHE_CONTROL
+            } else {
+                REVALIDATE_CACHE_CONTROL
+            },
+        }
+    }
+
+    /// A 304 response when `If-None-Match` matches the ETag
+    fn not_modified(&self, headers: &HeaderMap) -> Option<Response> {
+        let etag = self.etag.as_deref()?;
+        http_cache::if_none_match(headers, etag)
+            .then(|| http_cache::not_modified(etag, self.cache_control))
+    }
+
+    fn apply(&self, response: Response) -> Response {
+        match &self.etag {
+            Some(etag) => http_cache::with_cache_headers(response, etag, self.cache_control),
+            None => response,
+        }
+    }
+}
+
+fn metadata_etag(spec_name: &str, spec_version: u64, variant: &str) -> String {
+    http_cache::etag(&format!("{spec_name}-{spec_version}-{variant}"))
+}
+
+/// Fetch the hex-encoded `RuntimeMetadataPrefixed` at a specific metadata version
+/// via `Metadata_metadata_at_version`.
+///
+/// Returns `VersionNotAvailable` if the runtime doesn't list the version in
+/// `Metadata_metadata_versions` or returns `None` for it.
+async fn fetch_metadata_at_version(
+    state: &AppState,
+    block_hash: &str,
+    version_num: u32,
+) -> Result<String, GetMetadataError> {
     // First, check if the version is available
     let versions_call_data = "0x".to_string();
     let versions_result: String = state
@@ -299,7 +451,7 @@ pub async fn runtime_metadata_versioned(
             rpc_params![
                 "Metadata_metadata_versions",
                 &versions_call_data,
-                &block_hash
+                block_hash
             ],
         )
         .await
@@ -332,7 +484,7 @@ pub async fn runtime_metadata_versioned(
         .rpc_client
         .request(
             "state_call",
-            rpc_params!["Metadata_metadata_at_version", &call_data, &block_hash],
+            rpc_params!["Metadata_metadata_at_version", &call_data, block_hash],
         )
         .await
         .map_err(|e| {
@@ -344,40 +496,76 @@ pub async fn runtime_metadata_versioned(
             }
         })?;
 
-    // Decode the result - it's an Option<OpaqueMetadata>
-    let hex_str = result.strip_prefix("0x").unwrap_or(&result);
-    let bytes = hex::decode(hex_str).map_err(GetMetadataError::HexDecodeFailed)?;
+    metadata_hex_from_at_version_result(&result, version_num)
+}
+
+/// Extract the metadata hex from a `Metadata_metadata_at_version` result.
+///
+/// The result is an `Option<OpaqueMetadata>`, where `OpaqueMetadata` is a `Vec<u8>`.
+/// Only the Option tag and the length prefix are decoded; the rest is the metadata.
+fn metadata_hex_from_at_version_result(
+    result: &str,
+    version_num: u32,
+) -> Result<String, GetMetadataError> {
+    let hex_str = result.strip_prefix("0x").unwrap_or(result);
+
+    // Option tag (1 byte) followed by a compact u32 length (at most 5 bytes)
+    let prefix_hex = hex_str.get(..hex_str.len().min(12)).unwrap_or(hex_str);
+    let prefix = hex::decode(prefix_hex).map_err(GetMetadataError::HexDecodeFailed)?;
 
-    // The result is Option<OpaqueMetadata> where OpaqueMetadata is Vec<u8>
     // Option encoding: 0x00 = None, 0x01 + data = Some
-    if bytes.is_empty() || bytes[0] == 0 {
+    if prefix.first().is_none_or(|tag| *tag == 0) {
         return Err(GetMetadataError::VersionNotAvailable(version_num));
     }
 
-    // Skip the Option Some byte (0x01) and decode the inner Vec<u8>
-    let opaque_metadata: Vec<u8> =
-        Vec::<u8>::decode(&mut &bytes[1..]).map_err(GetMetadataError::ScaleDecodeFailed)?;
+    let mut cursor = &prefix[1..];
+    let len = Compact::<u32>::decode(&mut cursor)
+        .map_err(GetMetadataError::ScaleDecodeFailed)?
+        .0 as usize;
+    let metadata_hex = &hex_str[2 * (prefix.len() - cursor.len())..];
 
-    if opaque_metadata.len() < 4 {
-        return Err(GetMetadataError::MetadataTooShort);
+    if metadata_hex.len() != 2 * len {
+        return Err(GetMetadataError::ScaleDecodeFailed(
+            "Metadata length doesn't match its length prefix".into(),
+        ));
     }
 
-    // Magic number is the first 4 bytes as little-endian u32
-    let magic_number = u32::from_le_bytes(
-        opaque_metadata[0..4]
-            .try_into()
-            .map_err(|_| GetMetadataError::MetadataTooShort)?,
-    );
+    Ok(format!("0x{metadata_hex}"))
+}
 
-    let metadata_prefixed = RuntimeMetadataPrefixed::decode(&mut &opaque_metadata[..])
-        .map_err(GetMetadataError::ScaleDecodeFailed)?;
+/// Build a metadata response from hex-encoded `RuntimeMetadataPrefixed`.
+///
+/// The `hex` format passes the input through, so only `json` and `raw` decode it.
+fn encoded_metadata_response(
+    metadata_hex: &str,
+    format: MetadataFormat,
+) -> Result<RuntimeMetadataResponse, GetMetadataError> {
+    let hex_str = metadata_hex.strip_prefix("0x").unwrap_or(metadata_hex);
 
-    let metadata = convert_metadata(&metadata_prefixed.1)?;
+    // Magic number is the first 4 bytes as little-endian u32
+    let magic_hex = hex_str.get(..8).ok_or(GetMetadataError::MetadataTooShort)?;
+    let magic: [u8; 4] = hex::decode(magic_hex)
+        .map_err(GetMetadataError::HexDecodeFailed)?
+        .try_into()
+        .map_err(|_| GetMetadataError::MetadataTooShort)?;
+
+    let decode_bytes = || hex::decode(hex_str).map_err(GetMetadataError::HexDecodeFailed);
+    let metadata = match format {
+        MetadataFormat::Json => {
+            let metadata_prefixed = RuntimeMetadataPrefixed::decode(&mut &decode_bytes()?[..])
+                .map_err(GetMetadataError::ScaleDecodeFailed)?;
+            convert_metadata(&metadata_prefixed.1)?
+        }
+        MetadataFormat::Hex => Value::String(format!("0x{hex_str}")),
+        MetadataFormat::Raw => {
+            Value::String(base64::engine::general_purpose::STANDARD.encode(decode_bytes()?))
+        }
+    };
 
-    Ok(Json(RuntimeMetadataResponse {
-        magic_number: magic_number.to_string(),
+    Ok(RuntimeMetadataResponse {
+        magic_number: u32::from_le_bytes(magic).to_string(),
         metadata,
-    }))
+    })
 }
 
 pub fn convert_metadata(metadata: &RuntimeMetadata) -> Result<Value, GetMetadataError> {
@@ -821,4 +1009,86 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
+
+    #[test]
+    fn test_metadata_format_param() {
+        let params: RuntimeMetadataQueryParams = serde_json::from_str(r#"{"at": "1"}"#).unwrap();
+        assert_eq!(params.format, MetadataFormat::Json);
+
+        let params: RuntimeMetadataQueryParams =
+            serde_json::from_str(r#"{"format": "raw"}"#).unwrap();
+        assert_eq!(params.format, MetadataFormat::Raw);
+
+        let result: Result<RuntimeMetadataQueryParams, _> =
+            serde_json::from_str(r#"{"format": "scale"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_metadata_version_param() {
+        let params: RuntimeMetadataQueryParams =
+            serde_qs::from_str("version=15&format=hex").unwrap();
+        assert_eq!(params.version, Some(15));
+        assert_eq!(params.format, MetadataFormat::Hex);
+
+        let params: RuntimeMetadataQueryParams = serde_qs::from_str("").unwrap();
+        assert_eq!(params.version, None);
+
+        assert!(serde_qs::from_str::<RuntimeMetadataQueryParams>("version=v15").is_err());
+    }
+
+    #[test]
+    fn test_encoded_metadata_response() {
+        // "meta" magic followed by a version byte and some payload
+        let metadata_hex = "0x6d6574610e0102";
+
+        let hex = encoded_metadata_response(metadata_hex, MetadataFormat::Hex).unwrap();
+        assert_eq!(hex.magic_number, "1635018093");
+        assert_eq!(hex.metadata, json!(metadata_hex));
+
+        let raw = encoded_metadata_response(metadata_hex, MetadataFormat::Raw).unwrap();
+        assert_eq!(raw.magic_number, "1635018093");
+        assert_eq!(raw.metadata, json!("bWV0YQ4BAg=="));
+    }
+
+    #[test]
+    fn test_metadata_hex_from_at_version_result() {
+        // Some(7 bytes of metadata)
+        let result = "0x011c6d6574610e0102";
+        assert_eq!(
+            metadata_hex_from_at_version_result(result, 15).unwrap(),
+            "0x6d6574610e0102"
+        );
+
+        assert!(matches!(
+            metadata_hex_from_at_version_result("0x00", 15),
+            Err(GetMetadataError::VersionNotAvailable(15))
+        ));
+        assert!(matches!(
+            metadata_hex_from_at_version_result("0x", 15),
+            Err(GetMetadataError::VersionNotAvailable(15))
+        ));
+        assert!(matches!(
+            metadata_hex_from_at_version_result("0x011c6d65", 15),
+            Err(GetMetadataError::ScaleDecodeFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_metadata_etag() {
+        assert_eq!(
+            metadata_etag("polkadot", 1_003_000, MetadataFormat::Hex.as_str()),
+            "\"polkadot-1003000-hex\""
+        );
+        assert_eq!(
+            metadata_etag("polkadot", 1_003_000, "v15-json"),
+            "\"polkadot-1003000-v15-json\""
+        );
+    }
+
+    #[test]
+    fn test_encoded_metadata_response_too_short() {
+        let result = encoded_metadata_response("0x6d65", MetadataFormat::Hex);
+        assert!(matches!(result, Err(GetMetadataError::MetadataTooShort)));
+    }
 }
@@ -0,0 +1,233 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for `/runtime/metadata/pallets` endpoint.
+//!
+//! Returns a compact summary of every pallet in the runtime, including its index
+//! and the number of storage items, calls, events, constants and errors it defines.
+//! This lets tooling discover the surface of an unknown chain in a single request.
+
+use crate::extractors::JsonQuery;
+use crate::state::AppState;
+use crate::utils;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::Serialize;
+use serde_json::json;
+use subxt::Metadata;
+use subxt::error::OnlineClientAtBlockError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum GetMetadataPalletsError {
+    #[error("Invalid block parameter")]
+    InvalidBlockParam(#[from] crate::utils::BlockIdParseError),
+
+    #[error("Block resolution failed")]
+    BlockResolveFailed(#[from] utils::BlockResolveError),
+
+    #[error("Failed to get client at block")]
+    ClientAtBlockFailed(#[source] Box<OnlineClientAtBlockError>),
+}
+
+impl From<utils::ResolveClientAtBlockError> for GetMetadataPalletsError {
+    fn from(err: utils::ResolveClientAtBlockError) -> Self {
+        match err {
+            utils::ResolveClientAtBlockError::ParseError(e) => {
+                GetMetadataPalletsError::InvalidBlockParam(e)
+            }
+            utils::ResolveClientAtBlockError::BlockNotFound(msg) => {
+                GetMetadataPalletsError::BlockResolveFailed(utils::BlockResolveError::NotFound(msg))
+            }
+            utils::ResolveClientAtBlockError::SubxtError(e) => {
+                GetMetadataPalletsError::ClientAtBlockFailed(Box::new(e))
+            }
+        }
+    }
+}
+
+impl IntoResponse for GetMetadataPalletsError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, message) = match &self {
+            GetMetadataPalletsError::InvalidBlockParam(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
+            }
+            GetMetadataPalletsError::BlockResolveFailed(inner) => {
+                (inner.status_code(), inner.to_string())
+            }
+            GetMetadataPalletsError::ClientAtBlockFailed(err) => {
+                if utils::is_online_client_at_block_disconnected(err) {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "Service temporarily unavailable".to_string(),
+                    )
+                } else {
+                    (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+                }
+            }
+        };
+
+        let body = Json(json!({
+            "error": message,
+        }));
+
+        (status, body).into_response()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockInfo {
+    pub hash: String,
+    pub height: String,
+}
+
+/// Summary of a single pallet's metadata surface.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PalletSummary {
+    pub name: String,
+    pub index: u8,
+    pub storage_count: usize,
+    pub call_count: usize,
+    pub event_count: usize,
+    pub constant_count: usize,
+    pub error_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuntimeMetadataPalletsResponse {
+    pub at: BlockInfo,
+    pub pallets: Vec<PalletSummary>,
+}
+
+/// Query parameters for the runtime metadata pallets endpoint
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AtBlockParam {
+    pub at: Option<String>,
+}
+
+/// Handler for GET /runtime/metadata/pallets
+///
+/// Returns every pallet in the runtime with its index and item counts.
+///
+/// Query parameters:
+/// - `at` (optional): Block identifier (block number or block hash). Defaults to latest block.
+#[utoipa::path(
+    get,
+    path = "/v1/runtime/metadata/pallets",
+    tag = "runtime",
+    summary = "Runtime pallets summary",
+    description = "Returns every pallet in the runtime with its index and the number of storage items, calls, events, constants and errors it defines.",
+    params(
+        ("at" = Option<String>, Query, description = "Block hash or number to query at")
+    ),
+    responses(
+        (status = 200, description = "Pallets summary", body = Object),
+        (status = 400, description = "Invalid block parameter"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn runtime_metadata_pallets(
+    State(state): State<AppState>,
+    JsonQuery(params): JsonQuery<AtBlockParam>,
+) -> Result<Json<RuntimeMetadataPalletsResponse>, GetMetadataPalletsError> {
+    let client_at_block = utils::resolve_client_at_block(&state.client, params.at.as_ref()).await?;
+
+    let at = BlockInfo {
+        hash: format!("{:#x}", client_at_block.block_hash()),
+        height: client_at_block.block_number().to_string(),
+    };
+
+    // Subxt normalizes all metadata versions, so counts are consistent across runtimes
+    let metadata = client_at_block.metadata();
+
+    Ok(Json(RuntimeMetadataPalletsResponse {
+        at,
+        pallets: summarize_pallets(&metadata),
+    }))
+}
+
+/// Build a summary entry for each pallet, ordered by pallet index.
+pub fn summarize_pallets(metadata: &Metadata) -> Vec<PalletSummary> {
+    let mut pallets: Vec<PalletSummary> = metadata
+        .pallets()
+        .map(|pallet| PalletSummary {
+            name: pallet.name().to_string(),
+            index: pallet.call_index(),
+            storage_count: pallet.storage().map(|s| s.entries().len()).unwrap_or(0),
+            call_count: pallet.call_variants().map(|v| v.len()).unwrap_or(0),
+            event_count: pallet.event_variants().map(|v| v.len()).unwrap_or(0),
+            constant_count: pallet.constants().len(),
+            error_count: pallet.error_variants().map(|v| v.len()).unwrap_or(0),
+        })
+        .collect();
+
+    pallets.sort_by_key(|p| p.index);
+    pallets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::Decode;
+
+    fn asset_hub_metadata() -> Metadata {
+        Metadata::decode(&mut &ASSET_HUB_METADATA[..]).expect("fixture metadata decodes")
+    }
+
+    #[test]
+    fn test_summarize_pallets_covers_every_pallet() {
+        let metadata = asset_hub_metadata();
+        let pallets = summarize_pallets(&metadata);
+
+        assert_eq!(pallets.len(), metadata.pallets().len());
+        assert!(pallets.windows(2).all(|w| w[0].index < w[1].index));
+    }
+
+    #[test]
+    fn test_summarize_pallets_counts_match_metadata() {
+        let metadata = asset_hub_metadata();
+        let pallets = summarize_pallets(&metadata);
+
+        let system = pallets
+            .iter()
+            .find(|p| p.name == "System")
+            .expect("System pallet exists");
+        let system_meta = metadata.pallet_by_name("System").unwrap();
+
+        assert_eq!(system.index, 0);
+        assert_eq!(
+            system.storage_count,
+            system_meta.storage().unwrap().entries().len()
+        );
+        assert_eq!(
+            system.call_count,
+            system_meta.call_variants().unwrap().len()
+        );
+        assert_eq!(system.constant_count, system_meta.constants().len());
+        assert!(system.event_count > 0);
+        assert!(system.error_count > 0);
+    }
+
+    #[test]
+    fn test_pallet_summary_serializes_camel_case() {
+        let summary = PalletSummary {
+            name: "Balances".to_string(),
+            index: 10,
+            storage_count: 1,
+            call_count: 2,
+            event_count: 3,
+            constant_count: 4,
+            error_count: 5,
+        };
+
+        let value = serde_json::to_value(&summary).unwrap();
+        assert_eq!(value["storageCount"], 1);
+        assert_eq!(value["callCount"], 2);
+        assert_eq!(value["eventCount"], 3);
+        assert_eq!(value["constantCount"], 4);
+        assert_eq!(value["errorCount"], 5);
+    }
+}
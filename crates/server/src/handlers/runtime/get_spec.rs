@@ -127,11 +127,34 @@ pub fn transform_chain_type(chain_type: Value) -> Value {
     }
 }
 
+/// Normalize a property that may be a single value or an array (multi-token chains)
+/// into an array of strings. Array entries that are neither numbers nor strings become
+/// `null`, so each index still lines up with the parallel array.
+fn string_array(value: &Value) -> Vec<Value> {
+    let as_string = |v: &Value| match v {
+        Value::Number(n) => Some(Value::String(n.to_string())),
+        Value::String(s) => Some(Value::String(s.clone())),
+        _ => None,
+    };
+
+    match value {
+        Value::Array(arr) => arr
+            .iter()
+            .map(|v| as_string(v).unwrap_or(Value::Null))
+            .collect(),
+        other => as_string(other).into_iter().collect(),
+    }
+}
+
 /// Transform system properties to match expected format:
 /// - ss58Format: number -> string
 /// - tokenDecimals: number or array -> array of strings
 /// - tokenSymbol: string or array -> array of strings
 /// - isEthereum: add if missing (default false)
+///
+/// Multi-token chains report `tokenDecimals` and `tokenSymbol` as parallel arrays;
+/// single-token chains report scalars. Both come out as arrays, so the decimals of
+/// `tokenSymbol[i]` are always `tokenDecimals[i]`.
 pub fn transform_properties(properties: Value) -> Value {
     let mut result = serde_json::Map::new();
 
@@ -148,35 +171,18 @@ pub fn transform_properties(properties: Value) -> Value {
 
         // Transform tokenDecimals to array of strings
         if let Some(decimals) = props.get("tokenDecimals") {
-            let decimals_arr = match decimals {
-                Value::Number(n) => vec![Value::String(n.to_string())],
-                Value::Array(arr) => arr
-                    .iter()
-                    .map(|v| match v {
-                        Value::Number(n) => Value::String(n.to_string()),
-                        Value::String(s) => Value::String(s.clone()),
-                        _ => Value::String("0".to_string()),
-                    })
-                    .collect(),
-                _ => vec![],
-            };
-            result.insert("tokenDecimals".to_string(), Value::Array(decimals_arr));
+            result.insert(
+                "tokenDecimals".to_string(),
+                Value::Array(string_array(decimals)),
+            );
         }
 
         // Transform tokenSymbol to array of strings
         if let Some(symbol) = props.get("tokenSymbol") {
-            let symbol_arr = match symbol {
-                Value::String(s) => vec![Value::String(s.clone())],
-                Value::Array(arr) => arr
-                    .iter()
-                    .map(|v| match v {
-                        Value::String(s) => Value::String(s.clone()),
-                        _ => Value::String("".to_string()),
-                    })
-                    .collect(),
-                _ => vec![],
-            };
-            result.insert("tokenSymbol".to_string(), Value::Array(symbol_arr));
+            result.insert(
+                "tokenSymbol".to_string(),
+                Value::Array(string_array(symbol)),
+            );
         }
 
         // Add isEthereum (default false if not present)
@@ -195,7 +201,7 @@ pub fn transform_properties(properties: Value) -> Value {
     path = "/v1/runtime/spec",
     tag = "runtime",
     summary = "Runtime specification",
-    description = "Returns the runtime specification including version, APIs, and chain properties.",
+    description = "Returns the runtime specification including version, APIs, and chain properties. `properties` merges the node's `system_properties`: `ss58Format`, and `tokenDecimals` and `tokenSymbol` as arrays of strings (one entry per token on multi-token chains).",
     params(
         ("at" = Option<String>, Query, description = "Block hash or number to query at")
     ),
@@ -320,12 +326,16 @@ mod tests {
             relay_rpc_client: Arc::new(tokio::sync::OnceCell::new()),
             relay_chain_info: Arc::new(tokio::sync::OnceCell::new()),
             fee_details_cache: Arc::new(crate::utils::QueryFeeDetailsCache::new()),
+            metadata_cache: Arc::new(crate::utils::RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(crate::utils::IdempotencyCache::new()),
+            block_subscriptions: Arc::new(crate::utils::FinalizedHeadsHub::new(1)),
             chain_configs: Arc::new(polkadot_rest_api_config::ChainConfigs::default()),
             chain_config: Arc::new(polkadot_rest_api_config::Config::single_chain(
                 polkadot_rest_api_config::ChainConfig::default(),
             )),
             route_registry: crate::routes::RouteRegistry::new(),
             relay_chain_rpc: Arc::new(tokio::sync::OnceCell::new()),
+            chain_connections: Arc::new(Vec::new()),
         }
     }
 
@@ -500,6 +510,47 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_transform_properties_normalizes_scalars_and_arrays() {
+        let single = transform_properties(json!({
+            "ss58Format": 0,
+            "tokenDecimals": 10,
+            "tokenSymbol": "DOT"
+        }));
+        assert_eq!(
+            single,
+            json!({
+                "ss58Format": "0",
+                "tokenDecimals": ["10"],
+                "tokenSymbol": ["DOT"],
+                "isEthereum": false
+            })
+        );
+
+        let multi = transform_properties(json!({
+            "ss58Format": 10,
+            "tokenDecimals": [12, 12, "10"],
+            "tokenSymbol": ["ACA", "AUSD", "DOT"],
+            "isEthereum": true
+        }));
+        assert_eq!(
+            multi,
+            json!({
+                "ss58Format": "10",
+                "tokenDecimals": ["12", "12", "10"],
+                "tokenSymbol": ["ACA", "AUSD", "DOT"],
+                "isEthereum": true
+            })
+        );
+
+        let malformed = transform_properties(json!({
+            "tokenDecimals": [12, {"unexpected": true}, 10],
+            "tokenSymbol": ["ACA", "AUSD", "DOT"]
+        }));
+        assert_eq!(malformed["tokenDecimals"], json!(["12", null, "10"]));
+        assert_eq!(malformed["tokenSymbol"][2], "DOT");
+    }
+
     #[test]
     fn test_at_block_param_rejects_unknown_fields() {
         let json = r#"{"at": "123", "unknownField": true}"#;
@@ -1,14 +1,18 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod get_call;
 pub mod get_code;
 pub mod get_metadata;
+pub mod get_metadata_pallets;
 pub mod get_spec;
 
-pub use get_code::runtime_code;
+pub use get_call::runtime_call;
+pub use get_code::{runtime_code, runtime_code_hash};
 pub use get_metadata::runtime_metadata;
 pub use get_metadata::runtime_metadata_versioned;
 pub use get_metadata::runtime_metadata_versions;
+pub use get_metadata_pallets::runtime_metadata_pallets;
 pub use get_spec::runtime_spec;
 
 // Re-export types and helpers for RC runtime handlers
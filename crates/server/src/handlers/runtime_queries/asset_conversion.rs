@@ -3,9 +3,28 @@
 
 //! AssetConversion pallet storage query functions.
 //!
-//! This module provides standalone functions for querying AssetConversion pallet storage items.
+//! This module provides standalone functions for querying AssetConversion pallet storage items,
+//! and the `AssetConversionApi` runtime API for pool reserves.
 
+use parity_scale_codec::Decode;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
+use thiserror::Error;
+
+// ================================================================================================
+// Error Types
+// ================================================================================================
+
+/// Errors that can occur when querying the `AssetConversionApi` runtime API.
+#[derive(Debug, Error)]
+pub enum AssetConversionApiError {
+    /// The runtime API call failed.
+    #[error("Failed to call AssetConversionApi: {0}")]
+    CallFailed(#[from] subxt::error::RuntimeApiError),
+
+    /// Failed to decode the runtime API response.
+    #[error("Failed to decode AssetConversionApi response: {0}")]
+    DecodeFailed(#[from] parity_scale_codec::Error),
+}
 
 // ================================================================================================
 // AssetConversion Pallet Queries
@@ -24,3 +43,21 @@ pub async fn get_next_pool_asset_id(
         .ok()?;
     value.decode().ok()
 }
+
+/// Get a pool's reserves via `AssetConversionApi_get_reserves`.
+///
+/// `pair` is the SCALE-encoded asset pair exactly as it appears in the
+/// `AssetConversion::Pools` key, which is also the encoding of the call's two
+/// arguments. Returns `None` when the runtime reports no reserves, i.e. the
+/// pool holds no liquidity.
+pub async fn get_pool_reserves(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    pair: &[u8],
+) -> Result<Option<(u128, u128)>, AssetConversionApiError> {
+    let result_bytes = client_at_block
+        .runtime_apis()
+        .call_raw("AssetConversionApi_get_reserves", Some(pair))
+        .await?;
+
+    Ok(Option::<(u128, u128)>::decode(&mut &result_bytes[..])?)
+}
@@ -13,10 +13,14 @@
 //! - `Assets::Approvals` - Approval amounts for asset transfers
 
 use super::assets_common::{
-    AssetAccount, AssetAccountLegacy, AssetApproval, AssetDetails, AssetMetadata, format_account_id,
+    AccountStatus, AssetAccount, AssetAccountLegacy, AssetApproval, AssetDetails, AssetMetadata,
+    ExistenceReason, format_account_id,
 };
 use parity_scale_codec::Decode;
 use sp_core::crypto::AccountId32;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::time::{Duration, Instant};
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 use thiserror::Error;
 
@@ -87,6 +91,35 @@ pub struct DecodedAssetBalance {
     pub balance: String,
     pub is_frozen: bool,
     pub is_sufficient: bool,
+    /// Account status (`Liquid`, `Frozen` or `Blocked`), `None` if the account doesn't exist
+    pub status: Option<String>,
+    /// Why the account exists (`Consumer`, `Sufficient`, `DepositHeld`, ...)
+    pub reason: Option<String>,
+    /// The asset's `Assets::Metadata`, only fetched when requested
+    pub metadata: Option<AssetMetadata>,
+}
+
+/// An asset whose balance could not be determined because its query failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetQueryFailure {
+    pub asset_id: u32,
+    pub reason: String,
+}
+
+/// Asset balances for an account, along with the assets whose queries failed.
+#[derive(Debug, Clone, Default)]
+pub struct DecodedAssetBalances {
+    pub balances: Vec<(u32, DecodedAssetBalance)>,
+    pub failures: Vec<AssetQueryFailure>,
+}
+
+/// The largest holders of an asset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetHolders {
+    /// Holder accounts and their balances, largest balance first
+    pub holders: Vec<([u8; 32], u128)>,
+    /// False when the scan ran out of time before visiting every holder
+    pub complete: bool,
 }
 
 /// Decoded asset approval.
@@ -247,76 +280,150 @@ pub async fn get_asset_balance(
 /// When `show_empty` is false (default), only returns assets that have non-zero balances.
 /// When `show_empty` is true, returns all requested assets including those with zero balance.
 ///
-/// This function executes all asset queries **in parallel** for optimal performance.
-/// For 100 assets, this takes ~1 network roundtrip instead of 100 sequential roundtrips.
+/// Queries run in parallel with at most `concurrency` storage fetches in flight, so accounts
+/// holding many assets don't flood the RPC node. Results keep the order of `asset_ids`.
+/// Assets whose query or decode failed are reported in `failures` so callers can tell them
+/// apart from genuinely empty balances.
+///
+/// When `with_metadata` is true, each asset's `Assets::Metadata` is fetched alongside its
+/// balance and attached to it.
 pub async fn get_asset_balances(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
     asset_ids: &[u32],
     show_empty: bool,
-) -> Result<Vec<(u32, DecodedAssetBalance)>, AssetsStorageError> {
-    use futures::future::join_all;
+    with_metadata: bool,
+    concurrency: usize,
+) -> Result<DecodedAssetBalances, AssetsStorageError> {
+    use futures::StreamExt;
 
     let account_bytes: [u8; 32] = *account.as_ref();
 
-    // Create futures for all asset balance queries
+    // Create futures for all asset balance queries, each paired with its metadata query
     let futures: Vec<_> = asset_ids
         .iter()
         .map(|&asset_id| {
             let storage_addr = subxt::dynamic::storage::<_, ()>("Assets", "Account");
             async move {
-                let result = client_at_block
-                    .storage()
-                    .fetch(storage_addr, (asset_id, account_bytes))
-                    .await;
-
-                (asset_id, result)
+                let balance = async {
+                    client_at_block
+                        .storage()
+                        .try_fetch(storage_addr, (asset_id, account_bytes))
+                        .await
+                        .map(|value| value.map(|v| v.into_bytes()))
+                };
+                let metadata = async {
+                    if with_metadata {
+                        try_fetch_asset_metadata(client_at_block, asset_id).await
+                    } else {
+                        Ok(None)
+                    }
+                };
+                let (result, metadata) = tokio::join!(balance, metadata);
+
+                (asset_id, result, metadata)
             }
         })
         .collect();
 
-    // Execute all queries in parallel
-    let results = join_all(futures).await;
-
-    // Process results
-    let mut balances = Vec::new();
-    for (asset_id, result) in results {
-        match result {
-            Ok(value) => {
-                let raw_bytes = value.into_bytes();
-                if let Ok(Some(decoded)) = decode_asset_balance(&raw_bytes) {
-                    balances.push((asset_id, decoded));
-                } else if show_empty {
-                    balances.push((
+    // Execute queries with bounded parallelism, yielding results in input order
+    let results: Vec<_> = futures::stream::iter(futures)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    Ok(collect_asset_balances(results, show_empty))
+}
+
+/// Fetch and decode an asset's `Assets::Metadata`.
+///
+/// Returns `Ok(None)` if the asset has no metadata set; fetch and decode failures are errors.
+pub async fn try_fetch_asset_metadata(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    asset_id: u32,
+) -> Result<Option<AssetMetadata>, AssetsStorageError> {
+    let storage_addr = subxt::dynamic::storage::<_, AssetMetadata>("Assets", "Metadata");
+    let Some(value) = client_at_block
+        .storage()
+        .try_fetch(storage_addr, (asset_id,))
+        .await
+        .map_err(|e| AssetsStorageError::StorageFetchFailed(e.to_string()))?
+    else {
+        return Ok(None);
+    };
+
+    value
+        .decode()
+        .map(Some)
+        .map_err(|e| AssetsStorageError::DecodeFailed(e.to_string()))
+}
+
+/// Raw `Assets::Account` bytes for an asset, or `None` if the account holds none of it.
+type RawAssetAccountResult = Result<Option<Vec<u8>>, subxt::error::StorageError>;
+
+/// Turn raw `Assets::Account` query results into decoded balances and failures.
+///
+/// A missing storage entry means the account holds none of that asset and is not a failure.
+/// Each result's metadata, if any, is attached to its balance. A metadata failure is
+/// reported as a failure for that asset and the balance is kept without metadata.
+fn collect_asset_balances(
+    results: Vec<(
+        u32,
+        RawAssetAccountResult,
+        Result<Option<AssetMetadata>, AssetsStorageError>,
+    )>,
+    show_empty: bool,
+) -> DecodedAssetBalances {
+    let empty_balance = |metadata| DecodedAssetBalance {
+        balance: "0".to_string(),
+        is_frozen: false,
+        is_sufficient: false,
+        status: None,
+        reason: None,
+        metadata,
+    };
+
+    let mut out = DecodedAssetBalances::default();
+    for (asset_id, result, metadata) in results {
+        let metadata = metadata.unwrap_or_else(|e| {
+            tracing::debug!("Failed to fetch metadata for asset {asset_id}: {e}");
+            out.failures.push(AssetQueryFailure {
+                asset_id,
+                reason: e.to_string(),
+            });
+            None
+        });
+
+        let failure = match result {
+            Ok(Some(raw_bytes)) => match decode_asset_balance(&raw_bytes) {
+                Ok(Some(decoded)) => {
+                    out.balances.push((
                         asset_id,
                         DecodedAssetBalance {
-                            balance: "0".to_string(),
-                            is_frozen: false,
-                            is_sufficient: false,
+                            metadata,
+                            ..decoded
                         },
                     ));
+                    continue;
                 }
-            }
-            Err(e) if show_empty => {
-                tracing::debug!(
-                    "Failed to fetch asset balance for asset (show_empty) {asset_id}: {e:?}"
-                );
-                balances.push((
-                    asset_id,
-                    DecodedAssetBalance {
-                        balance: "0".to_string(),
-                        is_frozen: false,
-                        is_sufficient: false,
-                    },
-                ));
-            }
-            Err(e) => {
-                tracing::debug!("Failed to fetch asset balance for asset {asset_id}: {e:?}");
-            }
+                Ok(None) => None,
+                Err(e) => Some(e.to_string()),
+            },
+            Ok(None) => None,
+            Err(e) => Some(e.to_string()),
+        };
+
+        if let Some(reason) = failure {
+            tracing::debug!("Failed to fetch asset balance for asset {asset_id}: {reason}");
+            out.failures.push(AssetQueryFailure { asset_id, reason });
+        }
+
+        if show_empty {
+            out.balances.push((asset_id, empty_balance(metadata)));
         }
     }
 
-    Ok(balances)
+    out
 }
 
 /// Fetch asset approval from Assets::Approvals storage.
@@ -332,22 +439,136 @@ pub async fn get_asset_approval(
     let owner_bytes: [u8; 32] = *owner.as_ref();
     let delegate_bytes: [u8; 32] = *delegate.as_ref();
 
-    let value = match client_at_block
+    let Some(value) = client_at_block
         .storage()
-        .fetch(storage_addr, (asset_id, owner_bytes, delegate_bytes))
+        .try_fetch(storage_addr, (asset_id, owner_bytes, delegate_bytes))
         .await
-    {
-        Ok(v) => v,
-        Err(e) => {
-            tracing::debug!("Failed to fetch asset approval for asset {asset_id}: {e:?}");
-            return Ok(None);
-        }
+        .map_err(|e| AssetsStorageError::StorageFetchFailed(e.to_string()))?
+    else {
+        return Ok(None);
     };
 
     let raw_bytes = value.into_bytes();
     decode_asset_approval(&raw_bytes)
 }
 
+/// Fetch every approval an owner has granted for an asset from Assets::Approvals storage.
+///
+/// Iterates the `(asset_id, owner)` storage prefix and returns the delegates with their
+/// approvals, sorted by delegate account so paging over the result is deterministic.
+pub async fn get_asset_approvals_for_owner(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    asset_id: u32,
+    owner: &AccountId32,
+) -> Result<Vec<([u8; 32], DecodedAssetApproval)>, AssetsStorageError> {
+    let storage_addr =
+        subxt::dynamic::storage::<(u32, [u8; 32], [u8; 32]), ()>("Assets", "Approvals");
+    let owner_bytes: [u8; 32] = *owner.as_ref();
+
+    let mut iter = client_at_block
+        .storage()
+        .iter(storage_addr, (asset_id, owner_bytes))
+        .await
+        .map_err(|e| AssetsStorageError::StorageFetchFailed(e.to_string()))?;
+
+    let mut approvals = Vec::new();
+    while let Some(result) = iter.next().await {
+        let entry = result.map_err(|e| AssetsStorageError::StorageFetchFailed(e.to_string()))?;
+
+        let (_, _, delegate) = entry
+            .key()
+            .and_then(|key| key.decode())
+            .map_err(|e| AssetsStorageError::DecodeFailed(format!("approval key: {e}")))?;
+
+        let approval = decode_asset_approval(entry.value().bytes())?.ok_or_else(|| {
+            AssetsStorageError::DecodeFailed("Failed to decode asset approval".to_string())
+        })?;
+
+        approvals.push((delegate, approval));
+    }
+
+    approvals.sort_by_key(|(delegate, _)| *delegate);
+    Ok(approvals)
+}
+
+/// Find the `limit` largest holders of an asset by iterating its `Assets::Account` entries.
+///
+/// Every holder has to be visited to rank them, so the scan stops after `max_scan_time`
+/// and returns the largest holders seen so far with `complete` set to false.
+pub async fn get_top_asset_holders(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    asset_id: u32,
+    limit: usize,
+    max_scan_time: Duration,
+) -> Result<AssetHolders, AssetsStorageError> {
+    let storage_addr = subxt::dynamic::storage::<(u32, [u8; 32]), ()>("Assets", "Account");
+    let deadline = Instant::now() + max_scan_time;
+
+    let mut iter = client_at_block
+        .storage()
+        .iter(storage_addr, (asset_id,))
+        .await
+        .map_err(|e| AssetsStorageError::StorageFetchFailed(e.to_string()))?;
+
+    let mut largest = BinaryHeap::with_capacity(limit + 1);
+    let mut complete = true;
+    while let Some(result) = iter.next().await {
+        if Instant::now() >= deadline {
+            tracing::warn!("Asset {asset_id} holders scan stopped after {max_scan_time:?}");
+            complete = false;
+            break;
+        }
+
+        let entry = result.map_err(|e| AssetsStorageError::StorageFetchFailed(e.to_string()))?;
+
+        // The account is hashed with Blake2_128Concat, so it ends the key
+        let key = entry.key_bytes();
+        let Some(account) = key
+            .len()
+            .checked_sub(32)
+            .and_then(|start| <[u8; 32]>::try_from(&key[start..]).ok())
+        else {
+            continue;
+        };
+        // Every `AssetAccount` version starts with the balance
+        let Ok(balance) = u128::decode(&mut entry.value().bytes()) else {
+            continue;
+        };
+
+        retain_largest(&mut largest, (account, balance), limit);
+    }
+
+    Ok(AssetHolders {
+        holders: into_ranked_holders(largest),
+        complete,
+    })
+}
+
+/// Heap of the largest holders seen so far, smallest balance on top.
+type LargestHolders = BinaryHeap<Reverse<(u128, [u8; 32])>>;
+
+/// Add a holder, evicting the smallest one once more than `limit` are kept.
+fn retain_largest(
+    largest: &mut LargestHolders,
+    (account, balance): ([u8; 32], u128),
+    limit: usize,
+) {
+    largest.push(Reverse((balance, account)));
+    if largest.len() > limit {
+        largest.pop();
+    }
+}
+
+/// Order the kept holders by balance, largest first.
+fn into_ranked_holders(largest: LargestHolders) -> Vec<([u8; 32], u128)> {
+    // Ascending order of `Reverse` is descending order of balance
+    largest
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((balance, account))| (account, balance))
+        .collect()
+}
+
 // ================================================================================================
 // Internal Decoding Functions
 // ================================================================================================
@@ -362,15 +583,32 @@ fn decode_asset_balance(
             balance: account.balance.to_string(),
             is_frozen: account.status.is_frozen(),
             is_sufficient: account.reason.is_sufficient(),
+            status: Some(account.status.as_str().to_string()),
+            reason: Some(account.reason.as_str().to_string()),
+            metadata: None,
         }));
     }
 
-    // Fall back to legacy format (balance, is_frozen, sufficient)
+    // Fall back to legacy format (balance, is_frozen, sufficient), mapping the flags onto
+    // the statuses and reasons that replaced them
     if let Ok(account) = AssetAccountLegacy::decode(&mut &raw_bytes[..]) {
+        let status = if account.is_frozen {
+            AccountStatus::Frozen
+        } else {
+            AccountStatus::Liquid
+        };
+        let reason = if account.sufficient {
+            ExistenceReason::Sufficient
+        } else {
+            ExistenceReason::Consumer
+        };
         return Ok(Some(DecodedAssetBalance {
             balance: account.balance.to_string(),
             is_frozen: account.is_frozen,
             is_sufficient: account.sufficient,
+            status: Some(status.as_str().to_string()),
+            reason: Some(reason.as_str().to_string()),
+            metadata: None,
         }));
     }
 
@@ -402,9 +640,128 @@ fn decode_asset_approval(
 
 #[cfg(test)]
 mod tests {
-    use crate::handlers::runtime_queries::assets_common::{
-        AccountStatus, AssetStatus, ExistenceReason,
-    };
+    use super::*;
+    use parity_scale_codec::Encode;
+
+    use crate::handlers::runtime_queries::assets_common::AssetStatus;
+
+    fn encoded_balance(balance: u128) -> Vec<u8> {
+        // (balance, status: Liquid, reason: Sufficient, extra: ())
+        let mut bytes = balance.encode();
+        bytes.extend([0u8, 1u8]);
+        bytes
+    }
+
+    #[test]
+    fn test_collect_asset_balances_reports_failures() {
+        let results = vec![
+            (1, Ok(Some(encoded_balance(500))), Ok(None)),
+            (2, Ok(None), Ok(None)),
+            (3, Err(subxt::error::StorageError::NoValueFound), Ok(None)),
+            (4, Ok(Some(vec![0xff])), Ok(None)),
+        ];
+
+        let out = collect_asset_balances(results, false);
+
+        assert_eq!(out.balances.len(), 1);
+        assert_eq!(out.balances[0].0, 1);
+        assert_eq!(out.balances[0].1.balance, "500");
+        assert!(out.balances[0].1.is_sufficient);
+
+        let failed: Vec<u32> = out.failures.iter().map(|f| f.asset_id).collect();
+        assert_eq!(failed, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_collect_asset_balances_show_empty_keeps_order() {
+        let results = vec![
+            (3, Err(subxt::error::StorageError::NoValueFound), Ok(None)),
+            (1, Ok(None), Ok(None)),
+            (2, Ok(Some(encoded_balance(7))), Ok(None)),
+        ];
+
+        let out = collect_asset_balances(results, true);
+
+        let ids: Vec<u32> = out.balances.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![3, 1, 2]);
+        assert_eq!(out.balances[0].1.balance, "0");
+        assert_eq!(out.failures.len(), 1);
+    }
+
+    #[test]
+    fn test_collect_asset_balances_attaches_metadata() {
+        let metadata = AssetMetadata {
+            deposit: 0,
+            name: b"Tether USD".to_vec(),
+            symbol: b"USDt".to_vec(),
+            decimals: 6,
+            is_frozen: false,
+        };
+        let results = vec![
+            (
+                1984,
+                Ok(Some(encoded_balance(1_500_000))),
+                Ok(Some(metadata)),
+            ),
+            (1337, Ok(None), Ok(None)),
+        ];
+
+        let out = collect_asset_balances(results, true);
+
+        let attached = out.balances[0].1.metadata.as_ref().unwrap();
+        assert_eq!(attached.symbol, b"USDt");
+        assert_eq!(attached.decimals, 6);
+        assert!(out.balances[1].1.metadata.is_none());
+    }
+
+    #[test]
+    fn test_collect_asset_balances_reports_metadata_failures() {
+        let results = vec![(
+            1984,
+            Ok(Some(encoded_balance(1_500_000))),
+            Err(AssetsStorageError::StorageFetchFailed(
+                "connection reset".to_string(),
+            )),
+        )];
+
+        let out = collect_asset_balances(results, false);
+
+        assert_eq!(out.balances.len(), 1);
+        assert_eq!(out.balances[0].1.balance, "1500000");
+        assert!(out.balances[0].1.metadata.is_none());
+        assert_eq!(out.failures.len(), 1);
+        assert_eq!(out.failures[0].asset_id, 1984);
+        assert!(out.failures[0].reason.contains("connection reset"));
+    }
+
+    #[test]
+    fn test_retain_largest_keeps_top_holders_in_order() {
+        let mut largest = LargestHolders::new();
+        for (byte, balance) in [(1u8, 50u128), (2, 900), (3, 10), (4, 300), (5, 900)] {
+            retain_largest(&mut largest, ([byte; 32], balance), 3);
+        }
+
+        let ranked = into_ranked_holders(largest);
+        let balances: Vec<u128> = ranked.iter().map(|(_, balance)| *balance).collect();
+        assert_eq!(balances, [900, 900, 300]);
+        assert_eq!(ranked[2].0, [4u8; 32]);
+    }
+
+    #[test]
+    fn test_decode_asset_balance_status_and_reason() {
+        // (balance, status: Frozen, reason: DepositHeld(10), extra: ())
+        let mut bytes = 42u128.encode();
+        bytes.push(1);
+        bytes.push(2);
+        bytes.extend(10u128.encode());
+
+        let decoded = decode_asset_balance(&bytes).unwrap().unwrap();
+        assert_eq!(decoded.balance, "42");
+        assert!(decoded.is_frozen);
+        assert!(!decoded.is_sufficient);
+        assert_eq!(decoded.status.as_deref(), Some("Frozen"));
+        assert_eq!(decoded.reason.as_deref(), Some("DepositHeld"));
+    }
 
     #[test]
     fn test_asset_status_as_str() {
@@ -106,6 +106,15 @@ impl AccountStatus {
     pub fn is_frozen(&self) -> bool {
         matches!(self, AccountStatus::Frozen | AccountStatus::Blocked)
     }
+
+    /// Returns the status as a string for API responses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountStatus::Liquid => "Liquid",
+            AccountStatus::Frozen => "Frozen",
+            AccountStatus::Blocked => "Blocked",
+        }
+    }
 }
 
 /// Existence reason enum for modern asset accounts.
@@ -125,6 +134,17 @@ impl ExistenceReason {
     pub fn is_sufficient(&self) -> bool {
         matches!(self, ExistenceReason::Sufficient)
     }
+
+    /// Returns the reason variant name as a string for API responses.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExistenceReason::Consumer => "Consumer",
+            ExistenceReason::Sufficient => "Sufficient",
+            ExistenceReason::DepositHeld(_) => "DepositHeld",
+            ExistenceReason::DepositRefunded => "DepositRefunded",
+            ExistenceReason::DepositFrom(..) => "DepositFrom",
+        }
+    }
 }
 
 /// Modern asset account structure (with status and reason fields).
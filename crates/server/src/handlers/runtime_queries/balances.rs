@@ -292,7 +292,8 @@ fn decode_balance_locks(raw_bytes: &[u8]) -> Option<Vec<DecodedBalanceLock>> {
     )
 }
 
-fn decode_proxy_definitions(
+/// Decode a `Proxy::Proxies` value into its definitions and deposit.
+pub fn decode_proxy_definitions(
     raw_bytes: &[u8],
     ss58_prefix: u16,
 ) -> Option<(Vec<DecodedProxyDefinition>, u128)> {
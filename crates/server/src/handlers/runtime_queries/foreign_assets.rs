@@ -254,6 +254,26 @@ pub async fn iter_foreign_asset_metadata(
     Some(metadata_list)
 }
 
+/// Fetch a foreign asset's `ForeignAssets::Metadata`, or `None` if unset or undecodable.
+pub async fn try_fetch_foreign_asset_metadata(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    location: &Location,
+) -> Option<AssetMetadata> {
+    let storage_addr =
+        subxt::dynamic::storage::<(Location,), AssetMetadata>("ForeignAssets", "Metadata");
+    let value = client_at_block
+        .storage()
+        .try_fetch(storage_addr, (location.clone(),))
+        .await
+        .inspect_err(|e| tracing::debug!("Failed to fetch foreign asset metadata: {e:?}"))
+        .ok()??;
+
+    value
+        .decode()
+        .inspect_err(|e| tracing::debug!("Failed to decode foreign asset metadata: {e:?}"))
+        .ok()
+}
+
 /// Get foreign asset balance for a specific account and location.
 pub async fn get_foreign_asset_balance(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
@@ -18,10 +18,12 @@
 //! - `parachain_system` - ParachainSystem/ParachainInfo pallets (relay block number, para ID)
 //! - `paras` - Paras pallet (para lifecycles)
 //! - `pool_assets` - PoolAssets pallet (LP token details, metadata, balances, approvals)
+//! - `preimage` - Preimage pallet (noted preimages and their lengths)
 //! - `referenda` - Referenda pallet (referendum status, ongoing referenda)
 //! - `session` - Session pallet (validators, session index)
 //! - `staking` - Staking pallet (ledger, nominations, rewards, validators, etc.)
 //! - `system` - System pallet (events)
+//! - `tokens` - ORML Tokens pallet (multi-token account data and locks)
 
 pub mod asset_conversion;
 pub mod assets;
@@ -37,7 +39,9 @@ pub mod parachain_info;
 pub mod parachain_system;
 pub mod paras;
 pub mod pool_assets;
+pub mod preimage;
 pub mod referenda;
 pub mod session;
 pub mod staking;
 pub mod system;
+pub mod tokens;
@@ -134,6 +134,19 @@ where
     storage_value.decode_as().ok()
 }
 
+/// Checks whether NominationPools::BondedPools has an entry for `pool_id`.
+pub async fn bonded_pool_exists(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    pool_id: u32,
+) -> Result<bool, subxt::error::StorageError> {
+    let addr = subxt::dynamic::storage::<_, ()>("NominationPools", "BondedPools");
+    Ok(client_at_block
+        .storage()
+        .try_fetch(addr, (pool_id,))
+        .await?
+        .is_some())
+}
+
 /// Fetches bonded pool details from NominationPools::BondedPools storage.
 /// Automatically handles V1/V2 versioning.
 pub async fn get_bonded_pool(
@@ -8,7 +8,9 @@
 //!
 //! # Storage Items Covered
 //! - `Paras::ParaLifecycles` - Parachain lifecycle states
+//! - `Paras::Heads` - Latest included head data per parachain
 
+use parity_scale_codec::Decode;
 use subxt::ext::scale_decode::DecodeAsType;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 use thiserror::Error;
@@ -165,6 +167,43 @@ pub async fn get_para_lifecycles(
     Ok(lifecycles)
 }
 
+/// Fetches the raw head data stored in Paras::Heads for a parachain.
+///
+/// Returns `Ok(None)` when the para has no head, i.e. it is not registered.
+pub async fn get_para_head(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    para_id: u32,
+) -> Result<Option<Vec<u8>>, ParasStorageError> {
+    if !has_paras_pallet(client_at_block) {
+        return Err(ParasStorageError::PalletNotAvailable);
+    }
+
+    let heads_addr = subxt::dynamic::storage::<(u32,), ()>("Paras", "Heads");
+
+    let value = client_at_block
+        .storage()
+        .try_fetch(heads_addr, (para_id,))
+        .await
+        .map_err(|_| ParasStorageError::StorageFetchFailed {
+            pallet: "Paras",
+            entry: "Heads",
+        })?;
+
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    // HeadData is a SCALE-encoded `Vec<u8>` wrapping the parachain header
+    let bytes = value.into_bytes();
+    Vec::<u8>::decode(&mut &bytes[..]).map(Some).map_err(|e| {
+        ParasStorageError::StorageDecodeFailed {
+            pallet: "Paras",
+            entry: "Heads",
+            details: e.to_string(),
+        }
+    })
+}
+
 // ================================================================================================
 // Tests
 // ================================================================================================
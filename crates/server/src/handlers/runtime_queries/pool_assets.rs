@@ -85,6 +85,8 @@ pub struct DecodedPoolAssetBalance {
     pub balance: String,
     pub is_frozen: bool,
     pub is_sufficient: bool,
+    /// The LP token's `PoolAssets::Metadata`, only fetched when requested
+    pub metadata: Option<AssetMetadata>,
 }
 
 /// Decoded pool asset approval.
@@ -185,6 +187,29 @@ pub async fn get_pool_asset_info(
     }))
 }
 
+/// Fetch a pool asset's total supply from PoolAssets::Asset storage.
+///
+/// Returns `Ok(None)` if the pool asset does not exist.
+pub async fn get_pool_asset_supply(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    asset_id: u32,
+) -> Result<Option<u128>, PoolAssetsStorageError> {
+    let storage_addr = subxt::dynamic::storage::<_, AssetDetails>("PoolAssets", "Asset");
+    let Some(value) = client_at_block
+        .storage()
+        .try_fetch(storage_addr, (asset_id,))
+        .await
+        .map_err(|e| PoolAssetsStorageError::StorageFetchFailed(e.to_string()))?
+    else {
+        return Ok(None);
+    };
+
+    value
+        .decode()
+        .map(|details: AssetDetails| Some(details.supply))
+        .map_err(|e| PoolAssetsStorageError::DecodeFailed(e.to_string()))
+}
+
 /// Fetch pool asset metadata from PoolAssets::Metadata storage.
 ///
 /// Returns decoded metadata if it exists, None otherwise.
@@ -252,28 +277,42 @@ pub async fn get_pool_asset_balance(
 /// When `show_empty` is true, returns all requested assets including those with zero balance.
 ///
 /// This function executes all asset queries **in parallel** for optimal performance.
+/// When `with_metadata` is true, each LP token's `PoolAssets::Metadata` is fetched
+/// alongside its balance and attached to it.
 pub async fn get_pool_asset_balances(
     client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
     account: &AccountId32,
     asset_ids: &[u32],
     show_empty: bool,
+    with_metadata: bool,
 ) -> Result<Vec<(u32, DecodedPoolAssetBalance)>, PoolAssetsStorageError> {
     use futures::future::join_all;
 
     let account_bytes: [u8; 32] = *account.as_ref();
 
-    // Create futures for all pool asset balance queries
+    // Create futures for all pool asset balance queries, each paired with its metadata query
     let futures: Vec<_> = asset_ids
         .iter()
         .map(|&asset_id| {
             let storage_addr = subxt::dynamic::storage::<_, ()>("PoolAssets", "Account");
             async move {
-                let result = client_at_block
-                    .storage()
-                    .fetch(storage_addr, (asset_id, account_bytes))
-                    .await;
-
-                (asset_id, result)
+                let balance = async {
+                    client_at_block
+                        .storage()
+                        .fetch(storage_addr, (asset_id, account_bytes))
+                        .await
+                        .map(|value| value.into_bytes())
+                };
+                let metadata = async {
+                    if with_metadata {
+                        try_fetch_pool_asset_metadata(client_at_block, asset_id).await
+                    } else {
+                        Ok(None)
+                    }
+                };
+                let (result, metadata) = tokio::join!(balance, metadata);
+
+                (asset_id, result, metadata)
             }
         })
         .collect();
@@ -281,37 +320,56 @@ pub async fn get_pool_asset_balances(
     // Execute all queries in parallel
     let results = join_all(futures).await;
 
-    // Process results
+    Ok(collect_pool_asset_balances(results, show_empty))
+}
+
+/// Raw `PoolAssets::Account` bytes for an LP token.
+type RawPoolAssetAccountResult = Result<Vec<u8>, subxt::error::StorageError>;
+
+/// Turn raw `PoolAssets::Account` query results into decoded balances.
+///
+/// Each result's metadata, if any, is attached to its balance. A metadata failure only
+/// affects that LP token: it is logged and the raw balance is kept without metadata.
+fn collect_pool_asset_balances(
+    results: Vec<(
+        u32,
+        RawPoolAssetAccountResult,
+        Result<Option<AssetMetadata>, PoolAssetsStorageError>,
+    )>,
+    show_empty: bool,
+) -> Vec<(u32, DecodedPoolAssetBalance)> {
+    let empty_balance = |metadata| DecodedPoolAssetBalance {
+        balance: "0".to_string(),
+        is_frozen: false,
+        is_sufficient: false,
+        metadata,
+    };
+
     let mut balances = Vec::new();
-    for (asset_id, result) in results {
+    for (asset_id, result, metadata) in results {
+        let metadata = metadata.unwrap_or_else(|e| {
+            tracing::debug!("Failed to fetch metadata for pool asset {asset_id}: {e}");
+            None
+        });
         match result {
-            Ok(value) => {
-                let raw_bytes = value.into_bytes();
+            Ok(raw_bytes) => {
                 if let Ok(Some(decoded)) = decode_pool_asset_balance(&raw_bytes) {
-                    balances.push((asset_id, decoded));
-                } else if show_empty {
                     balances.push((
                         asset_id,
                         DecodedPoolAssetBalance {
-                            balance: "0".to_string(),
-                            is_frozen: false,
-                            is_sufficient: false,
+                            metadata,
+                            ..decoded
                         },
                     ));
+                } else if show_empty {
+                    balances.push((asset_id, empty_balance(metadata)));
                 }
             }
             Err(e) if show_empty => {
                 tracing::debug!(
                     "Failed to fetch pool asset balance for asset (show_empty) {asset_id}: {e:?}"
                 );
-                balances.push((
-                    asset_id,
-                    DecodedPoolAssetBalance {
-                        balance: "0".to_string(),
-                        is_frozen: false,
-                        is_sufficient: false,
-                    },
-                ));
+                balances.push((asset_id, empty_balance(metadata)));
             }
             Err(e) => {
                 tracing::debug!("Failed to fetch pool asset balance for asset {asset_id}: {e:?}");
@@ -319,7 +377,30 @@ pub async fn get_pool_asset_balances(
         }
     }
 
-    Ok(balances)
+    balances
+}
+
+/// Fetch and decode an LP token's `PoolAssets::Metadata`.
+///
+/// Returns `Ok(None)` if the token has no metadata set; fetch and decode failures are errors.
+pub async fn try_fetch_pool_asset_metadata(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    asset_id: u32,
+) -> Result<Option<AssetMetadata>, PoolAssetsStorageError> {
+    let storage_addr = subxt::dynamic::storage::<_, AssetMetadata>("PoolAssets", "Metadata");
+    let Some(value) = client_at_block
+        .storage()
+        .try_fetch(storage_addr, (asset_id,))
+        .await
+        .map_err(|e| PoolAssetsStorageError::StorageFetchFailed(e.to_string()))?
+    else {
+        return Ok(None);
+    };
+
+    value
+        .decode()
+        .map(Some)
+        .map_err(|e| PoolAssetsStorageError::DecodeFailed(e.to_string()))
 }
 
 /// Fetch pool asset approval from PoolAssets::Approvals storage.
@@ -365,6 +446,7 @@ fn decode_pool_asset_balance(
             balance: account.balance.to_string(),
             is_frozen: account.status.is_frozen(),
             is_sufficient: account.reason.is_sufficient(),
+            metadata: None,
         }));
     }
 
@@ -374,6 +456,7 @@ fn decode_pool_asset_balance(
             balance: account.balance.to_string(),
             is_frozen: account.is_frozen,
             is_sufficient: account.sufficient,
+            metadata: None,
         }));
     }
 
@@ -405,9 +488,47 @@ fn decode_pool_asset_approval(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::handlers::runtime_queries::assets_common::{
         AccountStatus, AssetStatus, ExistenceReason,
     };
+    use parity_scale_codec::Encode;
+
+    fn encoded_balance(balance: u128) -> Vec<u8> {
+        // (balance, status: Liquid, reason: Sufficient, extra: ())
+        let mut bytes = balance.encode();
+        bytes.extend([0u8, 1u8]);
+        bytes
+    }
+
+    #[test]
+    fn test_collect_pool_asset_balances_keeps_balance_on_metadata_failure() {
+        let metadata = AssetMetadata {
+            deposit: 0,
+            name: b"LP".to_vec(),
+            symbol: b"LP".to_vec(),
+            decimals: 12,
+            is_frozen: false,
+        };
+        let results = vec![
+            (
+                0,
+                Ok(encoded_balance(1_500_000)),
+                Err(PoolAssetsStorageError::StorageFetchFailed(
+                    "connection reset".to_string(),
+                )),
+            ),
+            (1, Ok(encoded_balance(7)), Ok(Some(metadata))),
+        ];
+
+        let balances = collect_pool_asset_balances(results, false);
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].0, 0);
+        assert_eq!(balances[0].1.balance, "1500000");
+        assert!(balances[0].1.metadata.is_none());
+        assert_eq!(balances[1].1.metadata.as_ref().unwrap().decimals, 12);
+    }
 
     #[test]
     fn test_asset_status_as_str() {
@@ -0,0 +1,132 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Preimage pallet storage query functions.
+//!
+//! This module provides standalone functions for looking up noted preimages,
+//! e.g. the calls that referenda propose by hash.
+
+use scale_decode::DecodeAsType;
+use subxt::{OnlineClientAtBlock, SubstrateConfig};
+use thiserror::Error;
+
+// ================================================================================================
+// Error Types
+// ================================================================================================
+
+/// Errors that can occur when reading a preimage from storage.
+#[derive(Debug, Error)]
+pub enum PreimageStorageError {
+    #[error("Failed to fetch Preimage::{entry}: {details}")]
+    FetchFailed {
+        entry: &'static str,
+        details: String,
+    },
+
+    #[error("Failed to decode Preimage::{entry}: {details}")]
+    DecodeFailed {
+        entry: &'static str,
+        details: String,
+    },
+}
+
+// ================================================================================================
+// SCALE Decode Types
+// ================================================================================================
+
+/// `Preimage::RequestStatusFor` value - we only care about the length
+#[derive(Debug, DecodeAsType)]
+enum RequestStatus {
+    Unrequested { len: u32 },
+    Requested { maybe_len: Option<u32> },
+}
+
+/// Legacy `Preimage::StatusFor` value - we only care about the length
+#[derive(Debug, DecodeAsType)]
+enum OldRequestStatus {
+    Unrequested { len: u32 },
+    Requested { len: Option<u32> },
+}
+
+// ================================================================================================
+// Storage Query Functions
+// ================================================================================================
+
+/// Get a preimage's length from its request status.
+///
+/// Reads `Preimage::RequestStatusFor`, or the legacy `Preimage::StatusFor` on runtimes
+/// that predate it. Returns None if the preimage is unknown or its length isn't recorded.
+pub async fn get_preimage_len(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    hash: [u8; 32],
+) -> Result<Option<u32>, PreimageStorageError> {
+    if has_entry(client_at_block, "RequestStatusFor") {
+        let storage_addr =
+            subxt::dynamic::storage::<([u8; 32],), RequestStatus>("Preimage", "RequestStatusFor");
+        let status =
+            fetch_entry(client_at_block, "RequestStatusFor", storage_addr, (hash,)).await?;
+        return Ok(status.and_then(|status| match status {
+            RequestStatus::Unrequested { len } => Some(len),
+            RequestStatus::Requested { maybe_len } => maybe_len,
+        }));
+    }
+
+    if !has_entry(client_at_block, "StatusFor") {
+        return Ok(None);
+    }
+    let legacy_addr =
+        subxt::dynamic::storage::<([u8; 32],), OldRequestStatus>("Preimage", "StatusFor");
+    let status = fetch_entry(client_at_block, "StatusFor", legacy_addr, (hash,)).await?;
+    Ok(status.and_then(|status| match status {
+        OldRequestStatus::Unrequested { len } => Some(len),
+        OldRequestStatus::Requested { len } => len,
+    }))
+}
+
+/// Get a preimage's bytes from `Preimage::PreimageFor`.
+/// Returns None if the preimage isn't stored.
+pub async fn get_preimage(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    hash: [u8; 32],
+    len: u32,
+) -> Result<Option<Vec<u8>>, PreimageStorageError> {
+    if !has_entry(client_at_block, "PreimageFor") {
+        return Ok(None);
+    }
+    let storage_addr =
+        subxt::dynamic::storage::<(([u8; 32], u32),), Vec<u8>>("Preimage", "PreimageFor");
+    fetch_entry(client_at_block, "PreimageFor", storage_addr, ((hash, len),)).await
+}
+
+/// Whether this runtime's Preimage pallet has the storage entry `entry`.
+fn has_entry(client_at_block: &OnlineClientAtBlock<SubstrateConfig>, entry: &str) -> bool {
+    client_at_block.storage().entry(("Preimage", entry)).is_ok()
+}
+
+/// Fetch and decode the Preimage pallet storage value `entry`, or None if it isn't set.
+async fn fetch_entry<Addr: subxt::storage::Address>(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    entry: &'static str,
+    storage_addr: Addr,
+    key_parts: Addr::KeyParts,
+) -> Result<Option<Addr::Value>, PreimageStorageError> {
+    let Some(value) = client_at_block
+        .storage()
+        .try_fetch(storage_addr, key_parts)
+        .await
+        .map_err(|e| PreimageStorageError::FetchFailed {
+            entry,
+            details: e.to_string(),
+        })?
+    else {
+        return Ok(None);
+    };
+
+    value
+        .decode()
+        .map(Some)
+        .map_err(|e| PreimageStorageError::DecodeFailed {
+            entry,
+            details: e.to_string(),
+        })
+}
@@ -8,6 +8,21 @@
 use futures::future::join_all;
 use scale_decode::DecodeAsType;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
+use thiserror::Error;
+
+// ================================================================================================
+// Error Types
+// ================================================================================================
+
+/// Errors that can occur when reading a referendum from storage.
+#[derive(Debug, Error)]
+pub enum ReferendaStorageError {
+    #[error("Failed to fetch Referenda::ReferendumInfoFor for referendum {id}: {details}")]
+    FetchFailed { id: u32, details: String },
+
+    #[error("Failed to decode Referenda::ReferendumInfoFor for referendum {id}: {details}")]
+    DecodeFailed { id: u32, details: String },
+}
 
 // ================================================================================================
 // SCALE Decode Types
@@ -62,6 +77,48 @@ pub struct DecidingDetails {
     pub confirming: Option<u32>,
 }
 
+/// Full referendum info, decoding every variant of `ReferendumInfoFor`.
+#[derive(Debug, DecodeAsType)]
+pub enum ReferendumDetails {
+    Ongoing(Box<OngoingFullDetails>),
+    Approved(u32, Option<DepositDetails>, Option<DepositDetails>),
+    Rejected(u32, Option<DepositDetails>, Option<DepositDetails>),
+    Cancelled(u32, Option<DepositDetails>, Option<DepositDetails>),
+    TimedOut(u32, Option<DepositDetails>, Option<DepositDetails>),
+    Killed(u32),
+}
+
+/// Details for ongoing referenda, including the proposal and tally.
+/// The origin and alarm are skipped.
+#[derive(Debug, DecodeAsType)]
+pub struct OngoingFullDetails {
+    pub track: u16,
+    pub proposal: BoundedProposal,
+    pub enactment: EnactmentType,
+    pub submitted: u32,
+    pub submission_deposit: DepositDetails,
+    pub decision_deposit: Option<DepositDetails>,
+    pub deciding: Option<DecidingDetails>,
+    pub tally: TallyDetails,
+    pub in_queue: bool,
+}
+
+/// A referendum's proposal, as a `Bounded<Call>`
+#[derive(Debug, DecodeAsType)]
+pub enum BoundedProposal {
+    Legacy { hash: [u8; 32] },
+    Inline(Vec<u8>),
+    Lookup { hash: [u8; 32], len: u32 },
+}
+
+/// Conviction voting tally
+#[derive(Debug, DecodeAsType)]
+pub struct TallyDetails {
+    pub ayes: u128,
+    pub nays: u128,
+    pub support: u128,
+}
+
 // ================================================================================================
 // Decoded Result Types
 // ================================================================================================
@@ -121,6 +178,37 @@ pub async fn get_referendum_info(
     }
 }
 
+/// Fetch a single referendum by ID, decoding every `ReferendumInfoFor` variant.
+///
+/// Returns `Ok(None)` if the referendum does not exist.
+pub async fn get_referendum_details(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    referendum_id: u32,
+) -> Result<Option<ReferendumDetails>, ReferendaStorageError> {
+    let storage_addr =
+        subxt::dynamic::storage::<_, ReferendumDetails>("Referenda", "ReferendumInfoFor");
+
+    let Some(value) = client_at_block
+        .storage()
+        .try_fetch(storage_addr, (referendum_id,))
+        .await
+        .map_err(|e| ReferendaStorageError::FetchFailed {
+            id: referendum_id,
+            details: e.to_string(),
+        })?
+    else {
+        return Ok(None);
+    };
+
+    value
+        .decode()
+        .map(Some)
+        .map_err(|e| ReferendaStorageError::DecodeFailed {
+            id: referendum_id,
+            details: e.to_string(),
+        })
+}
+
 /// Fetch all ongoing referenda in a batch.
 /// Returns a vector of (referendum_id, ReferendumStatus) pairs.
 pub async fn iter_referenda_batch(
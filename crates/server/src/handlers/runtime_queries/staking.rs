@@ -212,8 +212,35 @@ pub struct DecodedNominationsInfo {
     pub suppressed: bool,
 }
 
+/// Ledger field that lists the eras a stash has claimed rewards for. Runtimes with paged
+/// staking renamed `claimed_rewards` to `legacy_claimed_rewards` and track new claims in
+/// `Staking.ClaimedRewards` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimedRewardsSource {
+    ClaimedRewards,
+    LegacyClaimedRewards,
+}
+
+impl ClaimedRewardsSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClaimedRewardsSource::ClaimedRewards => "claimedRewards",
+            ClaimedRewardsSource::LegacyClaimedRewards => "legacyClaimedRewards",
+        }
+    }
+}
+
+/// Claimed-rewards eras read from the staking ledger
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedLedgerClaimedRewards {
+    /// Eras recorded as claimed
+    pub eras: Vec<u32>,
+    /// Field the eras were read from
+    pub source: ClaimedRewardsSource,
+}
+
 /// Decoded staking ledger
-#[derive(Debug, Clone, Decode)]
+#[derive(Debug, Clone)]
 pub struct DecodedStakingLedger {
     /// Stash account address
     pub stash: String,
@@ -223,6 +250,8 @@ pub struct DecodedStakingLedger {
     pub active: String,
     /// Unlocking chunks
     pub unlocking: Vec<DecodedUnlockingChunk>,
+    /// Claimed-rewards eras, when the runtime's ledger still carries them
+    pub claimed_rewards: Option<DecodedLedgerClaimedRewards>,
 }
 
 /// Decoded unlocking chunk
@@ -289,59 +318,101 @@ pub async fn get_staking_ledger(
         .await
     {
         let raw_bytes = value.into_bytes();
-        if let Ok(ledger) = StakingLedger::decode(&mut &raw_bytes[..]) {
-            return Ok(DecodedStakingLedger {
-                stash: AccountId32::from(ledger.stash)
-                    .to_ss58check_with_version(ss58_prefix.into()),
-                total: ledger.total.to_string(),
-                active: ledger.active.to_string(),
-                unlocking: ledger
-                    .unlocking
-                    .into_iter()
-                    .map(|chunk| DecodedUnlockingChunk {
-                        value: chunk.value.to_string(),
-                        era: chunk.era.to_string(),
-                    })
-                    .collect(),
-            });
-        } else if let Ok(ledger) = StakingLedgerLegacyCompact::decode(&mut &raw_bytes[..]) {
-            return Ok(DecodedStakingLedger {
-                stash: AccountId32::from(ledger.stash)
-                    .to_ss58check_with_version(ss58_prefix.into()),
-                total: ledger.total.to_string(),
-                active: ledger.active.to_string(),
-                unlocking: ledger
-                    .unlocking
-                    .into_iter()
-                    .map(|chunk| DecodedUnlockingChunk {
-                        value: chunk.value.to_string(),
-                        era: chunk.era.to_string(),
-                    })
-                    .collect(),
-            });
-        } else if let Ok(ledger) = StakingLedgerOld::decode(&mut &raw_bytes[..]) {
-            return Ok(DecodedStakingLedger {
-                stash: AccountId32::from(ledger.stash)
-                    .to_ss58check_with_version(ss58_prefix.into()),
-                total: ledger.total.to_string(),
-                active: ledger.active.to_string(),
-                unlocking: ledger
-                    .unlocking
-                    .into_iter()
-                    .map(|chunk| DecodedUnlockingChunk {
-                        value: chunk.value.to_string(),
-                        era: chunk.era.to_string(),
-                    })
-                    .collect(),
-            });
-        }
+        let claimed_rewards_source = ledger_claimed_rewards_source(&client_at_block.metadata());
+        return decode_staking_ledger(&raw_bytes, claimed_rewards_source, ss58_prefix);
+    }
+
+    Err(StakingStorageError::LedgerNotFound)
+}
+
+/// Find which field, if any, holds the claimed-rewards eras in this runtime's `Staking.Ledger`.
+fn ledger_claimed_rewards_source(metadata: &subxt::Metadata) -> Option<ClaimedRewardsSource> {
+    let entry = metadata
+        .pallet_by_name("Staking")?
+        .storage()?
+        .entry_by_name("Ledger")?;
+    let scale_info::TypeDef::Composite(ledger) =
+        &metadata.types().resolve(entry.value_ty())?.type_def
+    else {
+        return None;
+    };
+
+    ledger
+        .fields
+        .iter()
+        .find_map(|field| match field.name.as_deref() {
+            Some("claimed_rewards") => Some(ClaimedRewardsSource::ClaimedRewards),
+            Some("legacy_claimed_rewards") => Some(ClaimedRewardsSource::LegacyClaimedRewards),
+            _ => None,
+        })
+}
 
-        return Err(StakingStorageError::DecodeFailed(
-            "Failed to decode staking ledger: unknown type".into(),
+/// Decode a `Staking.Ledger` value. The claimed eras are only read when metadata says the
+/// ledger carries them, since the shorter layouts decode from the same prefix.
+fn decode_staking_ledger(
+    raw_bytes: &[u8],
+    claimed_rewards_source: Option<ClaimedRewardsSource>,
+    ss58_prefix: u16,
+) -> Result<DecodedStakingLedger, StakingStorageError> {
+    let build = |stash: [u8; 32],
+                 total: u128,
+                 active: u128,
+                 unlocking: Vec<(u128, u32)>,
+                 claimed_eras: Option<Vec<u32>>| DecodedStakingLedger {
+        stash: AccountId32::from(stash).to_ss58check_with_version(ss58_prefix.into()),
+        total: total.to_string(),
+        active: active.to_string(),
+        unlocking: unlocking
+            .into_iter()
+            .map(|(value, era)| DecodedUnlockingChunk {
+                value: value.to_string(),
+                era: era.to_string(),
+            })
+            .collect(),
+        claimed_rewards: claimed_eras
+            .zip(claimed_rewards_source)
+            .map(|(eras, source)| DecodedLedgerClaimedRewards { eras, source }),
+    };
+    let compact_chunks =
+        |chunks: Vec<UnlockChunkCompact>| chunks.into_iter().map(|c| (c.value, c.era)).collect();
+
+    if claimed_rewards_source.is_some()
+        && let Ok(ledger) = StakingLedgerLegacyCompact::decode(&mut &raw_bytes[..])
+    {
+        return Ok(build(
+            ledger.stash,
+            ledger.total,
+            ledger.active,
+            compact_chunks(ledger.unlocking),
+            Some(ledger.legacy_claimed_rewards),
+        ));
+    }
+    if let Ok(ledger) = StakingLedger::decode(&mut &raw_bytes[..]) {
+        return Ok(build(
+            ledger.stash,
+            ledger.total,
+            ledger.active,
+            compact_chunks(ledger.unlocking),
+            None,
+        ));
+    }
+    if let Ok(ledger) = StakingLedgerOld::decode(&mut &raw_bytes[..]) {
+        return Ok(build(
+            ledger.stash,
+            ledger.total,
+            ledger.active,
+            ledger
+                .unlocking
+                .into_iter()
+                .map(|c| (c.value, c.era))
+                .collect(),
+            Some(ledger.claimed_rewards),
         ));
     }
 
-    Err(StakingStorageError::LedgerNotFound)
+    Err(StakingStorageError::DecodeFailed(
+        "Failed to decode staking ledger: unknown type".into(),
+    ))
 }
 
 /// Get the reward destination (Payee) for a stash account.
@@ -490,10 +561,16 @@ pub async fn get_current_era(
     None
 }
 
-/// Get history depth from `Staking.HistoryDepth` storage.
+/// Get history depth from the `Staking.HistoryDepth` constant.
 ///
-/// Returns the history depth, defaulting to 84 if not found.
+/// Falls back to the legacy `Staking.HistoryDepth` storage item used by older runtimes,
+/// defaulting to 84 if neither is found.
 pub async fn get_history_depth(client_at_block: &OnlineClientAtBlock<SubstrateConfig>) -> u32 {
+    let addr = subxt::dynamic::constant::<u32>("Staking", "HistoryDepth");
+    if let Ok(depth) = client_at_block.constants().entry(addr) {
+        return depth;
+    }
+
     let storage_addr = subxt::dynamic::storage::<_, ()>("Staking", "HistoryDepth");
 
     if let Ok(value) = client_at_block.storage().fetch(storage_addr, ()).await {
@@ -1704,3 +1781,65 @@ pub async fn iter_unapplied_slashes(
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::{Compact, Encode};
+
+    fn encoded_ledger(claimed_eras: Option<&[u32]>) -> Vec<u8> {
+        let mut bytes = [7u8; 32].encode();
+        bytes.extend(Compact(300u128).encode());
+        bytes.extend(Compact(200u128).encode());
+        bytes.extend(vec![(Compact(100u128), Compact(1500u32))].encode());
+        if let Some(eras) = claimed_eras {
+            bytes.extend(eras.encode());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_ledger_claimed_rewards_source_from_metadata() {
+        // Asset Hub runs `pallet_staking_async`, whose ledger dropped the claimed eras
+        let metadata = subxt::Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        assert!(
+            metadata
+                .pallet_by_name("Staking")
+                .and_then(|p| p.storage())
+                .and_then(|s| s.entry_by_name("Ledger"))
+                .is_some()
+        );
+        assert_eq!(ledger_claimed_rewards_source(&metadata), None);
+    }
+
+    #[test]
+    fn test_decode_staking_ledger_reads_claimed_eras() {
+        let ledger = decode_staking_ledger(
+            &encoded_ledger(Some(&[1400, 1401])),
+            Some(ClaimedRewardsSource::LegacyClaimedRewards),
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(ledger.total, "300");
+        assert_eq!(ledger.active, "200");
+        assert_eq!(ledger.unlocking.len(), 1);
+        assert_eq!(ledger.unlocking[0].era, "1500");
+        assert_eq!(
+            ledger.claimed_rewards,
+            Some(DecodedLedgerClaimedRewards {
+                eras: vec![1400, 1401],
+                source: ClaimedRewardsSource::LegacyClaimedRewards,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_staking_ledger_without_claimed_field() {
+        let ledger = decode_staking_ledger(&encoded_ledger(None), None, 0).unwrap();
+
+        assert_eq!(ledger.total, "300");
+        assert_eq!(ledger.claimed_rewards, None);
+    }
+}
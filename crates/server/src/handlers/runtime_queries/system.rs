@@ -7,7 +7,12 @@
 //!
 //! # Storage Items Covered
 //! - `System::Events` - Block events
+//! - `System::BlockWeight` - Weight consumed per dispatch class
+//!
+//! # Constants Covered
+//! - `System::BlockWeights` - Block and per-class weight limits
 
+use scale_decode::DecodeAsType;
 use subxt::{OnlineClientAtBlock, SubstrateConfig};
 use thiserror::Error;
 
@@ -27,6 +32,46 @@ pub enum SystemStorageError {
     StorageError(#[from] subxt::error::StorageError),
 }
 
+// ================================================================================================
+// SCALE Decode Types
+// ================================================================================================
+
+/// Two-dimensional weight (`sp_weights::Weight`)
+#[derive(Debug, Clone, Copy, PartialEq, DecodeAsType)]
+pub struct Weight {
+    pub ref_time: u64,
+    pub proof_size: u64,
+}
+
+/// `System::BlockWeights` constant - we only care about the limits
+#[derive(Debug, Clone, DecodeAsType)]
+pub struct BlockWeights {
+    pub max_block: Weight,
+    pub per_class: PerDispatchClass,
+}
+
+/// Per-class weight limits, keyed by dispatch class
+#[derive(Debug, Clone, DecodeAsType)]
+pub struct PerDispatchClass {
+    pub normal: WeightsPerClass,
+    pub operational: WeightsPerClass,
+    pub mandatory: WeightsPerClass,
+}
+
+/// Weight limits of one dispatch class. `max_total` is None when the class is unlimited.
+#[derive(Debug, Clone, DecodeAsType)]
+pub struct WeightsPerClass {
+    pub max_total: Option<Weight>,
+}
+
+/// `System::BlockWeight` storage - weight consumed per dispatch class
+#[derive(Debug, Clone, PartialEq, DecodeAsType)]
+pub struct ConsumedWeight {
+    pub normal: Weight,
+    pub operational: Weight,
+    pub mandatory: Weight,
+}
+
 // ================================================================================================
 // Storage Query Functions
 // ================================================================================================
@@ -45,3 +90,87 @@ pub async fn get_events_raw(
         .await
         .map_err(SystemStorageError::from)
 }
+
+/// Fetches `System::BlockWeight`, the weight the block consumed per dispatch class.
+///
+/// This includes hooks and other weight registered outside of extrinsics. Returns None
+/// when the entry is missing or its weights predate the two-dimensional `Weight`.
+pub async fn get_block_weight(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+) -> Result<Option<ConsumedWeight>, subxt::error::StorageError> {
+    let addr = subxt::dynamic::storage::<(), ConsumedWeight>("System", "BlockWeight");
+    let Some(value) = client_at_block.storage().try_fetch(addr, ()).await? else {
+        return Ok(None);
+    };
+
+    Ok(value
+        .decode()
+        .inspect_err(|e| tracing::debug!("Failed to decode System::BlockWeight: {e:?}"))
+        .ok())
+}
+
+/// Fetches the `System::BlockWeights` constant.
+///
+/// Returns None on runtimes whose weights predate the two-dimensional `Weight`.
+pub fn get_block_weights(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+) -> Option<BlockWeights> {
+    let addr = subxt::dynamic::constant::<BlockWeights>("System", "BlockWeights");
+    client_at_block
+        .constants()
+        .entry(addr)
+        .inspect_err(|e| tracing::debug!("Failed to fetch System::BlockWeights constant: {e:?}"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::Decode;
+    use subxt::Metadata;
+
+    #[test]
+    fn test_block_weights_decode_from_metadata_constant() {
+        let metadata = Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let constant = metadata
+            .pallet_by_name("System")
+            .and_then(|p| p.constant_by_name("BlockWeights"))
+            .unwrap();
+
+        let weights =
+            BlockWeights::decode_as_type(&mut constant.value(), constant.ty(), metadata.types())
+                .unwrap();
+
+        assert!(weights.max_block.ref_time > 0);
+        assert!(weights.max_block.proof_size > 0);
+        let normal = weights.per_class.normal.max_total.unwrap();
+        assert!(normal.ref_time < weights.max_block.ref_time);
+    }
+
+    #[test]
+    fn test_consumed_weight_decodes_block_weight_storage_type() {
+        use parity_scale_codec::{Compact, Encode};
+
+        let metadata = Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let entry = metadata
+            .pallet_by_name("System")
+            .and_then(|p| p.storage())
+            .and_then(|s| s.entry_by_name("BlockWeight"))
+            .unwrap();
+
+        // PerDispatchClass<Weight> with compact-encoded weight fields
+        let bytes = [(1u64, 2u64), (3, 4), (5, 6)]
+            .iter()
+            .flat_map(|&(ref_time, proof_size)| (Compact(ref_time), Compact(proof_size)).encode())
+            .collect::<Vec<u8>>();
+
+        let consumed =
+            ConsumedWeight::decode_as_type(&mut &bytes[..], entry.value_ty(), metadata.types())
+                .unwrap();
+
+        assert_eq!(consumed.normal.ref_time, 1);
+        assert_eq!(consumed.operational.proof_size, 4);
+        assert_eq!(consumed.mandatory.ref_time, 5);
+    }
+}
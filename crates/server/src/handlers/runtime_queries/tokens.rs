@@ -0,0 +1,243 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! ORML Tokens pallet storage query functions.
+//!
+//! Chains with multiple native tokens (e.g. Acala, Bifrost) keep non-native token
+//! balances in `Tokens::Accounts` and `Tokens::Locks`, keyed by `(AccountId, CurrencyId)`.
+//! The `CurrencyId` type differs between chains, so keys are built dynamically and
+//! encoded against the runtime metadata.
+
+use crate::handlers::runtime_queries::balances::DecodedBalanceLock;
+use parity_scale_codec::Decode;
+use sp_core::crypto::AccountId32;
+use subxt::dynamic::Value;
+use subxt::error::StorageError;
+use subxt::{OnlineClientAtBlock, SubstrateConfig};
+use thiserror::Error;
+
+// ================================================================================================
+// Error Types
+// ================================================================================================
+
+/// Errors that can occur when reading ORML Tokens storage.
+#[derive(Debug, Error)]
+pub enum TokensStorageError {
+    #[error("Token {0} cannot be encoded as this chain's CurrencyId")]
+    UnsupportedCurrencyId(String),
+
+    #[error("Failed to fetch Tokens::{entry} for {symbol}: {source}")]
+    FetchFailed {
+        entry: &'static str,
+        symbol: String,
+        #[source]
+        source: Box<StorageError>,
+    },
+
+    #[error("Failed to decode Tokens::{entry} for {symbol}: {source}")]
+    DecodeFailed {
+        entry: &'static str,
+        symbol: String,
+        #[source]
+        source: parity_scale_codec::Error,
+    },
+}
+
+// ================================================================================================
+// SCALE Decode Types
+// ================================================================================================
+
+/// orml_tokens::AccountData
+#[derive(Debug, Clone, Decode)]
+struct TokenAccountData {
+    free: u128,
+    reserved: u128,
+    frozen: u128,
+}
+
+/// orml_tokens::BalanceLock
+#[derive(Debug, Clone, Decode)]
+struct TokenBalanceLock {
+    id: [u8; 8],
+    amount: u128,
+}
+
+// ================================================================================================
+// Public Data Types
+// ================================================================================================
+
+/// Decoded token account data
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DecodedTokenAccountData {
+    pub free: u128,
+    pub reserved: u128,
+    pub frozen: u128,
+}
+
+// ================================================================================================
+// Storage Query Functions
+// ================================================================================================
+
+/// Check whether the runtime includes the ORML Tokens pallet.
+pub fn has_tokens_pallet(client_at_block: &OnlineClientAtBlock<SubstrateConfig>) -> bool {
+    client_at_block
+        .storage()
+        .entry(("Tokens", "Accounts"))
+        .is_ok()
+}
+
+/// Get token account data from Tokens::Accounts storage.
+///
+/// Returns `Ok(None)` if the account holds no balance of this token.
+pub async fn get_token_account_data(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    account: &AccountId32,
+    symbol: &str,
+) -> Result<Option<DecodedTokenAccountData>, TokensStorageError> {
+    let Some(raw_bytes) = fetch_token_storage(client_at_block, "Accounts", account, symbol).await?
+    else {
+        return Ok(None);
+    };
+    decode_token_account_data(&raw_bytes)
+        .map(Some)
+        .map_err(|source| TokensStorageError::DecodeFailed {
+            entry: "Accounts",
+            symbol: symbol.to_string(),
+            source,
+        })
+}
+
+/// Get token locks from Tokens::Locks storage.
+pub async fn get_token_locks(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    account: &AccountId32,
+    symbol: &str,
+) -> Result<Vec<DecodedBalanceLock>, TokensStorageError> {
+    let Some(raw_bytes) = fetch_token_storage(client_at_block, "Locks", account, symbol).await?
+    else {
+        return Ok(Vec::new());
+    };
+    decode_token_locks(&raw_bytes).map_err(|source| TokensStorageError::DecodeFailed {
+        entry: "Locks",
+        symbol: symbol.to_string(),
+        source,
+    })
+}
+
+/// Fetch a `(AccountId, CurrencyId)` keyed Tokens storage entry, trying each
+/// supported `CurrencyId` shape until one encodes against the metadata.
+///
+/// Returns `Ok(None)` if the entry is not set for the key that encoded.
+async fn fetch_token_storage(
+    client_at_block: &OnlineClientAtBlock<SubstrateConfig>,
+    entry: &'static str,
+    account: &AccountId32,
+    symbol: &str,
+) -> Result<Option<Vec<u8>>, TokensStorageError> {
+    let account_bytes: [u8; 32] = *account.as_ref();
+
+    for currency_id in currency_id_candidates(symbol) {
+        let storage_addr = subxt::dynamic::storage::<([u8; 32], Value), ()>("Tokens", entry);
+        match client_at_block
+            .storage()
+            .try_fetch(storage_addr, (account_bytes, currency_id))
+            .await
+        {
+            Ok(value) => return Ok(value.map(|value| value.into_bytes())),
+            // This chain uses a different CurrencyId shape; try the next candidate
+            Err(StorageError::StorageKeyEncodeError(e)) => {
+                tracing::debug!("Tokens::{entry} key for {symbol} did not encode: {e:?}");
+            }
+            Err(e) => {
+                return Err(TokensStorageError::FetchFailed {
+                    entry,
+                    symbol: symbol.to_string(),
+                    source: Box::new(e),
+                });
+            }
+        }
+    }
+
+    Err(TokensStorageError::UnsupportedCurrencyId(
+        symbol.to_string(),
+    ))
+}
+
+/// Candidate `CurrencyId` values for a token symbol.
+///
+/// Most ORML chains use `CurrencyId::Token(TokenSymbol::X)`; some use a flat
+/// `CurrencyId::X` enum instead.
+fn currency_id_candidates(symbol: &str) -> [Value; 2] {
+    [
+        Value::unnamed_variant("Token", [Value::unnamed_variant(symbol, [])]),
+        Value::unnamed_variant(symbol, []),
+    ]
+}
+
+// ================================================================================================
+// Decoding Functions
+// ================================================================================================
+
+fn decode_token_account_data(
+    raw_bytes: &[u8],
+) -> Result<DecodedTokenAccountData, parity_scale_codec::Error> {
+    let data = TokenAccountData::decode(&mut &raw_bytes[..])?;
+    Ok(DecodedTokenAccountData {
+        free: data.free,
+        reserved: data.reserved,
+        frozen: data.frozen,
+    })
+}
+
+fn decode_token_locks(
+    raw_bytes: &[u8],
+) -> Result<Vec<DecodedBalanceLock>, parity_scale_codec::Error> {
+    let locks = Vec::<TokenBalanceLock>::decode(&mut &raw_bytes[..])?;
+
+    Ok(locks
+        .into_iter()
+        .map(|lock| DecodedBalanceLock {
+            id: format!("0x{}", hex::encode(lock.id)),
+            amount: lock.amount,
+            // ORML locks have no reasons; they freeze the balance for all withdrawals
+            reasons: "All".to_string(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+
+    #[test]
+    fn test_decode_token_account_data() {
+        let bytes = (100u128, 20u128, 5u128).encode();
+        assert_eq!(
+            decode_token_account_data(&bytes).ok(),
+            Some(DecodedTokenAccountData {
+                free: 100,
+                reserved: 20,
+                frozen: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_token_locks() {
+        let bytes = vec![(*b"staking ", 42u128)].encode();
+        let locks = decode_token_locks(&bytes).unwrap();
+
+        assert_eq!(locks.len(), 1);
+        assert_eq!(locks[0].id, "0x7374616b696e6720");
+        assert_eq!(locks[0].amount, 42);
+        assert_eq!(locks[0].reasons, "All");
+    }
+
+    #[test]
+    fn test_currency_id_candidates() {
+        let [nested, flat] = currency_id_candidates("KSM");
+        assert_eq!(nested.to_string(), "Token (KSM ())");
+        assert_eq!(flat.to_string(), "KSM ()");
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Query parameters, errors and payload building shared by the streaming block endpoints.
+//!
+//! Both transports read block numbers from the shared [`FinalizedHeadsHub`], so any number
+//! of WebSocket and SSE clients are served by a single RPC subscription.
+//!
+//! [`FinalizedHeadsHub`]: crate::utils::FinalizedHeadsHub
+
+use crate::handlers::blocks::get_block_head::{BlockHeadQueryParams, build_head_block_response};
+use crate::handlers::blocks::get_blocks_head_header::build_head_header_response;
+use crate::state::AppState;
+use crate::utils::{self, FinalizedHeadsError};
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use thiserror::Error;
+
+// ================================================================================================
+// Error Types
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum SubscriptionError {
+    #[error(transparent)]
+    Subscribe(#[from] FinalizedHeadsError),
+}
+
+impl IntoResponse for SubscriptionError {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            SubscriptionError::Subscribe(FinalizedHeadsError::TooManySubscriptions) => {
+                (StatusCode::SERVICE_UNAVAILABLE, self.to_string())
+            }
+            SubscriptionError::Subscribe(FinalizedHeadsError::SubscribeFailed(err)) => {
+                utils::rpc_error_to_status(err)
+            }
+        };
+
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
+
+// ================================================================================================
+// Query Parameters
+// ================================================================================================
+
+/// Query parameters for the streaming block endpoints
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BlockSubscriptionParams {
+    /// When true, push only block headers instead of full blocks
+    #[serde(default)]
+    pub header_only: bool,
+    /// When true, include documentation for events
+    #[serde(default)]
+    pub event_docs: bool,
+    /// When true, include documentation for extrinsics
+    #[serde(default)]
+    pub extrinsic_docs: bool,
+    /// When true, skip fee calculation for extrinsics
+    #[serde(default)]
+    pub no_fees: bool,
+}
+
+impl BlockSubscriptionParams {
+    fn head_params(&self) -> BlockHeadQueryParams {
+        BlockHeadQueryParams {
+            event_docs: self.event_docs,
+            extrinsic_docs: self.extrinsic_docs,
+            no_fees: self.no_fees,
+            ..Default::default()
+        }
+    }
+}
+
+// ================================================================================================
+// Payload Building
+// ================================================================================================
+
+/// Build the JSON payload pushed to clients for a finalized block.
+///
+/// Failures are reported in-band as `{"error", "number"}` so a single bad block does not
+/// terminate the stream.
+pub async fn build_block_payload(
+    state: &AppState,
+    params: &BlockSubscriptionParams,
+    number: u64,
+) -> Value {
+    let result = async {
+        let client_at_block = state
+            .client
+            .at_block(number)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if params.header_only {
+            build_head_header_response(&client_at_block, None, false)
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|header| serde_json::to_value(header).map_err(|e| e.to_string()))
+        } else {
+            build_head_block_response(state, &client_at_block, &params.head_params(), Some(true))
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|block| serde_json::to_value(block).map_err(|e| e.to_string()))
+        }
+    }
+    .await;
+
+    result.unwrap_or_else(|error| {
+        tracing::warn!("Failed to build payload for finalized block {number}: {error}");
+        json!({ "error": error, "number": number.to_string() })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_params_defaults_and_unknown_fields() {
+        let params: BlockSubscriptionParams = serde_json::from_str("{}").unwrap();
+        assert!(!params.header_only);
+        assert!(!params.no_fees);
+
+        let params: BlockSubscriptionParams =
+            serde_json::from_str(r#"{"headerOnly": true, "noFees": true}"#).unwrap();
+        assert!(params.header_only);
+        assert!(params.head_params().no_fees);
+        assert!(params.head_params().finalized);
+
+        let result: Result<BlockSubscriptionParams, _> =
+            serde_json::from_str(r#"{"finalized": false}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_too_many_subscriptions_is_service_unavailable() {
+        let response =
+            SubscriptionError::from(FinalizedHeadsError::TooManySubscriptions).into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
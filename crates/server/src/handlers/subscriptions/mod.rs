@@ -0,0 +1,12 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Streaming endpoints that push new finalized blocks to connected clients.
+
+pub mod heads;
+pub mod sse_blocks;
+pub mod ws_blocks;
+
+pub use heads::{BlockSubscriptionParams, SubscriptionError};
+pub use sse_blocks::sse_blocks;
+pub use ws_blocks::ws_blocks;
@@ -0,0 +1,65 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for the GET /sse/blocks Server-Sent Events endpoint.
+//!
+//! Serves the same payloads as `/ws/blocks` for clients behind proxies that block
+//! WebSockets. Each finalized block is sent as a `data:` event, and a heartbeat comment
+//! is sent when the stream has been idle for 15 seconds.
+
+use super::heads::{BlockSubscriptionParams, SubscriptionError, build_block_payload};
+use crate::extractors::JsonQuery;
+use crate::state::AppState;
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures::Stream;
+use std::convert::Infallible;
+use std::time::Duration;
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+#[utoipa::path(
+    get,
+    path = "/v1/sse/blocks",
+    tag = "blocks",
+    summary = "Stream finalized blocks (SSE)",
+    description = "Server-Sent Events stream that emits each newly finalized block as a `data:` event. Same payloads as /ws/blocks, with a heartbeat comment every 15 seconds of inactivity.",
+    params(
+        ("headerOnly" = Option<bool>, Query, description = "Push only block headers instead of full blocks"),
+        ("eventDocs" = Option<bool>, Query, description = "Include documentation for events"),
+        ("extrinsicDocs" = Option<bool>, Query, description = "Include documentation for extrinsics"),
+        ("noFees" = Option<bool>, Query, description = "Skip fee calculation for extrinsics")
+    ),
+    responses(
+        (status = 200, description = "Event stream of finalized blocks", content_type = "text/event-stream"),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 503, description = "Subscription limit reached or node unavailable")
+    )
+)]
+pub async fn sse_blocks(
+    State(state): State<AppState>,
+    JsonQuery(params): JsonQuery<BlockSubscriptionParams>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, SubscriptionError> {
+    let heads = state
+        .block_subscriptions
+        .subscribe(&state.rpc_client)
+        .await?;
+
+    let events = futures::stream::unfold(
+        (state, params, heads),
+        |(state, params, mut heads)| async move {
+            let number = heads.next().await?;
+            let payload = build_block_payload(&state, &params, number).await;
+            let event = Event::default().data(payload.to_string());
+            Some((Ok(event), (state, params, heads)))
+        },
+    );
+
+    Ok(Sse::new(events).keep_alive(
+        KeepAlive::new()
+            .interval(HEARTBEAT_INTERVAL)
+            .text("heartbeat"),
+    ))
+}
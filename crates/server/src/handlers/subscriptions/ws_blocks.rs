@@ -0,0 +1,77 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for the GET /ws/blocks WebSocket endpoint.
+//!
+//! Pushes one JSON message per newly finalized block, using the same block (or header)
+//! representation as `/blocks/head` and `/blocks/head/header`.
+
+use super::heads::{BlockSubscriptionParams, SubscriptionError, build_block_payload};
+use crate::extractors::JsonQuery;
+use crate::state::AppState;
+use crate::utils::HeadSubscription;
+use axum::{
+    extract::{
+        State,
+        ws::{Message, WebSocket, WebSocketUpgrade},
+    },
+    response::Response,
+};
+
+#[utoipa::path(
+    get,
+    path = "/v1/ws/blocks",
+    tag = "blocks",
+    summary = "Stream finalized blocks",
+    description = "Upgrades to a WebSocket that pushes each newly finalized block as a JSON message. Blocks that fail to decode are sent as `{\"error\", \"number\"}` messages.",
+    params(
+        ("headerOnly" = Option<bool>, Query, description = "Push only block headers instead of full blocks"),
+        ("eventDocs" = Option<bool>, Query, description = "Include documentation for events"),
+        ("extrinsicDocs" = Option<bool>, Query, description = "Include documentation for extrinsics"),
+        ("noFees" = Option<bool>, Query, description = "Skip fee calculation for extrinsics")
+    ),
+    responses(
+        (status = 101, description = "Switching to the WebSocket protocol"),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 503, description = "Subscription limit reached or node unavailable")
+    )
+)]
+pub async fn ws_blocks(
+    State(state): State<AppState>,
+    JsonQuery(params): JsonQuery<BlockSubscriptionParams>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, SubscriptionError> {
+    // Subscribe before upgrading so failures surface as a regular HTTP error
+    let heads = state
+        .block_subscriptions
+        .subscribe(&state.rpc_client)
+        .await?;
+
+    Ok(ws.on_upgrade(move |socket| stream_blocks(socket, state, params, heads)))
+}
+
+async fn stream_blocks(
+    mut socket: WebSocket,
+    state: AppState,
+    params: BlockSubscriptionParams,
+    mut heads: HeadSubscription,
+) {
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by axum; other client messages are ignored
+                Some(Ok(_)) => {}
+            },
+            number = heads.next() => {
+                let Some(number) = number else { break };
+                let payload = build_block_payload(&state, &params, number).await;
+                if socket.send(Message::Text(payload.to_string())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    tracing::debug!("Finalized block WebSocket closed");
+}
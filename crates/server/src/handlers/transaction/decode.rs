@@ -0,0 +1,230 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Handler for POST /transaction/decode.
+//!
+//! Decodes a hex-encoded extrinsic against the current runtime metadata without
+//! submitting it, using the same decoding as the block endpoints.
+
+use crate::handlers::blocks::processing::extrinsics::extrinsic_to_info;
+use crate::handlers::blocks::types::{MethodInfo, SignatureInfo};
+use crate::state::AppState;
+use crate::utils::EraInfo;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use heck::ToLowerCamelCase;
+use serde::{Deserialize, Serialize};
+use subxt::SubstrateConfig;
+use subxt::client::OnlineClientAtBlock;
+use thiserror::Error;
+
+/// Request body for transaction decoding.
+#[derive(Debug, Deserialize)]
+pub struct DecodeRequest {
+    /// Hex-encoded signed or unsigned extrinsic with 0x prefix.
+    pub tx: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct At {
+    pub hash: String,
+    pub height: String,
+}
+
+/// Decoded extrinsic, in the same shape as extrinsics in block responses.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodeResponse {
+    /// Block whose metadata was used for decoding
+    pub at: At,
+    pub method: MethodInfo,
+    /// Signature information - null for unsigned extrinsics
+    pub signature: Option<SignatureInfo>,
+    pub nonce: Option<String>,
+    pub args: serde_json::Map<String, serde_json::Value>,
+    pub tip: Option<String>,
+    pub hash: String,
+    pub era: EraInfo,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DecodeFailure {
+    pub code: u16,
+    pub error: String,
+    pub transaction: String,
+    pub cause: String,
+    pub stack: String,
+}
+
+#[derive(Debug, Error)]
+pub enum DecodeError {
+    #[error("Missing field `tx` on request body.")]
+    MissingTx,
+
+    #[error("Unable to decode transaction")]
+    DecodeFailed { transaction: String, cause: String },
+
+    #[error("Unable to fetch current block")]
+    FetchFailed { transaction: String, cause: String },
+}
+
+impl IntoResponse for DecodeError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, error, transaction, cause) = match self {
+            DecodeError::MissingTx => (
+                StatusCode::BAD_REQUEST,
+                "Unable to decode transaction",
+                String::new(),
+                "Missing field `tx` on request body.".to_string(),
+            ),
+            DecodeError::DecodeFailed { transaction, cause } => (
+                StatusCode::BAD_REQUEST,
+                "Unable to decode transaction",
+                transaction,
+                cause,
+            ),
+            DecodeError::FetchFailed { transaction, cause } => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Unable to fetch current block",
+                transaction,
+                cause,
+            ),
+        };
+
+        let body = Json(DecodeFailure {
+            code: status.as_u16(),
+            error: error.to_string(),
+            transaction,
+            stack: format!("Error: {}\n    at decode", cause),
+            cause,
+        });
+        (status, body).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/transaction/decode",
+    tag = "transaction",
+    summary = "Decode transaction",
+    description = "Decode a hex-encoded signed or unsigned extrinsic against the current runtime metadata without submitting it. Returns the call, its arguments and the signature details (signer, era, nonce, tip).",
+    request_body(content = Object, description = "Transaction with 'tx' field containing hex-encoded extrinsic"),
+    responses(
+        (status = 200, description = "Decoded transaction", body = Object),
+        (status = 400, description = "Invalid or undecodable transaction"),
+        (status = 503, description = "Service unavailable")
+    )
+)]
+pub async fn decode(
+    State(state): State<AppState>,
+    Json(body): Json<DecodeRequest>,
+) -> Result<Json<DecodeResponse>, DecodeError> {
+    let tx = body.tx.as_deref().unwrap_or_default();
+    if tx.is_empty() {
+        return Err(DecodeError::MissingTx);
+    }
+
+    let decode_failed = |cause: String| DecodeError::DecodeFailed {
+        transaction: tx.to_string(),
+        cause,
+    };
+
+    let tx_bytes = hex::decode(tx.strip_prefix("0x").unwrap_or(tx))
+        .map_err(|e| decode_failed(format!("Invalid hex encoding: {}", e)))?;
+
+    let client_at =
+        state
+            .client
+            .at_current_block()
+            .await
+            .map_err(|e| DecodeError::FetchFailed {
+                transaction: tx.to_string(),
+                cause: e.to_string(),
+            })?;
+
+    let metadata = client_at.metadata();
+    let extrinsics = client_at.extrinsics().from_bytes(vec![tx_bytes]).await;
+    let extrinsic = extrinsics
+        .iter()
+        .next()
+        .ok_or_else(|| decode_failed("No extrinsic found in input".to_string()))?
+        .map_err(|e| decode_failed(e.to_string()))?;
+
+    let info = extrinsic_to_info(state.chain_info.ss58_prefix, metadata.types(), &extrinsic)
+        .map_err(|e| decode_failed(e.to_string()))?;
+
+    Ok(Json(DecodeResponse {
+        at: At {
+            hash: format!("{:#}", client_at.block_ref().hash()),
+            height: client_at.block_number().to_string(),
+        },
+        method: info.method,
+        signature: info.signature,
+        nonce: info.nonce,
+        args: info.args,
+        tip: info.tip,
+        hash: info.hash,
+        era: info.era,
+    }))
+}
+
+/// Decode an extrinsic's call pallet name, cased as in block responses.
+///
+/// Returns `None` if the bytes can't be decoded against the given block's metadata.
+pub async fn decode_extrinsic_pallet(
+    client_at: &OnlineClientAtBlock<SubstrateConfig>,
+    tx_bytes: Vec<u8>,
+) -> Option<String> {
+    let extrinsics = client_at.extrinsics().from_bytes(vec![tx_bytes]).await;
+    let extrinsic = extrinsics.iter().next()?.ok()?;
+    Some(extrinsic.pallet_name().to_lower_camel_case())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_tx_response() {
+        let response = DecodeError::MissingTx.into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_fetch_failed_is_service_unavailable() {
+        let response = DecodeError::FetchFailed {
+            transaction: "0x1234".to_string(),
+            cause: "connection lost".to_string(),
+        }
+        .into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn test_decode_response_serialization() {
+        let response = DecodeResponse {
+            at: At {
+                hash: "0xabc".to_string(),
+                height: "100".to_string(),
+            },
+            method: MethodInfo {
+                pallet: "balances".to_string(),
+                method: "transferKeepAlive".to_string(),
+            },
+            signature: None,
+            nonce: None,
+            args: serde_json::Map::new(),
+            tip: None,
+            hash: "0xdef".to_string(),
+            era: EraInfo {
+                immortal_era: Some("0x00".to_string()),
+                mortal_era: None,
+            },
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["at"]["height"], "100");
+        assert_eq!(json["method"]["pallet"], "balances");
+        assert!(json["signature"].is_null());
+        assert_eq!(json["era"]["immortalEra"], "0x00");
+    }
+}
@@ -2,12 +2,13 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::state::{AppState, RelayChainError};
-use crate::utils::BlockId;
+use crate::utils::{self, BlockId};
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use scale_value::{Composite, ValueDef};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sp_core::crypto::Ss58Codec;
+use subxt::{OnlineClientAtBlock, SubstrateConfig};
 use thiserror::Error;
 
 #[derive(Debug, Deserialize)]
@@ -23,6 +24,12 @@ pub struct DryRunRequest {
 pub struct DryRunResponse {
     pub result_type: String,
     pub result: Value,
+    /// Nonce the transaction was signed with, omitted for unsigned transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// Tip the transaction was signed with, omitted for unsigned transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tip: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -241,7 +248,7 @@ impl From<subxt::error::RuntimeApiError> for DryRunError {
     path = "/v1/transaction/dry-run",
     tag = "transaction",
     summary = "Dry run transaction",
-    description = "Dry run a transaction to check validity without submitting.",
+    description = "Dry run a transaction to check validity without submitting. For signed transactions, the response also includes the nonce and tip that were signed.",
     request_body(content = Object, description = "Transaction with 'tx', 'senderAddress', and optional 'at' fields"),
     responses(
         (status = 200, description = "Dry run result", body = Object),
@@ -338,6 +345,7 @@ async fn dry_run_internal(
             cause: format!("Invalid hex encoding: {}", e),
             stack: format!("Error: Invalid hex encoding: {}\n    at dry_run", e),
         })?;
+    let (nonce, tip) = decode_nonce_and_tip(&client_at, tx_bytes.clone()).await;
     let call = subxt::dynamic::Value::from_bytes(tx_bytes);
 
     // Call DryRunApi.dry_run_call(origin, call)
@@ -348,7 +356,33 @@ async fn dry_run_internal(
     );
     let result = client_at.runtime_apis().call(method).await?;
 
-    parse_result_to_response(result, tx)
+    let mut response = parse_result_to_response(result, tx)?;
+    response.nonce = nonce;
+    response.tip = tip;
+    Ok(response)
+}
+
+/// Read the nonce and tip from a signed transaction's extensions, decoded with their
+/// types from the block's metadata so any extension order is handled.
+///
+/// Both are `None` for unsigned transactions or bytes that don't decode as an extrinsic.
+async fn decode_nonce_and_tip(
+    client_at: &OnlineClientAtBlock<SubstrateConfig>,
+    tx_bytes: Vec<u8>,
+) -> (Option<String>, Option<String>) {
+    let metadata = client_at.metadata();
+    let extrinsics = client_at.extrinsics().from_bytes(vec![tx_bytes]).await;
+    let Some(Ok(extrinsic)) = extrinsics.iter().next() else {
+        return (None, None);
+    };
+    let Some(extensions) = extrinsic.transaction_extensions() else {
+        return (None, None);
+    };
+
+    utils::nonce_and_tip_from_extensions(
+        extensions.iter().map(|ext| (ext.type_id(), ext.bytes())),
+        metadata.types(),
+    )
 }
 
 fn validate_sender<'a>(sender: &'a Option<String>, tx: &str) -> Result<&'a str, DryRunError> {
@@ -432,14 +466,20 @@ fn parse_result_to_response(
                         "Ok" => Ok(Json(DryRunResponse {
                             result_type: "DispatchOutcome".to_string(),
                             result: to_json(inner_value),
+                            nonce: None,
+                            tip: None,
                         })),
                         "Err" => Ok(Json(DryRunResponse {
                             result_type: "DispatchError".to_string(),
                             result: to_json(inner_value),
+                            nonce: None,
+                            tip: None,
                         })),
                         _ => Ok(Json(DryRunResponse {
                             result_type: "DispatchOutcome".to_string(),
                             result: to_json(ok_value),
+                            nonce: None,
+                            tip: None,
                         })),
                     };
                 };
@@ -450,6 +490,8 @@ fn parse_result_to_response(
                 return Ok(Json(DryRunResponse {
                     result_type: "TransactionValidityError".to_string(),
                     result: to_json(err_value),
+                    nonce: None,
+                    tip: None,
                 }));
             }
             _ => {}
@@ -460,6 +502,8 @@ fn parse_result_to_response(
     Ok(Json(DryRunResponse {
         result_type: "DispatchOutcome".to_string(),
         result: to_json(&result),
+        nonce: None,
+        tip: None,
     }))
 }
 
@@ -473,9 +517,26 @@ mod tests {
         let response = DryRunResponse {
             result_type: "DispatchOutcome".to_string(),
             result: json!({ "actualWeight": { "refTime": "1000", "proofSize": "2000" }, "paysFee": "Yes" }),
+            nonce: None,
+            tip: None,
         };
         let json = serde_json::to_value(&response).unwrap();
         assert_eq!(json["resultType"], "DispatchOutcome");
+        assert!(json.get("nonce").is_none());
+        assert!(json.get("tip").is_none());
+    }
+
+    #[test]
+    fn test_dry_run_response_with_nonce_and_tip() {
+        let response = DryRunResponse {
+            result_type: "DispatchOutcome".to_string(),
+            result: json!({}),
+            nonce: Some("29".to_string()),
+            tip: Some("0".to_string()),
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["nonce"], "29");
+        assert_eq!(json["tip"], "0");
     }
 
     #[test]
@@ -6,8 +6,10 @@ use crate::utils::{WeightRaw, decode_runtime_dispatch_info};
 use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use parity_scale_codec::Encode;
 use serde::{Deserialize, Serialize};
+use subxt::{Metadata, SubstrateConfig, client::OnlineClientAtBlock};
 use thiserror::Error;
 use utoipa::ToSchema;
+
 #[derive(Debug, Deserialize)]
 pub struct FeeEstimateRequest {
     pub tx: Option<String>,
@@ -131,8 +133,8 @@ impl IntoResponse for FeeEstimateError {
     path = "/v1/transaction/fee-estimate",
     tag = "transaction",
     summary = "Estimate transaction fee",
-    description = "Estimate the fee for a transaction.",
-    request_body(content = Object, description = "Transaction with 'tx' field containing hex-encoded transaction"),
+    description = "Estimate the fee for a transaction. `tx` can be a signed (or fake-signed) extrinsic, or just an encoded call, as used to pre-estimate fees before signing. For a call, the length fee only covers the call bytes, not the signature and extensions added when signing.",
+    request_body(content = Object, description = "Transaction with 'tx' field containing a hex-encoded extrinsic or call"),
     responses(
         (status = 200, description = "Fee estimate", body = FeeEstimateResponse),
         (status = 400, description = "Invalid transaction"),
@@ -152,8 +154,8 @@ pub async fn fee_estimate(
     path = "/v1/rc/transaction/fee-estimate",
     tag = "rc",
     summary = "RC fee estimate",
-    description = "Estimate the fee for a relay chain transaction.",
-    request_body(content = Object, description = "Transaction with 'tx' field"),
+    description = "Estimate the fee for a relay chain transaction. `tx` can be a signed (or fake-signed) extrinsic, or just an encoded call.",
+    request_body(content = Object, description = "Transaction with 'tx' field containing a hex-encoded extrinsic or call"),
     responses(
         (status = 200, description = "Fee estimate", body = FeeEstimateResponse),
         (status = 400, description = "Invalid transaction"),
@@ -209,13 +211,29 @@ async fn fee_estimate_internal(
         }
     })?;
 
+    // query_info charges nothing for bare extrinsics, so a call on its own is
+    // estimated with query_call_info instead of being wrapped in one.
+    let runtime_api = if is_extrinsic(&client_at, &tx_bytes).await {
+        "TransactionPaymentApi_query_info"
+    } else if is_call(&client_at.metadata(), &tx_bytes) {
+        "TransactionPaymentCallApi_query_call_info"
+    } else {
+        let cause = "Transaction is neither an extrinsic nor a call for the current runtime";
+        return Err(FeeEstimateError::FetchFailed {
+            at_hash: Some(block_hash),
+            transaction: tx.to_string(),
+            cause: cause.to_string(),
+            stack: format!("Error: {}\n    at fee_estimate", cause),
+        });
+    };
+
     let mut params = tx_bytes.to_vec();
     let len = tx_bytes.len() as u32;
     len.encode_to(&mut params);
 
     let result_bytes = client_at
         .runtime_apis()
-        .call_raw("TransactionPaymentApi_query_info", Some(&params))
+        .call_raw(runtime_api, Some(&params))
         .await
         .map_err(|e| {
             let cause = e.to_string();
@@ -257,9 +275,50 @@ async fn fee_estimate_internal(
     }))
 }
 
+/// Whether the bytes decode as a single (possibly fake-signed) extrinsic.
+async fn is_extrinsic(client_at: &OnlineClientAtBlock<SubstrateConfig>, tx_bytes: &[u8]) -> bool {
+    let extrinsics = client_at
+        .extrinsics()
+        .from_bytes(vec![tx_bytes.to_vec()])
+        .await;
+    matches!(extrinsics.iter().next(), Some(Ok(_)))
+}
+
+/// Whether the bytes are exactly one encoded `RuntimeCall`.
+fn is_call(metadata: &Metadata, call_bytes: &[u8]) -> bool {
+    let call_ty = metadata.outer_enums().call_enum_ty();
+    let cursor = &mut &call_bytes[..];
+    scale_value::scale::decode_as_type(cursor, call_ty, metadata.types()).is_ok()
+        && cursor.is_empty()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::Decode;
+
+    /// `System.remark` with the given remark, encoded as a call.
+    fn remark_call(metadata: &Metadata, remark: &[u8]) -> Vec<u8> {
+        let system = metadata.pallet_by_name("System").unwrap();
+        let remark_index = system.call_variant_by_name("remark").unwrap().index;
+        let mut call = vec![system.call_index(), remark_index];
+        remark.to_vec().encode_to(&mut call);
+        call
+    }
+
+    #[test]
+    fn test_is_call() {
+        let metadata = Metadata::decode(&mut &ASSET_HUB_METADATA[..]).unwrap();
+        let call = remark_call(&metadata, b"hello");
+        assert!(is_call(&metadata, &call));
+
+        let mut trailing = call.clone();
+        trailing.push(0);
+        assert!(!is_call(&metadata, &trailing));
+        assert!(!is_call(&metadata, &call[..call.len() - 1]));
+        assert!(!is_call(&metadata, &[0xff, 0xff]));
+    }
 
     #[test]
     fn test_fee_estimate_response_serialization() {
@@ -261,7 +261,13 @@ pub async fn material(
     State(state): State<AppState>,
     JsonQuery(query): JsonQuery<MaterialQuery>,
 ) -> Result<Json<MaterialResponse>, MaterialError> {
-    material_internal(&state.client, &state.rpc_client, query).await
+    material_internal(
+        &state.client,
+        &state.rpc_client,
+        &state.metadata_cache,
+        query,
+    )
+    .await
 }
 
 #[utoipa::path(
@@ -294,7 +300,13 @@ pub async fn material_rc(
         .await
         .map_err(MaterialError::RelayChain)?;
 
-    material_internal(&relay_client, &relay_rpc_client, query).await
+    material_internal(
+        &relay_client,
+        &relay_rpc_client,
+        &state.metadata_cache,
+        query,
+    )
+    .await
 }
 
 /// Parse and validate metadata version from path parameter.
@@ -532,6 +544,7 @@ async fn material_versioned_internal(
 async fn material_internal(
     client: &subxt::OnlineClient<subxt::SubstrateConfig>,
     rpc_client: &subxt_rpcs::RpcClient,
+    metadata_cache: &crate::utils::RuntimeMetadataCache,
     query: MaterialQuery,
 ) -> Result<Json<MaterialResponse>, MaterialError> {
     let metadata_format = parse_metadata_params(&query.metadata, query.no_meta)?;
@@ -629,8 +642,7 @@ async fn material_internal(
 
     // Get metadata if requested
     let metadata = if let Some(format) = metadata_format {
-        let metadata_hex: String = rpc_client
-            .request("state_getMetadata", subxt_rpcs::rpc_params![&block_hash])
+        let cached = crate::utils::fetch_runtime_metadata(rpc_client, metadata_cache, &block_hash)
             .await
             .map_err(|e| {
                 let cause = e.to_string();
@@ -641,32 +653,17 @@ async fn material_internal(
             })?;
 
         match format {
-            MetadataFormat::Scale => Some(Value::String(metadata_hex)),
+            MetadataFormat::Scale => Some(Value::String(cached.hex().to_string())),
             MetadataFormat::Json => {
-                // Decode the metadata and convert to JSON
-                let metadata_bytes =
-                    hex::decode(metadata_hex.trim_start_matches("0x")).map_err(|e| {
-                        let cause = format!("Failed to decode metadata hex: {}", e);
-                        MaterialError::FetchFailed {
-                            cause: cause.clone(),
-                            stack: format!("Error: {}\n    at material (metadata decode)", cause),
-                        }
-                    })?;
-
-                let metadata =
-                    frame_metadata::RuntimeMetadataPrefixed::decode(&mut &metadata_bytes[..])
-                        .map_err(|e| {
-                            let cause = format!("Failed to decode metadata: {}", e);
-                            MaterialError::FetchFailed {
-                                cause: cause.clone(),
-                                stack: format!(
-                                    "Error: {}\n    at material (metadata parse)",
-                                    cause
-                                ),
-                            }
-                        })?;
+                let metadata = cached.decoded().map_err(|e| {
+                    let cause = format!("Failed to decode metadata: {}", e);
+                    MaterialError::FetchFailed {
+                        cause: cause.clone(),
+                        stack: format!("Error: {}\n    at material (metadata parse)", cause),
+                    }
+                })?;
 
-                let json = serde_json::to_value(&metadata).map_err(|e| {
+                let json = serde_json::to_value(metadata).map_err(|e| {
                     let cause = format!("Failed to serialize metadata to JSON: {}", e);
                     MaterialError::FetchFailed {
                         cause: cause.clone(),
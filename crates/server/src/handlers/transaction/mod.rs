@@ -3,17 +3,21 @@
 
 //! Transaction-related handlers.
 //!
-//! This module provides handlers for transaction submission, dry-run, fee estimation,
-//! material, and metadata-blob endpoints.
+//! This module provides handlers for transaction submission, decoding, dry-run, fee
+//! estimation, material, metadata-blob, and signing payload endpoints.
 
+pub mod decode;
 pub mod dry_run;
 pub mod fee_estimate;
 pub mod material;
 pub mod metadata_blob;
+pub mod payload;
 pub mod submit;
 
+pub use decode::decode;
 pub use dry_run::{dry_run, dry_run_rc};
 pub use fee_estimate::{fee_estimate, fee_estimate_rc};
 pub use material::{material, material_rc, material_versioned, material_versioned_rc};
 pub use metadata_blob::{metadata_blob, metadata_blob_rc};
+pub use payload::{payload, payload_rc};
 pub use submit::{submit, submit_rc};
@@ -0,0 +1,333 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Assemble a ready-to-sign transaction from a pallet, method and JSON arguments.
+//!
+//! The call is encoded against the current runtime's metadata and combined with the
+//! genesis hash, spec/transaction versions, era, nonce and tip into the signing payload.
+//! The payload is returned as the exact bytes to sign: payloads longer than 256 bytes
+//! are already hashed with Blake2-256.
+
+use crate::state::{AppState, RelayChainError};
+use crate::utils::{CallArgsError, EraInfo, resolve_call};
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sp_core::crypto::Ss58Codec;
+use subxt::config::DefaultExtrinsicParamsBuilder;
+use subxt::{SubstrateConfig, utils::Era};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Blocks a transaction stays valid for when `eraPeriod` isn't given
+const DEFAULT_ERA_PERIOD: u64 = 64;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayloadRequest {
+    pub pallet: Option<String>,
+    pub method: Option<String>,
+    /// Call arguments keyed by name
+    #[serde(default)]
+    pub args: Value,
+    /// Signer address (SS58 or hex public key), used to look up the nonce
+    pub address: Option<String>,
+    /// Nonce to sign with instead of the account's current nonce
+    pub nonce: Option<u64>,
+    /// Tip in the native token, as a decimal string
+    pub tip: Option<String>,
+    /// Number of blocks the transaction is valid for; `0` makes it immortal
+    pub era_period: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct At {
+    pub hash: String,
+    pub height: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PayloadResponse {
+    #[schema(value_type = Object)]
+    pub at: At,
+    /// Pallet name as declared in the runtime
+    pub pallet: String,
+    /// Call name as declared in the runtime
+    pub method: String,
+    /// SCALE-encoded call
+    pub call_hex: String,
+    /// Bytes the signer must sign
+    pub signing_payload: String,
+    pub genesis_hash: String,
+    pub spec_version: String,
+    pub tx_version: String,
+    pub nonce: String,
+    pub tip: String,
+    #[schema(value_type = Object)]
+    pub era: EraInfo,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayloadFailure {
+    pub code: u16,
+    pub error: String,
+    pub cause: String,
+    pub stack: String,
+}
+
+#[derive(Debug, Error)]
+pub enum PayloadError {
+    #[error("Missing field `{0}` on request body.")]
+    MissingField(&'static str),
+
+    #[error("Invalid field `{field}`")]
+    InvalidField { field: &'static str, cause: String },
+
+    #[error(transparent)]
+    InvalidCall(#[from] CallArgsError),
+
+    #[error("Unable to assemble the signing payload")]
+    FetchFailed { cause: String },
+
+    #[error(transparent)]
+    RelayChain(#[from] RelayChainError),
+}
+
+impl IntoResponse for PayloadError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, error_msg, cause) = match self {
+            PayloadError::MissingField(_) => (
+                StatusCode::BAD_REQUEST,
+                "Invalid request body",
+                self.to_string(),
+            ),
+            PayloadError::InvalidField { field, cause } => (
+                StatusCode::BAD_REQUEST,
+                "Invalid request body",
+                format!("Invalid `{field}`: {cause}"),
+            ),
+            PayloadError::InvalidCall(err) => {
+                (StatusCode::BAD_REQUEST, "Invalid call", err.to_string())
+            }
+            PayloadError::FetchFailed { cause } => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Unable to assemble the signing payload",
+                cause,
+            ),
+            PayloadError::RelayChain(err) => {
+                let status = match err {
+                    RelayChainError::NotConfigured => StatusCode::BAD_REQUEST,
+                    RelayChainError::ConnectionFailed(_) => StatusCode::SERVICE_UNAVAILABLE,
+                };
+                (
+                    status,
+                    "Unable to assemble the signing payload",
+                    err.to_string(),
+                )
+            }
+        };
+
+        let body = Json(PayloadFailure {
+            code: status.as_u16(),
+            error: error_msg.to_string(),
+            stack: format!("Error: {}\n    at payload", cause),
+            cause,
+        });
+        (status, body).into_response()
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/transaction/payload",
+    tag = "transaction",
+    summary = "Assemble a signing payload",
+    description = "Encodes `method` of `pallet` with the JSON `args`, checked against the dispatchable's fields in the current metadata, and assembles the payload to sign with the genesis hash, spec and transaction versions, era, nonce and tip. The nonce defaults to the current nonce of `address`. Returns the call hex and the exact bytes to sign.",
+    request_body(content = Object, description = "Call as `pallet`, `method` and `args`, the signer `address`, and optional `nonce`, `tip` and `eraPeriod`"),
+    responses(
+        (status = 200, description = "Signing payload", body = PayloadResponse),
+        (status = 400, description = "Invalid call or request body"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn payload(
+    State(state): State<AppState>,
+    Json(body): Json<PayloadRequest>,
+) -> Result<Json<PayloadResponse>, PayloadError> {
+    payload_internal(&state.client, body).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/rc/transaction/payload",
+    tag = "rc",
+    summary = "RC signing payload",
+    description = "Assembles a relay chain signing payload from `pallet`, `method` and JSON `args`.",
+    request_body(content = Object, description = "Call as `pallet`, `method` and `args`, the signer `address`, and optional `nonce`, `tip` and `eraPeriod`"),
+    responses(
+        (status = 200, description = "Signing payload", body = PayloadResponse),
+        (status = 400, description = "Invalid call or request body"),
+        (status = 503, description = "Service unavailable"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn payload_rc(
+    State(state): State<AppState>,
+    Json(body): Json<PayloadRequest>,
+) -> Result<Json<PayloadResponse>, PayloadError> {
+    let relay_client = state.get_relay_chain_client().await?;
+    payload_internal(&relay_client, body).await
+}
+
+async fn payload_internal(
+    client: &subxt::OnlineClient<SubstrateConfig>,
+    body: PayloadRequest,
+) -> Result<Json<PayloadResponse>, PayloadError> {
+    let pallet = required(&body.pallet, "pallet")?;
+    let method = required(&body.method, "method")?;
+    let address = required(&body.address, "address")?;
+    let account_id = parse_account(address).map_err(|cause| PayloadError::InvalidField {
+        field: "address",
+        cause,
+    })?;
+    let tip = match &body.tip {
+        None => 0,
+        Some(tip) => tip
+            .parse::<u128>()
+            .map_err(|e| PayloadError::InvalidField {
+                field: "tip",
+                cause: e.to_string(),
+            })?,
+    };
+    let era_period = body.era_period.unwrap_or(DEFAULT_ERA_PERIOD);
+
+    let client_at = client.at_current_block().await.map_err(fetch_failed)?;
+    let call = resolve_call(&client_at.metadata(), pallet, method, &body.args)?;
+    let call = subxt::dynamic::tx(call.pallet, call.method, call.args);
+
+    let txs = client_at.tx();
+    let call_data = txs.call_data(&call).map_err(fetch_failed)?;
+    let nonce = match body.nonce {
+        Some(nonce) => nonce,
+        None => txs.account_nonce(&account_id).await.map_err(fetch_failed)?,
+    };
+
+    let block_number = client_at.block_number();
+    let block_hash = client_at.block_hash();
+    let mut params = DefaultExtrinsicParamsBuilder::<SubstrateConfig>::new()
+        .nonce(nonce)
+        .tip(tip);
+    let era = if era_period == 0 {
+        params = params.immortal();
+        EraInfo {
+            immortal_era: Some("0x00".to_string()),
+            mortal_era: None,
+        }
+    } else {
+        params = params.mortal_from_unchecked(era_period, block_number, block_hash);
+        era_info(Era::mortal(era_period, block_number))
+    };
+
+    let signing_payload = txs
+        .create_signable_offline(&call, params.build())
+        .and_then(|signable| signable.signer_payload())
+        .map_err(fetch_failed)?;
+
+    Ok(Json(PayloadResponse {
+        at: At {
+            hash: format!("{:#x}", block_hash),
+            height: block_number.to_string(),
+        },
+        pallet: call.pallet_name().to_string(),
+        method: call.call_name().to_string(),
+        call_hex: format!("0x{}", hex::encode(call_data)),
+        signing_payload: format!("0x{}", hex::encode(signing_payload)),
+        genesis_hash: format!("{:#x}", client.genesis_hash()),
+        spec_version: client_at.spec_version().to_string(),
+        tx_version: client_at.transaction_version().to_string(),
+        nonce: nonce.to_string(),
+        tip: tip.to_string(),
+        era,
+    }))
+}
+
+fn required<'a>(value: &'a Option<String>, field: &'static str) -> Result<&'a str, PayloadError> {
+    match value.as_deref() {
+        Some(value) if !value.is_empty() => Ok(value),
+        _ => Err(PayloadError::MissingField(field)),
+    }
+}
+
+fn fetch_failed(err: impl std::fmt::Display) -> PayloadError {
+    PayloadError::FetchFailed {
+        cause: err.to_string(),
+    }
+}
+
+/// Account id from an SS58 address or a `0x`-prefixed 32-byte public key.
+fn parse_account(address: &str) -> Result<subxt::utils::AccountId32, String> {
+    let bytes: [u8; 32] = match address.strip_prefix("0x") {
+        Some(hex_str) => hex::decode(hex_str)
+            .map_err(|e| format!("Invalid hex: {}", e))?
+            .try_into()
+            .map_err(|bytes: Vec<u8>| {
+                format!("Invalid address length: expected 32, got {}", bytes.len())
+            })?,
+        None => sp_core::crypto::AccountId32::from_ss58check(address)
+            .map(Into::into)
+            .map_err(|e| format!("Invalid SS58 address: {:?}", e))?,
+    };
+    Ok(subxt::utils::AccountId32(bytes))
+}
+
+fn era_info(era: Era) -> EraInfo {
+    match era {
+        Era::Immortal => EraInfo {
+            immortal_era: Some("0x00".to_string()),
+            mortal_era: None,
+        },
+        Era::Mortal { period, phase } => EraInfo {
+            immortal_era: None,
+            mortal_era: Some(vec![period.to_string(), phase.to_string()]),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    #[test]
+    fn test_parse_account_accepts_ss58_and_hex() {
+        let from_ss58 = parse_account(ALICE).unwrap();
+        let from_hex = parse_account(&format!("0x{}", hex::encode(from_ss58.0))).unwrap();
+        assert_eq!(from_ss58, from_hex);
+
+        assert!(parse_account("0x0102").unwrap_err().contains("expected 32"));
+        assert!(parse_account("not-an-address").is_err());
+    }
+
+    #[test]
+    fn test_era_info_reports_period_and_phase() {
+        let era = serde_json::to_value(era_info(Era::mortal(64, 1000))).unwrap();
+        assert_eq!(era, serde_json::json!({ "mortalEra": ["64", "40"] }));
+
+        let era = serde_json::to_value(era_info(Era::Immortal)).unwrap();
+        assert_eq!(era, serde_json::json!({ "immortalEra": "0x00" }));
+    }
+
+    #[test]
+    fn test_invalid_call_is_bad_request() {
+        let response = PayloadError::InvalidCall(CallArgsError::MissingArg("value".to_string()))
+            .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = PayloadError::MissingField("pallet").into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -1,12 +1,36 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+//! Handlers for POST /transaction and /rc/transaction.
+//!
+//! Submissions can carry an `Idempotency-Key` header. The response to the first
+//! submission with a key is kept for [`IDEMPOTENCY_KEY_TTL`] (10 minutes) and replayed to
+//! retries with the same key and `tx`, whether it was a hash or a rejection by the node,
+//! so a client retrying after a timeout doesn't submit the transaction twice. Failures to
+//! reach the node are not kept, so the retry submits again. Reusing a key for a different
+//! `tx` within the TTL is rejected with a 422.
+//!
+//! [`IDEMPOTENCY_KEY_TTL`]: crate::utils::idempotency::IDEMPOTENCY_KEY_TTL
+
 use crate::state::{AppState, RelayChainError};
-use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use crate::utils::{CachedResponse, IdempotencyCache, IdempotencyLookup, blake2_256_hex};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use subxt_rpcs::rpc_params;
 use thiserror::Error;
 
+/// Header identifying retries of the same submission
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Longest accepted `Idempotency-Key`
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
 /// Request body for transaction submission.
 #[derive(Debug, Deserialize)]
 pub struct SubmitRequest {
@@ -16,9 +40,13 @@ pub struct SubmitRequest {
 
 /// Response for successful transaction submission.
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SubmitResponse {
-    /// Transaction hash with 0x prefix.
+    /// Transaction hash with 0x prefix, as returned by the node.
     pub hash: String,
+    /// Blake2b-256 hash of the submitted extrinsic bytes, computed locally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
 }
 
 /// Error response when transaction fails to parse or parse.
@@ -51,55 +79,85 @@ pub enum SubmitError {
         stack: String,
     },
 
+    #[error("Failed to reach the node.")]
+    NodeUnreachable {
+        transaction: String,
+        cause: String,
+        stack: String,
+    },
+
     #[error("Relay chain error")]
     RelayChain {
         source: RelayChainError,
         transaction: String,
     },
+
+    #[error("Invalid `Idempotency-Key` header.")]
+    InvalidIdempotencyKey,
+
+    #[error("`Idempotency-Key` was already used for a different transaction.")]
+    IdempotencyKeyReused { transaction: String },
 }
 
-impl IntoResponse for SubmitError {
-    fn into_response(self) -> axum::response::Response {
+impl SubmitError {
+    /// Status and body of the error response.
+    fn into_parts(self) -> (StatusCode, TransactionError) {
+        let error_body = |code: StatusCode, error: &str, transaction, cause: String, stack| {
+            let body = TransactionError {
+                code: code.as_u16(),
+                error: error.to_string(),
+                transaction,
+                cause,
+                stack,
+            };
+            (code, body)
+        };
+
         match self {
             SubmitError::MissingTx => {
                 let cause = "Missing field `tx` on request body.".to_string();
-                let body = Json(TransactionError {
-                    code: 400,
-                    error: "Failed to parse transaction.".to_string(),
-                    transaction: String::new(),
-                    cause: cause.clone(),
-                    stack: format!("Error: {}\n    at submit_transaction", cause),
-                });
-                (StatusCode::BAD_REQUEST, body).into_response()
+                let stack = format!("Error: {}\n    at submit_transaction", cause);
+                error_body(
+                    StatusCode::BAD_REQUEST,
+                    "Failed to parse transaction.",
+                    String::new(),
+                    cause,
+                    stack,
+                )
             }
             SubmitError::ParseFailed {
                 transaction,
                 cause,
                 stack,
-            } => {
-                let body = Json(TransactionError {
-                    code: 400,
-                    error: "Failed to parse transaction.".to_string(),
-                    transaction,
-                    cause,
-                    stack,
-                });
-                (StatusCode::BAD_REQUEST, body).into_response()
-            }
+            } => error_body(
+                StatusCode::BAD_REQUEST,
+                "Failed to parse transaction.",
+                transaction,
+                cause,
+                stack,
+            ),
             SubmitError::SubmitFailed {
                 transaction,
                 cause,
                 stack,
-            } => {
-                let body = Json(TransactionError {
-                    code: 400,
-                    error: "Failed to submit transaction.".to_string(),
-                    transaction,
-                    cause,
-                    stack,
-                });
-                (StatusCode::BAD_REQUEST, body).into_response()
-            }
+            } => error_body(
+                StatusCode::BAD_REQUEST,
+                "Failed to submit transaction.",
+                transaction,
+                cause,
+                stack,
+            ),
+            SubmitError::NodeUnreachable {
+                transaction,
+                cause,
+                stack,
+            } => error_body(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Failed to submit transaction.",
+                transaction,
+                cause,
+                stack,
+            ),
             SubmitError::RelayChain {
                 source,
                 transaction,
@@ -109,19 +167,51 @@ impl IntoResponse for SubmitError {
                     RelayChainError::ConnectionFailed(_) => StatusCode::SERVICE_UNAVAILABLE,
                 };
                 let cause = source.to_string();
-                let body = Json(TransactionError {
-                    code: status.as_u16(),
-                    error: "Failed to submit transaction.".to_string(),
+                let stack = format!("Error: {}\n    at submit", cause);
+                error_body(
+                    status,
+                    "Failed to submit transaction.",
                     transaction,
-                    cause: cause.clone(),
-                    stack: format!("Error: {}\n    at submit", cause),
-                });
-                (status, body).into_response()
+                    cause,
+                    stack,
+                )
+            }
+            SubmitError::InvalidIdempotencyKey => {
+                let cause = format!(
+                    "`Idempotency-Key` must be 1 to {MAX_IDEMPOTENCY_KEY_LEN} visible ASCII characters."
+                );
+                let stack = format!("Error: {}\n    at submit_transaction", cause);
+                error_body(
+                    StatusCode::BAD_REQUEST,
+                    "Failed to submit transaction.",
+                    String::new(),
+                    cause,
+                    stack,
+                )
+            }
+            SubmitError::IdempotencyKeyReused { transaction } => {
+                let cause =
+                    "`Idempotency-Key` was already used for a different transaction.".to_string();
+                let stack = format!("Error: {}\n    at submit_transaction", cause);
+                error_body(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "Failed to submit transaction.",
+                    transaction,
+                    cause,
+                    stack,
+                )
             }
         }
     }
 }
 
+impl IntoResponse for SubmitError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, body) = self.into_parts();
+        (status, Json(body)).into_response()
+    }
+}
+
 /// Extract cause and stack from an RPC error.
 /// Mimics sidecar's extractCauseAndStack behavior.
 fn extract_cause_and_stack(err: &subxt_rpcs::Error) -> (String, String) {
@@ -136,6 +226,15 @@ fn extract_cause_and_stack(err: &subxt_rpcs::Error) -> (String, String) {
     (cause, stack)
 }
 
+/// Check if an RPC error means the node couldn't be reached, so whether the
+/// transaction was submitted is unknown.
+fn is_connection_error(err: &subxt_rpcs::Error) -> bool {
+    matches!(
+        err,
+        subxt_rpcs::Error::Client(_) | subxt_rpcs::Error::DisconnectedWillReconnect(_)
+    )
+}
+
 /// Check if an RPC error indicates a parsing/decoding failure.
 fn is_parse_error(err: &subxt_rpcs::Error) -> bool {
     let error_str = err.to_string().to_lowercase();
@@ -152,20 +251,33 @@ fn is_parse_error(err: &subxt_rpcs::Error) -> bool {
     path = "/v1/transaction",
     tag = "transaction",
     summary = "Submit transaction",
-    description = "Submit a signed extrinsic to the transaction pool.",
+    description = "Submit a signed extrinsic to the transaction pool. A retry carrying the same `Idempotency-Key` and `tx` within 10 minutes gets the first response back instead of submitting again.",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Client-chosen key (1-255 visible ASCII characters) identifying retries of the same submission")
+    ),
     request_body(content = Object, description = "Signed extrinsic with 'tx' field containing hex-encoded transaction"),
     responses(
         (status = 200, description = "Transaction hash", body = Object),
-        (status = 400, description = "Invalid transaction"),
+        (status = 400, description = "Invalid transaction or Idempotency-Key"),
+        (status = 422, description = "Idempotency-Key already used for a different transaction"),
         (status = 503, description = "Service unavailable"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn submit(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(body): Json<SubmitRequest>,
-) -> Result<Json<SubmitResponse>, SubmitError> {
-    submit_internal(&state.rpc_client, body).await
+) -> Response {
+    let tx = body.tx.clone().unwrap_or_default();
+    with_idempotency(
+        &state.submission_cache,
+        "parachain",
+        &headers,
+        tx,
+        submit_internal(&state.rpc_client, body),
+    )
+    .await
 }
 
 #[utoipa::path(
@@ -173,30 +285,122 @@ pub async fn submit(
     path = "/v1/rc/transaction",
     tag = "rc",
     summary = "Submit transaction (relay chain)",
-    description = "Submit a signed extrinsic to the relay chain transaction pool. Only available on parachains.",
+    description = "Submit a signed extrinsic to the relay chain transaction pool. Only available on parachains. A retry carrying the same `Idempotency-Key` and `tx` within 10 minutes gets the first response back instead of submitting again.",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Client-chosen key (1-255 visible ASCII characters) identifying retries of the same submission")
+    ),
     request_body(content = Object, description = "Signed extrinsic with 'tx' field containing hex-encoded transaction"),
     responses(
         (status = 200, description = "Transaction hash", body = Object),
-        (status = 400, description = "Invalid transaction"),
+        (status = 400, description = "Invalid transaction or Idempotency-Key"),
+        (status = 422, description = "Idempotency-Key already used for a different transaction"),
         (status = 503, description = "Relay chain not configured"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn submit_rc(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(body): Json<SubmitRequest>,
-) -> Result<Json<SubmitResponse>, SubmitError> {
-    let tx_str = body.tx.as_deref().unwrap_or_default();
-    let rpc_client =
-        state
-            .get_relay_chain_rpc_client()
-            .await
-            .map_err(|e| SubmitError::RelayChain {
+) -> Response {
+    let tx = body.tx.clone().unwrap_or_default();
+    let rpc_client = match state.get_relay_chain_rpc_client().await {
+        Ok(rpc_client) => rpc_client,
+        Err(e) => {
+            return SubmitError::RelayChain {
                 source: e,
-                transaction: tx_str.to_string(),
-            })?;
+                transaction: tx,
+            }
+            .into_response();
+        }
+    };
+
+    with_idempotency(
+        &state.submission_cache,
+        "relay",
+        &headers,
+        tx,
+        submit_internal(&rpc_client, body),
+    )
+    .await
+}
+
+/// Run `submission` unless the request's `Idempotency-Key` was already used for
+/// `tx`, in which case the earlier response is replayed.
+///
+/// Keys are scoped per chain. Requests without the header are always submitted.
+async fn with_idempotency(
+    cache: &IdempotencyCache,
+    chain: &str,
+    headers: &HeaderMap,
+    tx: String,
+    submission: impl Future<Output = Result<Json<SubmitResponse>, SubmitError>>,
+) -> Response {
+    let key = match idempotency_key(headers) {
+        Ok(Some(key)) => format!("{chain}:{key}"),
+        Ok(None) => return submission.await.into_response(),
+        Err(e) => return e.into_response(),
+    };
 
-    submit_internal(&rpc_client, body).await
+    // Requests racing on the same key wait for the one that reserved it
+    let reservation = loop {
+        match cache.reserve(&key, &tx) {
+            IdempotencyLookup::Miss(reservation) => break reservation,
+            IdempotencyLookup::Hit(cached) => {
+                return (cached.status, Json(cached.body)).into_response();
+            }
+            IdempotencyLookup::Pending(pending) => {
+                if let Some(cached) = pending.wait().await {
+                    return (cached.status, Json(cached.body)).into_response();
+                }
+            }
+            IdempotencyLookup::Mismatch => {
+                return SubmitError::IdempotencyKeyReused { transaction: tx }.into_response();
+            }
+        }
+    };
+
+    let (status, body) = match submission.await {
+        Ok(Json(response)) => (StatusCode::OK, serde_json::to_value(response)),
+        // Nothing was submitted, so dropping the reservation lets a retry go through
+        Err(e @ SubmitError::MissingTx) => return e.into_response(),
+        Err(e) => {
+            let (status, body) = e.into_parts();
+            (status, serde_json::to_value(body))
+        }
+    };
+    let body = body.unwrap_or_default();
+
+    // Only the node's answer is kept: after a connection failure or timeout the
+    // retry must be able to submit again
+    if !status.is_success() && !status.is_client_error() {
+        return (status, Json(body)).into_response();
+    }
+
+    reservation.complete(CachedResponse {
+        status,
+        body: body.clone(),
+    });
+    (status, Json(body)).into_response()
+}
+
+/// The `Idempotency-Key` header, if present.
+fn idempotency_key(headers: &HeaderMap) -> Result<Option<&str>, SubmitError> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+
+    let key = value
+        .to_str()
+        .map_err(|_| SubmitError::InvalidIdempotencyKey)?;
+    let valid = !key.is_empty()
+        && key.len() <= MAX_IDEMPOTENCY_KEY_LEN
+        && key.bytes().all(|b| b.is_ascii_graphic());
+    if !valid {
+        return Err(SubmitError::InvalidIdempotencyKey);
+    }
+
+    Ok(Some(key))
 }
 
 async fn submit_internal(
@@ -214,7 +418,13 @@ async fn submit_internal(
         .map_err(|e| {
             let (cause, stack) = extract_cause_and_stack(&e);
 
-            if is_parse_error(&e) {
+            if is_connection_error(&e) {
+                SubmitError::NodeUnreachable {
+                    transaction: tx.clone(),
+                    cause,
+                    stack,
+                }
+            } else if is_parse_error(&e) {
                 SubmitError::ParseFailed {
                     transaction: tx.clone(),
                     cause,
@@ -229,12 +439,223 @@ async fn submit_internal(
             }
         })?;
 
-    Ok(Json(SubmitResponse { hash }))
+    let tx_hash = hex::decode(tx.trim_start_matches("0x"))
+        .ok()
+        .map(|bytes| blake2_256_hex(&bytes));
+
+    Ok(Json(SubmitResponse { hash, tx_hash }))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::http::HeaderValue;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn submit_with_key(
+        cache: &IdempotencyCache,
+        key: Option<&str>,
+        tx: &str,
+        submissions: &AtomicUsize,
+    ) -> (StatusCode, serde_json::Value) {
+        let mut headers = HeaderMap::new();
+        if let Some(key) = key {
+            headers.insert(IDEMPOTENCY_KEY_HEADER, HeaderValue::from_str(key).unwrap());
+        }
+        let submission = async {
+            let n = submissions.fetch_add(1, Ordering::SeqCst);
+            Ok(Json(SubmitResponse {
+                hash: format!("0x{n:02x}"),
+                tx_hash: None,
+            }))
+        };
+
+        let response =
+            with_idempotency(cache, "parachain", &headers, tx.to_string(), submission).await;
+        let status = response.status();
+        let body = http_body_util::BodyExt::collect(response.into_body())
+            .await
+            .unwrap()
+            .to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_same_key_replays_response() {
+        let cache = IdempotencyCache::new();
+        let submissions = AtomicUsize::new(0);
+
+        let first = submit_with_key(&cache, Some("retry-1"), "0x01", &submissions).await;
+        let retry = submit_with_key(&cache, Some("retry-1"), "0x01", &submissions).await;
+
+        assert_eq!(
+            first,
+            (StatusCode::OK, serde_json::json!({ "hash": "0x00" }))
+        );
+        assert_eq!(retry, first);
+        assert_eq!(submissions.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_with_same_key_submit_once() {
+        let cache = IdempotencyCache::new();
+        let submissions = AtomicUsize::new(0);
+        let (gate, opened) = tokio::sync::oneshot::channel::<()>();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(IDEMPOTENCY_KEY_HEADER, HeaderValue::from_static("race-1"));
+        let slow_submission = async {
+            opened.await.unwrap();
+            let n = submissions.fetch_add(1, Ordering::SeqCst);
+            Ok(Json(SubmitResponse {
+                hash: format!("0x{n:02x}"),
+                tx_hash: None,
+            }))
+        };
+        let first = with_idempotency(
+            &cache,
+            "parachain",
+            &headers,
+            "0x01".to_string(),
+            slow_submission,
+        );
+        let second = submit_with_key(&cache, Some("race-1"), "0x01", &submissions);
+        let open_gate = async {
+            // Let both requests look up the key before the first submission finishes
+            tokio::task::yield_now().await;
+            gate.send(()).unwrap();
+        };
+
+        let (first, second, ()) = tokio::join!(first, second, open_gate);
+
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(
+            second,
+            (StatusCode::OK, serde_json::json!({ "hash": "0x00" }))
+        );
+        assert_eq!(submissions.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_node_rejection_is_replayed_but_connection_failure_is_not() {
+        let cache = IdempotencyCache::new();
+        let submissions = AtomicUsize::new(0);
+        let mut headers = HeaderMap::new();
+        headers.insert(IDEMPOTENCY_KEY_HEADER, HeaderValue::from_static("retry-1"));
+
+        let attempt = |error: fn() -> SubmitError| {
+            let submissions = &submissions;
+            let headers = &headers;
+            let cache = &cache;
+            async move {
+                let submission = async {
+                    submissions.fetch_add(1, Ordering::SeqCst);
+                    Err(error())
+                };
+                with_idempotency(cache, "parachain", headers, "0x01".to_string(), submission)
+                    .await
+                    .status()
+            }
+        };
+        let unreachable = || SubmitError::NodeUnreachable {
+            transaction: "0x01".to_string(),
+            cause: "Request timeout".to_string(),
+            stack: String::new(),
+        };
+        let rejected = || SubmitError::SubmitFailed {
+            transaction: "0x01".to_string(),
+            cause: "Transaction is outdated".to_string(),
+            stack: String::new(),
+        };
+
+        assert_eq!(attempt(unreachable).await, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(attempt(rejected).await, StatusCode::BAD_REQUEST);
+        assert_eq!(attempt(unreachable).await, StatusCode::BAD_REQUEST);
+        assert_eq!(submissions.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_connection_errors_are_not_node_rejections() {
+        assert!(is_connection_error(
+            &subxt_rpcs::Error::DisconnectedWillReconnect("Connection lost".to_string())
+        ));
+        assert!(is_connection_error(&subxt_rpcs::Error::Client(
+            "Request timeout".into()
+        )));
+        assert!(!is_connection_error(&subxt_rpcs::Error::User(
+            subxt_rpcs::UserError {
+                code: 1010,
+                message: "Invalid Transaction".to_string(),
+                data: None,
+            }
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_without_key_always_submits() {
+        let cache = IdempotencyCache::new();
+        let submissions = AtomicUsize::new(0);
+
+        submit_with_key(&cache, None, "0x01", &submissions).await;
+        submit_with_key(&cache, None, "0x01", &submissions).await;
+
+        assert_eq!(submissions.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_key_reused_for_other_tx_is_rejected() {
+        let cache = IdempotencyCache::new();
+        let submissions = AtomicUsize::new(0);
+
+        submit_with_key(&cache, Some("retry-1"), "0x01", &submissions).await;
+        let (status, body) = submit_with_key(&cache, Some("retry-1"), "0x02", &submissions).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["transaction"], "0x02");
+        assert_eq!(submissions.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key_is_bad_request() {
+        let cache = IdempotencyCache::new();
+        let submissions = AtomicUsize::new(0);
+        let too_long = "k".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1);
+
+        let (status, _) = submit_with_key(&cache, Some(&too_long), "0x01", &submissions).await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(submissions.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_submit_returns_locally_computed_tx_hash() {
+        use sp_runtime::traits::{BlakeTwo256, Hash};
+        use subxt_rpcs::client::{MockRpcClient, RpcClient, mock_rpc_client::Json as MockJson};
+
+        let tx = "0x2d028400d43593c715fdd31c61141abd04a99fd6822c8558";
+        let rpc_client = std::sync::Arc::new(RpcClient::new(
+            MockRpcClient::builder()
+                .method_handler("author_submitExtrinsic", |_params| async {
+                    Ok::<_, subxt_rpcs::Error>(MockJson("0x1234"))
+                })
+                .build(),
+        ));
+
+        let Json(response) = submit_internal(
+            &rpc_client,
+            SubmitRequest {
+                tx: Some(tx.to_string()),
+            },
+        )
+        .await
+        .unwrap();
+        let json = serde_json::to_value(response).unwrap();
+
+        let bytes = hex::decode(tx.trim_start_matches("0x")).unwrap();
+        let expected = format!("0x{}", hex::encode(BlakeTwo256::hash(&bytes)));
+        assert_eq!(json["hash"], "0x1234");
+        assert_eq!(json["txHash"], expected);
+    }
 
     #[test]
     fn test_parse_error_response_serialization() {
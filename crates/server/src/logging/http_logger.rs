@@ -1,6 +1,7 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use crate::middleware::request_id::RequestId;
 use axum::{extract::Request, middleware::Next, response::Response};
 use std::time::Instant;
 
@@ -12,10 +13,17 @@ use std::time::Instant;
 ///
 /// Log format: "METHOD /path STATUS DURATIONms"
 /// Example: "GET /api/blocks/latest 200 45ms"
+///
+/// Each event also carries a `request_id` field when `request_id_middleware` runs outside it.
 pub async fn http_logger_middleware(req: Request, next: Next) -> Response {
     let method = req.method().clone();
     let path = req.uri().path().to_string();
     let query = req.uri().query().map(|q| format!("?{}", q));
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .map(|id| id.0.clone())
+        .unwrap_or_default();
     let start = Instant::now();
 
     // Process the request
@@ -45,6 +53,7 @@ pub async fn http_logger_middleware(req: Request, next: Next) -> Response {
                 path = %full_path,
                 status = status_code,
                 duration_ms = duration_ms,
+                request_id = %request_id,
                 "{} {} {} {}ms",
                 method,
                 full_path,
@@ -59,6 +68,7 @@ pub async fn http_logger_middleware(req: Request, next: Next) -> Response {
                 path = %full_path,
                 status = status_code,
                 duration_ms = duration_ms,
+                request_id = %request_id,
                 "{} {} {} {}ms",
                 method,
                 full_path,
@@ -73,6 +83,7 @@ pub async fn http_logger_middleware(req: Request, next: Next) -> Response {
                 path = %full_path,
                 status = status_code,
                 duration_ms = duration_ms,
+                request_id = %request_id,
                 "{} {} {} {}ms",
                 method,
                 full_path,
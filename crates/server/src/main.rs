@@ -68,7 +68,11 @@ async fn main() -> Result<(), MainError> {
     )?;
 
     // Now create application state (connections happen here, warnings will be logged)
-    let state = state::AppState::new_with_config(config).await?;
+    let state = match state::AppState::new_with_config(config.clone()).await {
+        Ok(state) => state,
+        Err(err) if err.is_connection_error() => serve_until_connected(config, err).await?,
+        Err(err) => return Err(err.into()),
+    };
 
     // Extract remaining values we need
     let bind_host = state.config.express.bind_host.clone();
@@ -80,6 +84,7 @@ async fn main() -> Result<(), MainError> {
     let metrics_host = state.config.metrics.prom_host.clone();
     let metrics_port = state.config.metrics.prom_port;
     let metrics_prefix = state.config.metrics.prometheus_prefix.clone();
+    let metrics_cache_stats = state.config.metrics.cache_stats;
 
     // Parse bind_host to IpAddr
     let ip: IpAddr = bind_host.parse()?;
@@ -97,6 +102,9 @@ async fn main() -> Result<(), MainError> {
     // Initialize metrics if enabled
     if metrics_enabled {
         polkadot_rest_api::metrics::init(&metrics_prefix);
+        if metrics_cache_stats {
+            polkadot_rest_api::metrics::enable_cache_stats();
+        }
         tracing::info!(
             "Prometheus metrics enabled at http://{}:{}/metrics (prefix: {})",
             metrics_host,
@@ -130,14 +138,7 @@ async fn main() -> Result<(), MainError> {
         }
     }
 
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-
-    // Configure TCP keepalive on the listener's socket
-    let socket = socket2::Socket::from(listener.into_std()?);
-    let keepalive =
-        socket2::TcpKeepalive::new().with_time(Duration::from_millis(keep_alive_timeout));
-    socket.set_tcp_keepalive(&keepalive)?;
-    let listener = tokio::net::TcpListener::from_std(socket.into())?;
+    let listener = bind_listener(addr, keep_alive_timeout).await?;
 
     let app = NormalizePathLayer::trim_trailing_slash().layer(app);
     axum::serve(
@@ -148,3 +149,62 @@ async fn main() -> Result<(), MainError> {
 
     Ok(())
 }
+
+/// Bind the HTTP listener with TCP keepalive configured on its socket.
+async fn bind_listener(
+    addr: SocketAddr,
+    keep_alive_timeout: u64,
+) -> std::io::Result<tokio::net::TcpListener> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+
+    let socket = socket2::Socket::from(listener.into_std()?);
+    let keepalive =
+        socket2::TcpKeepalive::new().with_time(Duration::from_millis(keep_alive_timeout));
+    socket.set_tcp_keepalive(&keepalive)?;
+    tokio::net::TcpListener::from_std(socket.into())
+}
+
+/// Serve an API that reports itself unhealthy while retrying the node connection.
+///
+/// Reached when the reconnect retries are exhausted at startup. Another round of
+/// connection attempts starts every `SAS_SUBSTRATE_RECONNECT_MAX_DELAY_MS`; once
+/// one succeeds, this server shuts down and the application state is returned
+/// for the full API to be served in its place.
+async fn serve_until_connected(
+    config: SidecarConfig,
+    err: state::StateError,
+) -> Result<state::AppState, MainError> {
+    tracing::error!("{err}; serving /v1/health as unavailable until the node can be reached");
+
+    let ip: IpAddr = config.express.bind_host.parse()?;
+    let addr = SocketAddr::new(ip, config.express.port);
+    let listener = bind_listener(addr, config.express.keep_alive_timeout).await?;
+    let app = NormalizePathLayer::trim_trailing_slash().layer(app::create_unavailable_app(&config));
+
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let server = tokio::spawn(async move {
+        axum::serve(
+            listener,
+            axum::ServiceExt::<axum::extract::Request>::into_make_service(app),
+        )
+        .with_graceful_shutdown(async {
+            let _ = shutdown_rx.await;
+        })
+        .await
+    });
+
+    let retry_delay = Duration::from_millis(config.substrate.reconnect_max_delay_ms);
+    let result = loop {
+        tokio::time::sleep(retry_delay).await;
+        match state::AppState::new_with_config(config.clone()).await {
+            Err(err) if err.is_connection_error() => {
+                tracing::warn!("{err}; retrying in {}ms", retry_delay.as_millis());
+            }
+            result => break result,
+        }
+    };
+
+    let _ = shutdown_tx.send(());
+    server.await.map_err(std::io::Error::other)??;
+    Ok(result?)
+}
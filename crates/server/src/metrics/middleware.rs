@@ -4,19 +4,31 @@
 use axum::{
     body::Body,
     extract::{MatchedPath, Request, State},
-    http::StatusCode,
+    http::{StatusCode, header},
     middleware::Next,
     response::Response,
 };
+use futures::StreamExt;
 use http_body_util::BodyExt;
 use std::time::Instant;
 
-use crate::state::AppState;
-
 use super::registry::{
     HTTP_REQUEST_ERROR, HTTP_REQUEST_SUCCESS, HTTP_REQUESTS, REQUEST_DURATION_SECONDS,
-    RESPONSE_SIZE_BYTES, RESPONSE_SIZE_BYTES_SECONDS,
+    RESPONSE_SIZE_BYTES, RESPONSE_SIZE_BYTES_SECONDS, ROUTE_LATENCY_SECONDS,
 };
+use crate::routes::convert_path_params_for_display;
+
+/// Route label used for requests that didn't match any route (e.g. 404s).
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Route template label for the latency histogram, e.g. `/v1/blocks/{blockId}`.
+///
+/// Only the matched route pattern is used so the label cardinality stays bounded.
+fn route_template(matched_path: Option<&MatchedPath>) -> String {
+    matched_path
+        .map(|p| convert_path_params_for_display(p.as_str()))
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string())
+}
 
 /// Normalize a route path by replacing parameters with :paramName
 /// Example: /blocks/12345 -> /blocks/:blockId
@@ -70,8 +82,10 @@ fn normalize_route(path: &str, query_string: Option<&str>, include_query_params:
 }
 
 /// Metrics middleware for tracking HTTP requests
+///
+/// The state is whether query parameter names are included in route labels.
 pub async fn metrics_middleware(
-    State(state): State<AppState>,
+    State(include_query_params): State<bool>,
     matched_path: Option<MatchedPath>,
     req: Request,
     next: Next,
@@ -88,10 +102,11 @@ pub async fn metrics_middleware(
     // Start timer for request duration
     let start = Instant::now();
 
+    let route_template = route_template(matched_path.as_ref());
+
     // Get method, query string, and route
     let method = req.method().to_string();
     let query_string = req.uri().query();
-    let include_query_params = state.config.metrics.include_queryparams;
 
     let route = matched_path
         .as_ref()
@@ -120,32 +135,104 @@ pub async fn metrics_middleware(
         .with_label_values(&[&method, &route, &status_code])
         .observe(duration);
 
-    // Collect the response body to measure its size
+    ROUTE_LATENCY_SECONDS
+        .with_label_values(&[&method, &route_template])
+        .observe(duration);
+
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .map(|content_type| content_type.as_bytes().to_vec())
+        .unwrap_or_default();
+
+    // Event streams never complete, so their size can't be measured
+    if content_type.starts_with(b"text/event-stream") {
+        return Ok(response);
+    }
+
     let (parts, body) = response.into_parts();
+    let size = ResponseSize {
+        method,
+        route,
+        status_code,
+        start,
+        bytes: 0,
+    };
+
+    // Streamed bodies are passed through and counted as they're sent, since
+    // buffering them would hold the whole stream in memory
+    if content_type.starts_with(b"application/x-ndjson") {
+        return Ok(Response::from_parts(parts, size.count(body)));
+    }
+
+    // Collect the response body to measure its size
     let bytes = body
         .collect()
         .await
         .map(|collected| collected.to_bytes())
         .unwrap_or_default();
-    let response_size = bytes.len() as f64;
+    size.record(bytes.len(), duration);
+
+    // Reconstruct the response with the collected body
+    Ok(Response::from_parts(parts, Body::from(bytes)))
+}
+
+/// Labels and start time for recording a response's size
+struct ResponseSize {
+    method: String,
+    route: String,
+    status_code: String,
+    start: Instant,
+    bytes: usize,
+}
+
+impl ResponseSize {
+    fn record(&self, response_size: usize, duration: f64) {
+        if response_size == 0 {
+            return;
+        }
+        let response_size = response_size as f64;
+        let labels = [
+            self.method.as_str(),
+            self.route.as_str(),
+            self.status_code.as_str(),
+        ];
 
-    if response_size > 0.0 {
         // Record response size
         RESPONSE_SIZE_BYTES
-            .with_label_values(&[&method, &route, &status_code])
+            .with_label_values(&labels)
             .observe(response_size);
 
         // Record response size to latency ratio
         if duration > 0.0 {
-            let ratio = response_size / duration;
             RESPONSE_SIZE_BYTES_SECONDS
-                .with_label_values(&[&method, &route, &status_code])
-                .observe(ratio);
+                .with_label_values(&labels)
+                .observe(response_size / duration);
         }
     }
 
-    // Reconstruct the response with the collected body
-    Ok(Response::from_parts(parts, Body::from(bytes)))
+    /// Pass `body` through, recording its size once it ends or is dropped
+    fn count(mut self, body: Body) -> Body {
+        Body::from_stream(body.into_data_stream().map(move |chunk| {
+            if let Ok(chunk) = &chunk {
+                self.add(chunk.len());
+            }
+            chunk
+        }))
+    }
+
+    fn add(&mut self, bytes: usize) {
+        self.bytes += bytes;
+    }
+}
+
+impl Drop for ResponseSize {
+    fn drop(&mut self) {
+        // Only streamed bodies count bytes; buffered ones record directly
+        if self.bytes > 0 {
+            self.record(self.bytes, self.start.elapsed().as_secs_f64());
+        }
+    }
 }
 
 /// Extension trait for recording block-specific metrics in handlers
@@ -205,3 +292,76 @@ impl BlockMetrics for MetricsRecorder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, http::Request, routing::get};
+    use tower::ServiceExt;
+
+    async fn matched_route(uri: &str) -> String {
+        let inner = Router::new()
+            .route("/blocks/:blockId", get(|| async {}))
+            .layer(axum::middleware::from_fn(
+                |matched_path: Option<MatchedPath>, req: Request<Body>, next: Next| async move {
+                    let mut response = next.run(req).await;
+                    let route = route_template(matched_path.as_ref());
+                    response.extensions_mut().insert(route);
+                    response
+                },
+            ));
+        let app = Router::new().nest("/v1", inner);
+
+        let response = app
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        response.extensions().get::<String>().cloned().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_route_template_uses_matched_pattern() {
+        assert_eq!(
+            matched_route("/v1/blocks/12345").await,
+            "/v1/blocks/{blockId}"
+        );
+        assert_eq!(
+            matched_route("/v1/blocks/0xabcdef").await,
+            "/v1/blocks/{blockId}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_body_is_streamed_through() {
+        use futures::channel::mpsc;
+        use std::convert::Infallible;
+
+        let (tx, rx) = mpsc::unbounded::<&'static str>();
+        let body = Body::from_stream(rx.map(Ok::<_, Infallible>));
+        let size = ResponseSize {
+            method: "GET".to_string(),
+            route: "/v1/blocks".to_string(),
+            status_code: "200".to_string(),
+            start: Instant::now(),
+            bytes: 0,
+        };
+        let mut stream = size.count(body).into_data_stream();
+
+        // The first line arrives while the stream is still open
+        tx.unbounded_send("{\"number\":\"1\"}\n").unwrap();
+        let first = tokio::time::timeout(std::time::Duration::from_secs(1), stream.next())
+            .await
+            .expect("first line should arrive before the stream ends")
+            .unwrap()
+            .unwrap();
+        assert_eq!(&first[..], b"{\"number\":\"1\"}\n");
+
+        drop(tx);
+        assert!(stream.next().await.is_none());
+    }
+
+    #[test]
+    fn test_route_template_unmatched() {
+        assert_eq!(route_template(None), UNMATCHED_ROUTE);
+    }
+}
@@ -3,6 +3,8 @@
 
 pub mod middleware;
 pub mod registry;
+pub mod rpc;
 
 pub use middleware::{BlockMetrics, MetricsRecorder, metrics_middleware};
-pub use registry::{gather_metric_families, gather_metrics, init};
+pub use registry::{enable_cache_stats, gather_metric_families, gather_metrics, init};
+pub use rpc::InstrumentedRpcClient;
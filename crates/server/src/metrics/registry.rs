@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use lazy_static::lazy_static;
-use prometheus::{Counter, Encoder, HistogramOpts, HistogramVec, Registry, TextEncoder};
+use prometheus::{
+    Counter, CounterVec, Encoder, HistogramOpts, HistogramVec, Opts, Registry, TextEncoder,
+};
 use std::sync::{Mutex, Once};
 
 lazy_static! {
     pub static ref REGISTRY: Mutex<Option<Registry>> = Mutex::new(None);
     static ref INIT_ONCE: Once = Once::new();
+    static ref CACHE_STATS_ONCE: Once = Once::new();
 
     // Counter metrics - created without registering to default registry
     pub static ref HTTP_REQUESTS: Counter = Counter::new(
@@ -28,6 +31,43 @@ lazy_static! {
     )
     .expect("Failed to create http_request_error counter");
 
+    pub static ref RPC_REQUESTS: Counter = Counter::new(
+        "rpc_requests",
+        "Total number of RPC requests sent to the node"
+    )
+    .expect("Failed to create rpc_requests counter");
+
+    pub static ref RPC_ERRORS: CounterVec = CounterVec::new(
+        Opts::new("rpc_errors", "Number of failed RPC requests by error kind"),
+        &["kind"]
+    )
+    .expect("Failed to create rpc_errors counter");
+
+    pub static ref RPC_RECONNECTIONS: Counter = Counter::new(
+        "rpc_reconnections",
+        "Number of times an RPC connection recovered after being lost"
+    )
+    .expect("Failed to create rpc_reconnections counter");
+
+    // Cache counters - only registered when cache stats are enabled
+    pub static ref CACHE_HITS: CounterVec = CounterVec::new(
+        Opts::new("cache_hits", "Number of cache lookups that found an entry"),
+        &["cache"]
+    )
+    .expect("Failed to create cache_hits counter");
+
+    pub static ref CACHE_MISSES: CounterVec = CounterVec::new(
+        Opts::new("cache_misses", "Number of cache lookups that found no entry"),
+        &["cache"]
+    )
+    .expect("Failed to create cache_misses counter");
+
+    pub static ref CACHE_EVICTIONS: CounterVec = CounterVec::new(
+        Opts::new("cache_evictions", "Number of entries evicted from a cache"),
+        &["cache"]
+    )
+    .expect("Failed to create cache_evictions counter");
+
     // Histogram metrics - created without registering to default registry
     pub static ref REQUEST_DURATION_SECONDS: HistogramVec = HistogramVec::new(
         HistogramOpts::new(
@@ -38,6 +78,15 @@ lazy_static! {
     )
     .expect("Failed to create request_duration_seconds histogram");
 
+    pub static ref ROUTE_LATENCY_SECONDS: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "route_latency_seconds",
+            "Latency of HTTP requests per route template in seconds"
+        ).buckets(vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]),
+        &["method", "route"]
+    )
+    .expect("Failed to create route_latency_seconds histogram");
+
     pub static ref RESPONSE_SIZE_BYTES: HistogramVec = HistogramVec::new(
         HistogramOpts::new(
             "response_size_bytes",
@@ -114,10 +163,26 @@ pub fn init(prefix: &str) {
             .register(Box::new(HTTP_REQUEST_ERROR.clone()))
             .expect("Failed to register http_request_error");
 
+        registry
+            .register(Box::new(RPC_REQUESTS.clone()))
+            .expect("Failed to register rpc_requests");
+
+        registry
+            .register(Box::new(RPC_ERRORS.clone()))
+            .expect("Failed to register rpc_errors");
+
+        registry
+            .register(Box::new(RPC_RECONNECTIONS.clone()))
+            .expect("Failed to register rpc_reconnections");
+
         registry
             .register(Box::new(REQUEST_DURATION_SECONDS.clone()))
             .expect("Failed to register request_duration_seconds");
 
+        registry
+            .register(Box::new(ROUTE_LATENCY_SECONDS.clone()))
+            .expect("Failed to register route_latency_seconds");
+
         registry
             .register(Box::new(RESPONSE_SIZE_BYTES.clone()))
             .expect("Failed to register response_size_bytes");
@@ -147,6 +212,33 @@ pub fn init(prefix: &str) {
     });
 }
 
+/// Register the cache hit/miss/eviction counters.
+///
+/// Must be called after [`init`]; does nothing if metrics are not initialized.
+pub fn enable_cache_stats() {
+    CACHE_STATS_ONCE.call_once(|| {
+        let registry_guard = REGISTRY
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let Some(registry) = registry_guard.as_ref() else {
+            tracing::warn!("Metrics not initialized, cache stats are not exposed");
+            return;
+        };
+
+        registry
+            .register(Box::new(CACHE_HITS.clone()))
+            .expect("Failed to register cache_hits");
+
+        registry
+            .register(Box::new(CACHE_MISSES.clone()))
+            .expect("Failed to register cache_misses");
+
+        registry
+            .register(Box::new(CACHE_EVICTIONS.clone()))
+            .expect("Failed to register cache_evictions");
+    });
+}
+
 /// Gather metric families from the registry
 pub fn gather_metric_families()
 -> Result<Vec<prometheus::proto::MetricFamily>, Box<dyn std::error::Error>> {
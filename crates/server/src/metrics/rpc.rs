@@ -0,0 +1,124 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! RPC client wrapper that records request, error and reconnection metrics.
+
+use crate::utils::{is_disconnected_error, record_rpc_outcome};
+use std::sync::atomic::{AtomicBool, Ordering};
+use subxt_rpcs::client::{RawRpcFuture, RawRpcSubscription, RawValue, RpcClientT};
+
+use super::registry::RPC_RECONNECTIONS;
+
+/// Wraps a node RPC client and records the outcome of every call.
+///
+/// The reconnecting client doesn't report reconnections, so a reconnection is counted
+/// when a call succeeds after one failed with `DisconnectedWillReconnect`.
+pub struct InstrumentedRpcClient<C> {
+    inner: C,
+    disconnected: AtomicBool,
+}
+
+impl<C> InstrumentedRpcClient<C> {
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            disconnected: AtomicBool::new(false),
+        }
+    }
+
+    fn record<T>(&self, result: &Result<T, subxt_rpcs::Error>) {
+        record_rpc_outcome(result);
+        match result {
+            Ok(_) => {
+                if self.disconnected.swap(false, Ordering::Relaxed) {
+                    RPC_RECONNECTIONS.inc();
+                }
+            }
+            Err(err) if is_disconnected_error(err) => {
+                self.disconnected.store(true, Ordering::Relaxed);
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+impl<C: RpcClientT> RpcClientT for InstrumentedRpcClient<C> {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            let result = self.inner.request_raw(method, params).await;
+            self.record(&result);
+            result
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        Box::pin(async move {
+            let result = self.inner.subscribe_raw(sub, params, unsub).await;
+            self.record(&result);
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::registry::{RPC_ERRORS, RPC_REQUESTS};
+    use std::sync::Arc;
+    use subxt_rpcs::RpcClient;
+    use subxt_rpcs::client::MockRpcClient;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+    #[tokio::test]
+    async fn test_records_requests_errors_and_reconnections() {
+        let connected = Arc::new(AtomicBool::new(false));
+        let connected_in_handler = connected.clone();
+        let mock_client = MockRpcClient::builder()
+            .method_handler("system_name", move |_params| {
+                let connected = connected_in_handler.load(Ordering::SeqCst);
+                async move {
+                    if connected {
+                        Ok(MockJson("node"))
+                    } else {
+                        Err(subxt_rpcs::Error::DisconnectedWillReconnect(
+                            "Connection lost".to_string(),
+                        ))
+                    }
+                }
+            })
+            .build();
+        let rpc_client = RpcClient::new(InstrumentedRpcClient::new(mock_client));
+
+        // Counters are global, so compare deltas
+        let requests = RPC_REQUESTS.get();
+        let disconnects = RPC_ERRORS.with_label_values(&["disconnect"]).get();
+        let reconnections = RPC_RECONNECTIONS.get();
+
+        let failed = rpc_client
+            .request::<String>("system_name", subxt_rpcs::rpc_params![])
+            .await;
+        assert!(failed.is_err());
+
+        connected.store(true, Ordering::SeqCst);
+        for _ in 0..2 {
+            let name = rpc_client
+                .request::<String>("system_name", subxt_rpcs::rpc_params![])
+                .await
+                .unwrap();
+            assert_eq!(name, "node");
+        }
+
+        assert!(RPC_REQUESTS.get() - requests >= 3.0);
+        assert!(RPC_ERRORS.with_label_values(&["disconnect"]).get() - disconnects >= 1.0);
+        assert!(RPC_RECONNECTIONS.get() - reconnections >= 1.0);
+    }
+}
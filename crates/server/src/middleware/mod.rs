@@ -2,3 +2,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 pub mod rc_format;
+pub mod request_id;
+pub mod timeout;
@@ -0,0 +1,126 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+
+/// Header carrying the request id, both inbound and echoed on the response.
+pub static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied id that is accepted; longer ones are replaced.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Id of the current request, stored in the request extensions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /// Use the client's `X-Request-Id` if it is sane, otherwise generate a UUID.
+    ///
+    /// Only short, visible ASCII ids are kept so they can't inject text into log lines.
+    fn from_request(req: &Request) -> Self {
+        let incoming = req
+            .headers()
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| {
+                !id.is_empty()
+                    && id.len() <= MAX_REQUEST_ID_LEN
+                    && id.bytes().all(|b| b.is_ascii_graphic())
+            });
+
+        match incoming {
+            Some(id) => RequestId(id.to_string()),
+            None => RequestId(uuid::Uuid::new_v4().to_string()),
+        }
+    }
+}
+
+/// Assigns every request an id and echoes it in the `X-Request-Id` response header.
+///
+/// The id is stored in the request extensions for the HTTP logger and the request
+/// runs inside a `request` span carrying it, so logs emitted while handling the
+/// request (including RPC calls) can be correlated.
+pub async fn request_id_middleware(mut req: Request, next: Next) -> Response {
+    let request_id = RequestId::from_request(&req);
+    req.extensions_mut().insert(request_id.clone());
+
+    let span = tracing::info_span!("request", request_id = %request_id.0);
+    let mut response = next.run(req).instrument(span).await;
+
+    if let Ok(value) = HeaderValue::from_str(&request_id.0) {
+        response
+            .headers_mut()
+            .insert(REQUEST_ID_HEADER.clone(), value);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Extension, Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/",
+                get(|Extension(id): Extension<RequestId>| async move { id.0 }),
+            )
+            .layer(middleware::from_fn(request_id_middleware))
+    }
+
+    async fn request(header: Option<&str>) -> (Option<String>, String) {
+        let mut builder = axum::http::Request::builder().uri("/");
+        if let Some(value) = header {
+            builder = builder.header("x-request-id", value);
+        }
+        let response = app()
+            .oneshot(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let echoed = response
+            .headers()
+            .get("x-request-id")
+            .map(|v| v.to_str().unwrap().to_string());
+        let body = http_body_util::BodyExt::collect(response.into_body())
+            .await
+            .unwrap()
+            .to_bytes();
+        (echoed, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_incoming_request_id_is_propagated() {
+        let (echoed, seen_by_handler) = request(Some("client-abc-123")).await;
+
+        assert_eq!(echoed.as_deref(), Some("client-abc-123"));
+        assert_eq!(seen_by_handler, "client-abc-123");
+    }
+
+    #[tokio::test]
+    async fn test_request_id_is_generated_when_missing() {
+        let (echoed, seen_by_handler) = request(None).await;
+
+        let echoed = echoed.expect("response should carry a request id");
+        assert!(uuid::Uuid::parse_str(&echoed).is_ok());
+        assert_eq!(echoed, seen_by_handler);
+    }
+
+    #[tokio::test]
+    async fn test_unsafe_request_id_is_replaced() {
+        let too_long = "a".repeat(MAX_REQUEST_ID_LEN + 1);
+        for bad in ["has space", too_long.as_str()] {
+            let (echoed, _) = request(Some(bad)).await;
+            assert!(uuid::Uuid::parse_str(&echoed.unwrap()).is_ok());
+        }
+    }
+}
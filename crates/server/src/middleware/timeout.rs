@@ -0,0 +1,103 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+use std::time::Duration;
+
+/// Fails requests that don't produce a response within `timeout` with a 504.
+///
+/// Only the time until the response is returned is bounded, so streaming
+/// responses (SSE subscriptions) are not cut off once they have started.
+pub async fn request_timeout_middleware(
+    State(timeout): State<Duration>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let method = req.method().clone();
+    let path = req.uri().path().to_string();
+
+    match tokio::time::timeout(timeout, next.run(req)).await {
+        Ok(response) => response,
+        Err(_) => {
+            tracing::warn!(
+                "{} {} timed out after {}ms",
+                method,
+                path,
+                timeout.as_millis()
+            );
+            let body = Json(json!({
+                "error": "Request timed out while waiting for a response",
+            }));
+            (StatusCode::GATEWAY_TIMEOUT, body).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, middleware, routing::get};
+    use tower::ServiceExt;
+
+    fn app(timeout: Duration) -> Router {
+        Router::new()
+            .route("/fast", get(|| async { "ok" }))
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "too late"
+                }),
+            )
+            .layer(middleware::from_fn_with_state(
+                timeout,
+                request_timeout_middleware,
+            ))
+    }
+
+    async fn get_path(path: &str) -> (StatusCode, String) {
+        let response = app(Duration::from_millis(50))
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(path)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = http_body_util::BodyExt::collect(response.into_body())
+            .await
+            .unwrap()
+            .to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_slow_handler_times_out_with_504() {
+        let (status, body) = get_path("/slow").await;
+
+        assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            json["error"],
+            "Request timed out while waiting for a response"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fast_handler_is_unaffected() {
+        let (status, body) = get_path("/fast").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "ok");
+    }
+}
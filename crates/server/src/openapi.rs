@@ -23,11 +23,12 @@ use utoipa::OpenApi;
         (name = "accounts", description = "Account balance, staking, and proxy information"),
         (name = "pallets", description = "Runtime pallet metadata, storage, constants, events, errors"),
         (name = "runtime", description = "Runtime specification, metadata, and code"),
-        (name = "transaction", description = "Transaction submission, fee estimation, and construction material"),
+        (name = "transaction", description = "Transaction submission, fee estimation, construction material and signing payloads"),
         (name = "coretime", description = "Coretime system information"),
         (name = "paras", description = "Parachain inclusion data"),
         (name = "ahm", description = "Asset Hub Migration information"),
         (name = "capabilities", description = "API capabilities and chain pallets"),
+        (name = "chains", description = "Chains served by this instance"),
         (name = "rc", description = "Relay chain endpoints (available on parachains only)"),
     ),
     paths(
@@ -35,10 +36,13 @@ use utoipa::OpenApi;
         crate::handlers::health::get_health::get_health,
         crate::handlers::version::get_version::get_version,
         crate::handlers::capabilities::get_capabilities,
+        crate::handlers::chains::get_chains,
         crate::handlers::ahm::get_ahm_info::ahm_info,
         // Node
         crate::handlers::node::get_node_version::get_node_version,
+        crate::handlers::node::get_node_health::get_node_health,
         crate::handlers::node::get_node_network::get_node_network,
+        crate::handlers::node::get_node_peers::get_node_peers,
         crate::handlers::node::get_node_transaction_pool::get_node_transaction_pool,
         // Blocks
         crate::handlers::blocks::get_block::get_block,
@@ -49,6 +53,10 @@ use utoipa::OpenApi;
         crate::handlers::blocks::get_block_extrinsics_raw::get_block_extrinsics_raw,
         crate::handlers::blocks::get_extrinsic::get_extrinsic,
         crate::handlers::blocks::get_block_para_inclusions::get_block_para_inclusions,
+        crate::handlers::blocks::get_block_decoded_xcm_msgs::get_block_decoded_xcm_msgs,
+        // Subscriptions
+        crate::handlers::subscriptions::ws_blocks::ws_blocks,
+        crate::handlers::subscriptions::sse_blocks::sse_blocks,
         // Accounts
         crate::handlers::accounts::get_balance_info::get_balance_info,
         crate::handlers::accounts::get_asset_balances::get_asset_balances,
@@ -66,6 +74,7 @@ use utoipa::OpenApi;
         // Pallets
         crate::handlers::pallets::storage::get_pallets_storage,
         crate::handlers::pallets::storage::get_pallets_storage_item,
+        crate::handlers::pallets::storage::get_pallets_storage_item_keys,
         crate::handlers::pallets::consts::pallets_constants,
         crate::handlers::pallets::consts::pallets_constant_item,
         crate::handlers::pallets::errors::get_pallet_errors,
@@ -78,25 +87,34 @@ use utoipa::OpenApi;
         crate::handlers::pallets::staking_validators::pallets_staking_validators,
         crate::handlers::pallets::nomination_pools::pallets_nomination_pools_info,
         crate::handlers::pallets::nomination_pools::pallets_nomination_pools_pool,
+        crate::handlers::pallets::nomination_pools::pallets_nomination_pools_members,
         crate::handlers::pallets::assets::pallets_assets_asset_info,
         crate::handlers::pallets::pool_assets::pallets_pool_assets_asset_info,
         crate::handlers::pallets::foreign_assets::pallets_foreign_assets,
         crate::handlers::pallets::asset_conversion::get_liquidity_pools,
         crate::handlers::pallets::asset_conversion::get_next_available_id,
+        crate::handlers::pallets::asset_conversion::get_next_pool_asset_id,
+        crate::handlers::pallets::asset_conversion::get_pool_reserves,
         crate::handlers::pallets::on_going_referenda::pallets_on_going_referenda,
+        crate::handlers::pallets::referenda::pallets_referendum,
         // Runtime
         crate::handlers::runtime::get_spec::runtime_spec,
         crate::handlers::runtime::get_code::runtime_code,
+        crate::handlers::runtime::get_code::runtime_code_hash,
+        crate::handlers::runtime::get_call::runtime_call,
         crate::handlers::runtime::get_metadata::runtime_metadata,
         crate::handlers::runtime::get_metadata::runtime_metadata_versions,
+        crate::handlers::runtime::get_metadata_pallets::runtime_metadata_pallets,
         crate::handlers::runtime::get_metadata::runtime_metadata_versioned,
         // Transaction
         crate::handlers::transaction::submit::submit,
+        crate::handlers::transaction::decode::decode,
         crate::handlers::transaction::dry_run::dry_run,
         crate::handlers::transaction::fee_estimate::fee_estimate,
         crate::handlers::transaction::material::material,
         crate::handlers::transaction::material::material_versioned,
         crate::handlers::transaction::metadata_blob::metadata_blob,
+        crate::handlers::transaction::payload::payload,
         // Coretime
         crate::handlers::coretime::info::coretime_info,
         crate::handlers::coretime::overview::coretime_overview,
@@ -106,6 +124,7 @@ use utoipa::OpenApi;
         crate::handlers::coretime::reservations::coretime_reservations,
         // Paras
         crate::handlers::paras::paras_inclusion::get_paras_inclusion,
+        crate::handlers::paras::paras_head::get_paras_header,
         // RC - Blocks
         crate::handlers::rc::blocks::get_head::get_rc_blocks_head,
         crate::handlers::rc::blocks::get_head_header::get_rc_blocks_head_header,
@@ -152,6 +171,7 @@ use utoipa::OpenApi;
         crate::handlers::transaction::material::material_rc,
         crate::handlers::transaction::material::material_versioned_rc,
         crate::handlers::transaction::metadata_blob::metadata_blob_rc,
+        crate::handlers::transaction::payload::payload_rc,
     ),
 )]
 pub struct ApiDoc;
@@ -198,6 +218,7 @@ mod tests {
         let _ = routes::ahm::routes(&registry);
         let _ = routes::blocks::blocks_routes(&registry);
         let _ = routes::capabilities::routes(&registry);
+        let _ = routes::chains::routes(&registry);
         let _ = routes::coretime::routes(&registry, &chain_type);
         let _ = routes::health::routes(&registry);
         let _ = routes::node::routes(&registry);
@@ -205,6 +226,7 @@ mod tests {
         let _ = routes::paras::routes(&registry, &chain_type);
         let _ = routes::rc::routes(&registry, &chain_type);
         let _ = routes::runtime::routes(&registry);
+        let _ = routes::subscriptions::subscriptions_routes(&registry);
         let _ = routes::transaction::routes(&registry, &chain_type);
         let _ = routes::version::routes(&registry);
 
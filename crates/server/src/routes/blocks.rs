@@ -30,6 +30,13 @@ pub fn blocks_routes(registry: &RouteRegistry) -> Router<AppState> {
             "get",
             get(blocks::get_blocks),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/blocks/:blockId/decodedXcmMsgs",
+            "get",
+            get(blocks::get_block_decoded_xcm_msgs),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -0,0 +1,125 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use axum::{
+    Json, Router,
+    http::{StatusCode, Uri},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde_json::json;
+
+use crate::{
+    handlers::chains,
+    routes::{API_VERSION, RegisterRoute, RouteRegistry},
+    state::AppState,
+};
+
+pub fn routes(registry: &RouteRegistry) -> Router<AppState> {
+    Router::new().route_registered(
+        registry,
+        API_VERSION,
+        "/chains",
+        "get",
+        get(chains::get_chains),
+    )
+}
+
+/// Serve each chain's router under `/{chainType}`.
+///
+/// Requests for a chain type that isn't connected get a 404 naming the chain type.
+pub fn chain_routes<S: Clone + Send + Sync + 'static>(
+    chains: Vec<(&'static str, Router<S>)>,
+) -> Router<S> {
+    let served: Vec<&'static str> = chains.iter().map(|(chain_type, _)| *chain_type).collect();
+
+    chains
+        .into_iter()
+        .fold(Router::new(), |router, (chain_type, chain_router)| {
+            router.nest(&format!("/{chain_type}"), chain_router)
+        })
+        .fallback(move |uri: Uri| chain_not_found(uri, served))
+}
+
+async fn chain_not_found(uri: Uri, served: Vec<&'static str>) -> Response {
+    let chain_type = uri
+        .path()
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or_default();
+
+    // Unknown paths on a served chain are plain 404s, like on the main API
+    if served.contains(&chain_type) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let body = Json(json!({
+        "error": format!("Chain type '{chain_type}' is not configured"),
+    }));
+    (StatusCode::NOT_FOUND, body).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    async fn get_path(path: &str) -> (StatusCode, String) {
+        let app = Router::new().nest(
+            "/v1/chains",
+            chain_routes(vec![
+                (
+                    "relay",
+                    Router::new().route("/health", get(|| async { "relay" })),
+                ),
+                (
+                    "assethub",
+                    Router::new().route("/health", get(|| async { "assethub" })),
+                ),
+            ]),
+        );
+
+        let response = app
+            .oneshot(Request::builder().uri(path).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = http_body_util::BodyExt::collect(response.into_body())
+            .await
+            .unwrap()
+            .to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_dispatches_by_chain_type() {
+        assert_eq!(
+            get_path("/v1/chains/relay/health").await,
+            (StatusCode::OK, "relay".to_string())
+        );
+        assert_eq!(
+            get_path("/v1/chains/assethub/health").await,
+            (StatusCode::OK, "assethub".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unconfigured_chain_type_is_not_found() {
+        let (status, body) = get_path("/v1/chains/coretime/health").await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["error"], "Chain type 'coretime' is not configured");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_path_on_served_chain_is_plain_not_found() {
+        let (status, body) = get_path("/v1/chains/relay/nope").await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body.is_empty());
+    }
+}
@@ -1,10 +1,10 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::{handlers::metrics, state::AppState};
+use crate::handlers::metrics;
 use axum::{Router, routing::get};
 
-pub fn routes() -> Router<AppState> {
+pub fn routes<S: Clone + Send + Sync + 'static>() -> Router<S> {
     Router::new()
         .route("/metrics", get(metrics::get_metrics))
         .route("/metrics.json", get(metrics::get_metrics_json))
@@ -5,6 +5,7 @@ pub mod accounts;
 pub mod ahm;
 pub mod blocks;
 pub mod capabilities;
+pub mod chains;
 pub mod coretime;
 pub mod health;
 pub mod metrics;
@@ -15,7 +16,8 @@ pub mod rc;
 pub mod registry;
 pub mod root;
 pub mod runtime;
+pub mod subscriptions;
 pub mod transaction;
 pub mod version;
 
-pub use registry::{API_VERSION, RegisterRoute, RouteRegistry};
+pub use registry::{API_VERSION, RegisterRoute, RouteRegistry, convert_path_params_for_display};
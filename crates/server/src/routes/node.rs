@@ -11,6 +11,13 @@ use crate::{
 
 pub fn routes(registry: &RouteRegistry) -> Router<AppState> {
     Router::new()
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/node/health",
+            "get",
+            get(node::get_node_health),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -18,6 +25,13 @@ pub fn routes(registry: &RouteRegistry) -> Router<AppState> {
             "get",
             get(node::get_node_network),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/node/peers",
+            "get",
+            get(node::get_node_peers),
+        )
         .route_registered(
             registry,
             API_VERSION,
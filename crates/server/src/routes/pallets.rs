@@ -82,6 +82,13 @@ pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppSta
             "get",
             get(pallets::get_pallets_storage_item),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/pallets/:palletId/storage/:storageItemId/keys",
+            "get",
+            get(pallets::get_pallets_storage_item_keys),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -131,6 +138,13 @@ pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppSta
             "get",
             get(pallets::pallets_nomination_pools_pool),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/pallets/nomination-pools/:poolId/members",
+            "get",
+            get(pallets::pallets_nomination_pools_members),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -145,12 +159,33 @@ pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppSta
             "get",
             get(pallets::get_next_available_id),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/pallets/assetconversion/nextPoolAssetId",
+            "get",
+            get(pallets::get_next_pool_asset_id),
+        )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/pallets/asset-conversion/pools/:poolId/reserves",
+            "get",
+            get(pallets::get_pool_reserves),
+        )
         .route_registered(
             registry,
             API_VERSION,
             "/pallets/on-going-referenda",
             "get",
             get(pallets::pallets_on_going_referenda),
+        )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/pallets/referenda/:referendumId",
+            "get",
+            get(pallets::pallets_referendum),
         );
 
     // Only register /rc/ routes for parachains, not relay chains
@@ -3,8 +3,8 @@
 
 //! Routes for parachain-related endpoints.
 //!
-//! These routes are only available when connected to a parachain node,
-//! not when connected to a relay chain.
+//! The inclusion route is only available when connected to a parachain node.
+//! The header route reads relay chain state and is available on every chain.
 
 use axum::{Router, routing::get};
 use polkadot_rest_api_config::ChainType;
@@ -17,10 +17,16 @@ use crate::{
 
 /// Create routes for parachain endpoints.
 ///
-/// These routes are only registered when the connected chain is NOT a relay chain,
-/// as they require the `parachainInfo` pallet which only exists on parachains.
+/// The inclusion route is only registered when the connected chain is NOT a relay chain,
+/// as it requires the `parachainInfo` pallet which only exists on parachains.
 pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppState> {
-    let router = Router::new();
+    let router = Router::new().route_registered(
+        registry,
+        API_VERSION,
+        "/paras/:paraId/header",
+        "get",
+        get(paras::get_paras_header),
+    );
 
     // Only register paras routes for parachains, not relay chains
     if *chain_type != ChainType::Relay {
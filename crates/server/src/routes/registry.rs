@@ -92,7 +92,7 @@ impl<S: Clone + Send + Sync + 'static> RegisterRoute<S> for Router<S> {
 }
 
 /// Convert Axum path parameters (:param) to OpenAPI style ({param}) for display
-fn convert_path_params_for_display(path: &str) -> String {
+pub fn convert_path_params_for_display(path: &str) -> String {
     let mut result = String::new();
     let mut chars = path.chars().peekable();
 
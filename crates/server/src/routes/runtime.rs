@@ -25,6 +25,20 @@ pub fn routes(registry: &RouteRegistry) -> Router<AppState> {
             "get",
             get(runtime::runtime_code),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/runtime/code/hash",
+            "get",
+            get(runtime::runtime_code_hash),
+        )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/runtime/call/:api/:method",
+            "get",
+            get(runtime::runtime_call),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -39,6 +53,13 @@ pub fn routes(registry: &RouteRegistry) -> Router<AppState> {
             "get",
             get(runtime::runtime_metadata_versions),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/runtime/metadata/pallets",
+            "get",
+            get(runtime::runtime_metadata_pallets),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -0,0 +1,25 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::handlers::subscriptions;
+use crate::routes::{API_VERSION, RegisterRoute, RouteRegistry};
+use crate::state::AppState;
+use axum::{Router, routing::get};
+
+pub fn subscriptions_routes(registry: &RouteRegistry) -> Router<AppState> {
+    Router::new()
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/ws/blocks",
+            "get",
+            get(subscriptions::ws_blocks),
+        )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/sse/blocks",
+            "get",
+            get(subscriptions::sse_blocks),
+        )
+}
@@ -23,6 +23,13 @@ pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppSta
             "post",
             post(transaction::submit),
         )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/transaction/decode",
+            "post",
+            post(transaction::decode),
+        )
         .route_registered(
             registry,
             API_VERSION,
@@ -57,6 +64,13 @@ pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppSta
             "/transaction/metadata-blob",
             "post",
             post(transaction::metadata_blob),
+        )
+        .route_registered(
+            registry,
+            API_VERSION,
+            "/transaction/payload",
+            "post",
+            post(transaction::payload),
         );
 
     // Only register /rc/ routes for parachains, not relay chains
@@ -104,6 +118,13 @@ pub fn routes(registry: &RouteRegistry, chain_type: &ChainType) -> Router<AppSta
                 "post",
                 post(transaction::metadata_blob_rc),
             )
+            .route_registered(
+                registry,
+                API_VERSION,
+                "/rc/transaction/payload",
+                "post",
+                post(transaction::payload_rc),
+            )
     } else {
         router
     }
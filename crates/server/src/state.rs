@@ -1,17 +1,23 @@
 // Copyright (C) 2026 Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use crate::metrics::InstrumentedRpcClient;
 use crate::routes::RouteRegistry;
-use crate::utils::QueryFeeDetailsCache;
+use crate::utils::{
+    CachedMetadata, FinalizedHeadsHub, IdempotencyCache, MetadataFetchError, QueryFeeDetailsCache,
+    RuntimeMetadataCache, is_disconnected_error,
+};
 use polkadot_rest_api_config::{ChainType, SidecarConfig};
 use serde_json::Value;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 use subxt::config::RpcConfigFor;
 use subxt::{OnlineClient, SubstrateConfig};
 use subxt_rpcs::client::reconnecting_rpc_client::{
     ExponentialBackoff, RpcClient as ReconnectingRpcClient,
 };
+use subxt_rpcs::client::{RawRpcFuture, RawRpcSubscription, RawValue, RpcClientT};
 use subxt_rpcs::{LegacyRpcMethods, RpcClient, rpc_params};
 use tokio::sync::OnceCell;
 
@@ -41,6 +47,16 @@ pub enum StateError {
     SpecNameNotFound,
 }
 
+impl StateError {
+    /// Whether the node could not be reached, as opposed to misbehaving once connected.
+    pub fn is_connection_error(&self) -> bool {
+        matches!(
+            self,
+            StateError::ConnectionFailed { .. } | StateError::ConnectionTimeout { .. }
+        )
+    }
+}
+
 /// Error type for relay chain connection operations
 #[derive(Debug, Clone, Error)]
 pub enum RelayChainError {
@@ -66,6 +82,17 @@ pub struct ChainInfo {
     pub ss58_prefix: u16,
 }
 
+/// A connected chain: its clients, info and configuration
+#[derive(Clone)]
+pub struct ChainConnection {
+    pub url: String,
+    pub client: Arc<OnlineClient<SubstrateConfig>>,
+    pub legacy_rpc: Arc<SubstrateLegacyRpc>,
+    pub rpc_client: Arc<RpcClient>,
+    pub chain_info: ChainInfo,
+    pub chain_config: polkadot_rest_api_config::ChainConfig,
+}
+
 #[derive(Clone)]
 pub struct AppState {
     pub config: SidecarConfig,
@@ -83,6 +110,12 @@ pub struct AppState {
 
     /// Cache for tracking queryFeeDetails availability per spec version
     pub fee_details_cache: Arc<QueryFeeDetailsCache>,
+    /// LRU cache of decoded runtime metadata keyed by block hash
+    pub metadata_cache: Arc<RuntimeMetadataCache>,
+    /// Transaction submission responses by `Idempotency-Key`, replayed for retries
+    pub submission_cache: Arc<IdempotencyCache>,
+    /// Shared finalized head subscription fanned out to the streaming endpoints
+    pub block_subscriptions: Arc<FinalizedHeadsHub>,
     /// All chain configurations loaded from chain_config.json
     pub chain_configs: Arc<polkadot_rest_api_config::ChainConfigs>,
     /// Complete configuration with optional relay chain
@@ -93,6 +126,8 @@ pub struct AppState {
     pub relay_rpc_client: Arc<OnceCell<Arc<RpcClient>>>,
     /// Relay chain legacy RPC methods — lazy-init from relay_rpc_client
     pub relay_chain_rpc: Arc<OnceCell<Arc<SubstrateLegacyRpc>>>,
+    /// Non-relay chains from multi_chain_urls, served under `/v1/chains/{chainType}`
+    pub chain_connections: Arc<Vec<ChainConnection>>,
 }
 
 impl AppState {
@@ -104,9 +139,13 @@ impl AppState {
     pub async fn new_with_config(config: SidecarConfig) -> Result<Self, StateError> {
         let reconnecting_client =
             connect_with_progress_logging(&config.substrate.url, &config).await?;
+        let fallback_clients = connect_fallbacks(&config, &reconnecting_client).await;
 
         // Wrap in RpcClient for compatibility with existing code
-        let rpc_client = RpcClient::new(reconnecting_client);
+        let rpc_client = RpcClient::new(InstrumentedRpcClient::new(FailoverRpcClient::new(
+            reconnecting_client,
+            fallback_clients,
+        )));
 
         let legacy_rpc: SubstrateLegacyRpc = LegacyRpcMethods::new(rpc_client.clone());
 
@@ -142,10 +181,19 @@ impl AppState {
             // If relay chain URL is provided in multi_chain_urls, connect to it
             if let Some(relay_url) = config.substrate.get_relay_chain_url() {
                 // Relay chain connection now blocks and fails startup if it cannot connect
-                let (client, rpc, info, rc_config) =
-                    Self::connect_relay_chain(relay_url, relay_chain_name, &chain_configs, &config)
-                        .await?;
-                (Some(client), Some(rpc), Some(info), Some(rc_config))
+                let relay = Self::connect_chain(
+                    relay_url,
+                    &chain_configs,
+                    &config,
+                    "Connecting to relay chain at",
+                )
+                .await?;
+                (
+                    Some(relay.client),
+                    Some(relay.rpc_client),
+                    Some(relay.chain_info),
+                    Some(relay.chain_config),
+                )
             } else {
                 tracing::info!(
                     "Chain '{}' is a parachain with relay chain '{}', but no relay chain URL found in SAS_SUBSTRATE_MULTI_CHAIN_URL. \
@@ -190,6 +238,15 @@ impl AppState {
             relay_chain_info_cell.set(info).ok();
         }
 
+        let chain_connections =
+            Arc::new(Self::connect_other_chains(&config, &chain_info, &chain_configs).await);
+
+        let block_subscriptions = Arc::new(FinalizedHeadsHub::new(
+            config.express.max_block_subscriptions,
+        ));
+        let fee_details_cache =
+            Arc::new(QueryFeeDetailsCache::with_capacity(config.fee.cache_size));
+
         Ok(Self {
             config,
             client: Arc::new(client),
@@ -198,15 +255,133 @@ impl AppState {
             chain_info,
             relay_client: relay_client_cell,
             relay_chain_info: relay_chain_info_cell,
-            fee_details_cache: Arc::new(QueryFeeDetailsCache::new()),
+            fee_details_cache,
+            metadata_cache: Arc::new(RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(IdempotencyCache::new()),
+            block_subscriptions,
             chain_configs,
             chain_config: full_config,
             route_registry: RouteRegistry::new(),
             relay_rpc_client: relay_rpc_client_cell,
             relay_chain_rpc: relay_chain_rpc_cell,
+            chain_connections,
         })
     }
 
+    /// States for every chain this instance is connected to, one per chain type.
+    ///
+    /// Contains this state followed by one per `served_connections` entry. Each state uses
+    /// its chain's clients and caches, so the regular handlers can serve it under
+    /// `/v1/chains/{chainType}`.
+    pub fn chain_states(&self) -> Vec<AppState> {
+        let mut states = vec![self.clone()];
+
+        for connection in self.served_connections() {
+            let mut state = self.with_chain(&connection);
+            if connection.chain_info.chain_type == ChainType::Relay {
+                // The relay chain has no relay chain of its own
+                state.relay_client = Arc::new(OnceCell::new());
+                state.relay_chain_info = Arc::new(OnceCell::new());
+                state.relay_rpc_client = Arc::new(OnceCell::new());
+                state.relay_chain_rpc = Arc::new(OnceCell::new());
+                state.chain_config = Arc::new(polkadot_rest_api_config::Config::single_chain(
+                    connection.chain_config.clone(),
+                ));
+            }
+            states.push(state);
+        }
+
+        states
+    }
+
+    /// URL and info of every chain this instance is connected to, in `chain_states` order.
+    pub fn connected_chains(&self) -> Vec<(String, ChainInfo)> {
+        std::iter::once((self.config.substrate.url.clone(), self.chain_info.clone()))
+            .chain(
+                self.served_connections()
+                    .into_iter()
+                    .map(|connection| (connection.url, connection.chain_info)),
+            )
+            .collect()
+    }
+
+    /// Connections besides the primary chain: the relay chain (when connected) and the
+    /// other chains from multi_chain_urls, skipping chain types that are already served.
+    fn served_connections(&self) -> Vec<ChainConnection> {
+        let relay = match (
+            self.relay_client.get(),
+            self.relay_rpc_client.get(),
+            self.relay_chain_info.get(),
+            self.chain_config.rc.as_ref(),
+            self.config.substrate.get_relay_chain_url(),
+        ) {
+            (Some(client), Some(rpc_client), Some(chain_info), Some(rc_config), Some(url)) => {
+                Some(ChainConnection {
+                    url: url.to_string(),
+                    client: client.clone(),
+                    legacy_rpc: Arc::new(LegacyRpcMethods::new((**rpc_client).clone())),
+                    rpc_client: rpc_client.clone(),
+                    chain_info: chain_info.clone(),
+                    chain_config: rc_config.clone(),
+                })
+            }
+            _ => None,
+        };
+
+        let mut seen = vec![self.chain_info.chain_type.clone()];
+        relay
+            .into_iter()
+            .chain(self.chain_connections.iter().cloned())
+            .filter(|connection| {
+                let chain_type = &connection.chain_info.chain_type;
+                if seen.contains(chain_type) {
+                    return false;
+                }
+                seen.push(chain_type.clone());
+                true
+            })
+            .collect()
+    }
+
+    /// Copy of this state that serves `connection` instead of the primary chain.
+    ///
+    /// Relay chain access is shared, since all chains in a deployment belong to the same
+    /// relay chain. Caches are keyed per chain, so fresh ones are created.
+    fn with_chain(&self, connection: &ChainConnection) -> AppState {
+        let chain_config = match &self.chain_config.rc {
+            Some(rc_config) => polkadot_rest_api_config::Config::with_relay_chain(
+                connection.chain_config.clone(),
+                rc_config.clone(),
+            ),
+            None => polkadot_rest_api_config::Config::single_chain(connection.chain_config.clone()),
+        };
+
+        let mut config = self.config.clone();
+        config.substrate.url = connection.url.clone();
+        // Token overrides describe the primary chain only
+        config.substrate.token_symbol = None;
+        config.substrate.token_decimals = None;
+
+        AppState {
+            config,
+            client: connection.client.clone(),
+            legacy_rpc: connection.legacy_rpc.clone(),
+            rpc_client: connection.rpc_client.clone(),
+            chain_info: connection.chain_info.clone(),
+            chain_config: Arc::new(chain_config),
+            fee_details_cache: Arc::new(QueryFeeDetailsCache::with_capacity(
+                self.config.fee.cache_size,
+            )),
+            metadata_cache: Arc::new(RuntimeMetadataCache::new()),
+            submission_cache: Arc::new(IdempotencyCache::new()),
+            block_subscriptions: Arc::new(FinalizedHeadsHub::new(
+                self.config.express.max_block_subscriptions,
+            )),
+            chain_connections: Arc::new(Vec::new()),
+            ..self.clone()
+        }
+    }
+
     /// Get or lazily initialize the relay chain OnlineClient.
     ///
     /// If the connection was established at startup, returns the pre-populated client.
@@ -287,74 +462,113 @@ impl AppState {
                         .await
                         .map_err(|e| RelayChainError::ConnectionFailed(e.to_string()))?;
 
-                Ok(Arc::new(RpcClient::new(reconnecting_client)))
+                Ok(Arc::new(RpcClient::new(InstrumentedRpcClient::new(
+                    reconnecting_client,
+                ))))
             })
             .await
             .cloned()
     }
 
-    /// Connect to a relay chain with reconnection support and progress logging
-    async fn connect_relay_chain(
-        relay_url: &str,
-        _relay_chain_name: &str,
+    /// Connect to the non-relay chains in multi_chain_urls.
+    ///
+    /// Entries whose type matches the primary chain, or an earlier entry, are skipped
+    /// since only one chain per type can be served. A chain that can't be connected to is
+    /// skipped with a warning so it doesn't keep the server from starting.
+    async fn connect_other_chains(
+        config: &SidecarConfig,
+        chain_info: &ChainInfo,
+        chain_configs: &polkadot_rest_api_config::ChainConfigs,
+    ) -> Vec<ChainConnection> {
+        let mut connections: Vec<ChainConnection> = Vec::new();
+
+        for chain_url in &config.substrate.multi_chain_urls {
+            let chain_type = &chain_url.chain_type;
+            if *chain_type == ChainType::Relay {
+                continue;
+            }
+            if *chain_type == chain_info.chain_type
+                || connections
+                    .iter()
+                    .any(|c| c.chain_info.chain_type == *chain_type)
+            {
+                tracing::warn!(
+                    "Skipping {} in SAS_SUBSTRATE_MULTI_CHAIN_URL: a '{}' chain is already connected",
+                    chain_url.url,
+                    chain_type.as_str()
+                );
+                continue;
+            }
+
+            let mut connection =
+                match Self::connect_chain(&chain_url.url, chain_configs, config, "Connecting to")
+                    .await
+                {
+                    Ok(connection) => connection,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Skipping {} in SAS_SUBSTRATE_MULTI_CHAIN_URL: {}",
+                            chain_url.url,
+                            e
+                        );
+                        continue;
+                    }
+                };
+            // Serve the chain under the type it was configured with
+            connection.chain_info.chain_type = chain_type.clone();
+            connections.push(connection);
+        }
+
+        connections
+    }
+
+    /// Connect to a chain with reconnection support and progress logging
+    async fn connect_chain(
+        url: &str,
         chain_configs: &polkadot_rest_api_config::ChainConfigs,
         config: &SidecarConfig,
-    ) -> Result<
-        (
-            Arc<OnlineClient<SubstrateConfig>>,
-            Arc<RpcClient>,
-            ChainInfo,
-            polkadot_rest_api_config::ChainConfig,
-        ),
-        StateError,
-    > {
-        // Create relay chain RPC client with reconnection support
+        progress_prefix: &str,
+    ) -> Result<ChainConnection, StateError> {
+        // Create RPC client with reconnection support
         let reconnecting_client =
-            connect_relay_chain_with_progress_logging(relay_url, config).await?;
+            connect_with_progress_logging_impl(url, config, progress_prefix).await?;
 
-        let relay_rpc_client = RpcClient::new(reconnecting_client);
-        let relay_legacy_rpc: SubstrateLegacyRpc = LegacyRpcMethods::new(relay_rpc_client.clone());
+        let rpc_client = RpcClient::new(InstrumentedRpcClient::new(reconnecting_client));
+        let legacy_rpc: SubstrateLegacyRpc = LegacyRpcMethods::new(rpc_client.clone());
 
-        // Get relay chain info
-        let relay_chain_info = get_chain_info(&relay_legacy_rpc).await?;
+        let chain_info = get_chain_info(&legacy_rpc).await?;
 
-        // Load relay chain configuration
-        let relay_chain_config = chain_configs
-            .get(&relay_chain_info.spec_name)
+        let chain_config = chain_configs
+            .get(&chain_info.spec_name)
             .cloned()
             .unwrap_or_else(|| {
                 tracing::warn!(
-                    "No configuration found for relay chain '{}', using defaults",
-                    relay_chain_info.spec_name
+                    "No configuration found for chain '{}', using defaults",
+                    chain_info.spec_name
                 );
                 polkadot_rest_api_config::ChainConfig::default()
             });
 
         // Configure SubstrateConfig with appropriate legacy types
-        let relay_subxt_config = build_subxt_config(&relay_chain_config.legacy_types);
-
-        let relay_client =
-            OnlineClient::from_rpc_client_with_config(relay_subxt_config, relay_rpc_client.clone())
-                .await
-                .map_err(|e| StateError::ConnectionFailed {
-                    url: relay_url.to_string(),
-                    source: subxt_rpcs::Error::Client(Box::new(std::io::Error::other(
-                        e.to_string(),
-                    ))),
-                })?;
-
-        tracing::info!(
-            "Connected to relay chain '{}' at {}",
-            relay_chain_info.spec_name,
-            relay_url
-        );
-
-        Ok((
-            Arc::new(relay_client),
-            Arc::new(relay_rpc_client),
-            relay_chain_info,
-            relay_chain_config,
-        ))
+        let subxt_config = build_subxt_config(&chain_config.legacy_types);
+
+        let client = OnlineClient::from_rpc_client_with_config(subxt_config, rpc_client.clone())
+            .await
+            .map_err(|e| StateError::ConnectionFailed {
+                url: url.to_string(),
+                source: subxt_rpcs::Error::Client(Box::new(std::io::Error::other(e.to_string()))),
+            })?;
+
+        tracing::info!("Connected to chain '{}' at {}", chain_info.spec_name, url);
+
+        Ok(ChainConnection {
+            url: url.to_string(),
+            client: Arc::new(client),
+            legacy_rpc: Arc::new(legacy_rpc),
+            rpc_client: Arc::new(rpc_client),
+            chain_info,
+            chain_config,
+        })
     }
 
     /// Make a raw JSON-RPC call to get a header and return the result as a Value
@@ -395,6 +609,18 @@ impl AppState {
             .await
     }
 
+    /// Fetch the runtime metadata at a specific block hash
+    ///
+    /// Results are served from `metadata_cache` when available, so repeated requests
+    /// against the same block skip both the `state_getMetadata` RPC and the SCALE decode.
+    pub async fn get_runtime_metadata_at_hash(
+        &self,
+        block_hash: &str,
+    ) -> Result<Arc<CachedMetadata>, MetadataFetchError> {
+        crate::utils::fetch_runtime_metadata(&self.rpc_client, &self.metadata_cache, block_hash)
+            .await
+    }
+
     /// Query fee information for an extrinsic at a specific block hash
     ///
     /// Uses the `payment_queryInfo` RPC method to get weight, class, and partial fee
@@ -559,6 +785,196 @@ async fn get_chain_info(legacy_rpc: &SubstrateLegacyRpc) -> Result<ChainInfo, St
     })
 }
 
+/// How long to wait for each fallback endpoint at startup before skipping it.
+const FALLBACK_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Connect to the configured fallback URLs of the primary chain.
+///
+/// Fallbacks are connected concurrently. Those that can't be reached, or that
+/// serve a different chain than `primary` (a different genesis hash), are skipped
+/// with a warning rather than failing startup.
+async fn connect_fallbacks(
+    config: &SidecarConfig,
+    primary: &ReconnectingRpcClient,
+) -> Vec<ReconnectingRpcClient> {
+    if config.substrate.fallback_urls.is_empty() {
+        return Vec::new();
+    }
+
+    let primary_genesis = match genesis_hash(primary).await {
+        Ok(hash) => hash,
+        Err(e) => {
+            tracing::warn!(
+                "Skipping fallback endpoints: failed to fetch the primary's genesis hash: {e}"
+            );
+            return Vec::new();
+        }
+    };
+
+    let connections = config.substrate.fallback_urls.iter().map(|url| async move {
+        let connect = ReconnectingRpcClient::builder()
+            .retry_policy(
+                ExponentialBackoff::from_millis(config.substrate.reconnect_initial_delay_ms)
+                    .max_delay(Duration::from_millis(
+                        config.substrate.reconnect_max_delay_ms,
+                    )),
+            )
+            .request_timeout(Duration::from_millis(config.substrate.rpc_timeout_ms))
+            .max_concurrent_requests(config.substrate.max_connections)
+            .build(url.clone());
+
+        match tokio::time::timeout(FALLBACK_CONNECT_TIMEOUT, connect).await {
+            Ok(Ok(client)) => Some((url, client)),
+            Ok(Err(e)) => {
+                tracing::warn!("Skipping fallback endpoint {}: {}", url, e);
+                None
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Skipping fallback endpoint {}: not reachable within {}s",
+                    url,
+                    FALLBACK_CONNECT_TIMEOUT.as_secs()
+                );
+                None
+            }
+        }
+    });
+    let connected = futures::future::join_all(connections)
+        .await
+        .into_iter()
+        .flatten();
+
+    same_chain_fallbacks(&primary_genesis, connected).await
+}
+
+/// Genesis hash of the chain `client` is connected to
+async fn genesis_hash<C: RpcClientT>(client: &C) -> Result<String, subxt_rpcs::Error> {
+    let hash = client
+        .request_raw("chain_getBlockHash", rpc_params![0].build())
+        .await?;
+    serde_json::from_str(hash.get()).map_err(subxt_rpcs::Error::Deserialization)
+}
+
+/// Keep the fallbacks whose genesis hash matches `primary_genesis`, checking them concurrently.
+async fn same_chain_fallbacks<C: RpcClientT>(
+    primary_genesis: &str,
+    fallbacks: impl IntoIterator<Item = (&String, C)>,
+) -> Vec<C> {
+    let checks = fallbacks.into_iter().map(|(url, client)| async move {
+        match tokio::time::timeout(FALLBACK_CONNECT_TIMEOUT, genesis_hash(&client)).await {
+            Ok(Ok(genesis)) if genesis == primary_genesis => {
+                tracing::info!("Connected to fallback endpoint {}", url);
+                Some(client)
+            }
+            Ok(Ok(genesis)) => {
+                tracing::warn!(
+                    "Skipping fallback endpoint {}: genesis hash {} does not match the primary's {}",
+                    url,
+                    genesis,
+                    primary_genesis
+                );
+                None
+            }
+            Ok(Err(e)) => {
+                tracing::warn!("Skipping fallback endpoint {}: failed to fetch its genesis hash: {}", url, e);
+                None
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "Skipping fallback endpoint {}: genesis hash not returned within {}s",
+                    url,
+                    FALLBACK_CONNECT_TIMEOUT.as_secs()
+                );
+                None
+            }
+        }
+    });
+
+    futures::future::join_all(checks)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// RPC methods that must go to the primary endpoint.
+///
+/// Submissions are only sent to the primary node, and `chainHead_*` calls refer to
+/// a follow subscription that only exists on the connection that created it.
+fn requires_primary(method: &str) -> bool {
+    method.starts_with("author_")
+        || method.starts_with("transaction_")
+        || method.starts_with("transactionWatch_")
+        || method.starts_with("chainHead_")
+}
+
+/// Routes read-only requests to fallback endpoints while the primary is disconnected.
+///
+/// Every request goes to the primary first. When it fails with `DisconnectedWillReconnect`,
+/// read-only requests are retried on the fallbacks, starting with the last one that
+/// answered. Writes and subscriptions always use the primary.
+pub struct FailoverRpcClient<C> {
+    primary: C,
+    fallbacks: Vec<C>,
+    /// Index of the fallback to try first
+    next: AtomicUsize,
+}
+
+impl<C> FailoverRpcClient<C> {
+    pub fn new(primary: C, fallbacks: Vec<C>) -> Self {
+        Self {
+            primary,
+            fallbacks,
+            next: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<C: RpcClientT> RpcClientT for FailoverRpcClient<C> {
+    fn request_raw<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Box<RawValue>>,
+    ) -> RawRpcFuture<'a, Box<RawValue>> {
+        Box::pin(async move {
+            if self.fallbacks.is_empty() || requires_primary(method) {
+                return self.primary.request_raw(method, params).await;
+            }
+
+            let err = match self.primary.request_raw(method, params.clone()).await {
+                Err(err) if is_disconnected_error(&err) => err,
+                result => return result,
+            };
+
+            let start = self.next.load(Ordering::Relaxed);
+            for offset in 0..self.fallbacks.len() {
+                let index = (start + offset) % self.fallbacks.len();
+                match self.fallbacks[index]
+                    .request_raw(method, params.clone())
+                    .await
+                {
+                    Err(e) if is_disconnected_error(&e) => continue,
+                    result => {
+                        self.next.store(index, Ordering::Relaxed);
+                        return result;
+                    }
+                }
+            }
+
+            Err(err)
+        })
+    }
+
+    fn subscribe_raw<'a>(
+        &'a self,
+        sub: &'a str,
+        params: Option<Box<RawValue>>,
+        unsub: &'a str,
+    ) -> RawRpcFuture<'a, RawRpcSubscription> {
+        self.primary.subscribe_raw(sub, params, unsub)
+    }
+}
+
 /// Connect to the substrate node with CLI progress indicator.
 /// Shows a live progress line that updates every second, independent of log levels.
 /// Terminates after 60 seconds with a clear error message.
@@ -589,16 +1005,26 @@ async fn connect_with_progress_logging_impl(
     use std::io::Write;
     use subxt_rpcs::client::reconnecting_rpc_client::RpcClient as ReconnectingClient;
 
-    let connect_future = ReconnectingClient::builder()
-        .retry_policy(
-            ExponentialBackoff::from_millis(config.substrate.reconnect_initial_delay_ms).max_delay(
-                Duration::from_millis(config.substrate.reconnect_max_delay_ms),
-            ),
-        )
-        .request_timeout(Duration::from_millis(
-            config.substrate.reconnect_request_timeout_ms,
-        ))
-        .build(url);
+    let backoff = ExponentialBackoff::from_millis(config.substrate.reconnect_initial_delay_ms)
+        .max_delay(Duration::from_millis(
+            config.substrate.reconnect_max_delay_ms,
+        ));
+    let request_timeout = Duration::from_millis(config.substrate.rpc_timeout_ms);
+
+    // The retry limit only applies to this startup wait, which is still cut off after
+    // `TIMEOUT_SECS` below. Once connected, the client keeps reconnecting for as long
+    // as the server runs.
+    let connect_future = async {
+        if config.substrate.reconnect_max_retries > 0 {
+            wait_for_node(url, backoff, config.substrate.reconnect_max_retries).await?;
+        }
+        ReconnectingClient::builder()
+            .retry_policy(backoff)
+            .request_timeout(request_timeout)
+            .max_concurrent_requests(config.substrate.max_connections)
+            .build(url)
+            .await
+    };
 
     tokio::pin!(connect_future);
 
@@ -631,7 +1057,6 @@ async fn connect_with_progress_logging_impl(
                     // Clear line and print final error
                     eprintln!("\r\x1b[K");
                     eprintln!("Failed to connect to {} after {} seconds.", url, TIMEOUT_SECS);
-                    eprintln!("Terminating: no active connection with the RPC node.");
 
                     return Err(StateError::ConnectionTimeout {
                         url: url.to_string(),
@@ -657,3 +1082,137 @@ async fn connect_with_progress_logging_impl(
         }
     }
 }
+
+/// Try to connect to `url`, retrying up to `max_retries` times and waiting `backoff`
+/// between attempts.
+///
+/// Each attempt uses a client without retries that is dropped straight away, so
+/// the limit doesn't carry over to the reconnect policy of the client that is kept.
+async fn wait_for_node(
+    url: &str,
+    backoff: ExponentialBackoff,
+    max_retries: u32,
+) -> Result<(), subxt_rpcs::client::reconnecting_rpc_client::RpcError> {
+    use subxt_rpcs::client::reconnecting_rpc_client::RpcClient as ReconnectingClient;
+
+    let mut delays = backoff.take(max_retries as usize);
+    loop {
+        let attempt = ReconnectingClient::builder()
+            .retry_policy(std::iter::empty())
+            .build(url)
+            .await;
+        match (attempt, delays.next()) {
+            (Ok(_), _) => return Ok(()),
+            (Err(e), None) => return Err(e),
+            (Err(e), Some(delay)) => {
+                tracing::debug!("Failed to connect to {url}: {e}; retrying in {delay:?}");
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt_rpcs::client::MockRpcClient;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+    fn disconnected_node() -> MockRpcClient {
+        MockRpcClient::builder()
+            .method_handler("system_name", |_params| async {
+                Err::<MockJson<&str>, _>(subxt_rpcs::Error::DisconnectedWillReconnect(
+                    "Connection lost".to_string(),
+                ))
+            })
+            .method_handler("author_submitExtrinsic", |_params| async {
+                Err::<MockJson<&str>, _>(subxt_rpcs::Error::DisconnectedWillReconnect(
+                    "Connection lost".to_string(),
+                ))
+            })
+            .build()
+    }
+
+    fn node(name: &'static str) -> MockRpcClient {
+        MockRpcClient::builder()
+            .method_handler("system_name", move |_params| async move {
+                Ok::<_, subxt_rpcs::Error>(MockJson(name))
+            })
+            .method_handler("author_submitExtrinsic", |_params| async {
+                Ok::<_, subxt_rpcs::Error>(MockJson("0x00"))
+            })
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_failover_routes_reads_to_fallback() {
+        let rpc_client = RpcClient::new(FailoverRpcClient::new(
+            disconnected_node(),
+            vec![disconnected_node(), node("fallback")],
+        ));
+
+        let name = rpc_client
+            .request::<String>("system_name", rpc_params![])
+            .await
+            .unwrap();
+        assert_eq!(name, "fallback");
+
+        let submitted = rpc_client
+            .request::<String>("author_submitExtrinsic", rpc_params!["0x00"])
+            .await;
+        assert!(matches!(
+            submitted,
+            Err(subxt_rpcs::Error::DisconnectedWillReconnect(_))
+        ));
+    }
+
+    fn chain_node(name: &'static str, genesis: &'static str) -> MockRpcClient {
+        MockRpcClient::builder()
+            .method_handler("system_name", move |_params| async move {
+                Ok::<_, subxt_rpcs::Error>(MockJson(name))
+            })
+            .method_handler("chain_getBlockHash", move |_params| async move {
+                Ok::<_, subxt_rpcs::Error>(MockJson(genesis))
+            })
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_same_chain_fallbacks_rejects_other_genesis() {
+        let urls = [
+            "ws://a".to_string(),
+            "ws://b".to_string(),
+            "ws://c".to_string(),
+        ];
+        let fallbacks = same_chain_fallbacks(
+            "0x91b1",
+            [
+                (&urls[0], chain_node("same", "0x91b1")),
+                (&urls[1], chain_node("other", "0xb0a8")),
+                (&urls[2], disconnected_node()),
+            ],
+        )
+        .await;
+
+        assert_eq!(fallbacks.len(), 1);
+        let name = RpcClient::new(fallbacks.into_iter().next().unwrap())
+            .request::<String>("system_name", rpc_params![])
+            .await
+            .unwrap();
+        assert_eq!(name, "same");
+    }
+
+    #[tokio::test]
+    async fn test_failover_prefers_connected_primary() {
+        let rpc_client = RpcClient::new(FailoverRpcClient::new(
+            node("primary"),
+            vec![node("fallback")],
+        ));
+
+        let name = rpc_client
+            .request::<String>("system_name", rpc_params![])
+            .await
+            .unwrap();
+        assert_eq!(name, "primary");
+    }
+}
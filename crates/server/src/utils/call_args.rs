@@ -0,0 +1,610 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Build a call from JSON arguments, checked against the metadata.
+//!
+//! Each argument is converted by walking the dispatchable's field types in the
+//! portable registry, so a wrongly shaped argument is reported by its path
+//! (e.g. `dest.Id`) instead of failing later during SCALE encoding.
+//!
+//! JSON shapes accepted for each type:
+//! - integers: a JSON number or a decimal string (for values beyond 2^53)
+//! - structs: an object keyed by field name (`snake_case` or `camelCase`)
+//! - single-field tuple structs (e.g. `AccountId32`): the inner value directly
+//! - enums: a variant name string for variants without fields, or `{ "Variant": fields }`
+//! - `Option<T>`: `null` for `None`, the inner value for `Some`
+//! - byte arrays and vectors: a `0x` hex string; byte vectors also take plain text,
+//!   and 32-byte arrays also take an SS58 address
+
+use heck::{ToLowerCamelCase, ToSnakeCase};
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive, form::PortableForm};
+use scale_value::{Composite, Value, ValueDef};
+use sp_core::crypto::Ss58Codec;
+use subxt::Metadata;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum CallArgsError {
+    #[error("Pallet '{0}' not found")]
+    PalletNotFound(String),
+
+    #[error("Call '{method}' not found in pallet '{pallet}'")]
+    CallNotFound { pallet: String, method: String },
+
+    #[error("`args` must be an object keyed by the call's argument names")]
+    ArgsNotObject,
+
+    #[error("Missing argument '{0}'")]
+    MissingArg(String),
+
+    #[error("Unexpected argument '{0}'")]
+    UnexpectedArg(String),
+
+    #[error("Invalid value for '{path}': {cause}")]
+    InvalidArg { path: String, cause: String },
+}
+
+/// A call resolved against the metadata, with its arguments converted to SCALE values.
+#[derive(Debug, Clone)]
+pub struct ResolvedCall {
+    /// Pallet name as declared in the runtime (e.g. `Balances`)
+    pub pallet: String,
+    /// Call name as declared in the runtime (e.g. `transfer_keep_alive`)
+    pub method: String,
+    pub args: Composite<()>,
+}
+
+/// Resolve `pallet`/`method` and convert `args` into the call's fields.
+///
+/// Pallet names match case-insensitively and method names may be given in
+/// `camelCase`, mirroring how they are rendered elsewhere in the API.
+pub fn resolve_call(
+    metadata: &Metadata,
+    pallet: &str,
+    method: &str,
+    args: &serde_json::Value,
+) -> Result<ResolvedCall, CallArgsError> {
+    let pallet_meta = metadata
+        .pallet_by_name(pallet)
+        .or_else(|| {
+            metadata
+                .pallets()
+                .find(|p| p.name().eq_ignore_ascii_case(pallet))
+        })
+        .ok_or_else(|| CallArgsError::PalletNotFound(pallet.to_string()))?;
+    let variant = pallet_meta
+        .call_variant_by_name(method)
+        .or_else(|| pallet_meta.call_variant_by_name(&method.to_snake_case()))
+        .ok_or_else(|| CallArgsError::CallNotFound {
+            pallet: pallet_meta.name().to_string(),
+            method: method.to_string(),
+        })?;
+
+    let args = match args {
+        serde_json::Value::Null if variant.fields.is_empty() => serde_json::Map::new(),
+        serde_json::Value::Object(map) => map.clone(),
+        _ => return Err(CallArgsError::ArgsNotObject),
+    };
+
+    let mut fields = Vec::with_capacity(variant.fields.len());
+    for field in &variant.fields {
+        let name = field.name.clone().unwrap_or_default();
+        let camel = name.to_lower_camel_case();
+        let json = args
+            .get(&name)
+            .or_else(|| args.get(&camel))
+            .ok_or_else(|| CallArgsError::MissingArg(camel.clone()))?;
+        let value = json_to_value(json, field.ty.id, metadata.types(), &camel)?;
+        fields.push((name, value));
+    }
+    if let Some(unexpected) = args.keys().find(|key| {
+        !variant.fields.iter().any(|field| {
+            let name = field.name.as_deref().unwrap_or_default();
+            *key == name || **key == name.to_lower_camel_case()
+        })
+    }) {
+        return Err(CallArgsError::UnexpectedArg(unexpected.clone()));
+    }
+
+    Ok(ResolvedCall {
+        pallet: pallet_meta.name().to_string(),
+        method: variant.name.clone(),
+        args: Composite::Named(fields),
+    })
+}
+
+fn invalid(path: &str, cause: impl Into<String>) -> CallArgsError {
+    CallArgsError::InvalidArg {
+        path: path.to_string(),
+        cause: cause.into(),
+    }
+}
+
+fn composite_value(composite: Composite<()>) -> Value<()> {
+    Value::without_context(ValueDef::Composite(composite))
+}
+
+/// Convert `json` into a value of type `type_id`.
+fn json_to_value(
+    json: &serde_json::Value,
+    type_id: u32,
+    types: &PortableRegistry,
+    path: &str,
+) -> Result<Value<()>, CallArgsError> {
+    let ty = types
+        .resolve(type_id)
+        .ok_or_else(|| invalid(path, format!("type {type_id} not found in metadata")))?;
+
+    match &ty.type_def {
+        TypeDef::Primitive(primitive) => primitive_to_value(json, primitive, path),
+        TypeDef::Compact(compact) => json_to_value(json, compact.type_param.id, types, path),
+        TypeDef::Sequence(seq) => {
+            if is_u8(seq.type_param.id, types)
+                && let Some(text) = json.as_str()
+            {
+                return Ok(Value::from_bytes(text_to_bytes(text, path)?));
+            }
+            let items = json
+                .as_array()
+                .ok_or_else(|| invalid(path, "expected an array"))?;
+            elements_to_composite(items, seq.type_param.id, types, path)
+        }
+        TypeDef::Array(array) => {
+            let len = array.len as usize;
+            if is_u8(array.type_param.id, types)
+                && let Some(text) = json.as_str()
+            {
+                return Ok(Value::from_bytes(fixed_bytes(text, len, path)?));
+            }
+            let items = json
+                .as_array()
+                .ok_or_else(|| invalid(path, format!("expected an array of {len} elements")))?;
+            if items.len() != len {
+                return Err(invalid(
+                    path,
+                    format!("expected {len} elements, got {}", items.len()),
+                ));
+            }
+            elements_to_composite(items, array.type_param.id, types, path)
+        }
+        TypeDef::Tuple(tuple) => {
+            let field_types: Vec<u32> = tuple.fields.iter().map(|f| f.id).collect();
+            unnamed_fields(json, &field_types, types, path).map(composite_value)
+        }
+        TypeDef::Composite(composite) => {
+            fields_to_composite(json, &composite.fields, types, path).map(composite_value)
+        }
+        TypeDef::Variant(variant) => {
+            let is_option = ty.path.segments.last().map(String::as_str) == Some("Option");
+            if is_option {
+                return match json {
+                    serde_json::Value::Null => Ok(Value::unnamed_variant("None", [])),
+                    inner => {
+                        let some = variant
+                            .variants
+                            .iter()
+                            .find(|v| v.name == "Some")
+                            .and_then(|v| v.fields.first())
+                            .ok_or_else(|| invalid(path, "malformed Option type"))?;
+                        let value = json_to_value(inner, some.ty.id, types, path)?;
+                        Ok(Value::unnamed_variant("Some", [value]))
+                    }
+                };
+            }
+
+            let (name, fields_json) = match json {
+                serde_json::Value::String(name) => (name.as_str(), &serde_json::Value::Null),
+                serde_json::Value::Object(map) if map.len() == 1 => {
+                    let (name, fields) = map.iter().next().expect("map has one entry");
+                    (name.as_str(), fields)
+                }
+                _ => {
+                    return Err(invalid(
+                        path,
+                        "expected a variant name or an object with a single variant key",
+                    ));
+                }
+            };
+            let selected = variant
+                .variants
+                .iter()
+                .find(|v| v.name == name)
+                .or_else(|| {
+                    variant
+                        .variants
+                        .iter()
+                        .find(|v| v.name.eq_ignore_ascii_case(name))
+                })
+                .ok_or_else(|| {
+                    let options: Vec<&str> =
+                        variant.variants.iter().map(|v| v.name.as_str()).collect();
+                    invalid(
+                        path,
+                        format!(
+                            "unknown variant '{name}', expected one of: {}",
+                            options.join(", ")
+                        ),
+                    )
+                })?;
+            let variant_path = format!("{path}.{}", selected.name);
+            let fields = fields_to_composite(fields_json, &selected.fields, types, &variant_path)?;
+            Ok(Value::variant(selected.name.clone(), fields))
+        }
+        TypeDef::BitSequence(_) => Err(invalid(path, "bit sequences are not supported")),
+    }
+}
+
+fn primitive_to_value(
+    json: &serde_json::Value,
+    primitive: &TypeDefPrimitive,
+    path: &str,
+) -> Result<Value<()>, CallArgsError> {
+    match primitive {
+        TypeDefPrimitive::Bool => json
+            .as_bool()
+            .map(Value::bool)
+            .ok_or_else(|| invalid(path, "expected a boolean")),
+        TypeDefPrimitive::Char => {
+            let mut chars = json.as_str().unwrap_or_default().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Value::char(c)),
+                _ => Err(invalid(path, "expected a single character")),
+            }
+        }
+        TypeDefPrimitive::Str => json
+            .as_str()
+            .map(Value::string)
+            .ok_or_else(|| invalid(path, "expected a string")),
+        TypeDefPrimitive::U8
+        | TypeDefPrimitive::U16
+        | TypeDefPrimitive::U32
+        | TypeDefPrimitive::U64
+        | TypeDefPrimitive::U128 => {
+            let parsed = match json {
+                serde_json::Value::Number(n) => n.as_u64().map(u128::from),
+                serde_json::Value::String(s) => s.parse::<u128>().ok(),
+                _ => None,
+            };
+            let value = parsed.ok_or_else(|| invalid(path, "expected an unsigned integer"))?;
+            let (name, max) = match primitive {
+                TypeDefPrimitive::U8 => ("u8", u8::MAX.into()),
+                TypeDefPrimitive::U16 => ("u16", u16::MAX.into()),
+                TypeDefPrimitive::U32 => ("u32", u32::MAX.into()),
+                TypeDefPrimitive::U64 => ("u64", u64::MAX.into()),
+                _ => ("u128", u128::MAX),
+            };
+            if value > max {
+                return Err(invalid(path, format!("{value} is out of range for {name}")));
+            }
+            Ok(Value::u128(value))
+        }
+        TypeDefPrimitive::I8
+        | TypeDefPrimitive::I16
+        | TypeDefPrimitive::I32
+        | TypeDefPrimitive::I64
+        | TypeDefPrimitive::I128 => {
+            let parsed = match json {
+                serde_json::Value::Number(n) => n.as_i64().map(i128::from),
+                serde_json::Value::String(s) => s.parse::<i128>().ok(),
+                _ => None,
+            };
+            let value = parsed.ok_or_else(|| invalid(path, "expected an integer"))?;
+            let (name, min, max) = match primitive {
+                TypeDefPrimitive::I8 => ("i8", i8::MIN.into(), i8::MAX.into()),
+                TypeDefPrimitive::I16 => ("i16", i16::MIN.into(), i16::MAX.into()),
+                TypeDefPrimitive::I32 => ("i32", i32::MIN.into(), i32::MAX.into()),
+                TypeDefPrimitive::I64 => ("i64", i64::MIN.into(), i64::MAX.into()),
+                _ => ("i128", i128::MIN, i128::MAX),
+            };
+            if !(min..=max).contains(&value) {
+                return Err(invalid(path, format!("{value} is out of range for {name}")));
+            }
+            Ok(Value::i128(value))
+        }
+        TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => {
+            Err(invalid(path, "256-bit integers are not supported"))
+        }
+    }
+}
+
+/// Convert the fields of a struct or enum variant.
+fn fields_to_composite(
+    json: &serde_json::Value,
+    fields: &[scale_info::Field<PortableForm>],
+    types: &PortableRegistry,
+    path: &str,
+) -> Result<Composite<()>, CallArgsError> {
+    if fields.is_empty() {
+        return match json {
+            serde_json::Value::Null => Ok(Composite::Unnamed(vec![])),
+            serde_json::Value::Array(a) if a.is_empty() => Ok(Composite::Unnamed(vec![])),
+            serde_json::Value::Object(o) if o.is_empty() => Ok(Composite::Unnamed(vec![])),
+            _ => Err(invalid(path, "expected no value")),
+        };
+    }
+
+    if fields.iter().all(|f| f.name.is_some()) {
+        let map = json
+            .as_object()
+            .ok_or_else(|| invalid(path, "expected an object"))?;
+        let mut values = Vec::with_capacity(fields.len());
+        for field in fields {
+            let name = field.name.clone().unwrap_or_default();
+            let camel = name.to_lower_camel_case();
+            let field_path = format!("{path}.{camel}");
+            let field_json = map
+                .get(&name)
+                .or_else(|| map.get(&camel))
+                .ok_or_else(|| invalid(&field_path, "missing field"))?;
+            values.push((
+                name,
+                json_to_value(field_json, field.ty.id, types, &field_path)?,
+            ));
+        }
+        if let Some(unexpected) = map.keys().find(|key| {
+            !fields.iter().any(|field| {
+                let name = field.name.as_deref().unwrap_or_default();
+                *key == name || **key == name.to_lower_camel_case()
+            })
+        }) {
+            return Err(invalid(path, format!("unexpected field '{unexpected}'")));
+        }
+        return Ok(Composite::Named(values));
+    }
+
+    let field_types: Vec<u32> = fields.iter().map(|f| f.ty.id).collect();
+    unnamed_fields(json, &field_types, types, path)
+}
+
+/// Convert unnamed fields; a single field takes the value directly rather than a one-element array.
+fn unnamed_fields(
+    json: &serde_json::Value,
+    field_types: &[u32],
+    types: &PortableRegistry,
+    path: &str,
+) -> Result<Composite<()>, CallArgsError> {
+    if let [single] = field_types {
+        return Ok(Composite::Unnamed(vec![json_to_value(
+            json, *single, types, path,
+        )?]));
+    }
+
+    let items = json.as_array().ok_or_else(|| {
+        invalid(
+            path,
+            format!("expected an array of {} elements", field_types.len()),
+        )
+    })?;
+    if items.len() != field_types.len() {
+        return Err(invalid(
+            path,
+            format!(
+                "expected {} elements, got {}",
+                field_types.len(),
+                items.len()
+            ),
+        ));
+    }
+    items
+        .iter()
+        .zip(field_types)
+        .enumerate()
+        .map(|(i, (item, ty))| json_to_value(item, *ty, types, &format!("{path}[{i}]")))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Composite::Unnamed)
+}
+
+fn elements_to_composite(
+    items: &[serde_json::Value],
+    type_id: u32,
+    types: &PortableRegistry,
+    path: &str,
+) -> Result<Value<()>, CallArgsError> {
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| json_to_value(item, type_id, types, &format!("{path}[{i}]")))
+        .collect::<Result<Vec<_>, _>>()
+        .map(|values| composite_value(Composite::Unnamed(values)))
+}
+
+fn is_u8(type_id: u32, types: &PortableRegistry) -> bool {
+    matches!(
+        types.resolve(type_id).map(|ty| &ty.type_def),
+        Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+    )
+}
+
+/// Bytes from `0x`-prefixed hex, or the UTF-8 bytes of any other text.
+fn text_to_bytes(text: &str, path: &str) -> Result<Vec<u8>, CallArgsError> {
+    match text.strip_prefix("0x") {
+        Some(hex_str) => {
+            hex::decode(hex_str).map_err(|e| invalid(path, format!("invalid hex: {e}")))
+        }
+        None => Ok(text.as_bytes().to_vec()),
+    }
+}
+
+/// Bytes of a fixed-size array from `0x`-prefixed hex, or from an SS58 address for 32-byte arrays.
+fn fixed_bytes(text: &str, len: usize, path: &str) -> Result<Vec<u8>, CallArgsError> {
+    let bytes = match text.strip_prefix("0x") {
+        Some(hex_str) => {
+            hex::decode(hex_str).map_err(|e| invalid(path, format!("invalid hex: {e}")))?
+        }
+        None if len == 32 => sp_core::crypto::AccountId32::from_ss58check(text)
+            .map(|account| <[u8; 32]>::from(account).to_vec())
+            .map_err(|e| invalid(path, format!("invalid SS58 address: {e:?}")))?,
+        None => {
+            return Err(invalid(
+                path,
+                format!("expected {len} bytes as 0x-prefixed hex"),
+            ));
+        }
+    };
+    if bytes.len() != len {
+        return Err(invalid(
+            path,
+            format!("expected {len} bytes, got {}", bytes.len()),
+        ));
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::{Compact, Decode, Encode};
+    use serde_json::json;
+
+    const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+
+    fn asset_hub_metadata() -> Metadata {
+        Metadata::decode(&mut &ASSET_HUB_METADATA[..]).expect("fixture metadata decodes")
+    }
+
+    fn encode_args(metadata: &Metadata, call: &ResolvedCall) -> Vec<u8> {
+        let variant = metadata
+            .pallet_by_name(&call.pallet)
+            .unwrap()
+            .call_variant_by_name(&call.method)
+            .unwrap();
+        let mut out = Vec::new();
+        for (field, value) in variant.fields.iter().zip(call.args.values()) {
+            scale_value::scale::encode_as_type(value, field.ty.id, metadata.types(), &mut out)
+                .unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn test_resolve_transfer_keep_alive() {
+        let metadata = asset_hub_metadata();
+        let args = json!({ "dest": { "Id": ALICE }, "value": "1000000000000" });
+
+        let call = resolve_call(&metadata, "balances", "transferKeepAlive", &args).unwrap();
+
+        assert_eq!(call.pallet, "Balances");
+        assert_eq!(call.method, "transfer_keep_alive");
+
+        let alice: [u8; 32] = sp_core::crypto::AccountId32::from_ss58check(ALICE)
+            .unwrap()
+            .into();
+        let mut expected = vec![0u8]; // MultiAddress::Id
+        expected.extend_from_slice(&alice);
+        Compact(1_000_000_000_000u128).encode_to(&mut expected);
+        assert_eq!(encode_args(&metadata, &call), expected);
+    }
+
+    #[test]
+    fn test_resolve_remark_bytes() {
+        let metadata = asset_hub_metadata();
+
+        let hex = resolve_call(
+            &metadata,
+            "System",
+            "remark",
+            &json!({ "remark": "0x0102" }),
+        );
+        let text = resolve_call(&metadata, "System", "remark", &json!({ "remark": "hi" }));
+
+        assert_eq!(encode_args(&metadata, &hex.unwrap()), vec![2u8 << 2, 1, 2]);
+        assert_eq!(
+            encode_args(&metadata, &text.unwrap()),
+            vec![2u8 << 2, b'h', b'i']
+        );
+    }
+
+    #[test]
+    fn test_resolve_call_rejects_bad_shapes() {
+        let metadata = asset_hub_metadata();
+        let resolve = |pallet: &str, method: &str, args: serde_json::Value| {
+            resolve_call(&metadata, pallet, method, &args).unwrap_err()
+        };
+
+        assert_eq!(
+            resolve("Nope", "remark", json!({})),
+            CallArgsError::PalletNotFound("Nope".to_string())
+        );
+        assert!(matches!(
+            resolve("System", "nope", json!({})),
+            CallArgsError::CallNotFound { .. }
+        ));
+        assert_eq!(
+            resolve("System", "remark", json!(["0x00"])),
+            CallArgsError::ArgsNotObject
+        );
+        assert_eq!(
+            resolve(
+                "Balances",
+                "transferKeepAlive",
+                json!({ "dest": { "Id": ALICE } })
+            ),
+            CallArgsError::MissingArg("value".to_string())
+        );
+        assert_eq!(
+            resolve("System", "remark", json!({ "remark": "0x", "extra": 1 })),
+            CallArgsError::UnexpectedArg("extra".to_string())
+        );
+
+        let err = resolve(
+            "Balances",
+            "transferKeepAlive",
+            json!({ "dest": { "Nope": ALICE }, "value": 1 }),
+        );
+        assert!(matches!(err, CallArgsError::InvalidArg { ref path, .. } if path == "dest"));
+
+        let err = resolve(
+            "Balances",
+            "transferKeepAlive",
+            json!({ "dest": { "Id": "0x01" }, "value": 1 }),
+        );
+        assert!(matches!(err, CallArgsError::InvalidArg { ref path, .. } if path == "dest.Id"));
+
+        let err = resolve(
+            "Balances",
+            "transferKeepAlive",
+            json!({ "dest": { "Id": ALICE }, "value": -1 }),
+        );
+        assert!(matches!(err, CallArgsError::InvalidArg { ref path, .. } if path == "value"));
+    }
+
+    #[test]
+    fn test_integer_args_are_range_checked() {
+        let metadata = asset_hub_metadata();
+        let create = |id: serde_json::Value| {
+            resolve_call(
+                &metadata,
+                "Assets",
+                "create",
+                &json!({ "id": id, "admin": { "Id": ALICE }, "minBalance": 1 }),
+            )
+        };
+
+        assert!(create(json!(u32::MAX)).is_ok());
+        let err = create(json!("4294967296")).unwrap_err();
+        assert_eq!(
+            err,
+            CallArgsError::InvalidArg {
+                path: "id".to_string(),
+                cause: "4294967296 is out of range for u32".to_string(),
+            }
+        );
+
+        let primitive = |json: serde_json::Value, primitive: TypeDefPrimitive| {
+            primitive_to_value(&json, &primitive, "arg")
+        };
+        assert!(primitive(json!(255), TypeDefPrimitive::U8).is_ok());
+        assert!(primitive(json!(300), TypeDefPrimitive::U8).is_err());
+        assert!(primitive(json!(-1), TypeDefPrimitive::U8).is_err());
+        assert!(primitive(json!(-128), TypeDefPrimitive::I8).is_ok());
+        assert!(primitive(json!(-129), TypeDefPrimitive::I8).is_err());
+        assert!(
+            primitive(
+                json!("170141183460469231731687303715884105727"),
+                TypeDefPrimitive::I128
+            )
+            .is_ok()
+        );
+    }
+}
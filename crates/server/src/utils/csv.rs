@@ -0,0 +1,92 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! CSV serialization for list responses.
+//!
+//! Endpoints opt in by implementing [`CsvTable`] for their response and rendering it
+//! through [`crate::extractors::ResponseFormat`], which picks CSV or JSON from the
+//! request's `Accept` header.
+
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+
+/// Media type served for CSV responses.
+pub const CSV_CONTENT_TYPE: &str = "text/csv; charset=utf-8";
+
+/// A response that can be flattened into CSV rows.
+pub trait CsvTable {
+    /// Column names, written as the first line.
+    const HEADER: &'static [&'static str];
+
+    /// One entry per row, in the same order as [`CsvTable::HEADER`].
+    fn rows(&self) -> Vec<Vec<String>>;
+}
+
+/// Render `table` as an RFC 4180 CSV document with a header line.
+pub fn to_csv<T: CsvTable>(table: &T) -> String {
+    let mut out = String::new();
+    write_record(&mut out, T::HEADER.iter().copied());
+    for row in table.rows() {
+        write_record(&mut out, row.iter().map(String::as_str));
+    }
+    out
+}
+
+/// Build a `text/csv` response from `table`.
+pub fn csv_response<T: CsvTable>(table: &T) -> Response {
+    ([(header::CONTENT_TYPE, CSV_CONTENT_TYPE)], to_csv(table)).into_response()
+}
+
+fn write_record<'a>(out: &mut String, fields: impl Iterator<Item = &'a str>) {
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            out.push('"');
+            out.push_str(&field.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(field);
+        }
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Table(Vec<Vec<String>>);
+
+    impl CsvTable for Table {
+        const HEADER: &'static [&'static str] = &["id", "note"];
+
+        fn rows(&self) -> Vec<Vec<String>> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_to_csv_writes_header_and_rows() {
+        let table = Table(vec![
+            vec!["1".to_string(), "plain".to_string()],
+            vec!["2".to_string(), String::new()],
+        ]);
+        assert_eq!(to_csv(&table), "id,note\r\n1,plain\r\n2,\r\n");
+    }
+
+    #[test]
+    fn test_to_csv_quotes_special_characters() {
+        let table = Table(vec![vec!["a,b".to_string(), "say \"hi\"\nbye".to_string()]]);
+        assert_eq!(
+            to_csv(&table),
+            "id,note\r\n\"a,b\",\"say \"\"hi\"\"\nbye\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_empty_table_is_header_only() {
+        assert_eq!(to_csv(&Table(vec![])), "id,note\r\n");
+    }
+}
@@ -3,7 +3,7 @@
 
 //! Extrinsic parsing utilities
 //!
-//! This module handles era/mortality extraction from Substrate extrinsics.
+//! This module handles era/mortality, nonce and tip extraction from Substrate extrinsics.
 //!
 //! ## Why Manual Parsing?
 //!
@@ -24,9 +24,11 @@
 //! ## Functions
 //!
 //! - [`extract_era_from_extrinsic_bytes`]: Main function to extract era from raw extrinsic bytes
+//! - [`nonce_and_tip_from_extensions`]: Find nonce and tip by the extensions' metadata types
 //! - [`decode_era_from_bytes`]: Low-level SCALE decoder for era bytes
 //! - [`parse_era_info`]: JSON parser for era from transaction extension data
 
+use scale_info::PortableRegistry;
 use serde::Serialize;
 use serde_json::Value;
 
@@ -153,8 +155,6 @@ pub fn decode_era_from_bytes(bytes: &[u8], offset: &mut usize) -> Option<EraInfo
 ///
 /// Era is the first field in the extra/SignedExtra section.
 pub fn extract_era_from_extrinsic_bytes(bytes: &[u8]) -> Option<EraInfo> {
-    use parity_scale_codec::Decode;
-
     if bytes.is_empty() {
         tracing::debug!("Cannot extract era: empty extrinsic bytes");
         return None;
@@ -171,7 +171,32 @@ pub fn extract_era_from_extrinsic_bytes(bytes: &[u8]) -> Option<EraInfo> {
         });
     }
 
-    // For signed extrinsics, manually parse SCALE encoding to find era
+    // For signed extrinsics, skip the address and signature to reach the extra section
+    let cursor = signed_extra_section(bytes)?;
+
+    // Now we're at the SignedExtra/TransactionExtensions section
+    // Era is the first field encoded here
+    tracing::trace!(
+        "Remaining bytes after address+signature: {} bytes, first few: {:?}",
+        cursor.len(),
+        &cursor[..cursor.len().min(10)]
+    );
+
+    let mut offset = 0;
+    let result = decode_era_from_bytes(cursor, &mut offset);
+
+    tracing::trace!("Era decode result: {:?}", result);
+
+    result
+}
+
+/// Skip the version byte, address and signature of a signed extrinsic.
+///
+/// Returns the remaining bytes, which start with the SignedExtra/TransactionExtensions
+/// section, or `None` if the bytes are malformed.
+fn signed_extra_section(bytes: &[u8]) -> Option<&[u8]> {
+    use parity_scale_codec::Decode;
+
     let mut cursor = &bytes[1..]; // Skip version byte
 
     // Decode and skip MultiAddress enum
@@ -289,20 +314,89 @@ pub fn extract_era_from_extrinsic_bytes(bytes: &[u8]) -> Option<EraInfo> {
         }
     }
 
-    // Now we're at the SignedExtra/TransactionExtensions section
-    // Era is the first field encoded here
-    tracing::trace!(
-        "Remaining bytes after address+signature: {} bytes, first few: {:?}",
-        cursor.len(),
-        &cursor[..cursor.len().min(10)]
-    );
+    Some(cursor)
+}
 
-    let mut offset = 0;
-    let result = decode_era_from_bytes(cursor, &mut offset);
+/// Find the nonce and tip among a signed extrinsic's transaction extensions
+///
+/// Each extension is decoded with its type from the metadata, and the nonce and tip
+/// are read from the values whose type is `CheckNonce`, `ChargeTransactionPayment`
+/// or `ChargeAssetTxPayment`. This also finds them when the runtime wraps those
+/// extensions in another one (e.g. `SkipCheckIfFeeless<ChargeAssetTxPayment>`).
+///
+/// `extensions` yields each extension's type id and encoded bytes. A field is `None`
+/// when no extension carries it or an extension fails to decode against the metadata.
+pub fn nonce_and_tip_from_extensions<'a>(
+    extensions: impl IntoIterator<Item = (u32, &'a [u8])>,
+    types: &PortableRegistry,
+) -> (Option<String>, Option<String>) {
+    let mut nonce = None;
+    let mut tip = None;
+
+    for (type_id, bytes) in extensions {
+        let Ok(value) = scale_value::scale::decode_as_type(&mut &bytes[..], type_id, types) else {
+            tracing::debug!("Failed to decode transaction extension type {}", type_id);
+            return (None, None);
+        };
+        find_nonce_and_tip(&value, types, &mut nonce, &mut tip);
+    }
 
-    tracing::trace!("Era decode result: {:?}", result);
+    (nonce, tip)
+}
 
-    result
+fn find_nonce_and_tip(
+    value: &scale_value::Value<u32>,
+    types: &PortableRegistry,
+    nonce: &mut Option<String>,
+    tip: &mut Option<String>,
+) {
+    use scale_value::ValueDef;
+
+    let ident = types.resolve(value.context).and_then(|ty| ty.path.ident());
+    match ident.as_deref() {
+        Some("CheckNonce") => {
+            *nonce = first_unsigned(value).map(|n| n.to_string());
+            return;
+        }
+        Some("ChargeTransactionPayment") => {
+            *tip = first_unsigned(value).map(|n| n.to_string());
+            return;
+        }
+        Some("ChargeAssetTxPayment") => {
+            *tip = match &value.value {
+                ValueDef::Composite(composite) => composite
+                    .values()
+                    .next()
+                    .and_then(first_unsigned)
+                    .map(|n| n.to_string()),
+                _ => None,
+            };
+            return;
+        }
+        _ => {}
+    }
+
+    match &value.value {
+        ValueDef::Composite(composite) => composite
+            .values()
+            .for_each(|inner| find_nonce_and_tip(inner, types, nonce, tip)),
+        ValueDef::Variant(variant) => variant
+            .values
+            .values()
+            .for_each(|inner| find_nonce_and_tip(inner, types, nonce, tip)),
+        _ => {}
+    }
+}
+
+/// The first unsigned integer in `value`, searching depth first
+fn first_unsigned(value: &scale_value::Value<u32>) -> Option<u128> {
+    use scale_value::{Primitive, ValueDef};
+
+    match &value.value {
+        ValueDef::Primitive(Primitive::U128(n)) => Some(*n),
+        ValueDef::Composite(composite) => composite.values().find_map(first_unsigned),
+        _ => None,
+    }
 }
 
 /// Parse era information from transaction extension JSON
@@ -619,4 +713,84 @@ mod tests {
         );
         assert_eq!(offset, 2, "Should consume both era bytes");
     }
+
+    mod extensions {
+        use parity_scale_codec::Encode;
+        use scale_info::TypeInfo;
+
+        #[derive(Encode, TypeInfo)]
+        pub struct CheckMortality(pub u8);
+
+        #[derive(Encode, TypeInfo)]
+        pub struct CheckNonce(#[codec(compact)] pub u32);
+
+        #[derive(Encode, TypeInfo)]
+        pub struct ChargeTransactionPayment(#[codec(compact)] pub u128);
+
+        #[derive(Encode, TypeInfo)]
+        pub struct ChargeAssetTxPayment {
+            #[codec(compact)]
+            pub tip: u128,
+            pub asset_id: Option<u32>,
+        }
+
+        #[derive(Encode, TypeInfo)]
+        pub struct SkipCheckIfFeeless(pub ChargeAssetTxPayment);
+    }
+
+    fn register<T: scale_info::TypeInfo + 'static>(registry: &mut scale_info::Registry) -> u32 {
+        registry.register_type(&scale_info::MetaType::new::<T>()).id
+    }
+
+    #[test]
+    fn test_nonce_and_tip_from_extensions() {
+        use extensions::*;
+        use parity_scale_codec::Encode;
+
+        let mut registry = scale_info::Registry::new();
+        let era_ty = register::<CheckMortality>(&mut registry);
+        let nonce_ty = register::<CheckNonce>(&mut registry);
+        let payment_ty = register::<ChargeTransactionPayment>(&mut registry);
+        let wrapped_ty = register::<SkipCheckIfFeeless>(&mut registry);
+        let types: PortableRegistry = registry.into();
+
+        let era = CheckMortality(0).encode();
+        let nonce = CheckNonce(29).encode();
+        let payment = ChargeTransactionPayment(1_000_000).encode();
+        let wrapped = SkipCheckIfFeeless(ChargeAssetTxPayment {
+            tip: 5,
+            asset_id: Some(1984),
+        })
+        .encode();
+
+        assert_eq!(
+            nonce_and_tip_from_extensions(
+                [
+                    (era_ty, &era[..]),
+                    (nonce_ty, &nonce),
+                    (payment_ty, &payment)
+                ],
+                &types
+            ),
+            (Some("29".to_string()), Some("1000000".to_string()))
+        );
+        // The tip is found inside a wrapping extension
+        assert_eq!(
+            nonce_and_tip_from_extensions([(nonce_ty, &nonce[..]), (wrapped_ty, &wrapped)], &types),
+            (Some("29".to_string()), Some("5".to_string()))
+        );
+        assert_eq!(
+            nonce_and_tip_from_extensions([(era_ty, &era[..])], &types),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn test_nonce_and_tip_from_extensions_unknown_type() {
+        let types: PortableRegistry = scale_info::Registry::new().into();
+        assert_eq!(
+            nonce_and_tip_from_extensions([(0, &[0x74][..])], &types),
+            (None, None)
+        );
+    }
 }
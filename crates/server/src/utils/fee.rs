@@ -8,11 +8,10 @@
 //! - `QueryFeeDetailsCache`: Tracks whether `payment_queryFeeDetails` is available per spec_version
 //! - `parse_fee_details` / `extract_estimated_weight`: RPC response parsing utilities
 
+use super::lru::LruCache;
 use polkadot_rest_api_config::ChainConfigs;
 use serde_json::Value;
 use sp_runtime::Perbill;
-use std::collections::HashMap;
-use std::sync::RwLock;
 use thiserror::Error;
 
 // ================================================================================================
@@ -286,23 +285,33 @@ pub fn calc_partial_fee_raw(
 // QueryFeeDetails Cache
 // ================================================================================================
 
+/// Default number of spec versions kept in the queryFeeDetails runtime cache.
+pub const DEFAULT_FEE_CACHE_CAPACITY: usize = 1024;
+
 /// Cache for tracking whether `payment_queryFeeDetails` is available at different spec versions.
 ///
 /// The cache uses a three-tier approach:
 /// 1. Static config check: Uses chain fee configs to determine availability without RPC
 /// 2. Runtime cache: Caches results from actual RPC calls
 /// 3. Default: Falls back to trying the RPC call if unknown
+///
+/// The runtime cache is bounded and evicts the least recently used spec version once full.
 pub struct QueryFeeDetailsCache {
     /// Cached results: spec_version -> is_available
-    cache: RwLock<HashMap<u32, bool>>,
+    cache: LruCache<u32, bool>,
     /// Chain configurations for static lookup
     chain_configs: ChainConfigs,
 }
 
 impl QueryFeeDetailsCache {
     pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_FEE_CACHE_CAPACITY)
+    }
+
+    /// Create a cache holding at most `capacity` runtime results (minimum 1).
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            cache: RwLock::new(HashMap::new()),
+            cache: LruCache::new("query_fee_details", capacity),
             chain_configs: ChainConfigs::default(),
         }
     }
@@ -326,15 +335,12 @@ impl QueryFeeDetailsCache {
         }
 
         // Check runtime cache
-        let cache = self.cache.read().ok()?;
-        cache.get(&spec_version).copied()
+        self.cache.get(&spec_version)
     }
 
     /// Record the result of a queryFeeDetails availability check
     pub fn set_available(&self, spec_version: u32, available: bool) {
-        if let Ok(mut cache) = self.cache.write() {
-            cache.insert(spec_version, available);
-        }
+        self.cache.insert(spec_version, available);
     }
 
     /// Check if fee calculation is supported for a given chain and spec version
@@ -492,6 +498,30 @@ pub fn calculate_accurate_fee(
     .map_err(FeeServiceError::from)
 }
 
+/// Fee details with `adjusted_weight_fee` scaled from the estimated to the actual weight,
+/// the same way [`calculate_accurate_fee`] does, so the components add up to the
+/// post-dispatch partial fee.
+pub fn post_dispatch_fee_details(
+    fee_details: &FeeDetails,
+    estimated_weight: &str,
+    actual_weight: &str,
+) -> Result<FeeDetails, FeeServiceError> {
+    // With zero base and length fees, the partial fee is just the scaled weight fee
+    let adjusted_weight_fee = calc_partial_fee(
+        "0",
+        "0",
+        &fee_details.adjusted_weight_fee,
+        estimated_weight,
+        actual_weight,
+    )?;
+
+    Ok(FeeDetails {
+        base_fee: fee_details.base_fee.clone(),
+        len_fee: fee_details.len_fee.clone(),
+        adjusted_weight_fee,
+    })
+}
+
 // ================================================================================================
 // Tests
 // ================================================================================================
@@ -639,6 +669,25 @@ mod tests {
         assert_eq!(cache.is_available("statemine", 1000), Some(false));
     }
 
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let capacity = 4;
+        let cache = QueryFeeDetailsCache::with_capacity(capacity);
+
+        for spec_version in 0..=capacity as u32 {
+            cache.set_available(spec_version, true);
+        }
+
+        // The oldest entry is evicted, the rest are kept
+        assert_eq!(cache.is_available("unknown-chain", 0), None);
+        for spec_version in 1..=capacity as u32 {
+            assert_eq!(
+                cache.is_available("unknown-chain", spec_version),
+                Some(true)
+            );
+        }
+    }
+
     #[test]
     fn test_supports_fee_calculation() {
         let cache = QueryFeeDetailsCache::new();
@@ -752,4 +801,29 @@ mod tests {
         // 100 + 50 + (1000 * 500/1000) = 100 + 50 + 500 = 650
         assert_eq!(fee, "650");
     }
+
+    #[test]
+    fn test_post_dispatch_fee_details_sum_to_accurate_fee() {
+        let fee_details = FeeDetails {
+            base_fee: "100".to_string(),
+            len_fee: "50".to_string(),
+            adjusted_weight_fee: "1000".to_string(),
+        };
+
+        let adjusted = post_dispatch_fee_details(&fee_details, "500", "1000").unwrap();
+        assert_eq!(adjusted.base_fee, "100");
+        assert_eq!(adjusted.len_fee, "50");
+        assert_eq!(adjusted.adjusted_weight_fee, "500");
+
+        let total: u128 = [
+            &adjusted.base_fee,
+            &adjusted.len_fee,
+            &adjusted.adjusted_weight_fee,
+        ]
+        .iter()
+        .map(|fee| fee.parse::<u128>().unwrap())
+        .sum();
+        let accurate = calculate_accurate_fee(&fee_details, "500", "1000").unwrap();
+        assert_eq!(total.to_string(), accurate);
+    }
 }
@@ -0,0 +1,342 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Shared finalized head subscription for the streaming endpoints.
+//!
+//! `chain_subscribeFinalizedHeads` only reports the latest head when several blocks are
+//! finalized at once, so [`FinalizedHeads`] fills in the skipped block numbers. When the
+//! RPC connection drops, the subscription is re-established transparently.
+//!
+//! [`FinalizedHeadsHub`] runs a single [`FinalizedHeads`] subscription and broadcasts the
+//! block numbers to every connected client, regardless of transport. The subscription is
+//! opened for the first client and closed once the last one disconnects.
+
+use crate::utils::{is_disconnected_error, parse_block_number_from_json};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use subxt_rpcs::client::RpcSubscription;
+use subxt_rpcs::{RpcClient, rpc_params};
+use thiserror::Error;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore, broadcast};
+
+/// Maximum number of skipped block numbers back-filled after a gap.
+///
+/// Longer gaps (e.g. after a reconnect) only emit the latest head.
+const MAX_HEAD_GAP: u64 = 32;
+
+/// Number of heads buffered per client before slow clients start skipping blocks.
+const HEAD_CHANNEL_CAPACITY: usize = 64;
+
+const RESUBSCRIBE_INITIAL_DELAY: Duration = Duration::from_millis(500);
+const RESUBSCRIBE_MAX_DELAY: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Error)]
+pub enum FinalizedHeadsError {
+    #[error("Too many active block subscriptions, try again later")]
+    TooManySubscriptions,
+
+    #[error("Failed to subscribe to finalized heads")]
+    SubscribeFailed(#[source] subxt_rpcs::Error),
+}
+
+// ================================================================================================
+// Finalized Head Stream
+// ================================================================================================
+
+/// A `chain_subscribeFinalizedHeads` subscription yielding consecutive block numbers.
+pub struct FinalizedHeads {
+    rpc_client: Arc<RpcClient>,
+    subscription: Option<RpcSubscription<Value>>,
+    last_number: Option<u64>,
+    pending: VecDeque<u64>,
+}
+
+impl FinalizedHeads {
+    /// Open a new finalized head subscription.
+    pub async fn subscribe(rpc_client: Arc<RpcClient>) -> Result<Self, subxt_rpcs::Error> {
+        let subscription = open_subscription(&rpc_client).await?;
+
+        Ok(Self {
+            rpc_client,
+            subscription: Some(subscription),
+            last_number: None,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Wait for the next finalized block number.
+    ///
+    /// Resubscribes with backoff if the RPC connection drops, so this only returns heads.
+    pub async fn next(&mut self) -> u64 {
+        loop {
+            if let Some(number) = self.pending.pop_front() {
+                return number;
+            }
+
+            let Some(subscription) = self.subscription.as_mut() else {
+                self.resubscribe().await;
+                continue;
+            };
+
+            match subscription.next().await {
+                Some(Ok(header)) => match header.get("number").map(parse_block_number_from_json) {
+                    Some(Ok(number)) => {
+                        enqueue_heads(&mut self.pending, self.last_number, number);
+                        if let Some(&latest) = self.pending.back() {
+                            self.last_number = Some(latest);
+                        }
+                    }
+                    _ => tracing::warn!("Finalized head without a valid block number: {header}"),
+                },
+                Some(Err(e)) if is_disconnected_error(&e) => {
+                    tracing::debug!("Finalized head subscription disconnected, resubscribing");
+                    self.subscription = None;
+                }
+                Some(Err(e)) => {
+                    tracing::warn!("Finalized head subscription failed: {e}, resubscribing");
+                    self.subscription = None;
+                }
+                None => {
+                    tracing::debug!("Finalized head subscription closed, resubscribing");
+                    self.subscription = None;
+                }
+            }
+        }
+    }
+
+    async fn resubscribe(&mut self) {
+        let mut delay = RESUBSCRIBE_INITIAL_DELAY;
+        loop {
+            // Back off before every attempt so a subscription that keeps closing
+            // immediately does not spin
+            tokio::time::sleep(delay).await;
+            match open_subscription(&self.rpc_client).await {
+                Ok(subscription) => {
+                    self.subscription = Some(subscription);
+                    return;
+                }
+                Err(e) => {
+                    tracing::debug!("Failed to resubscribe to finalized heads: {e}");
+                    delay = (delay * 2).min(RESUBSCRIBE_MAX_DELAY);
+                }
+            }
+        }
+    }
+}
+
+async fn open_subscription(
+    rpc_client: &RpcClient,
+) -> Result<RpcSubscription<Value>, subxt_rpcs::Error> {
+    rpc_client
+        .subscribe(
+            "chain_subscribeFinalizedHeads",
+            rpc_params![],
+            "chain_unsubscribeFinalizedHeads",
+        )
+        .await
+}
+
+/// Queue the block numbers to emit for a newly finalized head.
+///
+/// Heads at or below the last emitted number are ignored. Small gaps are back-filled so
+/// clients see every finalized block; larger ones only emit the new head.
+fn enqueue_heads(pending: &mut VecDeque<u64>, last_number: Option<u64>, number: u64) {
+    match last_number {
+        Some(last) if number <= last => {}
+        Some(last) if number - last <= MAX_HEAD_GAP => pending.extend(last + 1..=number),
+        _ => pending.push_back(number),
+    }
+}
+
+// ================================================================================================
+// Fan-out
+// ================================================================================================
+
+type HeadSender = Arc<Mutex<Option<broadcast::Sender<u64>>>>;
+
+/// Fans a single finalized head subscription out to all streaming clients.
+///
+/// Also bounds the number of concurrently connected clients.
+pub struct FinalizedHeadsHub {
+    permits: Arc<Semaphore>,
+    sender: HeadSender,
+}
+
+impl FinalizedHeadsHub {
+    pub fn new(max_subscribers: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_subscribers)),
+            sender: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Register a new client, opening the shared RPC subscription if none is running.
+    pub async fn subscribe(
+        &self,
+        rpc_client: &Arc<RpcClient>,
+    ) -> Result<HeadSubscription, FinalizedHeadsError> {
+        let permit = self
+            .permits
+            .clone()
+            .try_acquire_owned()
+            .map_err(|_| FinalizedHeadsError::TooManySubscriptions)?;
+
+        let mut sender = self.sender.lock().await;
+        if let Some(tx) = sender.as_ref() {
+            return Ok(HeadSubscription {
+                receiver: tx.subscribe(),
+                _permit: permit,
+            });
+        }
+
+        // Subscribe up front so RPC failures reach the client that triggered them
+        let heads = FinalizedHeads::subscribe(rpc_client.clone())
+            .await
+            .map_err(FinalizedHeadsError::SubscribeFailed)?;
+        let (tx, receiver) = broadcast::channel(HEAD_CHANNEL_CAPACITY);
+        *sender = Some(tx.clone());
+        tokio::spawn(forward_heads(heads, tx, self.sender.clone()));
+
+        Ok(HeadSubscription {
+            receiver,
+            _permit: permit,
+        })
+    }
+}
+
+/// Forward heads to all receivers until the last one is dropped.
+async fn forward_heads(mut heads: FinalizedHeads, tx: broadcast::Sender<u64>, slot: HeadSender) {
+    loop {
+        let number = heads.next().await;
+        if tx.send(number).is_err() {
+            // Re-check under the lock, a client may have subscribed in the meantime
+            let mut sender = slot.lock().await;
+            if tx.receiver_count() == 0 {
+                *sender = None;
+                tracing::debug!("No block subscribers left, closing finalized head subscription");
+                return;
+            }
+        }
+    }
+}
+
+/// A client's view of the shared finalized head stream.
+///
+/// Holds one of the hub's subscriber permits until dropped.
+pub struct HeadSubscription {
+    receiver: broadcast::Receiver<u64>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl HeadSubscription {
+    /// Wait for the next finalized block number, or `None` if the stream has ended.
+    ///
+    /// Clients that fall behind skip the heads they missed rather than blocking others.
+    pub async fn next(&mut self) -> Option<u64> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(number) => return Some(number),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Block subscriber lagged behind, skipped {skipped} heads");
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use subxt_rpcs::client::MockRpcClient;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+    use tokio::sync::mpsc;
+
+    fn enqueued(last_number: Option<u64>, number: u64) -> Vec<u64> {
+        let mut pending = VecDeque::new();
+        enqueue_heads(&mut pending, last_number, number);
+        pending.into_iter().collect()
+    }
+
+    #[test]
+    fn test_enqueue_heads_first_head() {
+        assert_eq!(enqueued(None, 100), vec![100]);
+    }
+
+    #[test]
+    fn test_enqueue_heads_backfills_small_gaps() {
+        assert_eq!(enqueued(Some(100), 101), vec![101]);
+        assert_eq!(enqueued(Some(100), 103), vec![101, 102, 103]);
+    }
+
+    #[test]
+    fn test_enqueue_heads_skips_large_gaps() {
+        assert_eq!(enqueued(Some(100), 100 + MAX_HEAD_GAP + 1), vec![133]);
+    }
+
+    #[test]
+    fn test_enqueue_heads_ignores_stale_heads() {
+        assert!(enqueued(Some(100), 100).is_empty());
+        assert!(enqueued(Some(100), 99).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_hub_shares_one_rpc_subscription() {
+        let opened = Arc::new(AtomicUsize::new(0));
+        let (heads_tx, heads_rx) = mpsc::unbounded_channel();
+        let mut heads_rx = Some(heads_rx);
+
+        let opened_in_handler = opened.clone();
+        let mock_client = MockRpcClient::builder()
+            .subscription_handler("chain_subscribeFinalizedHeads", move |_, _| {
+                opened_in_handler.fetch_add(1, Ordering::SeqCst);
+                let rx = heads_rx.take();
+                async move { rx }
+            })
+            .build();
+        let rpc_client = Arc::new(RpcClient::new(mock_client));
+
+        let hub = FinalizedHeadsHub::new(2);
+        let mut first = hub.subscribe(&rpc_client).await.unwrap();
+        let mut second = hub.subscribe(&rpc_client).await.unwrap();
+        assert_eq!(opened.load(Ordering::SeqCst), 1);
+
+        assert!(matches!(
+            hub.subscribe(&rpc_client).await,
+            Err(FinalizedHeadsError::TooManySubscriptions)
+        ));
+
+        heads_tx
+            .send(MockJson(serde_json::json!({ "number": "0x10" })))
+            .unwrap();
+        heads_tx
+            .send(MockJson(serde_json::json!({ "number": "0x12" })))
+            .unwrap();
+
+        for subscription in [&mut first, &mut second] {
+            assert_eq!(subscription.next().await, Some(16));
+            assert_eq!(subscription.next().await, Some(17));
+            assert_eq!(subscription.next().await, Some(18));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hub_releases_permits_on_drop() {
+        let mock_client = MockRpcClient::builder()
+            .subscription_handler("chain_subscribeFinalizedHeads", |_, _| async {
+                Vec::<MockJson<Value>>::new()
+            })
+            .build();
+        let rpc_client = Arc::new(RpcClient::new(mock_client));
+
+        let hub = FinalizedHeadsHub::new(1);
+        let subscription = hub.subscribe(&rpc_client).await.unwrap();
+        assert!(hub.subscribe(&rpc_client).await.is_err());
+
+        drop(subscription);
+        assert!(hub.subscribe(&rpc_client).await.is_ok());
+    }
+}
@@ -22,6 +22,30 @@ pub fn lowercase_first_char(s: &str) -> String {
     }
 }
 
+/// Highest SS58 address format that fits in the 14-bit prefix
+pub const MAX_SS58_FORMAT: u16 = 16_383;
+
+/// The `ss58Format` query parameter is outside the encodable range
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid `ss58Format` {0}: expected a network prefix between 0 and {MAX_SS58_FORMAT}")]
+pub struct InvalidSs58Format(pub u16);
+
+/// SS58 prefix to encode the accounts in a response with.
+///
+/// Endpoints accepting an `ss58Format` query parameter re-encode the accounts they
+/// return with it (e.g. the generic `42` form on any chain); otherwise the chain's
+/// own prefix is used.
+pub fn response_ss58_prefix(
+    requested: Option<u16>,
+    chain_prefix: u16,
+) -> Result<u16, InvalidSs58Format> {
+    match requested {
+        Some(prefix) if prefix > MAX_SS58_FORMAT => Err(InvalidSs58Format(prefix)),
+        Some(prefix) => Ok(prefix),
+        None => Ok(chain_prefix),
+    }
+}
+
 /// Decode account address bytes to SS58 format
 /// Tries to decode:
 /// 1. MultiAddress::Id variant (0x00 + 32 bytes)
@@ -98,3 +122,32 @@ pub fn to_camel_case(s: &str) -> String {
         Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_ss58_prefix() {
+        assert_eq!(response_ss58_prefix(None, 0).unwrap(), 0);
+        assert_eq!(response_ss58_prefix(Some(42), 0).unwrap(), 42);
+        assert_eq!(
+            response_ss58_prefix(Some(MAX_SS58_FORMAT), 0).unwrap(),
+            MAX_SS58_FORMAT
+        );
+        assert!(response_ss58_prefix(Some(MAX_SS58_FORMAT + 1), 0).is_err());
+    }
+
+    #[test]
+    fn test_decode_address_to_ss58_with_requested_prefix() {
+        let hex_account = format!("0x{}", "d4".repeat(32));
+        let polkadot = decode_address_to_ss58(&hex_account, 0).unwrap();
+        let generic = decode_address_to_ss58(&hex_account, 42).unwrap();
+
+        assert_ne!(polkadot, generic);
+        assert_eq!(
+            AccountId32::from_ss58check(&polkadot).unwrap(),
+            AccountId32::from_ss58check(&generic).unwrap()
+        );
+    }
+}
@@ -38,6 +38,15 @@ impl HasherExt for Hasher {
     }
 }
 
+/// Blake2b-256 hash of `data`, as a `0x`-prefixed hex string.
+///
+/// Used both for a runtime Wasm blob's `codeHash`, as referred to by `system.setCode`
+/// and runtime upgrade tooling, and for an encoded extrinsic's transaction hash, as
+/// reported by `author_submitExtrinsic` and indexed by block explorers.
+pub fn blake2_256_hex(data: &[u8]) -> String {
+    format!("0x{}", hex::encode(sp_core::blake2_256(data)))
+}
+
 /// Compute the block hash from header JSON fields
 ///
 /// This reconstructs the SCALE-encoded header and hashes it with Blake2b-256,
@@ -298,6 +307,26 @@ mod tests {
         assert_eq!(hash_str.len(), 66); // "0x" + 64 hex chars
     }
 
+    #[test]
+    fn test_blake2_256_hex() {
+        assert_eq!(
+            blake2_256_hex(b""),
+            "0x0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_eq!(blake2_256_hex(b"\0asm").len(), 66);
+    }
+
+    #[test]
+    fn test_blake2_256_hex_matches_block_extrinsic_hash() {
+        use sp_runtime::traits::{BlakeTwo256, Hash};
+
+        // A signed-looking payload; the hash only depends on the raw bytes
+        let extrinsic = hex::decode("2d028400d43593c715fdd31c61141abd04a99fd6822c8558").unwrap();
+        let expected = format!("0x{}", hex::encode(BlakeTwo256::hash(&extrinsic)));
+
+        assert_eq!(blake2_256_hex(&extrinsic), expected);
+    }
+
     #[test]
     fn test_hasher_blake2_256() {
         use polkadot_rest_api_config::Hasher;
@@ -0,0 +1,103 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Conditional GET helpers.
+//!
+//! Cacheable responses carry an `ETag` and a `Cache-Control`, and a matching
+//! `If-None-Match` is answered with `304 Not Modified`.
+
+use axum::{
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+
+/// `Cache-Control` for responses that never change
+pub const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// `Cache-Control` for responses that may change, letting caches keep them as
+/// long as they revalidate with `If-None-Match`
+pub const REVALIDATE_CACHE_CONTROL: &str = "public, no-cache";
+
+/// Build a strong entity tag from `value`
+pub fn etag(value: &str) -> String {
+    format!("\"{value}\"")
+}
+
+/// Whether the request's `If-None-Match` header matches `etag`.
+///
+/// Accepts `*` and comma-separated lists. Weak tags (`W/"..."`) match their
+/// strong counterpart, as required for `If-None-Match`.
+pub fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|candidate| {
+            candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+        })
+}
+
+/// Add `ETag` and `Cache-Control` headers to a response
+pub fn with_cache_headers(mut response: Response, etag: &str, cache_control: &str) -> Response {
+    let headers = response.headers_mut();
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        headers.insert(header::ETAG, value);
+    }
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_str(cache_control)
+            .unwrap_or(HeaderValue::from_static(IMMUTABLE_CACHE_CONTROL)),
+    );
+    response
+}
+
+/// Empty `304 Not Modified` response carrying the cache headers
+pub fn not_modified(etag: &str, cache_control: &str) -> Response {
+    with_cache_headers(
+        StatusCode::NOT_MODIFIED.into_response(),
+        etag,
+        cache_control,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(if_none_match: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::IF_NONE_MATCH,
+            HeaderValue::from_str(if_none_match).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_if_none_match() {
+        let tag = etag("0xabc");
+        assert_eq!(tag, "\"0xabc\"");
+
+        assert!(if_none_match(&headers("\"0xabc\""), &tag));
+        assert!(if_none_match(&headers("W/\"0xabc\""), &tag));
+        assert!(if_none_match(&headers("\"0x1\", \"0xabc\""), &tag));
+        assert!(if_none_match(&headers("*"), &tag));
+        assert!(!if_none_match(&headers("\"0x1\""), &tag));
+        assert!(!if_none_match(&headers("0xabc"), &tag));
+        assert!(!if_none_match(&HeaderMap::new(), &tag));
+    }
+
+    #[test]
+    fn test_not_modified_has_cache_headers() {
+        let response = not_modified("\"0xabc\"", IMMUTABLE_CACHE_CONTROL);
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], "\"0xabc\"");
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            IMMUTABLE_CACHE_CONTROL
+        );
+    }
+}
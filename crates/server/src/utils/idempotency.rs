@@ -0,0 +1,228 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Responses remembered by `Idempotency-Key` for transaction submission.
+//!
+//! A client retrying a submission after a timeout can't tell whether the first
+//! attempt reached the node. Replaying the stored response for a retried key
+//! avoids submitting the transaction twice.
+
+use super::lru::LruCache;
+use axum::http::StatusCode;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
+
+/// How long a submission's response is kept for its idempotency key.
+pub const IDEMPOTENCY_KEY_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Maximum number of idempotency keys kept; the least recently used are evicted first.
+pub const DEFAULT_IDEMPOTENCY_CACHE_CAPACITY: usize = 10_000;
+
+/// A response stored for replay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedResponse {
+    pub status: StatusCode,
+    pub body: serde_json::Value,
+}
+
+/// Result of looking up an idempotency key.
+#[derive(Debug)]
+pub enum IdempotencyLookup {
+    /// Key not seen within the TTL: it is now reserved for this request, which should
+    /// be processed and its response handed to the reservation
+    Miss(Reservation),
+    /// Key seen with the same request: replay its response
+    Hit(CachedResponse),
+    /// Key reserved by the same request, which is still being processed
+    Pending(PendingResponse),
+    /// Key seen with a different request
+    Mismatch,
+}
+
+/// Claim on an idempotency key by the request processing it.
+///
+/// Dropping it without calling [`Reservation::complete`] releases the key, so the
+/// next request with it is processed again.
+#[derive(Debug)]
+pub struct Reservation {
+    response: watch::Sender<Option<CachedResponse>>,
+}
+
+impl Reservation {
+    /// Store the response, replaying it to requests waiting on the key and to later retries.
+    pub fn complete(self, response: CachedResponse) {
+        self.response.send_replace(Some(response));
+    }
+}
+
+/// Response of a request still being processed under the same key.
+#[derive(Debug)]
+pub struct PendingResponse {
+    response: watch::Receiver<Option<CachedResponse>>,
+}
+
+impl PendingResponse {
+    /// Wait for the response, or `None` if the reservation was released without one.
+    pub async fn wait(mut self) -> Option<CachedResponse> {
+        self.response
+            .wait_for(Option::is_some)
+            .await
+            .ok()
+            .and_then(|response| response.clone())
+    }
+}
+
+#[derive(Clone)]
+struct Entry {
+    stored_at: Instant,
+    /// Identifies the request the key was first used with
+    request: String,
+    /// `None` until the reserving request completes
+    response: watch::Receiver<Option<CachedResponse>>,
+}
+
+impl Entry {
+    /// Whether the reserving request was dropped without storing a response.
+    fn is_released(&self) -> bool {
+        self.response.borrow().is_none() && self.response.has_changed().is_err()
+    }
+}
+
+/// Bounded map from idempotency key to the response it produced, expiring after a TTL.
+pub struct IdempotencyCache {
+    inner: LruCache<String, Entry>,
+    ttl: Duration,
+}
+
+impl IdempotencyCache {
+    pub fn new() -> Self {
+        Self::with_ttl(DEFAULT_IDEMPOTENCY_CACHE_CAPACITY, IDEMPOTENCY_KEY_TTL)
+    }
+
+    pub fn with_ttl(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner: LruCache::new("idempotency_keys", capacity),
+            ttl,
+        }
+    }
+
+    /// Look up `key`, checking that it was first used for the same `request`, and
+    /// reserve it for `request` if it is unused.
+    ///
+    /// Checking and reserving is atomic, so of several concurrent requests with the
+    /// same key only one gets [`IdempotencyLookup::Miss`].
+    pub fn reserve(&self, key: &str, request: &str) -> IdempotencyLookup {
+        let (sender, receiver) = watch::channel(None);
+        let existing = self.inner.get_or_insert_with(
+            key.to_string(),
+            |entry| entry.stored_at.elapsed() >= self.ttl || entry.is_released(),
+            || Entry {
+                stored_at: Instant::now(),
+                request: request.to_string(),
+                response: receiver,
+            },
+        );
+
+        let Some(entry) = existing else {
+            return IdempotencyLookup::Miss(Reservation { response: sender });
+        };
+        if entry.request != request {
+            return IdempotencyLookup::Mismatch;
+        }
+        let response = entry.response.borrow().clone();
+        match response {
+            Some(response) => IdempotencyLookup::Hit(response),
+            None => IdempotencyLookup::Pending(PendingResponse {
+                response: entry.response,
+            }),
+        }
+    }
+}
+
+impl Default for IdempotencyCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn response(hash: &str) -> CachedResponse {
+        CachedResponse {
+            status: StatusCode::OK,
+            body: json!({ "hash": hash }),
+        }
+    }
+
+    fn reserve(cache: &IdempotencyCache, key: &str, request: &str) -> Reservation {
+        match cache.reserve(key, request) {
+            IdempotencyLookup::Miss(reservation) => reservation,
+            other => panic!("expected a miss, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_replays_response_for_same_request() {
+        let cache = IdempotencyCache::new();
+        reserve(&cache, "key", "0x01").complete(response("0xaa"));
+
+        assert!(matches!(
+            cache.reserve("key", "0x01"),
+            IdempotencyLookup::Hit(cached) if cached == response("0xaa")
+        ));
+        assert!(matches!(
+            cache.reserve("other", "0x01"),
+            IdempotencyLookup::Miss(_)
+        ));
+    }
+
+    #[test]
+    fn test_reused_key_with_different_request_is_mismatch() {
+        let cache = IdempotencyCache::new();
+        reserve(&cache, "key", "0x01").complete(response("0xaa"));
+
+        assert!(matches!(
+            cache.reserve("key", "0x02"),
+            IdempotencyLookup::Mismatch
+        ));
+    }
+
+    #[test]
+    fn test_entries_expire_after_ttl() {
+        let cache = IdempotencyCache::with_ttl(10, Duration::ZERO);
+        reserve(&cache, "key", "0x01").complete(response("0xaa"));
+
+        // An expired key can be used again, even for another request
+        reserve(&cache, "key", "0x02");
+    }
+
+    #[tokio::test]
+    async fn test_reserved_key_is_pending_until_completed() {
+        let cache = IdempotencyCache::new();
+        let reservation = reserve(&cache, "key", "0x01");
+
+        let IdempotencyLookup::Pending(pending) = cache.reserve("key", "0x01") else {
+            panic!("expected the key to be pending");
+        };
+        reservation.complete(response("0xaa"));
+
+        assert_eq!(pending.wait().await, Some(response("0xaa")));
+    }
+
+    #[tokio::test]
+    async fn test_released_reservation_frees_the_key() {
+        let cache = IdempotencyCache::new();
+        let reservation = reserve(&cache, "key", "0x01");
+
+        let IdempotencyLookup::Pending(pending) = cache.reserve("key", "0x01") else {
+            panic!("expected the key to be pending");
+        };
+        drop(reservation);
+
+        assert_eq!(pending.wait().await, None);
+        reserve(&cache, "key", "0x01");
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Small thread-safe LRU cache shared by the in-memory caches.
+//!
+//! Lookups, misses and evictions are recorded in the cache metrics under the
+//! cache's name.
+
+use crate::metrics::registry::{CACHE_EVICTIONS, CACHE_HITS, CACHE_MISSES};
+use std::hash::Hash;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// Bounded cache evicting the least recently used entry once full.
+pub struct LruCache<K, V> {
+    /// `cache` label used in the cache metrics
+    name: &'static str,
+    inner: Mutex<lru::LruCache<K, V>>,
+}
+
+impl<K: Eq + Hash, V: Clone> LruCache<K, V> {
+    /// Create a cache holding at most `capacity` entries (minimum 1).
+    pub fn new(name: &'static str, capacity: usize) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            name,
+            inner: Mutex::new(lru::LruCache::new(capacity)),
+        }
+    }
+
+    /// Look up an entry, marking it as most recently used.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut inner = self.inner.lock().ok()?;
+        let Some(value) = inner.get(key).cloned() else {
+            CACHE_MISSES.with_label_values(&[self.name]).inc();
+            return None;
+        };
+        CACHE_HITS.with_label_values(&[self.name]).inc();
+        Some(value)
+    }
+
+    /// Insert an entry, evicting the least recently used one if full.
+    pub fn insert(&self, key: K, value: V) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        self.insert_locked(&mut inner, key, value);
+    }
+
+    /// Look up an entry, inserting `make()` instead if it is missing or `is_stale`.
+    ///
+    /// The lookup and the insert happen under one lock, so of several concurrent
+    /// callers only one inserts. Returns the existing entry, or `None` if `make()`
+    /// was inserted.
+    pub fn get_or_insert_with(
+        &self,
+        key: K,
+        is_stale: impl FnOnce(&V) -> bool,
+        make: impl FnOnce() -> V,
+    ) -> Option<V> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(value) = inner.get(&key).filter(|v| !is_stale(v)).cloned() {
+            CACHE_HITS.with_label_values(&[self.name]).inc();
+            return Some(value);
+        }
+
+        CACHE_MISSES.with_label_values(&[self.name]).inc();
+        self.insert_locked(&mut inner, key, make());
+        None
+    }
+
+    fn insert_locked(&self, inner: &mut lru::LruCache<K, V>, key: K, value: V) {
+        let replaced = inner.contains(&key);
+        if inner.push(key, value).is_some() && !replaced {
+            CACHE_EVICTIONS.with_label_values(&[self.name]).inc();
+        }
+    }
+
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.inner.lock().map(|inner| inner.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LruCache::new("test", 2);
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        assert_eq!(cache.get(&1), Some("a"));
+
+        cache.insert(3, "c");
+
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some("a"));
+        assert_eq!(cache.get(&3), Some("c"));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_get_or_insert_with_replaces_stale_entry() {
+        let cache = LruCache::new("test", 2);
+        assert_eq!(cache.get_or_insert_with(1, |_| false, || 10), None);
+        assert_eq!(cache.get_or_insert_with(1, |_| false, || 20), Some(10));
+        assert_eq!(cache.get_or_insert_with(1, |v| *v == 10, || 30), None);
+        assert_eq!(cache.get(&1), Some(30));
+        assert_eq!(cache.len(), 1);
+    }
+}
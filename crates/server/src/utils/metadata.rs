@@ -0,0 +1,248 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Runtime metadata fetching with a bounded per-block cache.
+//!
+//! Fetching metadata requires a `state_getMetadata` RPC call followed by a full
+//! SCALE decode, which is expensive for large runtimes. Since metadata only
+//! changes on runtime upgrades, metadata is cached by block hash in a small LRU
+//! cache so bursts of requests against the same block only pay the cost once.
+//!
+//! Each entry keeps the hex returned by `state_getMetadata` and decodes it on
+//! first use, so endpoints serving the encoded metadata never pay for a decode.
+//!
+//! Handlers working with subxt's `client_at_block.metadata()` don't go through
+//! this cache: subxt already caches its decoded metadata per spec version.
+
+use super::lru::LruCache;
+use frame_metadata::RuntimeMetadataPrefixed;
+use parity_scale_codec::Decode;
+use std::sync::{Arc, OnceLock};
+use subxt_rpcs::{RpcClient, rpc_params};
+use thiserror::Error;
+
+/// Default number of decoded metadata entries kept in the cache.
+pub const DEFAULT_METADATA_CACHE_CAPACITY: usize = 32;
+
+/// `cache` label of this cache in the cache metrics.
+const CACHE_LABEL: &str = "runtime_metadata";
+
+#[derive(Debug, Error)]
+pub enum MetadataFetchError {
+    #[error("Failed to get metadata from RPC")]
+    RpcFailed(#[source] subxt_rpcs::Error),
+
+    #[error("Failed to decode metadata hex")]
+    HexDecodeFailed(#[source] hex::FromHexError),
+
+    #[error("Failed to decode metadata SCALE")]
+    ScaleDecodeFailed(#[source] parity_scale_codec::Error),
+}
+
+/// Hex-encoded `RuntimeMetadataPrefixed`, decoded on first use.
+#[derive(Debug)]
+pub struct CachedMetadata {
+    hex: String,
+    decoded: OnceLock<RuntimeMetadataPrefixed>,
+}
+
+impl CachedMetadata {
+    pub fn new(hex: String) -> Self {
+        Self {
+            hex,
+            decoded: OnceLock::new(),
+        }
+    }
+
+    /// The metadata as returned by `state_getMetadata`
+    pub fn hex(&self) -> &str {
+        &self.hex
+    }
+
+    /// The decoded metadata, decoding the hex the first time it's needed.
+    pub fn decoded(&self) -> Result<&RuntimeMetadataPrefixed, MetadataFetchError> {
+        if let Some(decoded) = self.decoded.get() {
+            return Ok(decoded);
+        }
+        let bytes = decode_metadata_hex(&self.hex)?;
+        let decoded = RuntimeMetadataPrefixed::decode(&mut &bytes[..])
+            .map_err(MetadataFetchError::ScaleDecodeFailed)?;
+        // A concurrent caller may have decoded it first; both results are equal
+        Ok(self.decoded.get_or_init(|| decoded))
+    }
+}
+
+/// LRU cache of runtime metadata keyed by block hash.
+pub struct RuntimeMetadataCache {
+    inner: LruCache<String, Arc<CachedMetadata>>,
+}
+
+impl RuntimeMetadataCache {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_METADATA_CACHE_CAPACITY)
+    }
+
+    /// Create a cache holding at most `capacity` entries (minimum 1).
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: LruCache::new(CACHE_LABEL, capacity),
+        }
+    }
+
+    /// Look up metadata for a block hash, marking it as most recently used.
+    pub fn get(&self, block_hash: &str) -> Option<Arc<CachedMetadata>> {
+        self.inner.get(&normalize_key(block_hash))
+    }
+
+    /// Insert metadata for a block hash, evicting the least recently used entry if full.
+    pub fn insert(&self, block_hash: &str, metadata: Arc<CachedMetadata>) {
+        self.inner.insert(normalize_key(block_hash), metadata);
+    }
+
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl Default for RuntimeMetadataCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn normalize_key(block_hash: &str) -> String {
+    block_hash.to_ascii_lowercase()
+}
+
+/// Fetch the runtime metadata at a block hash, consulting the cache first.
+///
+/// `rpc_client` must belong to the chain `block_hash` is from; hashes of different
+/// chains never collide, so one cache can serve the parachain and its relay chain.
+pub async fn fetch_runtime_metadata(
+    rpc_client: &RpcClient,
+    cache: &RuntimeMetadataCache,
+    block_hash: &str,
+) -> Result<Arc<CachedMetadata>, MetadataFetchError> {
+    if let Some(metadata) = cache.get(block_hash) {
+        return Ok(metadata);
+    }
+
+    let metadata_hex: String = rpc_client
+        .request("state_getMetadata", rpc_params![block_hash])
+        .await
+        .map_err(MetadataFetchError::RpcFailed)?;
+
+    let metadata = Arc::new(CachedMetadata::new(metadata_hex));
+    cache.insert(block_hash, metadata.clone());
+
+    Ok(metadata)
+}
+
+/// Decode the hex string returned by `state_getMetadata` into its SCALE bytes.
+pub fn decode_metadata_hex(metadata_hex: &str) -> Result<Vec<u8>, MetadataFetchError> {
+    let hex_str = metadata_hex.strip_prefix("0x").unwrap_or(metadata_hex);
+    hex::decode(hex_str).map_err(MetadataFetchError::HexDecodeFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_metadata::{RuntimeMetadata, v14::RuntimeMetadataV14};
+    use scale_info::{MetaType, Registry};
+
+    fn dummy_metadata() -> Arc<CachedMetadata> {
+        let mut registry = Registry::new();
+        let ty = registry.register_type(&MetaType::new::<()>());
+        let extrinsic = frame_metadata::v14::ExtrinsicMetadata {
+            ty,
+            version: 4,
+            signed_extensions: vec![],
+        };
+        let v14 = RuntimeMetadataV14 {
+            types: registry.into(),
+            pallets: vec![],
+            extrinsic,
+            ty,
+        };
+        let prefixed =
+            RuntimeMetadataPrefixed(frame_metadata::META_RESERVED, RuntimeMetadata::V14(v14));
+        let bytes = parity_scale_codec::Encode::encode(&prefixed);
+        Arc::new(CachedMetadata::new(format!("0x{}", hex::encode(bytes))))
+    }
+
+    #[test]
+    fn test_get_returns_inserted_entry() {
+        let cache = RuntimeMetadataCache::new();
+        assert!(cache.get("0xabc").is_none());
+
+        cache.insert("0xabc", dummy_metadata());
+        assert!(cache.get("0xabc").is_some());
+        assert!(cache.get("0xABC").is_some());
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = RuntimeMetadataCache::with_capacity(2);
+        cache.insert("0x01", dummy_metadata());
+        cache.insert("0x02", dummy_metadata());
+
+        // Touch 0x01 so 0x02 becomes the LRU entry
+        assert!(cache.get("0x01").is_some());
+        cache.insert("0x03", dummy_metadata());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("0x01").is_some());
+        assert!(cache.get("0x02").is_none());
+        assert!(cache.get("0x03").is_some());
+    }
+
+    #[test]
+    fn test_reinsert_does_not_grow_cache() {
+        let cache = RuntimeMetadataCache::with_capacity(2);
+        cache.insert("0x01", dummy_metadata());
+        cache.insert("0x01", dummy_metadata());
+
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_cached_metadata_decodes_lazily() {
+        let metadata = dummy_metadata();
+        assert!(metadata.decoded.get().is_none());
+        assert!(metadata.hex().starts_with("0x6d657461"));
+
+        let decoded = metadata.decoded().unwrap();
+        assert_eq!(decoded.0, frame_metadata::META_RESERVED);
+        assert!(matches!(decoded.1, RuntimeMetadata::V14(_)));
+        assert!(metadata.decoded.get().is_some());
+    }
+
+    #[test]
+    fn test_cached_metadata_reports_decode_failure() {
+        let metadata = CachedMetadata::new("0x6d65".to_string());
+        assert!(matches!(
+            metadata.decoded(),
+            Err(MetadataFetchError::ScaleDecodeFailed(_))
+        ));
+
+        let metadata = CachedMetadata::new("0xzz".to_string());
+        assert!(matches!(
+            metadata.decoded(),
+            Err(MetadataFetchError::HexDecodeFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_metadata_hex_rejects_invalid_hex() {
+        assert!(matches!(
+            decode_metadata_hex("0xzz"),
+            Err(MetadataFetchError::HexDecodeFailed(_))
+        ));
+    }
+}
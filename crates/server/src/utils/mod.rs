@@ -2,35 +2,61 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 pub mod block;
+pub mod call_args;
 pub mod capabilities;
 pub mod concurrency;
+pub mod csv;
 pub mod extrinsic;
 pub mod fee;
+pub mod finalized_heads;
 pub mod format;
 pub mod hash;
+pub mod http_cache;
+pub mod idempotency;
+pub mod lru;
+pub mod metadata;
+pub mod module_error;
 pub mod rc_block;
+pub mod storage_scan;
 
 pub use block::{
     AtBlockError, BlockId, BlockIdParseError, BlockResolveError, ResolveClientAtBlockError,
     ResolvedBlock, fetch_block_timestamp, get_block_number_from_hash_with_rpc, resolve_block,
     resolve_block_with_rpc, resolve_client_at_block,
 };
+pub use call_args::{CallArgsError, ResolvedCall, resolve_call};
 pub use concurrency::{DEFAULT_CONCURRENCY, run_with_concurrency, run_with_concurrency_collect};
 pub use extrinsic::{
-    EraInfo, decode_era_from_bytes, extract_era_from_extrinsic_bytes, parse_era_info,
+    EraInfo, decode_era_from_bytes, extract_era_from_extrinsic_bytes,
+    nonce_and_tip_from_extensions, parse_era_info,
 };
 pub use fee::{
     FeeCalcError, FeeDetails, FeeServiceError, QueryFeeDetailsCache, RuntimeDispatchInfoRaw,
     WeightRaw, calc_partial_fee, calc_partial_fee_raw, calculate_accurate_fee,
     decode_runtime_dispatch_info, dispatch_class_from_u8, extract_estimated_weight,
-    parse_fee_details,
+    parse_fee_details, post_dispatch_fee_details,
 };
-pub use format::{decode_address_to_ss58, hex_with_prefix, lowercase_first_char};
-pub use hash::{HashError, compute_block_hash_from_header_json, parse_block_number_from_json};
+pub use finalized_heads::{
+    FinalizedHeads, FinalizedHeadsError, FinalizedHeadsHub, HeadSubscription,
+};
+pub use format::{
+    InvalidSs58Format, decode_address_to_ss58, hex_with_prefix, lowercase_first_char,
+    response_ss58_prefix,
+};
+pub use hash::{
+    HashError, blake2_256_hex, compute_block_hash_from_header_json, parse_block_number_from_json,
+};
+pub use idempotency::{CachedResponse, IdempotencyCache, IdempotencyLookup};
+pub use metadata::{
+    CachedMetadata, MetadataFetchError, RuntimeMetadataCache, decode_metadata_hex,
+    fetch_runtime_metadata,
+};
+pub use module_error::{ModuleErrorInfo, lookup_module_error};
 pub use rc_block::{
     AhBlockInfo, RcBlockError, RcClientAtBlock, extract_block_number_from_header,
     find_ah_blocks_in_rc_block, find_ah_blocks_in_rc_block_at,
 };
+pub use storage_scan::{SCAN_PAGE_SIZE, StoragePage, fetch_storage_page, storage_map_prefix};
 
 /// Check if an RPC error indicates the connection was lost and reconnection is in progress.
 ///
@@ -47,6 +73,18 @@ pub fn is_backend_disconnected_error(err: &subxt::error::BackendError) -> bool {
     err.is_disconnected_will_reconnect()
 }
 
+/// Check if a StorageError was caused by the connection being lost while reading storage.
+pub fn is_storage_disconnected_error(err: &subxt::error::StorageError) -> bool {
+    use subxt::error::StorageError;
+
+    match err {
+        StorageError::CannotFetchValue(reason)
+        | StorageError::CannotIterateValues(reason)
+        | StorageError::StreamFailure(reason) => is_backend_disconnected_error(reason),
+        _ => false,
+    }
+}
+
 /// Check if an OnlineClientAtBlockError contains a disconnection error.
 ///
 /// The OnlineClientAtBlockError may wrap a BackendError (e.g., in CannotGetBlockHash)
@@ -77,7 +115,7 @@ pub fn is_online_client_at_block_disconnected(
 /// Check if an RPC error is a request timeout.
 ///
 /// Timeout errors occur when an RPC request takes longer than the configured
-/// request_timeout (default: 30s). This typically happens when the node is
+/// `SAS_SUBSTRATE_RPC_TIMEOUT_MS` (default: 30s). This typically happens when the node is
 /// unresponsive or the connection is degraded.
 pub fn is_timeout_error(err: &subxt_rpcs::Error) -> bool {
     match err {
@@ -89,6 +127,65 @@ pub fn is_timeout_error(err: &subxt_rpcs::Error) -> bool {
     }
 }
 
+/// Check if an RPC error means the method is not exposed by the node.
+///
+/// Nodes answer unknown methods with the JSON-RPC "method not found" code (-32601).
+/// Unsafe methods (e.g. `system_peers`) are rejected with the same code when the node
+/// runs with `--rpc-methods safe`, which is the default for externally exposed RPC.
+pub fn is_method_unavailable_error(err: &subxt_rpcs::Error) -> bool {
+    match err {
+        subxt_rpcs::Error::User(user_err) => {
+            user_err.code == subxt_rpcs::UserError::method_not_found().code
+                || user_err.message.contains("unsafe")
+        }
+        _ => false,
+    }
+}
+
+/// Coarse classification of RPC errors, shared by HTTP status mapping and metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcErrorKind {
+    Timeout,
+    Disconnect,
+    Other,
+}
+
+impl RpcErrorKind {
+    pub fn of(err: &subxt_rpcs::Error) -> Self {
+        if is_timeout_error(err) {
+            RpcErrorKind::Timeout
+        } else if is_disconnected_error(err) {
+            RpcErrorKind::Disconnect
+        } else {
+            RpcErrorKind::Other
+        }
+    }
+
+    /// Label used for the `kind` of the RPC error counter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RpcErrorKind::Timeout => "timeout",
+            RpcErrorKind::Disconnect => "disconnect",
+            RpcErrorKind::Other => "other",
+        }
+    }
+}
+
+/// Record the outcome of an RPC call in the RPC request and error counters.
+///
+/// All node connections are instrumented through [`crate::metrics::InstrumentedRpcClient`],
+/// so handlers only need this for RPC traffic that bypasses those clients.
+pub fn record_rpc_outcome<T>(result: &Result<T, subxt_rpcs::Error>) {
+    use crate::metrics::registry::{RPC_ERRORS, RPC_REQUESTS};
+
+    RPC_REQUESTS.inc();
+    if let Err(err) = result {
+        RPC_ERRORS
+            .with_label_values(&[RpcErrorKind::of(err).as_str()])
+            .inc();
+    }
+}
+
 /// Convert an RPC error to an appropriate HTTP status code and message.
 ///
 /// This centralizes the logic for handling different RPC error types:
@@ -98,18 +195,16 @@ pub fn is_timeout_error(err: &subxt_rpcs::Error) -> bool {
 pub fn rpc_error_to_status(err: &subxt_rpcs::Error) -> (axum::http::StatusCode, String) {
     use axum::http::StatusCode;
 
-    if is_timeout_error(err) {
-        (
+    match RpcErrorKind::of(err) {
+        RpcErrorKind::Timeout => (
             StatusCode::GATEWAY_TIMEOUT,
             "Request timed out while waiting for node response".to_string(),
-        )
-    } else if is_disconnected_error(err) {
-        (
+        ),
+        RpcErrorKind::Disconnect => (
             StatusCode::SERVICE_UNAVAILABLE,
             format!("Service temporarily unavailable: {}", err),
-        )
-    } else {
-        (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+        ),
+        RpcErrorKind::Other => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
     }
 }
 
@@ -172,6 +267,22 @@ mod rpc_error_tests {
         assert!(!is_timeout_error(&err));
     }
 
+    #[test]
+    fn test_is_method_unavailable_error() {
+        let not_found = subxt_rpcs::Error::User(subxt_rpcs::UserError::method_not_found());
+        assert!(is_method_unavailable_error(&not_found));
+
+        let unsafe_call = subxt_rpcs::Error::User(subxt_rpcs::UserError {
+            code: -32000,
+            message: "RPC call is unsafe to be called externally".to_string(),
+            data: None,
+        });
+        assert!(is_method_unavailable_error(&unsafe_call));
+
+        assert!(!is_method_unavailable_error(&make_generic_error()));
+        assert!(!is_method_unavailable_error(&make_disconnected_error()));
+    }
+
     #[test]
     fn test_rpc_error_to_status_timeout() {
         let err = make_timeout_error();
@@ -0,0 +1,86 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Resolve pallet errors from the metadata by index.
+//!
+//! `DispatchError::Module { index, error }` only carries the pallet's error index
+//! and the error variant index (the first byte of `error`). This maps them back
+//! to the pallet name, error name and docs, using subxt's normalized metadata so
+//! it works the same for every metadata version.
+
+use serde::Serialize;
+use subxt::Metadata;
+
+/// A pallet error resolved from the metadata.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModuleErrorInfo {
+    /// Pallet name as declared in the runtime (e.g. `Balances`)
+    pub pallet: String,
+    /// Error variant name (e.g. `InsufficientBalance`)
+    pub name: String,
+    /// Index of the error variant within the pallet's error enum
+    #[serde(skip)]
+    pub index: u8,
+    pub docs: Vec<String>,
+}
+
+/// Look up the error at `error_index` of the pallet with error index `pallet_index`.
+pub fn lookup_module_error(
+    metadata: &Metadata,
+    pallet_index: u8,
+    error_index: u8,
+) -> Option<ModuleErrorInfo> {
+    let pallet = metadata
+        .pallets()
+        .find(|pallet| pallet.error_index() == pallet_index)?;
+    let variant = pallet.error_variant_by_index(error_index)?;
+
+    Some(ModuleErrorInfo {
+        pallet: pallet.name().to_string(),
+        name: variant.name.clone(),
+        index: variant.index,
+        docs: variant.docs.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::ASSET_HUB_METADATA;
+    use parity_scale_codec::Decode;
+
+    fn asset_hub_metadata() -> Metadata {
+        Metadata::decode(&mut &ASSET_HUB_METADATA[..]).expect("fixture metadata decodes")
+    }
+
+    #[test]
+    fn test_lookup_module_error_resolves_names_and_docs() {
+        let metadata = asset_hub_metadata();
+        let balances = metadata.pallet_by_name("Balances").unwrap();
+        let expected = balances
+            .error_variants()
+            .unwrap()
+            .iter()
+            .find(|v| v.name == "InsufficientBalance")
+            .unwrap();
+
+        let info = lookup_module_error(&metadata, balances.error_index(), expected.index).unwrap();
+
+        assert_eq!(info.pallet, "Balances");
+        assert_eq!(info.name, "InsufficientBalance");
+        assert_eq!(info.index, expected.index);
+        assert_eq!(info.docs, expected.docs);
+    }
+
+    #[test]
+    fn test_lookup_module_error_unknown_indices() {
+        let metadata = asset_hub_metadata();
+        let balances = metadata.pallet_by_name("Balances").unwrap();
+
+        assert!(lookup_module_error(&metadata, balances.error_index(), 255).is_none());
+        let unused_index = (0..=u8::MAX)
+            .find(|i| metadata.pallets().all(|p| p.error_index() != *i))
+            .unwrap();
+        assert!(lookup_module_error(&metadata, unused_index, 0).is_none());
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2026 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Paged scans over the entries of a storage map.
+//!
+//! Some lookups have no storage index to go through (e.g. the proxy delegators of an
+//! account or the members of a nomination pool), so they page through the whole map with
+//! `state_getKeysPaged` and fetch each page's values with `state_queryStorageAt`.
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use subxt_rpcs::{RpcClient, rpc_params};
+
+/// Number of keys fetched per `state_getKeysPaged` call.
+pub const SCAN_PAGE_SIZE: u32 = 1000;
+
+/// Entry of a `state_queryStorageAt` result
+#[derive(Debug, Deserialize)]
+struct StorageChangeSet {
+    changes: Vec<(String, Option<String>)>,
+}
+
+/// One page of a storage map scan.
+#[derive(Debug, Default)]
+pub struct StoragePage {
+    /// `(key, value)` pairs in key order, hex-encoded. Keys without a value are left out.
+    pub entries: Vec<(String, String)>,
+    /// Key to continue the scan after, or `None` once the end of the map is reached.
+    pub next_start_key: Option<String>,
+}
+
+/// Hex-encoded storage prefix of a map, `twox128(pallet) ++ twox128(entry)`.
+pub fn storage_map_prefix(pallet: &str, entry: &str) -> String {
+    let mut prefix = sp_crypto_hashing::twox_128(pallet.as_bytes()).to_vec();
+    prefix.extend(sp_crypto_hashing::twox_128(entry.as_bytes()));
+    format!("0x{}", hex::encode(prefix))
+}
+
+/// Fetch the next [`SCAN_PAGE_SIZE`] entries under `prefix` after `start_key`.
+pub async fn fetch_storage_page(
+    rpc_client: &RpcClient,
+    prefix: &str,
+    start_key: Option<&str>,
+    block_hash: &str,
+) -> Result<StoragePage, subxt_rpcs::Error> {
+    let keys: Vec<String> = rpc_client
+        .request(
+            "state_getKeysPaged",
+            rpc_params![prefix, SCAN_PAGE_SIZE, start_key, block_hash],
+        )
+        .await?;
+    if keys.is_empty() {
+        return Ok(StoragePage::default());
+    }
+
+    let change_sets: Vec<StorageChangeSet> = rpc_client
+        .request("state_queryStorageAt", rpc_params![&keys, block_hash])
+        .await?;
+
+    // `state_queryStorageAt` doesn't guarantee key order, so go by the requested keys
+    let mut values: HashMap<String, String> = change_sets
+        .into_iter()
+        .flat_map(|set| set.changes)
+        .filter_map(|(key, value)| Some((key, value?)))
+        .collect();
+
+    let next_start_key = if keys.len() == SCAN_PAGE_SIZE as usize {
+        keys.last().cloned()
+    } else {
+        None
+    };
+    let entries = keys
+        .into_iter()
+        .filter_map(|key| {
+            let value = values.remove(&key)?;
+            Some((key, value))
+        })
+        .collect();
+
+    Ok(StoragePage {
+        entries,
+        next_start_key,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use subxt_rpcs::client::MockRpcClient;
+    use subxt_rpcs::client::mock_rpc_client::Json as MockJson;
+
+    #[tokio::test]
+    async fn test_fetch_storage_page_keeps_key_order() {
+        let mock_client = MockRpcClient::builder()
+            .method_handler("state_getKeysPaged", |_params| async {
+                MockJson(vec!["0x01", "0x02", "0x03"])
+            })
+            .method_handler("state_queryStorageAt", |_params| async {
+                MockJson(json!([{
+                    "block": "0x00",
+                    "changes": [["0x03", "0x33"], ["0x02", null], ["0x01", "0x11"]]
+                }]))
+            })
+            .build();
+        let rpc_client = RpcClient::new(mock_client);
+
+        let page = fetch_storage_page(&rpc_client, "0x", None, "0x00")
+            .await
+            .unwrap();
+        assert_eq!(
+            page.entries,
+            vec![
+                ("0x01".to_string(), "0x11".to_string()),
+                ("0x03".to_string(), "0x33".to_string()),
+            ]
+        );
+        assert_eq!(page.next_start_key, None);
+    }
+}
@@ -90,3 +90,17 @@ fn test_histogram_metrics() {
     assert!(metrics_text.contains("test_request_duration_seconds"));
     assert!(metrics_text.contains("bucket"));
 }
+
+#[test]
+fn test_cache_stats_metrics() {
+    use polkadot_rest_api::utils::RuntimeMetadataCache;
+
+    metrics::init("test");
+    metrics::enable_cache_stats();
+
+    let cache = RuntimeMetadataCache::with_capacity(1);
+    assert!(cache.get("0x01").is_none());
+
+    let metrics_text = metrics::gather_metrics().unwrap();
+    assert!(metrics_text.contains("test_cache_misses{cache=\"runtime_metadata\"}"));
+}